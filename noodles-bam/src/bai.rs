@@ -26,10 +26,15 @@
 #[cfg(feature = "async")]
 pub mod r#async;
 
+mod convert;
 mod reader;
 mod writer;
 
-pub use self::{reader::Reader, writer::Writer};
+pub use self::{
+    convert::{to_csi_index, try_from_csi_index},
+    reader::Reader,
+    writer::Writer,
+};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
@@ -42,6 +47,7 @@ use std::{
 
 use noodles_csi::Index;
 
+const MIN_SHIFT: u8 = 14;
 const DEPTH: u8 = 5;
 
 static MAGIC_NUMBER: &[u8] = b"BAI\x01";
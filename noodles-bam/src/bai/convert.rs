@@ -0,0 +1,346 @@
+use std::{collections::HashMap, io};
+
+use noodles_bgzf as bgzf;
+use noodles_csi::{
+    index::{reference_sequence::Bin, ReferenceSequence},
+    Index,
+};
+
+use super::{DEPTH, MIN_SHIFT};
+
+// _Sequence Alignment/Map Format Specification_ (2022-08-22) § 5.1.2 "Combining with linear
+// index": "...each tiling 16384bp window..."
+const LINEAR_INDEX_WINDOW_SHIFT: u8 = 14;
+
+/// Converts a coordinate-sorted index (CSI) to an index that can be written as a BAM index (BAI).
+///
+/// A BAM index uses a fixed binning scheme, which is equivalent to a CSI with a min shift of 14
+/// and a depth of 5. Rather than storing a linear offset for each bin, it stores a linear index
+/// of 16 kbp windows. If the given index does not have a linear index, one is built from the
+/// chunks of the bins that overlap each window.
+///
+/// The reference sequences, including their bins and metadata, and the number of unplaced,
+/// unmapped records are kept as is.
+///
+/// This returns an error if the index uses a different binning scheme or has a tabix header,
+/// neither of which can be represented in a BAM index.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bam::bai;
+/// use noodles_csi as csi;
+///
+/// let index = csi::Index::default();
+/// let bai_index = bai::try_from_csi_index(&index)?;
+/// assert_eq!(bai_index, index);
+///
+/// let index = csi::Index::builder().set_depth(6).build();
+/// assert!(bai::try_from_csi_index(&index).is_err());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn try_from_csi_index(index: &Index) -> io::Result<Index> {
+    if index.min_shift() != MIN_SHIFT || index.depth() != DEPTH {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "invalid min shift and depth: expected ({MIN_SHIFT}, {DEPTH}), got ({}, {})",
+                index.min_shift(),
+                index.depth()
+            ),
+        ));
+    }
+
+    if index.header().is_some() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "a BAM index cannot have a tabix header",
+        ));
+    }
+
+    let reference_sequences = index
+        .reference_sequences()
+        .iter()
+        .map(|reference_sequence| {
+            if reference_sequence.linear_index().is_empty() {
+                let linear_index = build_linear_index(reference_sequence.bins());
+
+                ReferenceSequence::new(
+                    reference_sequence.bins().clone(),
+                    linear_index,
+                    reference_sequence.metadata().cloned(),
+                )
+            } else {
+                reference_sequence.clone()
+            }
+        })
+        .collect();
+
+    let mut builder = Index::builder()
+        .set_min_shift(index.min_shift())
+        .set_depth(index.depth())
+        .set_reference_sequences(reference_sequences);
+
+    if let Some(n) = index.unplaced_unmapped_record_count() {
+        builder = builder.set_unplaced_unmapped_record_count(n);
+    }
+
+    Ok(builder.build())
+}
+
+/// Converts a BAM index (BAI) to an index that can be written as a coordinate-sorted index (CSI).
+///
+/// A BAM index does not store the linear offset of each bin. This sets the linear offset of each
+/// bin to the linear index entry of the window the bin starts in, if available.
+///
+/// The reference sequences, including their bins, linear indices, and metadata, and the number
+/// of unplaced, unmapped records are kept as is.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam::bai;
+/// use noodles_csi as csi;
+///
+/// let index = csi::Index::default();
+/// let csi_index = bai::to_csi_index(&index);
+/// assert_eq!(csi_index, index);
+/// ```
+pub fn to_csi_index(index: &Index) -> Index {
+    let min_shift = index.min_shift();
+    let depth = index.depth();
+
+    let reference_sequences = index
+        .reference_sequences()
+        .iter()
+        .map(|reference_sequence| {
+            let linear_index = reference_sequence.linear_index();
+
+            let bins = reference_sequence
+                .bins()
+                .iter()
+                .map(|(&id, bin)| {
+                    let i = bin_start(min_shift, depth, id) >> LINEAR_INDEX_WINDOW_SHIFT;
+                    let loffset = linear_index.get(i).copied().unwrap_or(bin.loffset());
+                    (id, Bin::new(loffset, bin.chunks().to_vec()))
+                })
+                .collect();
+
+            ReferenceSequence::new(
+                bins,
+                linear_index.to_vec(),
+                reference_sequence.metadata().cloned(),
+            )
+        })
+        .collect();
+
+    let mut builder = Index::builder()
+        .set_min_shift(min_shift)
+        .set_depth(depth)
+        .set_reference_sequences(reference_sequences);
+
+    if let Some(header) = index.header() {
+        builder = builder.set_header(header.clone());
+    }
+
+    if let Some(n) = index.unplaced_unmapped_record_count() {
+        builder = builder.set_unplaced_unmapped_record_count(n);
+    }
+
+    builder.build()
+}
+
+// Each linear index entry is the smallest chunk start of the bins that overlap the window.
+fn build_linear_index(bins: &HashMap<usize, Bin>) -> Vec<bgzf::VirtualPosition> {
+    let len = bins
+        .keys()
+        .map(|&id| (bin_start(MIN_SHIFT, DEPTH, id) >> LINEAR_INDEX_WINDOW_SHIFT) + 1)
+        .max()
+        .unwrap_or_default();
+
+    (0..len)
+        .map(|i| {
+            let start = i << LINEAR_INDEX_WINDOW_SHIFT;
+
+            (0..=DEPTH)
+                .filter_map(|level| bins.get(&bin_id(MIN_SHIFT, DEPTH, level, start)))
+                .flat_map(|bin| bin.chunks().iter().map(|chunk| chunk.start()))
+                .min()
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+// The ID of the first bin in the given level.
+fn level_start_id(level: u8) -> usize {
+    ((1 << (3 * usize::from(level))) - 1) / 7
+}
+
+// The ID of the bin in the given level that contains the 0-based position.
+fn bin_id(min_shift: u8, depth: u8, level: u8, position: usize) -> usize {
+    let shift = usize::from(min_shift) + 3 * usize::from(depth - level);
+    level_start_id(level) + (position >> shift)
+}
+
+// The 0-based start position of the given bin.
+fn bin_start(min_shift: u8, depth: u8, id: usize) -> usize {
+    let level = (1..=depth)
+        .take_while(|&level| level_start_id(level) <= id)
+        .last()
+        .unwrap_or(0);
+
+    let shift = usize::from(min_shift) + 3 * usize::from(depth - level);
+    (id - level_start_id(level)) << shift
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_csi::index::reference_sequence::bin::Chunk;
+
+    use super::*;
+
+    fn chunk(start: u64, end: u64) -> Chunk {
+        Chunk::new(
+            bgzf::VirtualPosition::from(start),
+            bgzf::VirtualPosition::from(end),
+        )
+    }
+
+    #[test]
+    fn test_try_from_csi_index() -> io::Result<()> {
+        let bins = [
+            (
+                0,
+                Bin::new(bgzf::VirtualPosition::from(5), vec![chunk(13, 21)]),
+            ),
+            (
+                4681,
+                Bin::new(bgzf::VirtualPosition::from(5), vec![chunk(5, 8)]),
+            ),
+            (
+                4683,
+                Bin::new(bgzf::VirtualPosition::from(34), vec![chunk(34, 55)]),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let index = Index::builder()
+            .set_reference_sequences(vec![ReferenceSequence::new(bins, Vec::new(), None)])
+            .set_unplaced_unmapped_record_count(3)
+            .build();
+
+        let actual = try_from_csi_index(&index)?;
+
+        assert_eq!(actual.unplaced_unmapped_record_count(), Some(3));
+
+        let reference_sequence = &actual.reference_sequences()[0];
+        assert_eq!(
+            reference_sequence.bins(),
+            index.reference_sequences()[0].bins()
+        );
+        assert_eq!(
+            reference_sequence.linear_index(),
+            [
+                bgzf::VirtualPosition::from(5),
+                bgzf::VirtualPosition::from(13),
+                bgzf::VirtualPosition::from(13),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_csi_index_with_invalid_index() {
+        let index = Index::builder().set_min_shift(12).build();
+        assert!(matches!(
+            try_from_csi_index(&index),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let index = Index::builder().set_depth(6).build();
+        assert!(matches!(
+            try_from_csi_index(&index),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let index = Index::builder()
+            .set_header(noodles_csi::index::Header::default())
+            .build();
+        assert!(matches!(
+            try_from_csi_index(&index),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+
+    #[test]
+    fn test_to_csi_index() {
+        let bins = [
+            (
+                0,
+                Bin::new(bgzf::VirtualPosition::default(), vec![chunk(13, 21)]),
+            ),
+            (
+                4681,
+                Bin::new(bgzf::VirtualPosition::default(), vec![chunk(5, 8)]),
+            ),
+            (
+                4683,
+                Bin::new(bgzf::VirtualPosition::default(), vec![chunk(34, 55)]),
+            ),
+            (
+                4685,
+                Bin::new(bgzf::VirtualPosition::default(), vec![chunk(89, 144)]),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let linear_index = vec![
+            bgzf::VirtualPosition::from(5),
+            bgzf::VirtualPosition::from(13),
+            bgzf::VirtualPosition::from(13),
+        ];
+
+        let index = Index::builder()
+            .set_reference_sequences(vec![ReferenceSequence::new(
+                bins,
+                linear_index.clone(),
+                None,
+            )])
+            .build();
+
+        let actual = to_csi_index(&index);
+
+        let reference_sequence = &actual.reference_sequences()[0];
+        assert_eq!(reference_sequence.linear_index(), linear_index);
+
+        let bins = reference_sequence.bins();
+        assert_eq!(bins[&0].loffset(), bgzf::VirtualPosition::from(5));
+        assert_eq!(bins[&4681].loffset(), bgzf::VirtualPosition::from(5));
+        assert_eq!(bins[&4683].loffset(), bgzf::VirtualPosition::from(13));
+        assert_eq!(bins[&4685].loffset(), bgzf::VirtualPosition::default());
+        assert_eq!(bins[&4683].chunks(), [chunk(34, 55)]);
+    }
+
+    #[test]
+    fn test_bin_start() {
+        assert_eq!(bin_start(14, 5, 0), 0);
+        assert_eq!(bin_start(14, 5, 1), 0);
+        assert_eq!(bin_start(14, 5, 2), 1 << 26);
+        assert_eq!(bin_start(14, 5, 9), 0);
+        assert_eq!(bin_start(14, 5, 4681), 0);
+        assert_eq!(bin_start(14, 5, 4682), 1 << 14);
+        assert_eq!(bin_start(14, 5, 37448), 32767 << 14);
+    }
+
+    #[test]
+    fn test_bin_id() {
+        assert_eq!(bin_id(14, 5, 0, 0), 0);
+        assert_eq!(bin_id(14, 5, 1, 1 << 26), 2);
+        assert_eq!(bin_id(14, 5, 5, 0), 4681);
+        assert_eq!(bin_id(14, 5, 5, 1 << 14), 4682);
+    }
+}
@@ -0,0 +1,285 @@
+//! Indexed BAM writer.
+
+use std::io::{self, Write};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::{self as csi, index::reference_sequence::bin::Chunk};
+use noodles_sam::{self as sam, alignment::Record};
+
+use super::Writer;
+
+/// An indexed BAM writer.
+///
+/// This writes a coordinate-sorted BAM and builds its index at the same time, which removes the
+/// need to read the output again to index it.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use noodles_bam as bam;
+/// use noodles_core::Position;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::Record,
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(
+///         "sq0".parse()?,
+///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+///     )
+///     .build();
+///
+/// let mut buf = Vec::new();
+/// let mut writer = bam::IndexedWriter::new(&mut buf);
+/// writer.write_header(&header)?;
+///
+/// let record = Record::builder()
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::MIN)
+///     .set_cigar("4M".parse()?)
+///     .build();
+///
+/// writer.write_record(&header, &record)?;
+///
+/// let index = writer.finish()?;
+/// assert_eq!(index.reference_sequences().len(), 1);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: Writer<bgzf::Writer<W>>,
+    indexer: csi::index::Indexer,
+    reference_sequence_count: Option<usize>,
+    last_sort_key: Option<SortKey>,
+}
+
+// The coordinate sort key of a record. Unplaced records (`None`) sort after placed records.
+type SortKey = Option<(usize, Position)>;
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed BAM writer with a default compression level.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner: Writer::new(inner),
+            indexer: csi::index::Indexer::default(),
+            reference_sequence_count: None,
+            last_sort_key: None,
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &bgzf::Writer<W> {
+        self.inner.get_ref()
+    }
+
+    /// Writes a SAM header.
+    pub fn write_header(&mut self, header: &sam::Header) -> io::Result<()> {
+        self.reference_sequence_count = Some(header.reference_sequences().len());
+        self.inner.write_header(header)
+    }
+
+    /// Writes a record and adds it to the index.
+    ///
+    /// Records must be coordinate-sorted, i.e., ordered by reference sequence ID and alignment
+    /// start, with unplaced records last.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the record is out of order.
+    pub fn write_record(&mut self, header: &sam::Header, record: &Record) -> io::Result<()> {
+        let sort_key = sort_key(record);
+
+        if let Some(last_sort_key) = self.last_sort_key {
+            if !is_in_order(last_sort_key, sort_key) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "record is out of order: records must be coordinate-sorted to be indexed",
+                ));
+            }
+        }
+
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_record(header, record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        let alignment_context = sort_key.map(|(reference_sequence_id, start)| {
+            let end = record.alignment_end().unwrap_or(start).max(start);
+            let is_mapped = !record.flags().is_unmapped();
+            (reference_sequence_id, start, end, is_mapped)
+        });
+
+        self.indexer
+            .add_record(alignment_context, Chunk::new(start_position, end_position))?;
+
+        self.last_sort_key = Some(sort_key);
+
+        Ok(())
+    }
+
+    /// Finishes the output stream and returns the built index.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the header was not written. The header is needed to know the
+    /// number of reference sequences in the index.
+    pub fn finish(mut self) -> io::Result<csi::Index> {
+        let reference_sequence_count = self
+            .reference_sequence_count
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing header"))?;
+
+        self.inner.try_finish()?;
+
+        Ok(self.indexer.build(reference_sequence_count))
+    }
+}
+
+fn sort_key(record: &Record) -> SortKey {
+    record.reference_sequence_id().zip(record.alignment_start())
+}
+
+fn is_in_order(a: SortKey, b: SortKey) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a <= b,
+        (Some(_), None) | (None, None) => true,
+        (None, Some(_)) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use sam::header::record::value::{map::ReferenceSequence, Map};
+
+    use super::*;
+
+    fn build_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        Ok(sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .add_reference_sequence(
+                "sq1".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(21)?),
+            )
+            .build())
+    }
+
+    fn build_record(
+        reference_sequence_id: usize,
+        alignment_start: usize,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        Ok(Record::builder()
+            .set_reference_sequence_id(reference_sequence_id)
+            .set_alignment_start(Position::try_from(alignment_start)?)
+            .set_cigar("4M".parse()?)
+            .build())
+    }
+
+    #[test]
+    fn test_write_record() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::Reader;
+
+        let header = build_header()?;
+
+        let records = [
+            build_record(0, 1)?,
+            build_record(0, 8)?,
+            build_record(1, 3)?,
+            Record::builder()
+                .set_flags(sam::record::Flags::UNMAPPED)
+                .build(),
+        ];
+
+        let mut buf = Vec::new();
+        let mut writer = IndexedWriter::new(&mut buf);
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_record(&header, record)?;
+        }
+
+        let index = writer.finish()?;
+
+        assert_eq!(index.reference_sequences().len(), 2);
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(1));
+
+        let mut reader = Reader::new(io::Cursor::new(buf));
+        let header = reader.read_header()?;
+
+        let region = "sq0:5-13".parse()?;
+        let actual: Vec<_> = reader
+            .query(&header, &index, &region)?
+            .collect::<io::Result<_>>()?;
+        assert_eq!(actual, [records[1].clone()]);
+
+        let region = "sq1".parse()?;
+        let actual: Vec<_> = reader
+            .query(&header, &index, &region)?
+            .collect::<io::Result<_>>()?;
+        assert_eq!(actual, [records[2].clone()]);
+
+        let actual: Vec<_> = reader
+            .query_unmapped(&header, &index)?
+            .collect::<io::Result<_>>()?;
+        assert_eq!(actual, [records[3].clone()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_without_header() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_record(&header, &build_record(0, 8)?)?;
+
+        assert!(matches!(
+            writer.finish(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_record(&header, &build_record(0, 8)?)?;
+
+        assert!(matches!(
+            writer.write_record(&header, &build_record(0, 1)?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        writer.write_record(&header, &build_record(1, 1)?)?;
+
+        assert!(matches!(
+            writer.write_record(&header, &build_record(0, 13)?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        writer.write_record(&header, &Record::default())?;
+
+        assert!(matches!(
+            writer.write_record(&header, &build_record(1, 8)?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
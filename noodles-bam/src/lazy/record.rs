@@ -93,6 +93,81 @@ impl Record {
         sam::record::Flags::from(n)
     }
 
+    /// Returns whether the record is segmented (paired).
+    ///
+    /// This is a shortcut for `record.flags().is_segmented()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let record = bam::lazy::Record::default();
+    /// assert!(!record.is_paired());
+    /// ```
+    pub fn is_paired(&self) -> bool {
+        self.flags().is_segmented()
+    }
+
+    /// Returns whether the record is a secondary alignment.
+    ///
+    /// This is a shortcut for `record.flags().is_secondary()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let record = bam::lazy::Record::default();
+    /// assert!(!record.is_secondary());
+    /// ```
+    pub fn is_secondary(&self) -> bool {
+        self.flags().is_secondary()
+    }
+
+    /// Returns whether the record is a supplementary alignment.
+    ///
+    /// This is a shortcut for `record.flags().is_supplementary()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let record = bam::lazy::Record::default();
+    /// assert!(!record.is_supplementary());
+    /// ```
+    pub fn is_supplementary(&self) -> bool {
+        self.flags().is_supplementary()
+    }
+
+    /// Returns whether the record is marked as failing quality checks.
+    ///
+    /// This is a shortcut for `record.flags().is_qc_fail()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let record = bam::lazy::Record::default();
+    /// assert!(!record.is_qc_fail());
+    /// ```
+    pub fn is_qc_fail(&self) -> bool {
+        self.flags().is_qc_fail()
+    }
+
+    /// Returns whether the record is marked as a PCR or optical duplicate.
+    ///
+    /// This is a shortcut for `record.flags().is_duplicate()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let record = bam::lazy::Record::default();
+    /// assert!(!record.is_duplicate());
+    /// ```
+    pub fn is_duplicate(&self) -> bool {
+        self.flags().is_duplicate()
+    }
+
     /// Returns the mate reference sequence ID.
     ///
     /// # Examples
@@ -173,6 +248,58 @@ impl Record {
         Cigar::new(src)
     }
 
+    /// Returns the CIGAR operations, resolving a long CIGAR stored in the data.
+    ///
+    /// A record with more than 65535 CIGAR operations stores a placeholder CIGAR of `kSmN`, where
+    /// `k` is the read length, and the real CIGAR in the `CG` data field. When the placeholder is
+    /// detected and a `CG` field is present, this returns the operations from the data field.
+    /// Otherwise, this is the same as [`Self::cigar`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let record = bam::lazy::Record::default();
+    /// assert!(record.resolved_cigar()?.is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn resolved_cigar(&self) -> io::Result<Cigar<'_>> {
+        use self::data::field::value::{Array, Value};
+        use sam::record::{cigar::op::Kind, data::field::tag};
+
+        let cigar = self.cigar();
+
+        if cigar.len() != 2 {
+            return Ok(cigar);
+        }
+
+        let is_placeholder = {
+            let mut ops = cigar.iter();
+
+            match (ops.next().transpose()?, ops.next().transpose()?) {
+                (Some(op_0), Some(op_1)) => {
+                    op_0.kind() == Kind::SoftClip
+                        && op_0.len() == self.sequence().len()
+                        && op_1.kind() == Kind::Skip
+                }
+                _ => false,
+            }
+        };
+
+        if !is_placeholder {
+            return Ok(cigar);
+        }
+
+        match self.data().get(&tag::CIGAR).transpose()? {
+            Some(Value::Array(Array::UInt32(buf))) => Ok(Cigar::new(buf)),
+            Some(_) => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid CG data field type",
+            )),
+            None => Ok(cigar),
+        }
+    }
+
     /// Returns the sequence.
     ///
     /// # Examples
@@ -217,6 +344,28 @@ impl Record {
         Data::new(src)
     }
 
+    /// Returns whether the record passes the given filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// use noodles_sam::record::{Filter, Flags};
+    ///
+    /// let record = bam::lazy::Record::default();
+    ///
+    /// assert!(record.matches(&Filter::default().forbid(Flags::SECONDARY))?);
+    /// assert!(!record.matches(&Filter::default().forbid(Flags::UNMAPPED))?);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn matches(&self, filter: &sam::record::Filter) -> io::Result<bool> {
+        Ok(filter.matches_fields(
+            self.flags(),
+            self.mapping_quality(),
+            self.reference_sequence_id()?,
+        ))
+    }
+
     pub(crate) fn index(&mut self) -> io::Result<()> {
         index(&self.buf[..], &mut self.bounds)
     }
@@ -451,6 +600,88 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_resolved_cigar() -> io::Result<()> {
+        let record = Record::try_from(DATA.to_vec())?;
+        assert_eq!(record.resolved_cigar()?, record.cigar());
+
+        let buf = [
+            0xff, 0xff, 0xff, 0xff, // ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // pos = -1
+            0x02, // l_read_name = 2
+            0xff, // mapq = 255
+            0x48, 0x12, // bin = 4680
+            0x02, 0x00, // n_cigar_op = 2
+            0x04, 0x00, // flag = 4
+            0x04, 0x00, 0x00, 0x00, // l_seq = 4
+            0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // next_pos = -1
+            0x00, 0x00, 0x00, 0x00, // tlen = 0
+            b'*', 0x00, // read_name = "*\x00"
+            0x44, 0x00, 0x00, 0x00, 0x83, 0x00, 0x00, 0x00, // cigar = 4S8N
+            0x12, 0x48, // sequence = ACGT
+            b'N', b'D', b'L', b'S', // quality scores
+            b'C', b'G', b'B', b'I', 0x01, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00,
+            0x00, // CG:B:I,4M
+        ];
+
+        let record = Record::try_from(buf.to_vec())?;
+        let cigar = record.resolved_cigar()?;
+        assert_eq!(cigar.as_ref(), [0x40, 0x00, 0x00, 0x00]);
+        assert_eq!(cigar.reference_len()?, 4);
+        assert_eq!(cigar.query_len()?, 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flag_predicates() {
+        let mut record = Record::default();
+
+        assert!(!record.is_paired());
+        assert!(!record.is_secondary());
+        assert!(!record.is_supplementary());
+        assert!(!record.is_qc_fail());
+        assert!(!record.is_duplicate());
+
+        // flag = 0x0f01 (SEGMENTED | SECONDARY | QC_FAIL | DUPLICATE | SUPPLEMENTARY)
+        record.buf[bounds::FLAGS_RANGE].copy_from_slice(&[0x01, 0x0f]);
+
+        assert!(record.is_paired());
+        assert!(record.is_secondary());
+        assert!(record.is_supplementary());
+        assert!(record.is_qc_fail());
+        assert!(record.is_duplicate());
+    }
+
+    #[test]
+    fn test_matches() -> io::Result<()> {
+        use sam::record::{Filter, Flags};
+
+        let mut record = Record::default();
+
+        // ref_id = 1
+        record.buf[bounds::REFERENCE_SEQUENCE_ID_RANGE].copy_from_slice(&[0x01, 0x00, 0x00, 0x00]);
+        // mapq = 30
+        record.buf[bounds::MAPPING_QUALITY_INDEX] = 0x1e;
+        // flag = 0x0001 (SEGMENTED)
+        record.buf[bounds::FLAGS_RANGE].copy_from_slice(&[0x01, 0x00]);
+
+        let filter = Filter::default()
+            .require(Flags::SEGMENTED)
+            .forbid(Flags::SECONDARY | Flags::SUPPLEMENTARY)
+            .min_mapping_quality(20);
+        assert!(record.matches(&filter)?);
+
+        let filter = Filter::default().min_mapping_quality(40);
+        assert!(!record.matches(&filter)?);
+
+        let filter = Filter::default().forbid(Flags::SEGMENTED);
+        assert!(!record.matches(&filter)?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_try_from_record_for_sam_alignment_record() -> io::Result<()> {
         let lazy_record = Record::default();
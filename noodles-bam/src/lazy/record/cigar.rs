@@ -37,6 +37,40 @@ impl<'a> Cigar<'a> {
             decode_op(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
         })
     }
+
+    /// Calculates the number of reference bases covered by the CIGAR operations.
+    ///
+    /// This sums the lengths of the operations that consume the reference sequence (`M`, `D`,
+    /// `N`, `=`, and `X`).
+    ///
+    /// Records with more than 65535 operations store a placeholder CIGAR (`kSmN`) and the real
+    /// CIGAR in the `CG` data field. Use [`super::Record::resolved_cigar`] to calculate the
+    /// length of the real CIGAR.
+    pub fn reference_len(&self) -> io::Result<usize> {
+        self.iter().try_fold(0, |len, result| {
+            let op = result?;
+            Ok(if op.kind().consumes_reference() {
+                len + op.len()
+            } else {
+                len
+            })
+        })
+    }
+
+    /// Calculates the number of query (read) bases covered by the CIGAR operations.
+    ///
+    /// This sums the lengths of the operations that consume the query sequence (`M`, `I`, `S`,
+    /// `=`, and `X`).
+    pub fn query_len(&self) -> io::Result<usize> {
+        self.iter().try_fold(0, |len, result| {
+            let op = result?;
+            Ok(if op.kind().consumes_read() {
+                len + op.len()
+            } else {
+                len
+            })
+        })
+    }
 }
 
 impl<'a> AsRef<[u8]> for Cigar<'a> {
@@ -87,4 +121,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_reference_len() -> io::Result<()> {
+        let cigar = Cigar::new(&[]);
+        assert_eq!(cigar.reference_len()?, 0);
+
+        // 2S4M1I3D
+        let src = &[
+            0x24, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x32, 0x00,
+            0x00, 0x00,
+        ];
+        let cigar = Cigar::new(src);
+        assert_eq!(cigar.reference_len()?, 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_len() -> io::Result<()> {
+        let cigar = Cigar::new(&[]);
+        assert_eq!(cigar.query_len()?, 0);
+
+        // 2S4M1I3D
+        let src = &[
+            0x24, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x32, 0x00,
+            0x00, 0x00,
+        ];
+        let cigar = Cigar::new(src);
+        assert_eq!(cigar.query_len()?, 7);
+
+        Ok(())
+    }
 }
@@ -23,7 +23,21 @@ impl<'a> Data<'a> {
     }
 
     /// Returns the value of the given tag.
-    pub fn get<K>(&self, tag: &K) -> Option<io::Result<Value<'_>>>
+    ///
+    /// This scans the fields in order and stops at the first field with the given tag. Values of
+    /// fields before it are skipped without being copied, including variable-length string, hex,
+    /// and array values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// use noodles_sam::record::data::field::tag;
+    ///
+    /// let record = bam::lazy::Record::default();
+    /// assert!(record.data().get(&tag::READ_GROUP).is_none());
+    /// ```
+    pub fn get<K>(&self, tag: &K) -> Option<io::Result<Value<'a>>>
     where
         K: Borrow<[u8; 2]>,
     {
@@ -41,8 +55,79 @@ impl<'a> Data<'a> {
         None
     }
 
+    /// Returns the value of the given tag as a SAM record data field value.
+    ///
+    /// Fields before the matching field are skipped without decoding their values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// use noodles_sam::record::data::field::tag;
+    ///
+    /// let record = bam::lazy::Record::default();
+    /// assert!(record.data().typed_get(&tag::ALIGNMENT_HIT_COUNT).is_none());
+    /// ```
+    pub fn typed_get<K>(&self, tag: &K) -> Option<io::Result<sam::record::data::field::Value>>
+    where
+        K: Borrow<[u8; 2]>,
+    {
+        let mut src = self.0;
+
+        while !src.is_empty() {
+            let mut field_src = src;
+
+            match decode_field(&mut src) {
+                Ok((t, _)) => {
+                    if &t == tag.borrow() {
+                        return Some(decode_typed_field(&mut field_src).map(|(_, value)| value));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+
+        None
+    }
+
+    /// Returns an iterator over all tag-value pairs as SAM record data fields.
+    ///
+    /// Unlike [`Self::iter`], each value is fully decoded to a SAM record data field value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let record = bam::lazy::Record::default();
+    /// assert!(record.data().typed_iter().next().is_none());
+    /// ```
+    pub fn typed_iter(
+        &self,
+    ) -> impl Iterator<
+        Item = io::Result<(
+            sam::record::data::field::Tag,
+            sam::record::data::field::Value,
+        )>,
+    > + '_ {
+        let mut src = self.0;
+        let mut is_done = false;
+
+        // The fields are read in sequence, so stop after an error, as the position of the next
+        // field is unknown.
+        iter::from_fn(move || {
+            if is_done || src.is_empty() {
+                return None;
+            }
+
+            let result = decode_typed_field(&mut src);
+            is_done = result.is_err();
+
+            Some(result)
+        })
+    }
+
     /// Returns an iterator over all tag-value pairs.
-    pub fn iter(&self) -> impl Iterator<Item = io::Result<(Tag, Value<'_>)>> + '_ {
+    pub fn iter(&self) -> impl Iterator<Item = io::Result<(Tag, Value<'a>)>> + '_ {
         let mut src = self.0;
 
         iter::from_fn(move || {
@@ -55,6 +140,16 @@ impl<'a> Data<'a> {
     }
 }
 
+fn decode_typed_field(
+    src: &mut &[u8],
+) -> io::Result<(
+    sam::record::data::field::Tag,
+    sam::record::data::field::Value,
+)> {
+    use crate::record::codec::decoder::data::field::get_field;
+    get_field(src).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
 impl<'a> AsRef<[u8]> for Data<'a> {
     fn as_ref(&self) -> &[u8] {
         self.0
@@ -91,9 +186,100 @@ mod tests {
 
         assert!(data.get(&tag::COMMENT).is_none());
 
+        let data = Data::new(&[
+            b'C', b'O', b'Z', b'n', b'd', b'l', b's', 0x00, // CO:Z:ndls
+            b'Z', b'A', b'B', b'S', 0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0d,
+            0x00, // ZA:B:S,8,13
+            b'R', b'G', b'Z', b'r', b'g', b'0', 0x00, // RG:Z:rg0
+            b'N', b'M', b'C', 0x02, // NM:C:2
+        ]);
+
+        assert_eq!(
+            data.get(&tag::READ_GROUP).transpose()?,
+            Some(Value::String(b"rg0"))
+        );
+        assert_eq!(
+            data.get(&tag::EDIT_DISTANCE).transpose()?,
+            Some(Value::UInt8(2))
+        );
+
+        // The array has 2 elements but only 1 is present.
+        let data = Data::new(&[b'Z', b'A', b'B', b'S', 0x02, 0x00, 0x00, 0x00, 0x08, 0x00]);
+
+        assert!(matches!(
+            data.get(&tag::EDIT_DISTANCE),
+            Some(Err(e)) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_typed_get() -> io::Result<()> {
+        use sam::record::data::field::{tag, Value};
+
+        let data = Data::new(&[
+            b'N', b'H', b'C', 0x01, // NH:C:1
+            b'C', b'O', b'Z', b'n', b'd', b'l', b's', 0x00, // CO:Z:ndls
+        ]);
+
+        assert_eq!(
+            data.typed_get(&tag::COMMENT).transpose()?,
+            Some(Value::String(String::from("ndls")))
+        );
+        assert_eq!(
+            data.typed_get(&tag::ALIGNMENT_HIT_COUNT).transpose()?,
+            Some(Value::UInt8(1))
+        );
+        assert!(data.typed_get(&tag::READ_GROUP).is_none());
+
+        let data = Data::new(&[b'N', b'H', b'?', 0x01]);
+        assert!(matches!(
+            data.typed_get(&tag::ALIGNMENT_HIT_COUNT),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_typed_iter() -> io::Result<()> {
+        use sam::record::data::field::{tag, Value};
+
+        let data = Data::new(&[]);
+        assert!(data.typed_iter().next().is_none());
+
+        let data = Data::new(&[
+            b'N', b'H', b'C', 0x01, // NH:C:1
+            b'C', b'O', b'Z', b'n', b'd', b'l', b's', 0x00, // CO:Z:ndls
+        ]);
+        let actual: Vec<_> = data.typed_iter().collect::<io::Result<_>>()?;
+        let expected = [
+            (tag::ALIGNMENT_HIT_COUNT, Value::UInt8(1)),
+            (tag::COMMENT, Value::String(String::from("ndls"))),
+        ];
+        assert_eq!(actual, expected);
+
         Ok(())
     }
 
+    #[test]
+    fn test_typed_iter_with_truncated_data() {
+        let data = Data::new(&[
+            b'N', b'H', b'C', 0x01, // NH:C:1
+            b'C', b'O', b'Z', b'n', b'd', // CO:Z:nd (missing NUL terminator)
+        ]);
+
+        let actual: Vec<_> = data.typed_iter().collect();
+
+        assert_eq!(actual.len(), 2);
+        assert!(actual[0].is_ok());
+        assert!(matches!(
+            &actual[1],
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
     #[test]
     fn test_iter() -> io::Result<()> {
         let data = Data::new(&[]);
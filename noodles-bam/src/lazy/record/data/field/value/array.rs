@@ -1,9 +1,6 @@
 mod subtype;
 
-use std::{
-    io::{self, BufRead},
-    mem,
-};
+use std::{io, mem};
 
 use byteorder::{LittleEndian, ReadBytesExt};
 use noodles_sam::record::data::field::value::array::Subtype;
@@ -37,49 +34,31 @@ pub(super) fn decode_array<'a>(src: &mut &'a [u8]) -> io::Result<Array<'a>> {
     })?;
 
     match subtype {
-        Subtype::Int8 => {
-            let buf = &src[..n];
-            src.consume(n);
-            Ok(Array::Int8(buf))
-        }
-        Subtype::UInt8 => {
-            let buf = &src[..n];
-            src.consume(n);
-            Ok(Array::UInt8(buf))
-        }
-        Subtype::Int16 => {
-            let len = n * mem::size_of::<i16>();
-            let buf = &src[..len];
-            src.consume(len);
-            Ok(Array::Int16(buf))
-        }
-        Subtype::UInt16 => {
-            let len = n * mem::size_of::<u16>();
-            let buf = &src[..len];
-            src.consume(len);
-            Ok(Array::UInt16(buf))
-        }
-        Subtype::Int32 => {
-            let len = n * mem::size_of::<i32>();
-            let buf = &src[..len];
-            src.consume(len);
-            Ok(Array::Int32(buf))
-        }
-        Subtype::UInt32 => {
-            let len = n * mem::size_of::<u32>();
-            let buf = &src[..len];
-            src.consume(len);
-            Ok(Array::UInt32(buf))
-        }
-        Subtype::Float => {
-            let len = n * mem::size_of::<f32>();
-            let buf = &src[..len];
-            src.consume(len);
-            Ok(Array::Float(buf))
-        }
+        Subtype::Int8 => split_to(src, n, mem::size_of::<i8>()).map(Array::Int8),
+        Subtype::UInt8 => split_to(src, n, mem::size_of::<u8>()).map(Array::UInt8),
+        Subtype::Int16 => split_to(src, n, mem::size_of::<i16>()).map(Array::Int16),
+        Subtype::UInt16 => split_to(src, n, mem::size_of::<u16>()).map(Array::UInt16),
+        Subtype::Int32 => split_to(src, n, mem::size_of::<i32>()).map(Array::Int32),
+        Subtype::UInt32 => split_to(src, n, mem::size_of::<u32>()).map(Array::UInt32),
+        Subtype::Float => split_to(src, n, mem::size_of::<f32>()).map(Array::Float),
     }
 }
 
+fn split_to<'a>(src: &mut &'a [u8], n: usize, size: usize) -> io::Result<&'a [u8]> {
+    let len = n
+        .checked_mul(size)
+        .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData))?;
+
+    if src.len() < len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    let (buf, rest) = src.split_at(len);
+    *src = rest;
+
+    Ok(buf)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,6 +93,12 @@ mod tests {
             Array::Float(&[0x00, 0x00, 0x00, 0x00]),
         )?;
 
+        let mut src = &[b'S', 0x02, 0x00, 0x00, 0x00, 0x00, 0x00][..];
+        assert!(matches!(
+            decode_array(&mut src),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
         Ok(())
     }
 }
@@ -1,6 +1,6 @@
 use std::io;
 
-use noodles_sam as sam;
+use noodles_sam::{self as sam, record::sequence::Base};
 
 /// A raw BAM record sequence.
 #[derive(Debug, Eq, PartialEq)]
@@ -25,6 +25,31 @@ impl<'a> Sequence<'a> {
     pub fn len(&self) -> usize {
         self.base_count
     }
+
+    /// Decodes the bases into the given buffer.
+    ///
+    /// The buffer is cleared before decoding but keeps its capacity, which avoids an allocation
+    /// per record when it is reused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    ///
+    /// let record = bam::lazy::Record::default();
+    ///
+    /// let mut bases = Vec::new();
+    /// record.sequence().decode_into(&mut bases)?;
+    /// assert!(bases.is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn decode_into(&self, dst: &mut Vec<Base>) -> io::Result<()> {
+        use crate::record::codec::decoder::sequence::get_bases;
+
+        let mut src = self.src;
+        get_bases(&mut src, dst, self.base_count)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
 }
 
 impl<'a> AsRef<[u8]> for Sequence<'a> {
@@ -48,3 +73,25 @@ impl<'a> TryFrom<Sequence<'a>> for sam::record::Sequence {
         Ok(sam_sequence)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_into() -> io::Result<()> {
+        let sequence = Sequence::new(&[0x12, 0x48], 3);
+
+        let mut bases = Vec::new();
+        sequence.decode_into(&mut bases)?;
+        assert_eq!(bases, [Base::A, Base::C, Base::G]);
+
+        let sequence = Sequence::new(&[0x12], 4);
+        assert!(matches!(
+            sequence.decode_into(&mut bases),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
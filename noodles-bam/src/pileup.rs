@@ -0,0 +1,491 @@
+//! BAM record pileup.
+
+mod column;
+mod element;
+
+pub use self::{column::PileupColumn, element::PileupElement};
+
+use std::{io, sync::Arc};
+
+use noodles_core::Position;
+use noodles_sam::{
+    alignment::Record,
+    record::cigar::{op::Kind, Op},
+};
+
+/// Creates an iterator over pileup columns from an iterator over coordinate-sorted records.
+///
+/// A column is emitted for each reference sequence position covered by at least one record.
+/// Unmapped records and records without an alignment start or CIGAR operations (`*`) do not
+/// contribute to any column.
+///
+/// An error is returned if the records are not sorted by reference sequence ID and alignment
+/// start.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam as bam;
+/// use noodles_core::Position;
+/// use noodles_sam::{alignment::Record, record::Flags};
+///
+/// let record = Record::builder()
+///     .set_flags(Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(8)?)
+///     .set_cigar("2M".parse()?)
+///     .set_sequence("AC".parse()?)
+///     .build();
+///
+/// let columns: Vec<_> = bam::pileup([Ok(record)]).collect::<std::io::Result<_>>()?;
+///
+/// assert_eq!(columns.len(), 2);
+/// assert_eq!(columns[0].position(), Position::try_from(8)?);
+/// assert_eq!(columns[1].elements().len(), 1);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn pileup<I>(records: I) -> Pileup<I::IntoIter>
+where
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    Pileup::new(records.into_iter())
+}
+
+/// An iterator over pileup columns.
+///
+/// This is created by calling [`pileup`].
+pub struct Pileup<I> {
+    records: I,
+    pending: Option<Segment>,
+    last_key: Option<(usize, usize)>,
+    reference_sequence_id: usize,
+    position: usize,
+    active: Vec<Segment>,
+    is_eof: bool,
+}
+
+impl<I> Pileup<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    fn new(records: I) -> Self {
+        Self {
+            records,
+            pending: None,
+            last_key: None,
+            reference_sequence_id: 0,
+            position: 0,
+            active: Vec::new(),
+            is_eof: false,
+        }
+    }
+
+    fn next_segment(&mut self) -> io::Result<Option<Segment>> {
+        if let Some(segment) = self.pending.take() {
+            return Ok(Some(segment));
+        }
+
+        if self.is_eof {
+            return Ok(None);
+        }
+
+        for result in self.records.by_ref() {
+            let record = result?;
+
+            if let Some(segment) = Segment::new(record) {
+                let key = (segment.reference_sequence_id, segment.start);
+
+                if self
+                    .last_key
+                    .map(|last_key| key < last_key)
+                    .unwrap_or(false)
+                {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "records are not coordinate-sorted",
+                    ));
+                }
+
+                self.last_key = Some(key);
+
+                return Ok(Some(segment));
+            }
+        }
+
+        self.is_eof = true;
+
+        Ok(None)
+    }
+
+    fn fill(&mut self) -> io::Result<()> {
+        while let Some(segment) = self.next_segment()? {
+            if self.active.is_empty() {
+                self.reference_sequence_id = segment.reference_sequence_id;
+                self.position = segment.start;
+            }
+
+            let key = (segment.reference_sequence_id, segment.start);
+            let current = (self.reference_sequence_id, self.position);
+
+            if key == current {
+                self.active.push(segment);
+            } else {
+                self.pending = Some(segment);
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<I> Iterator for Pileup<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<PileupColumn>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Err(e) = self.fill() {
+            return Some(Err(e));
+        }
+
+        if self.active.is_empty() {
+            return None;
+        }
+
+        let position = self.position;
+
+        let elements = self
+            .active
+            .iter_mut()
+            .map(|segment| segment.step(position))
+            .collect();
+
+        self.active.retain(|segment| segment.end > position);
+        self.position += 1;
+
+        // `position` is never 0 since it is within the alignment of an active record.
+        let position = Position::new(position).expect("invalid position");
+
+        Some(Ok(PileupColumn::new(
+            self.reference_sequence_id,
+            position,
+            elements,
+        )))
+    }
+}
+
+// A record with a cursor over its reference-consuming CIGAR operations.
+struct Segment {
+    record: Arc<Record>,
+    reference_sequence_id: usize,
+    start: usize,
+    end: usize,
+    op_index: usize,
+    op_offset: usize,
+    query_position: usize,
+}
+
+impl Segment {
+    fn new(record: Record) -> Option<Self> {
+        if record.flags().is_unmapped() || record.cigar().is_empty() {
+            return None;
+        }
+
+        let reference_sequence_id = record.reference_sequence_id()?;
+        let start = record.alignment_start().map(usize::from)?;
+        let span = record.cigar().reference_len();
+
+        if span == 0 {
+            return None;
+        }
+
+        Some(Self {
+            record: Arc::new(record),
+            reference_sequence_id,
+            start,
+            end: start + span - 1,
+            op_index: 0,
+            op_offset: 0,
+            query_position: 0,
+        })
+    }
+
+    fn ops(&self) -> &[Op] {
+        self.record.cigar()
+    }
+
+    // Skips operations that do not consume the reference, e.g., soft clips and insertions.
+    fn current_op(&mut self) -> Op {
+        loop {
+            let op = self.ops()[self.op_index];
+
+            if op.kind().consumes_reference() && !op.is_empty() {
+                return op;
+            }
+
+            if op.kind().consumes_read() {
+                self.query_position += op.len();
+            }
+
+            self.op_index += 1;
+        }
+    }
+
+    fn step(&mut self, position: usize) -> PileupElement {
+        let op = self.current_op();
+
+        let (base, query_position, is_deletion, is_reference_skip) = match op.kind() {
+            Kind::Deletion => (None, None, true, false),
+            Kind::Skip => (None, None, false, true),
+            _ => {
+                let base = self
+                    .record
+                    .sequence()
+                    .as_ref()
+                    .get(self.query_position)
+                    .copied();
+
+                (base, Some(self.query_position), false, false)
+            }
+        };
+
+        let element = PileupElement::new(
+            self.record.clone(),
+            base,
+            query_position,
+            position == self.start,
+            position == self.end,
+            is_deletion,
+            is_reference_skip,
+        );
+
+        if op.kind().consumes_read() {
+            self.query_position += 1;
+        }
+
+        self.op_offset += 1;
+
+        if self.op_offset >= op.len() {
+            self.op_index += 1;
+            self.op_offset = 0;
+        }
+
+        element
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::record::{sequence::Base, Flags};
+
+    use super::*;
+
+    fn build_record(
+        reference_sequence_id: usize,
+        alignment_start: usize,
+        cigar: &str,
+        sequence: &str,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        let mut builder = Record::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(reference_sequence_id)
+            .set_alignment_start(Position::try_from(alignment_start)?);
+
+        if !cigar.is_empty() {
+            builder = builder.set_cigar(cigar.parse()?);
+        }
+
+        if !sequence.is_empty() {
+            builder = builder.set_sequence(sequence.parse()?);
+        }
+
+        Ok(builder.build())
+    }
+
+    fn depths(columns: &[PileupColumn]) -> Vec<(usize, usize, usize)> {
+        columns
+            .iter()
+            .map(|column| {
+                (
+                    column.reference_sequence_id(),
+                    usize::from(column.position()),
+                    column.elements().len(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_pileup() -> Result<(), Box<dyn std::error::Error>> {
+        let records = [
+            build_record(0, 1, "2S3M", "GGACG")?,
+            build_record(0, 2, "1M1D1M", "TA")?,
+            build_record(0, 8, "1M", "C")?,
+        ];
+
+        let columns: Vec<_> = pileup(records.into_iter().map(Ok)).collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            depths(&columns),
+            [(0, 1, 1), (0, 2, 2), (0, 3, 2), (0, 4, 1), (0, 8, 1)]
+        );
+
+        // soft clip overhang
+        let element = &columns[0].elements()[0];
+        assert_eq!(element.base(), Some(Base::A));
+        assert_eq!(element.query_position(), Some(2));
+        assert!(element.is_head());
+        assert!(!element.is_tail());
+
+        assert_eq!(columns[2].depth(), 1);
+
+        // deletion
+        let element = &columns[2].elements()[1];
+        assert!(element.is_deletion());
+        assert!(element.base().is_none());
+        assert!(element.query_position().is_none());
+
+        let element = &columns[3].elements()[0];
+        assert_eq!(element.base(), Some(Base::A));
+        assert!(element.is_tail());
+
+        let element = &columns[4].elements()[0];
+        assert!(element.is_head());
+        assert!(element.is_tail());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pileup_with_quality_scores() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::record::quality_scores::Score;
+
+        let record = Record::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar("1S1M1D1M".parse()?)
+            .set_sequence("GAC".parse()?)
+            .set_quality_scores("NDL".parse()?)
+            .build();
+
+        let columns: Vec<_> = pileup([Ok(record)]).collect::<io::Result<_>>()?;
+
+        let quality_scores: Vec<_> = columns
+            .iter()
+            .map(|column| column.elements()[0].quality_score())
+            .collect();
+
+        assert_eq!(
+            quality_scores,
+            [
+                Some(Score::try_from('D')?),
+                None,
+                Some(Score::try_from('L')?)
+            ]
+        );
+
+        let record = build_record(0, 1, "1M", "A")?;
+        let columns: Vec<_> = pileup([Ok(record)]).collect::<io::Result<_>>()?;
+        assert!(columns[0].elements()[0].quality_score().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pileup_with_skip() -> Result<(), Box<dyn std::error::Error>> {
+        let records = [build_record(0, 5, "1M2N1M", "AC")?];
+        let columns: Vec<_> = pileup(records.into_iter().map(Ok)).collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            depths(&columns),
+            [(0, 5, 1), (0, 6, 1), (0, 7, 1), (0, 8, 1)]
+        );
+
+        assert!(columns[1].elements()[0].is_reference_skip());
+        assert_eq!(columns[1].depth(), 0);
+        assert!(columns[2].elements()[0].is_reference_skip());
+        assert_eq!(columns[3].elements()[0].base(), Some(Base::C));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pileup_with_missing_cigar_or_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        let records = [
+            build_record(0, 1, "", "ACGT")?,
+            build_record(0, 2, "2M", "")?,
+        ];
+
+        let columns: Vec<_> = pileup(records.into_iter().map(Ok)).collect::<io::Result<_>>()?;
+
+        assert_eq!(depths(&columns), [(0, 2, 1), (0, 3, 1)]);
+        assert!(columns[0].elements()[0].base().is_none());
+        assert_eq!(columns[0].elements()[0].query_position(), Some(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pileup_with_multiple_reference_sequences() -> Result<(), Box<dyn std::error::Error>> {
+        let records = [
+            build_record(0, 3, "2M", "AC")?,
+            build_record(1, 1, "1M", "G")?,
+            build_record(1, 4, "1M", "T")?,
+        ];
+
+        let columns: Vec<_> = pileup(records.into_iter().map(Ok)).collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            depths(&columns),
+            [(0, 3, 1), (0, 4, 1), (1, 1, 1), (1, 4, 1)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pileup_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>> {
+        let records = [
+            build_record(0, 2, "1M", "A")?,
+            build_record(0, 1, "1M", "C")?,
+        ];
+
+        let mut columns = pileup(records.into_iter().map(Ok));
+
+        assert!(matches!(
+            columns.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let records = [
+            build_record(1, 1, "1M", "A")?,
+            build_record(0, 1, "1M", "C")?,
+        ];
+
+        let mut columns = pileup(records.into_iter().map(Ok));
+
+        assert!(matches!(
+            columns.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let records = [
+            build_record(0, 1, "1M", "A")?,
+            build_record(0, 5, "1M", "C")?,
+            build_record(0, 3, "1M", "G")?,
+        ];
+
+        let mut columns = pileup(records.into_iter().map(Ok));
+
+        assert!(matches!(columns.next(), Some(Ok(_))));
+        assert!(matches!(
+            columns.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use noodles_core::Position;
+
+use super::PileupElement;
+
+/// A pileup column.
+///
+/// A pileup column is the set of records that cover a single reference sequence position.
+#[derive(Clone, Debug)]
+pub struct PileupColumn {
+    reference_sequence_id: usize,
+    position: Position,
+    elements: Vec<PileupElement>,
+}
+
+impl PileupColumn {
+    pub(super) fn new(
+        reference_sequence_id: usize,
+        position: Position,
+        elements: Vec<PileupElement>,
+    ) -> Self {
+        Self {
+            reference_sequence_id,
+            position,
+            elements,
+        }
+    }
+
+    /// Returns the reference sequence ID.
+    pub fn reference_sequence_id(&self) -> usize {
+        self.reference_sequence_id
+    }
+
+    /// Returns the reference sequence position.
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the elements of records that cover the position.
+    ///
+    /// Elements are in the order of the records in the input.
+    pub fn elements(&self) -> &[PileupElement] {
+        &self.elements
+    }
+
+    /// Returns the number of records with a base at the position.
+    ///
+    /// This excludes records with a deletion or reference skip at the position.
+    pub fn depth(&self) -> usize {
+        self.elements
+            .iter()
+            .filter(|element| !element.is_deletion() && !element.is_reference_skip())
+            .count()
+    }
+}
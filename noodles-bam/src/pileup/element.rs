@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use noodles_sam::{
+    alignment::Record,
+    record::{quality_scores::Score, sequence::Base},
+};
+
+/// A pileup element.
+///
+/// A pileup element is the contribution of a single record to a pileup column.
+#[derive(Clone, Debug)]
+pub struct PileupElement {
+    record: Arc<Record>,
+    base: Option<Base>,
+    query_position: Option<usize>,
+    is_head: bool,
+    is_tail: bool,
+    is_deletion: bool,
+    is_reference_skip: bool,
+}
+
+impl PileupElement {
+    pub(super) fn new(
+        record: Arc<Record>,
+        base: Option<Base>,
+        query_position: Option<usize>,
+        is_head: bool,
+        is_tail: bool,
+        is_deletion: bool,
+        is_reference_skip: bool,
+    ) -> Self {
+        Self {
+            record,
+            base,
+            query_position,
+            is_head,
+            is_tail,
+            is_deletion,
+            is_reference_skip,
+        }
+    }
+
+    /// Returns the contributing record.
+    ///
+    /// The record is shared with all other elements it contributes to.
+    pub fn record(&self) -> &Record {
+        &self.record
+    }
+
+    /// Returns the base at the column.
+    ///
+    /// This is `None` if the column is within a deletion or reference skip or if the record is
+    /// missing a sequence.
+    pub fn base(&self) -> Option<Base> {
+        self.base
+    }
+
+    /// Returns the quality score of the base at the column.
+    ///
+    /// This is `None` if the column is within a deletion or reference skip or if the record is
+    /// missing quality scores.
+    pub fn quality_score(&self) -> Option<Score> {
+        self.query_position
+            .and_then(|i| self.record.quality_scores().as_ref().get(i))
+            .copied()
+    }
+
+    /// Returns the 0-based position in the record sequence of the base at the column.
+    ///
+    /// This is `None` if the column is within a deletion or reference skip.
+    pub fn query_position(&self) -> Option<usize> {
+        self.query_position
+    }
+
+    /// Returns whether the column is the first aligned position of the record.
+    pub fn is_head(&self) -> bool {
+        self.is_head
+    }
+
+    /// Returns whether the column is the last aligned position of the record.
+    pub fn is_tail(&self) -> bool {
+        self.is_tail
+    }
+
+    /// Returns whether the column is within a deletion (`D`).
+    pub fn is_deletion(&self) -> bool {
+        self.is_deletion
+    }
+
+    /// Returns whether the column is within a reference skip (`N`), e.g., an intron.
+    pub fn is_reference_skip(&self) -> bool {
+        self.is_reference_skip
+    }
+}
@@ -358,6 +358,15 @@ where
 
     /// Returns an iterator of unmapped records after querying for the unmapped region.
     ///
+    /// This seeks directly to the unplaced, unmapped records at the end of the file using the end
+    /// position stored in the metadata pseudo-bin of the last reference sequence with records.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the index has records on a reference sequence but is missing its
+    /// metadata. In that case, the start of the unplaced, unmapped records cannot be determined
+    /// without scanning the file.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -381,7 +390,7 @@ where
         header: &'r sam::Header,
         index: &csi::Index,
     ) -> io::Result<impl Iterator<Item = io::Result<Record>> + 'r> {
-        if let Some(pos) = index.first_record_in_last_linear_bin_start_position() {
+        if let Some(pos) = resolve_unplaced_unmapped_start_position(index)? {
             self.seek(pos)?;
         } else {
             self.seek_to_first_record()?;
@@ -436,14 +445,248 @@ pub(crate) fn resolve_region(
     reference_sequences: &ReferenceSequences,
     region: &Region,
 ) -> io::Result<usize> {
-    reference_sequences
-        .get_index_of(region.name())
-        .ok_or_else(|| {
+    let (reference_sequence_id, _, reference_sequence) =
+        reference_sequences.get_full(region.name()).ok_or_else(|| {
             io::Error::new(
                 io::ErrorKind::InvalidInput,
                 format!(
                     "region reference sequence does not exist in reference sequences: {region:?}"
                 ),
             )
+        })?;
+
+    let reference_sequence_length = reference_sequence.length().get();
+    let interval = region.interval();
+
+    let is_out_of_bounds = [interval.start(), interval.end()]
+        .into_iter()
+        .flatten()
+        .any(|position| usize::from(position) > reference_sequence_length);
+
+    if is_out_of_bounds {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "region extends past the end of the reference sequence (length = {reference_sequence_length}): {region}"
+            ),
+        ));
+    }
+
+    Ok(reference_sequence_id)
+}
+
+// Returns the end position of the last placed record, i.e., the start position of the unplaced,
+// unmapped records. `None` is returned if there are no placed records.
+fn resolve_unplaced_unmapped_start_position(
+    index: &csi::Index,
+) -> io::Result<Option<bgzf::VirtualPosition>> {
+    let reference_sequence = match index
+        .reference_sequences()
+        .iter()
+        .rev()
+        .find(|reference_sequence| !reference_sequence.bins().is_empty())
+    {
+        Some(reference_sequence) => reference_sequence,
+        None => return Ok(None),
+    };
+
+    reference_sequence
+        .metadata()
+        .map(|metadata| Some(metadata.end_position()))
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "missing index reference sequence metadata",
+            )
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use sam::header::record::value::{map::ReferenceSequence, Map};
+
+    use super::*;
+
+    fn build_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        Ok(sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .add_reference_sequence(
+                "sq1".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(21)?),
+            )
+            .build())
+    }
+
+    #[test]
+    fn test_resolve_region() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+        let reference_sequences = header.reference_sequences();
+
+        assert_eq!(resolve_region(reference_sequences, &"sq0".parse()?)?, 0);
+        assert_eq!(
+            resolve_region(reference_sequences, &"sq1:8-21".parse()?)?,
+            1
+        );
+        assert_eq!(resolve_region(reference_sequences, &"sq1:8".parse()?)?, 1);
+
+        for raw_region in ["sq2", "sq0:8-14", "sq0:14"] {
+            let region = raw_region.parse()?;
+
+            assert!(matches!(
+                resolve_region(reference_sequences, &region),
+                Err(e) if e.kind() == io::ErrorKind::InvalidInput
+            ));
+        }
+
+        Ok(())
+    }
+
+    type BamReader = Reader<bgzf::Reader<io::Cursor<Vec<u8>>>>;
+
+    fn build_reader_and_index(
+        header: &sam::Header,
+        records: &[Record],
+    ) -> Result<(BamReader, csi::Index), Box<dyn std::error::Error>> {
+        use noodles_csi::index::reference_sequence::bin::Chunk;
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_header(header)?;
+
+        for record in records {
+            writer.write_record(header, record)?;
+        }
+
+        let data = writer.into_inner().finish()?;
+
+        let mut reader = Reader::new(io::Cursor::new(data));
+        reader.read_header()?;
+
+        let mut indexer = csi::index::Indexer::default();
+        let mut record = Record::default();
+        let mut start_position = reader.virtual_position();
+
+        while reader.read_record(header, &mut record)? != 0 {
+            let end_position = reader.virtual_position();
+
+            let alignment_context = match (
+                record.reference_sequence_id(),
+                record.alignment_start(),
+                record.alignment_end(),
+            ) {
+                (Some(id), Some(start), Some(end)) => {
+                    Some((id, start, end, !record.flags().is_unmapped()))
+                }
+                _ => None,
+            };
+
+            indexer.add_record(alignment_context, Chunk::new(start_position, end_position))?;
+            start_position = end_position;
+        }
+
+        let index = indexer.build(header.reference_sequences().len());
+
+        Ok((reader, index))
+    }
+
+    fn build_record(
+        flags: sam::record::Flags,
+        reference_sequence_id: usize,
+        alignment_start: usize,
+        cigar: &str,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        Ok(Record::builder()
+            .set_flags(flags)
+            .set_reference_sequence_id(reference_sequence_id)
+            .set_alignment_start(Position::try_from(alignment_start)?)
+            .set_cigar(cigar.parse()?)
+            .build())
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::Flags;
+
+        let header = build_header()?;
+
+        let records = [
+            build_record(Flags::empty(), 0, 1, "4M")?,
+            build_record(Flags::empty(), 0, 5, "4M")?,
+            build_record(Flags::empty(), 0, 10, "4M")?,
+            build_record(Flags::empty(), 1, 2, "8M")?,
+            build_record(Flags::empty(), 1, 12, "2M")?,
+        ];
+
+        let (mut reader, index) = build_reader_and_index(&header, &records)?;
+
+        let region = "sq0:6-10".parse()?;
+        let actual: Vec<_> = reader
+            .query(&header, &index, &region)?
+            .collect::<io::Result<_>>()?;
+        assert_eq!(actual, [records[1].clone(), records[2].clone()]);
+
+        let region = "sq1:10-21".parse()?;
+        let actual: Vec<_> = reader
+            .query(&header, &index, &region)?
+            .collect::<io::Result<_>>()?;
+        assert_eq!(actual, [records[4].clone()]);
+
+        let region = "sq1:20-22".parse()?;
+        assert!(matches!(
+            reader.query(&header, &index, &region),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_unmapped() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::Flags;
+
+        let header = build_header()?;
+
+        let records = [
+            build_record(Flags::empty(), 0, 1, "4M")?,
+            build_record(Flags::UNMAPPED, 0, 1, "4M")?,
+            build_record(Flags::empty(), 1, 2, "8M")?,
+            Record::builder().set_flags(Flags::UNMAPPED).build(),
+            Record::builder().set_flags(Flags::UNMAPPED).build(),
+        ];
+
+        let (mut reader, index) = build_reader_and_index(&header, &records)?;
+
+        let actual: Vec<_> = reader
+            .query_unmapped(&header, &index)?
+            .collect::<io::Result<_>>()?;
+        assert_eq!(actual, [records[3].clone(), records[4].clone()]);
+
+        let reference_sequences = index
+            .reference_sequences()
+            .iter()
+            .map(|reference_sequence| {
+                csi::index::ReferenceSequence::new(
+                    reference_sequence.bins().clone(),
+                    reference_sequence.linear_index().to_vec(),
+                    None,
+                )
+            })
+            .collect();
+
+        let index = csi::Index::builder()
+            .set_reference_sequences(reference_sequences)
+            .build();
+
+        assert!(matches!(
+            reader.query_unmapped(&header, &index),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -1 +1,8 @@
+//! BAM record utilities.
+
+#[doc(hidden)]
 pub mod codec;
+pub mod data;
+mod template_length;
+
+pub use self::template_length::compute_template_length;
@@ -49,23 +49,45 @@ pub fn get_sequence<B>(
 where
     B: Buf,
 {
-    let seq_len = (l_seq + 1) / 2;
+    get_bases(src, sequence.as_mut(), l_seq)
+}
+
+/// Decodes `l_seq` 4-bit encoded bases into the given buffer.
+///
+/// The buffer is cleared but keeps its capacity, so it can be reused between records.
+pub(crate) fn get_bases<B>(
+    src: &mut B,
+    dst: &mut Vec<Base>,
+    l_seq: usize,
+) -> Result<(), DecodeError>
+where
+    B: Buf,
+{
+    let mut seq_len = (l_seq + 1) / 2;
 
     if src.remaining() < seq_len {
         return Err(DecodeError::UnexpectedEof);
     }
 
-    let seq = src.take(seq_len);
-    let bases = seq
-        .chunk()
-        .iter()
-        .flat_map(|&b| [decode_base(b >> 4), decode_base(b)]);
+    dst.clear();
+    // Each byte decodes to 2 bases, so an odd-length sequence has a trailing base that is
+    // truncated.
+    dst.reserve(seq_len * 2);
 
-    sequence.clear();
-    sequence.as_mut().extend(bases);
-    sequence.as_mut().truncate(l_seq);
+    while seq_len > 0 {
+        let chunk = src.chunk();
+        let len = chunk.len().min(seq_len);
 
-    src.advance(seq_len);
+        for &b in &chunk[..len] {
+            dst.push(decode_base(b >> 4));
+            dst.push(decode_base(b));
+        }
+
+        src.advance(len);
+        seq_len -= len;
+    }
+
+    dst.truncate(l_seq);
 
     Ok(())
 }
@@ -130,6 +152,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_bases() -> Result<(), DecodeError> {
+        let mut bases = vec![Base::N; 8];
+
+        let mut src = &[0x12, 0x48][..];
+        get_bases(&mut src, &mut bases, 3)?;
+        assert_eq!(bases, [Base::A, Base::C, Base::G]);
+
+        // non-contiguous buffer
+        let mut src = (&[0x12][..]).chain(&[0x48, 0xf0][..]);
+        get_bases(&mut src, &mut bases, 5)?;
+        assert_eq!(bases, [Base::A, Base::C, Base::G, Base::T, Base::N]);
+        assert!(!src.has_remaining());
+
+        Ok(())
+    }
+
     #[test]
     fn test_decode_base() {
         assert_eq!(decode_base(0), Base::Eq);
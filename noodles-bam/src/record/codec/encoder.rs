@@ -164,9 +164,12 @@ fn put_bin<B>(
 where
     B: BufMut,
 {
+    // § 4.2.1 "BIN field calculation" (2021-06-03): An alignment with a position but no span,
+    // e.g., an unmapped read placed with its mate, is treated as having a length of 1.
     let bin = match (alignment_start, alignment_end) {
-        (Some(start), Some(end)) => region_to_bin(start, end)?,
-        _ => UNMAPPED_BIN,
+        (Some(start), Some(end)) if end >= start => region_to_bin(start, end)?,
+        (Some(start), _) => region_to_bin(start, start)?,
+        (None, _) => UNMAPPED_BIN,
     };
 
     dst.put_u16_le(bin);
@@ -407,6 +410,32 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_put_bin() -> Result<(), Box<dyn std::error::Error>> {
+        fn t(
+            alignment_start: Option<Position>,
+            alignment_end: Option<Position>,
+            expected: u16,
+        ) -> io::Result<()> {
+            let mut buf = Vec::new();
+            put_bin(&mut buf, alignment_start, alignment_end)?;
+            assert_eq!(buf, expected.to_le_bytes());
+            Ok(())
+        }
+
+        // unmapped
+        t(None, None, 4680)?;
+
+        t(Position::new(8), Position::new(13), 4681)?;
+        t(Position::new(16385), Position::new(16392), 4682)?;
+
+        // zero span
+        t(Position::new(1), None, 4681)?;
+        t(Position::new(16385), Position::new(16384), 4682)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_region_to_bin() -> Result<(), Box<dyn std::error::Error>> {
         let start = Position::try_from(8)?;
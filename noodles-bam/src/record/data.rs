@@ -0,0 +1,3 @@
+//! BAM record data.
+
+pub mod field;
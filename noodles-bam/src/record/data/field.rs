@@ -0,0 +1,3 @@
+//! BAM record data field.
+
+pub mod md;
@@ -0,0 +1,168 @@
+//! BAM record data field mismatched positions (`MD`).
+
+use std::fmt::Write;
+
+use noodles_sam::record::{
+    cigar::op::Kind,
+    sequence::{Base, Sequence},
+    Cigar,
+};
+
+/// Calculates the mismatched positions string (`MD`) of an alignment.
+///
+/// `ref_seq` is the reference sequence, and `ref_start` is the 0-based offset of the alignment
+/// start in `ref_seq`.
+///
+/// This follows `samtools calmd`. Read bases are compared to reference bases case-insensitively,
+/// a read base of `=` is counted as a match, and an ambiguous base (`N`) in either the read or
+/// reference is counted as a mismatch. Mismatched and deleted reference bases are written in
+/// uppercase. Reference bases outside of `ref_seq` are written as `N`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam::record::data::field::md;
+/// use noodles_sam::record::{Cigar, Sequence};
+///
+/// let cigar: Cigar = "4M2D4M".parse()?;
+/// let read_seq: Sequence = "ACTTGTAC".parse()?;
+/// let ref_seq = b"ACGTACGTAC";
+///
+/// assert_eq!(md::calculate(&cigar, &read_seq, ref_seq, 0), "2G1^AC4");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn calculate(cigar: &Cigar, read_seq: &Sequence, ref_seq: &[u8], ref_start: usize) -> String {
+    const MISSING: u8 = b'N';
+
+    let read_bases = read_seq.as_ref();
+    let reference_base = |i: usize| {
+        ref_seq
+            .get(i)
+            .map(|b| b.to_ascii_uppercase())
+            .unwrap_or(MISSING)
+    };
+
+    let mut md = String::new();
+    let mut match_count = 0;
+
+    let mut read_position = 0;
+    let mut reference_position = ref_start;
+
+    for op in cigar.iter() {
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                for i in 0..len {
+                    let expected = reference_base(reference_position + i);
+
+                    match read_bases.get(read_position + i) {
+                        Some(&actual) if bases_eq(actual, expected) => match_count += 1,
+                        _ => {
+                            write!(md, "{match_count}").ok();
+                            md.push(char::from(expected));
+                            match_count = 0;
+                        }
+                    }
+                }
+
+                read_position += len;
+                reference_position += len;
+            }
+            Kind::Deletion => {
+                write!(md, "{match_count}^").ok();
+
+                for i in 0..len {
+                    md.push(char::from(reference_base(reference_position + i)));
+                }
+
+                match_count = 0;
+                reference_position += len;
+            }
+            Kind::Insertion | Kind::SoftClip => read_position += len,
+            Kind::Skip => reference_position += len,
+            Kind::HardClip | Kind::Pad => {}
+        }
+    }
+
+    write!(md, "{match_count}").ok();
+
+    md
+}
+
+fn bases_eq(read_base: Base, reference_base: u8) -> bool {
+    if read_base == Base::Eq {
+        return true;
+    }
+
+    let read_base = u8::from(read_base);
+    read_base == reference_base && read_base != b'N'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate() {
+        fn t(cigar: &str, read_seq: &str, ref_seq: &[u8], ref_start: usize, expected: &str) {
+            let cigar = cigar.parse().unwrap();
+            let read_seq = read_seq.parse().unwrap();
+            assert_eq!(calculate(&cigar, &read_seq, ref_seq, ref_start), expected);
+        }
+
+        const REF_SEQ: &[u8] = b"ACGTACGTACGTACGT";
+
+        t("8M", "ACGTACGT", REF_SEQ, 0, "8");
+        t("8M", "ACGAACGT", REF_SEQ, 0, "3T4");
+        t("4M", "TCGT", REF_SEQ, 0, "0A3");
+        t("4M", "ACGA", REF_SEQ, 0, "3T0");
+        t("4M", "ACGT", REF_SEQ, 4, "4");
+        t("4M", "ACGT", b"acgt", 0, "4");
+
+        // deletions
+        t("4M2D4M", "ACGTGTAC", REF_SEQ, 0, "4^AC4");
+        t("2M1D2M", "ACAA", REF_SEQ, 0, "2^G0T1");
+        t("2M3D2M", "ACCG", REF_SEQ, 0, "2^GTA2");
+
+        // insertions and clips
+        t("2S4M2I4M", "TTACGTGGACGT", REF_SEQ, 0, "8");
+        t("2H4M1I3M", "ACGTTATT", REF_SEQ, 0, "5C0G0");
+
+        // skips
+        t("2M4N2M", "ACGT", REF_SEQ, 0, "4");
+
+        // sequence matches and mismatches
+        t("3=1X4=", "ACGAACGT", REF_SEQ, 0, "3T4");
+
+        // ambiguous bases
+        t("4M", "ACNT", REF_SEQ, 0, "2G1");
+        t("4M", "ACGT", b"ACNT", 0, "2N1");
+        t("4M", "AC=T", REF_SEQ, 0, "4");
+    }
+
+    #[test]
+    fn test_calculate_is_consistent_with_nm() {
+        use noodles_sam::{alignment::calculate_nm, record::data::nm_from_md};
+
+        const REF_SEQ: &[u8] = b"ACGTACGTACGTACGT";
+
+        for (cigar, read_seq) in [
+            ("8M", "ACGAACGT"),
+            ("4M2D4M", "ACGTGTAC"),
+            ("2M1D2M", "ACAA"),
+            ("2S4M2I4M", "TTACGTGGACGT"),
+            ("2H4M1I3M", "ACGTTATT"),
+        ] {
+            let cigar = cigar.parse().unwrap();
+            let read_seq = read_seq.parse().unwrap();
+
+            let md = calculate(&cigar, &read_seq, REF_SEQ, 0);
+
+            assert_eq!(
+                nm_from_md(&md, &cigar),
+                calculate_nm(&cigar, &read_seq, REF_SEQ)
+            );
+        }
+    }
+}
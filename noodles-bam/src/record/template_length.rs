@@ -0,0 +1,155 @@
+use noodles_sam::alignment::Record;
+
+/// Calculates the template length (`TLEN`) of the first record of a mate pair.
+///
+/// This uses the same convention as `samtools fixmate`: the template length is the distance
+/// between the 5' ends of the two mates, i.e., the alignment start of a forward strand record or
+/// the alignment end of a reverse strand record. It is positive when the mate's 5' end is to the
+/// right of the first record's 5' end and negative when it is to the left.
+///
+/// The template length of the second record is the negation of the returned value.
+///
+/// This returns 0 if either record is unmapped or the records are on different reference
+/// sequences.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam as bam;
+/// use noodles_core::Position;
+/// use noodles_sam::{self as sam, record::Flags};
+///
+/// let first = sam::alignment::Record::builder()
+///     .set_flags(Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(1)?)
+///     .set_cigar("4M".parse()?)
+///     .build();
+///
+/// let second = sam::alignment::Record::builder()
+///     .set_flags(Flags::REVERSE_COMPLEMENTED)
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(8)?)
+///     .set_cigar("4M".parse()?)
+///     .build();
+///
+/// assert_eq!(bam::record::compute_template_length(&first, &second), 11);
+/// assert_eq!(bam::record::compute_template_length(&second, &first), -11);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn compute_template_length(first: &Record, second: &Record) -> i32 {
+    let (first_reference_sequence_id, first_position) = match five_prime_position(first) {
+        Some(value) => value,
+        None => return 0,
+    };
+
+    let (second_reference_sequence_id, second_position) = match five_prime_position(second) {
+        Some(value) => value,
+        None => return 0,
+    };
+
+    if first_reference_sequence_id != second_reference_sequence_id {
+        return 0;
+    }
+
+    let n = second_position - first_position;
+    n.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32
+}
+
+// Returns the reference sequence ID and 0-based 5' position of a mapped record.
+//
+// As in htslib, the 5' position of a reverse strand record is its exclusive alignment end.
+fn five_prime_position(record: &Record) -> Option<(usize, i64)> {
+    if record.flags().is_unmapped() {
+        return None;
+    }
+
+    let reference_sequence_id = record.reference_sequence_id()?;
+    let start = usize::from(record.alignment_start()?) - 1;
+
+    let position = if record.flags().is_reverse_complemented() {
+        start + record.alignment_span()
+    } else {
+        start
+    };
+
+    i64::try_from(position)
+        .ok()
+        .map(|position| (reference_sequence_id, position))
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_sam::record::Flags;
+
+    use super::*;
+
+    fn build_record(
+        flags: Flags,
+        reference_sequence_id: usize,
+        alignment_start: usize,
+        cigar: &str,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        Ok(Record::builder()
+            .set_flags(flags)
+            .set_reference_sequence_id(reference_sequence_id)
+            .set_alignment_start(Position::try_from(alignment_start)?)
+            .set_cigar(cigar.parse()?)
+            .build())
+    }
+
+    #[test]
+    fn test_compute_template_length() -> Result<(), Box<dyn std::error::Error>> {
+        const FORWARD: Flags = Flags::empty();
+        const REVERSE: Flags = Flags::REVERSE_COMPLEMENTED;
+
+        // FR pair
+        let first = build_record(FORWARD, 0, 100, "50M")?;
+        let second = build_record(REVERSE, 0, 200, "50M")?;
+        assert_eq!(compute_template_length(&first, &second), 150);
+        assert_eq!(compute_template_length(&second, &first), -150);
+
+        // RF pair
+        let first = build_record(REVERSE, 0, 100, "50M")?;
+        let second = build_record(FORWARD, 0, 200, "50M")?;
+        assert_eq!(compute_template_length(&first, &second), 50);
+        assert_eq!(compute_template_length(&second, &first), -50);
+
+        // FF pair
+        let first = build_record(FORWARD, 0, 100, "50M")?;
+        let second = build_record(FORWARD, 0, 200, "50M")?;
+        assert_eq!(compute_template_length(&first, &second), 100);
+
+        // overlapping mates with soft clips and a deletion
+        let first = build_record(FORWARD, 0, 100, "5S45M")?;
+        let second = build_record(REVERSE, 0, 120, "20M2D28M2S")?;
+        assert_eq!(compute_template_length(&first, &second), 70);
+        assert_eq!(compute_template_length(&second, &first), -70);
+
+        // mates with the same 5' position
+        let first = build_record(FORWARD, 0, 100, "50M")?;
+        let second = build_record(FORWARD, 0, 100, "30M")?;
+        assert_eq!(compute_template_length(&first, &second), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compute_template_length_with_unpaired_mates() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let first = build_record(Flags::empty(), 0, 100, "50M")?;
+
+        let second = build_record(Flags::REVERSE_COMPLEMENTED, 1, 200, "50M")?;
+        assert_eq!(compute_template_length(&first, &second), 0);
+
+        let second = build_record(Flags::UNMAPPED, 0, 100, "50M")?;
+        assert_eq!(compute_template_length(&first, &second), 0);
+        assert_eq!(compute_template_length(&second, &first), 0);
+
+        let second = Record::default();
+        assert_eq!(compute_template_length(&first, &second), 0);
+
+        Ok(())
+    }
+}
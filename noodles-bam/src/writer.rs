@@ -32,6 +32,7 @@ use noodles_sam::{self as sam, alignment::Record};
 pub struct Writer<W> {
     inner: W,
     buf: Vec<u8>,
+    default_read_group: Option<String>,
 }
 
 impl<W> Writer<W>
@@ -77,6 +78,37 @@ where
         self.inner
     }
 
+    /// Sets a read group ID to add to records that do not have one.
+    ///
+    /// Records that already have a read group (`RG`) data field are written as is. The read group
+    /// must be in the header given when writing a record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::Record,
+    ///     header::record::value::{map::ReadGroup, Map},
+    /// };
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_read_group("rg0", Map::<ReadGroup>::default())
+    ///     .build();
+    ///
+    /// let mut writer = bam::Writer::new(io::sink());
+    /// writer.set_default_read_group(Some(String::from("rg0")));
+    ///
+    /// writer.write_header(&header)?;
+    /// writer.write_record(&header, &Record::default())?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn set_default_read_group(&mut self, read_group_id: Option<String>) {
+        self.default_read_group = read_group_id;
+    }
+
     /// Writes a SAM header.
     ///
     /// This writes the BAM magic number, the raw SAM header, and a copy of the reference sequence
@@ -121,9 +153,19 @@ where
     pub fn write_record(&mut self, header: &sam::Header, record: &Record) -> io::Result<()> {
         use super::record::codec::encode;
 
+        use sam::writer::resolve_default_read_group;
+
+        let read_group_id =
+            resolve_default_read_group(header, self.default_read_group.as_deref(), record)?;
+
         self.buf.clear();
         encode(&mut self.buf, header, record)?;
 
+        // The data fields are last in an encoded record.
+        if let Some(id) = read_group_id {
+            put_read_group(&mut self.buf, id);
+        }
+
         let block_size = u32::try_from(self.buf.len())
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
         self.inner.write_u32::<LittleEndian>(block_size)?;
@@ -177,6 +219,7 @@ impl<W> From<W> for Writer<W> {
         Self {
             inner,
             buf: Vec::new(),
+            default_read_group: None,
         }
     }
 }
@@ -198,6 +241,16 @@ where
     }
 }
 
+fn put_read_group(dst: &mut Vec<u8>, id: &str) {
+    const STRING_TYPE: u8 = b'Z';
+    const NUL: u8 = 0x00;
+
+    dst.extend_from_slice(b"RG");
+    dst.push(STRING_TYPE);
+    dst.extend_from_slice(id.as_bytes());
+    dst.push(NUL);
+}
+
 #[cfg(test)]
 mod tests {
     use sam::AlignmentWriter;
@@ -373,4 +426,61 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_record_with_default_read_group() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::{
+            header::record::value::{map::ReadGroup, Map},
+            record::data::field::{tag, Value},
+        };
+
+        use crate::Reader;
+
+        let header = sam::Header::builder()
+            .add_read_group("rg0", Map::<ReadGroup>::default())
+            .add_read_group("rg1", Map::<ReadGroup>::default())
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.set_default_read_group(Some(String::from("rg0")));
+        writer.write_header(&header)?;
+
+        writer.write_record(&header, &Record::default())?;
+
+        let record = Record::builder()
+            .set_data(
+                [(tag::READ_GROUP, Value::String(String::from("rg1")))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+        writer.write_record(&header, &record)?;
+
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().get_ref().as_slice());
+        reader.read_header()?;
+
+        let read_group_ids: Vec<_> = reader
+            .records(&header)
+            .map(|result| result.map(|record| record.data().get(&tag::READ_GROUP).cloned()))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            read_group_ids,
+            [
+                Some(Value::String(String::from("rg0"))),
+                Some(Value::String(String::from("rg1"))),
+            ]
+        );
+
+        let mut writer = Writer::new(io::sink());
+        writer.set_default_read_group(Some(String::from("rg2")));
+        assert!(matches!(
+            writer.write_record(&header, &Record::default()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
 }
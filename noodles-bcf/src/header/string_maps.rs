@@ -2,7 +2,10 @@
 
 mod string_map;
 
-use std::str::{FromStr, Lines};
+use std::{
+    error, fmt,
+    str::{FromStr, Lines},
+};
 
 use noodles_vcf::{
     self as vcf,
@@ -98,6 +101,107 @@ impl StringMaps {
     fn contigs_mut(&mut self) -> &mut ContigStringMap {
         &mut self.contig_string_map
     }
+
+    /// Validates the string maps against a VCF header.
+    ///
+    /// Every FILTER, INFO, and FORMAT ID and contig name in the header must have an entry in the
+    /// string maps, and every entry in the string maps must be defined in the header. The filter
+    /// ID "PASS" is always allowed, as it is implicitly defined.
+    ///
+    /// String maps built from the same header (see `StringMaps::try_from`) are always valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::header::StringMaps;
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Filter, Map}};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+    ///     .build();
+    ///
+    /// let string_maps = StringMaps::try_from(&header)?;
+    /// assert!(string_maps.validate_against_header(&header).is_ok());
+    ///
+    /// let string_maps = StringMaps::default();
+    /// assert!(string_maps.validate_against_header(&header).is_err());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate_against_header(&self, header: &vcf::Header) -> Result<(), ValidationError> {
+        const PASS: &str = "PASS";
+
+        let string_ids = header
+            .infos()
+            .keys()
+            .map(|id| id.as_ref())
+            .chain(header.filters().keys().map(|id| id.as_str()))
+            .chain(header.formats().keys().map(|id| id.as_ref()));
+
+        for id in string_ids {
+            if self.strings().get_index_of(id).is_none() {
+                return Err(ValidationError::MissingString(id.into()));
+            }
+        }
+
+        for (i, entry) in self.strings().entries.iter().enumerate() {
+            if let Some(id) = entry {
+                let is_defined = id == PASS
+                    || header.filters().contains_key(id)
+                    || header.infos().keys().any(|key| key.as_ref() == id)
+                    || header.formats().keys().any(|key| key.as_ref() == id);
+
+                if !is_defined {
+                    return Err(ValidationError::UndefinedString(i, id.clone()));
+                }
+            }
+        }
+
+        for name in header.contigs().keys() {
+            if self.contigs().get_index_of(name.as_ref()).is_none() {
+                return Err(ValidationError::MissingContig(name.to_string()));
+            }
+        }
+
+        for (i, entry) in self.contigs().entries.iter().enumerate() {
+            if let Some(name) = entry {
+                if !header.contigs().keys().any(|n| n.as_ref() == name) {
+                    return Err(ValidationError::UndefinedContig(i, name.clone()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when string maps fail to validate against a VCF header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// A header FILTER, INFO, or FORMAT ID is missing from the string string map.
+    MissingString(String),
+    /// A string string map entry is not defined in the header.
+    UndefinedString(usize, String),
+    /// A header contig name is missing from the contig string map.
+    MissingContig(String),
+    /// A contig string map entry is not defined in the header.
+    UndefinedContig(usize, String),
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingString(id) => write!(f, "missing string map entry: {id}"),
+            Self::UndefinedString(i, id) => {
+                write!(f, "undefined string map entry at index {i}: {id}")
+            }
+            Self::MissingContig(name) => write!(f, "missing contig string map entry: {name}"),
+            Self::UndefinedContig(i, name) => {
+                write!(f, "undefined contig string map entry at index {i}: {name}")
+            }
+        }
+    }
 }
 
 impl Default for StringMaps {
@@ -234,6 +338,59 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_validate_against_header() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::{
+            header::record::value::{
+                map::{Contig, Filter, Info},
+                Map,
+            },
+            record::info,
+        };
+
+        let header = vcf::Header::builder()
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_info(
+                info::field::key::TOTAL_DEPTH,
+                Map::<Info>::from(&info::field::key::TOTAL_DEPTH),
+            )
+            .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+            .build();
+
+        let string_maps = StringMaps::try_from(&header)?;
+        assert_eq!(string_maps.validate_against_header(&header), Ok(()));
+
+        let mut string_maps = StringMaps::try_from(&header)?;
+        string_maps.strings_mut().insert(String::from("MQ"));
+        assert_eq!(
+            string_maps.validate_against_header(&header),
+            Err(ValidationError::UndefinedString(3, String::from("MQ")))
+        );
+
+        let mut string_maps = StringMaps::default();
+        string_maps.strings_mut().insert(String::from("DP"));
+        assert_eq!(
+            string_maps.validate_against_header(&header),
+            Err(ValidationError::MissingString(String::from("q10")))
+        );
+
+        let mut string_maps = StringMaps::try_from(&header)?;
+        string_maps.contigs_mut().insert(String::from("sq1"));
+        assert_eq!(
+            string_maps.validate_against_header(&header),
+            Err(ValidationError::UndefinedContig(1, String::from("sq1")))
+        );
+
+        let mut string_maps = StringMaps::default();
+        *string_maps.strings_mut() = StringMaps::try_from(&header)?.strings().clone();
+        assert_eq!(
+            string_maps.validate_against_header(&header),
+            Err(ValidationError::MissingContig(String::from("sq0")))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str() {
         let s = r#"##fileformat=VCFv4.3
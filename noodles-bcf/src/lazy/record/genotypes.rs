@@ -56,6 +56,90 @@ impl Genotypes {
         Ok(genotypes)
     }
 
+    /// Creates genotypes by wrapping the given buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::lazy::record::Genotypes;
+    ///
+    /// let data = vec![
+    ///     0x11, 0x01, 0x21, 0x02, 0x04, 0x02, 0x02, // GT=0/1,0/0
+    /// ];
+    ///
+    /// let genotypes = Genotypes::new(data, 1, 2);
+    /// ```
+    pub fn new(buf: Vec<u8>, format_count: usize, sample_count: usize) -> Self {
+        Self {
+            buf,
+            format_count,
+            sample_count,
+        }
+    }
+
+    /// Returns the values of the given field for all samples.
+    ///
+    /// Only the values of the matching field are decoded. The values of the other fields are
+    /// skipped.
+    ///
+    /// This returns `None` if the field is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::{header::StringMaps, lazy::record::Genotypes};
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::record::value::{map, Map},
+    ///     record::genotypes::{keys::key, sample::Value},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_format(key::GENOTYPE, Map::<map::Format>::from(&key::GENOTYPE))
+    ///     .add_format(key::READ_DEPTH, Map::<map::Format>::from(&key::READ_DEPTH))
+    ///     .build();
+    ///
+    /// let string_maps = StringMaps::try_from(&header)?;
+    ///
+    /// let data = vec![
+    ///     0x11, 0x01, 0x21, 0x02, 0x04, 0x02, 0x02, // GT=0/1,0/0
+    ///     0x11, 0x02, 0x11, 0x05, 0x08, // DP=5,8
+    /// ];
+    ///
+    /// let genotypes = Genotypes::new(data, 2, 2);
+    ///
+    /// assert_eq!(
+    ///     genotypes.get(&header, string_maps.strings(), &key::READ_DEPTH).transpose()?,
+    ///     Some(vec![Some(Value::Integer(5)), Some(Value::Integer(8))])
+    /// );
+    ///
+    /// assert!(genotypes
+    ///     .get(&header, string_maps.strings(), &key::CONDITIONAL_GENOTYPE_QUALITY)
+    ///     .is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn get(
+        &self,
+        header: &vcf::Header,
+        string_map: &StringStringMap,
+        key: &vcf::record::genotypes::keys::Key,
+    ) -> Option<io::Result<Vec<Option<vcf::record::genotypes::sample::Value>>>> {
+        use crate::record::codec::decoder::find_values;
+
+        let mut reader = &self.buf[..];
+
+        find_values(
+            &mut reader,
+            header.formats(),
+            string_map,
+            self.len(),
+            self.format_count(),
+            key,
+        )
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        .transpose()
+    }
+
     /// Returns the number of samples.
     ///
     /// # Examples
@@ -105,6 +105,8 @@ impl Info {
 
     /// Returns the value with the given key.
     ///
+    /// Only the value of the matching field is decoded.
+    ///
     /// # Examples
     ///
     /// ```
@@ -144,22 +146,26 @@ impl Info {
         string_string_map: &StringStringMap,
         key: &vcf::record::info::field::Key,
     ) -> Option<io::Result<Option<vcf::record::info::field::Value>>> {
-        for result in self.iter(header, string_string_map) {
-            match result {
-                Ok((k, v)) => {
-                    if &k == key {
-                        return Some(Ok(v));
-                    }
-                }
-                Err(e) => return Some(Err(e)),
-            }
-        }
+        use crate::record::codec::decoder::info::find_value;
+
+        let mut reader = &self.buf[..];
 
-        None
+        find_value(
+            &mut reader,
+            header.infos(),
+            string_string_map,
+            self.len(),
+            key,
+        )
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        .transpose()
     }
 
     /// Returns an iterator over all info fields.
     ///
+    /// Fields are decoded one at a time as the iterator advances. The iterator ends after the
+    /// first error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -212,10 +218,21 @@ impl Info {
         use crate::record::codec::decoder::info::read_field;
 
         let mut reader = &self.buf[..];
+        let mut is_done = false;
+
+        // The fields are read in sequence, so stop after an error, as the position of the next
+        // field is unknown.
+        (0..self.len()).map_while(move |_| {
+            if is_done {
+                return None;
+            }
 
-        (0..self.len()).map(move |_| {
-            read_field(&mut reader, header.infos(), string_string_map)
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            let result = read_field(&mut reader, header.infos(), string_string_map)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e));
+
+            is_done = result.is_err();
+
+            Some(result)
         })
     }
 
@@ -277,3 +294,71 @@ impl AsMut<Vec<u8>> for Info {
         &mut self.buf
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use vcf::{
+        header::record::value::{map, Map},
+        record::info::field::{key, Value},
+    };
+
+    use super::*;
+    use crate::header::StringMaps;
+
+    fn build_header() -> vcf::Header {
+        vcf::Header::builder()
+            .add_info(
+                key::ALLELE_COUNT,
+                Map::<map::Info>::from(&key::ALLELE_COUNT),
+            )
+            .add_info(key::TOTAL_DEPTH, Map::<map::Info>::from(&key::TOTAL_DEPTH))
+            .build()
+    }
+
+    #[test]
+    fn test_get() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let data = vec![
+            0x11, 0x01, 0x32, 0x05, 0x00, 0x08, 0x00, 0x0d, 0x00, // AC=5,8,13
+            0x11, 0x02, 0x11, 0x08, // DP=8
+        ];
+
+        let info = Info::new(data, 2);
+
+        assert_eq!(
+            info.get(&header, string_maps.strings(), &key::TOTAL_DEPTH)
+                .transpose()?,
+            Some(Some(Value::Integer(8)))
+        );
+
+        let info = Info::new(vec![0x11, 0x01, 0x32, 0x05], 1);
+
+        assert!(matches!(
+            info.get(&header, string_maps.strings(), &key::TOTAL_DEPTH),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter_with_invalid_field() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let data = vec![
+            0x11, 0x08, 0x11, 0x05, // (invalid string map index)=5
+            0x11, 0x02, 0x11, 0x08, // DP=8
+        ];
+
+        let info = Info::new(data, 2);
+        let mut fields = info.iter(&header, string_maps.strings());
+
+        assert!(matches!(fields.next(), Some(Err(_))));
+        assert!(fields.next().is_none());
+
+        Ok(())
+    }
+}
@@ -9,7 +9,7 @@ pub mod header;
 pub mod indexed_reader;
 pub mod lazy;
 pub mod reader;
-pub(crate) mod record;
+pub mod record;
 mod writer;
 
 pub use self::{indexed_reader::IndexedReader, reader::Reader, writer::Writer};
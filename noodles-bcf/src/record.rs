@@ -1 +1,3 @@
+//! BCF record codec.
+
 pub mod codec;
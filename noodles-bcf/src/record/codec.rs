@@ -1,2 +1,4 @@
+//! BCF record encoder and decoder.
+
 pub mod decoder;
 pub mod encoder;
@@ -1,9 +1,11 @@
+//! BCF record decoder.
+
 mod bases;
 mod chromosome_id;
 mod filters;
-mod genotypes;
+pub mod genotypes;
 mod ids;
-pub mod info;
+pub(crate) mod info;
 mod position;
 mod quality_score;
 mod raw_value;
@@ -16,13 +18,13 @@ use byteorder::{LittleEndian, ReadBytesExt};
 use noodles_vcf as vcf;
 
 pub(crate) use self::{
-    bases::read_ref_alt, chromosome_id::read_chrom, filters::read_filter, ids::read_id,
-    position::read_pos, quality_score::read_qual,
+    bases::read_ref_alt, chromosome_id::read_chrom, filters::read_filter, genotypes::find_values,
+    ids::read_id, position::read_pos, quality_score::read_qual,
 };
-pub use self::{genotypes::read_genotypes, info::read_info, value::read_value};
+pub(crate) use self::{genotypes::read_genotypes, info::read_info, value::read_value};
 use crate::{header::StringMaps, lazy};
 
-pub fn read_site(
+pub(crate) fn read_site(
     src: &mut &[u8],
     header: &vcf::Header,
     string_maps: &StringMaps,
@@ -70,7 +72,7 @@ pub fn read_site(
     Ok((n_fmt, n_sample))
 }
 
-pub fn read_rlen(src: &mut &[u8]) -> io::Result<usize> {
+pub(crate) fn read_rlen(src: &mut &[u8]) -> io::Result<usize> {
     src.read_i32::<LittleEndian>()
         .and_then(|n| usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
 }
@@ -1,20 +1,27 @@
+//! BCF record genotypes decoder.
+
 mod key;
 mod values;
 
+pub use self::values::{read_genotype_field, DecodeError as GenotypeFieldDecodeError};
+
 use std::{error, fmt};
 
 use noodles_vcf::{
     self as vcf,
-    record::{genotypes::Keys, Genotypes},
+    record::{
+        genotypes::{sample::Value, Keys},
+        Genotypes,
+    },
 };
 
 use self::{
     key::read_key,
-    values::{read_genotype_values, read_values},
+    values::{read_genotype_values, read_values, skip_values},
 };
 use crate::header::string_maps::StringStringMap;
 
-pub fn read_genotypes(
+pub(crate) fn read_genotypes(
     src: &mut &[u8],
     formats: &vcf::header::Formats,
     string_map: &StringStringMap,
@@ -47,9 +54,38 @@ pub fn read_genotypes(
     Ok(Genotypes::new(keys, values))
 }
 
+pub(crate) fn find_values(
+    src: &mut &[u8],
+    formats: &vcf::header::Formats,
+    string_map: &StringStringMap,
+    sample_count: usize,
+    format_count: usize,
+    key: &vcf::record::genotypes::keys::Key,
+) -> Result<Option<Vec<Option<Value>>>, DecodeError> {
+    use vcf::record::genotypes::keys::key;
+
+    for _ in 0..format_count {
+        let k = read_key(src, formats, string_map).map_err(DecodeError::InvalidKey)?;
+
+        if k == key {
+            let values = if k == &key::GENOTYPE {
+                read_genotype_values(src, sample_count)
+            } else {
+                read_values(src, sample_count)
+            };
+
+            return values.map(Some).map_err(DecodeError::InvalidValues);
+        }
+
+        skip_values(src, sample_count).map_err(DecodeError::InvalidValues)?;
+    }
+
+    Ok(None)
+}
+
 #[allow(clippy::enum_variant_names)]
 #[derive(Debug, Eq, PartialEq)]
-pub enum DecodeError {
+pub(crate) enum DecodeError {
     InvalidKey(key::DecodeError),
     InvalidValues(values::DecodeError),
     InvalidKeys(vcf::record::genotypes::keys::TryFromKeyVectorError),
@@ -16,21 +16,21 @@ pub(super) fn read_key<'h>(
         .and_then(|j| {
             string_map
                 .get_index(j)
-                .ok_or(DecodeError::MissingStringMapEntry)
+                .ok_or(DecodeError::MissingStringMapEntry(j))
         })
         .and_then(|raw_key| {
             formats
                 .get_key_value(raw_key)
                 .map(|(k, _)| k)
-                .ok_or(DecodeError::MissingFormatMapEntry)
+                .ok_or_else(|| DecodeError::MissingFormatMapEntry(raw_key.into()))
         })
 }
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum DecodeError {
     InvalidStringMapIndex(crate::record::codec::decoder::string_map::DecodeError),
-    MissingStringMapEntry,
-    MissingFormatMapEntry,
+    MissingStringMapEntry(usize),
+    MissingFormatMapEntry(String),
 }
 
 impl error::Error for DecodeError {
@@ -46,8 +46,10 @@ impl fmt::Display for DecodeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::InvalidStringMapIndex(_) => write!(f, "invalid string map index"),
-            Self::MissingStringMapEntry => write!(f, "missing string map entry"),
-            Self::MissingFormatMapEntry => write!(f, "missing format map entry"),
+            Self::MissingStringMapEntry(i) => {
+                write!(f, "FORMAT key index {i} not in header string map")
+            }
+            Self::MissingFormatMapEntry(key) => write!(f, "FORMAT key not in header: {key}"),
         }
     }
 }
@@ -76,4 +78,33 @@ mod tests {
             Ok(&key::GENOTYPE),
         );
     }
+
+    #[test]
+    fn test_read_key_with_missing_entries() {
+        use vcf::{header::record::value::Map, record::genotypes::keys::key};
+
+        let formats = [(key::GENOTYPE, Map::from(&key::GENOTYPE))]
+            .into_iter()
+            .collect();
+
+        let mut string_map = StringStringMap::default();
+        string_map.insert("PASS".into());
+        string_map.insert("ndls".into());
+
+        // Some(Type::Int8(Some(Int8::Value(37))))
+        let mut src = &[0x11, 0x25][..];
+        let result = read_key(&mut src, &formats, &string_map);
+        assert_eq!(result, Err(DecodeError::MissingStringMapEntry(37)));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "FORMAT key index 37 not in header string map"
+        );
+
+        // Some(Type::Int8(Some(Int8::Value(1))))
+        let mut src = &[0x11, 0x01][..];
+        assert_eq!(
+            read_key(&mut src, &formats, &string_map),
+            Err(DecodeError::MissingFormatMapEntry(String::from("ndls")))
+        );
+    }
 }
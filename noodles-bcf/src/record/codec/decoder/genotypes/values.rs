@@ -1,6 +1,12 @@
 use std::{error, fmt, str};
 
-use noodles_vcf::record::genotypes::sample::Value;
+use noodles_vcf::record::genotypes::sample::{
+    value::{
+        genotype::{allele::Phasing, Allele, TryFromAllelesError},
+        Genotype,
+    },
+    Value,
+};
 
 use crate::{
     lazy::record::value::{Float, Int16, Int32, Int8, Type},
@@ -9,7 +15,7 @@ use crate::{
             self, read_f32, read_f32s, read_i16, read_i16s, read_i32, read_i32s, read_i8, read_i8s,
             read_string,
         },
-        value::{read_type, ty},
+        value::{read_type, ty, value_len},
     },
 };
 
@@ -35,6 +41,25 @@ pub(super) fn read_values(
     }
 }
 
+/// Advances past the values of a genotype field for all samples without decoding them.
+pub(super) fn skip_values(src: &mut &[u8], sample_count: usize) -> Result<(), DecodeError> {
+    let ty = read_type(src).map_err(DecodeError::InvalidType)?;
+
+    let len = value_len(ty)
+        .checked_mul(sample_count)
+        .ok_or(DecodeError::InvalidLength)?;
+
+    if src.len() < len {
+        return Err(DecodeError::InvalidRawValue(
+            raw_value::DecodeError::UnexpectedEof,
+        ));
+    }
+
+    *src = &src[len..];
+
+    Ok(())
+}
+
 fn read_int8_values(
     src: &mut &[u8],
     sample_count: usize,
@@ -273,45 +298,145 @@ pub(super) fn read_genotype_values(
     src: &mut &[u8],
     sample_count: usize,
 ) -> Result<Vec<Option<Value>>, DecodeError> {
+    let ty = read_type(src).map_err(DecodeError::InvalidType)?;
+
     let mut values = Vec::with_capacity(sample_count);
 
-    match read_type(src).map_err(DecodeError::InvalidType)? {
-        Some(Type::Int8(len)) => match len {
-            0 => values.push(None),
-            1 => {
-                for _ in 0..sample_count {
-                    let value = read_i8(src)
-                        .map(|v| parse_genotype_values(&[v]))
-                        .map(Value::from)
-                        .map_err(DecodeError::InvalidRawValue)?;
-
-                    values.push(Some(value));
-                }
-            }
-            _ => {
-                for _ in 0..sample_count {
-                    let buf = read_i8s(src, len).map_err(DecodeError::InvalidRawValue)?;
-                    let value = Value::from(parse_genotype_values(&buf));
-                    values.push(Some(value));
-                }
+    for _ in 0..sample_count {
+        // Values are read up to the first end-of-vector value. A missing value is `None`.
+        let raw_values: Vec<Option<i32>> = match ty {
+            Some(Type::Int8(len)) => read_i8s(src, len)
+                .map_err(DecodeError::InvalidRawValue)?
+                .into_iter()
+                .map(Int8::from)
+                .take_while(|n| !matches!(n, Int8::EndOfVector))
+                .map(|n| match n {
+                    Int8::Value(n) => Some(i32::from(n)),
+                    _ => None,
+                })
+                .collect(),
+            Some(Type::Int16(len)) => read_i16s(src, len)
+                .map_err(DecodeError::InvalidRawValue)?
+                .into_iter()
+                .map(Int16::from)
+                .take_while(|n| !matches!(n, Int16::EndOfVector))
+                .map(|n| match n {
+                    Int16::Value(n) => Some(i32::from(n)),
+                    _ => None,
+                })
+                .collect(),
+            Some(Type::Int32(len)) => read_i32s(src, len)
+                .map_err(DecodeError::InvalidRawValue)?
+                .into_iter()
+                .map(Int32::from)
+                .take_while(|n| !matches!(n, Int32::EndOfVector))
+                .map(|n| match n {
+                    Int32::Value(n) => Some(n),
+                    _ => None,
+                })
+                .collect(),
+            _ => return Err(DecodeError::InvalidLength),
+        };
+
+        // A sample with a missing first value has no genotype, e.g., when the sample is missing
+        // the field.
+        let value = match raw_values.first() {
+            None | Some(None) => None,
+            Some(Some(_)) => {
+                let raw_values: Vec<_> = raw_values
+                    .into_iter()
+                    .collect::<Option<_>>()
+                    .ok_or(DecodeError::InvalidGenotypeValue)?;
+
+                Some(Value::from(parse_genotype_values(&raw_values)))
             }
-        },
-        ty => todo!("unhandled type: {:?}", ty),
+        };
+
+        values.push(value);
     }
 
     Ok(values)
 }
 
-fn parse_genotype_values(values: &[i8]) -> String {
+/// Reads a BCF record genotype field value (`GT`) for a single sample.
+///
+/// This is the inverse of [`crate::record::codec::encoder::genotypes::write_genotype_field`].
+/// The phasing bit of the first allele is ignored. As in VCF, the first allele is phased if all
+/// other alleles are phased.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bcf::record::codec::decoder::genotypes::read_genotype_field;
+/// use noodles_vcf::record::genotypes::sample::value::Genotype;
+///
+/// let mut src = &[0x21, 0x02, 0x05][..];
+/// let genotype = read_genotype_field(&mut src)?;
+///
+/// let expected: Genotype = "0|1".parse()?;
+/// assert_eq!(genotype, expected);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn read_genotype_field(src: &mut &[u8]) -> Result<Genotype, DecodeError> {
+    let values: Vec<i32> = match read_type(src).map_err(DecodeError::InvalidType)? {
+        Some(Type::Int8(len)) => read_i8s(src, len)
+            .map_err(DecodeError::InvalidRawValue)?
+            .into_iter()
+            .take_while(|&n| !matches!(Int8::from(n), Int8::EndOfVector))
+            .map(i32::from)
+            .collect(),
+        Some(Type::Int16(len)) => read_i16s(src, len)
+            .map_err(DecodeError::InvalidRawValue)?
+            .into_iter()
+            .take_while(|&n| !matches!(Int16::from(n), Int16::EndOfVector))
+            .map(i32::from)
+            .collect(),
+        Some(Type::Int32(len)) => read_i32s(src, len)
+            .map_err(DecodeError::InvalidRawValue)?
+            .into_iter()
+            .take_while(|&n| !matches!(Int32::from(n), Int32::EndOfVector))
+            .collect(),
+        _ => return Err(DecodeError::InvalidLength),
+    };
+
+    parse_genotype(&values)
+}
+
+fn parse_genotype(values: &[i32]) -> Result<Genotype, DecodeError> {
+    let mut alleles = Vec::with_capacity(values.len());
+
+    for &value in values {
+        let position = match (value >> 1) - 1 {
+            -1 => None,
+            j => Some(usize::try_from(j).map_err(|_| DecodeError::InvalidGenotypeValue)?),
+        };
+
+        let phasing = if value & 0x01 == 1 {
+            Phasing::Phased
+        } else {
+            Phasing::Unphased
+        };
+
+        alleles.push(Allele::new(position, phasing));
+    }
+
+    if let Some((first, rest)) = alleles.split_first_mut() {
+        *first.phasing_mut() = if rest.iter().all(|a| a.phasing() == Phasing::Phased) {
+            Phasing::Phased
+        } else {
+            Phasing::Unphased
+        };
+    }
+
+    Genotype::try_from(alleles).map_err(DecodeError::InvalidGenotype)
+}
+
+fn parse_genotype_values(values: &[i32]) -> String {
     use std::fmt::Write;
 
     let mut genotype = String::new();
 
     for (i, &value) in values.iter().enumerate() {
-        if let Int8::EndOfVector = Int8::from(value) {
-            break;
-        }
-
         let j = (value >> 1) - 1;
         let is_phased = value & 0x01 == 1;
 
@@ -333,13 +458,22 @@ fn parse_genotype_values(values: &[i8]) -> String {
     genotype
 }
 
+/// An error when genotype values fail to decode.
 #[allow(clippy::enum_variant_names)]
 #[derive(Debug, Eq, PartialEq)]
 pub enum DecodeError {
+    /// The type is invalid.
     InvalidType(ty::DecodeError),
+    /// The length is invalid.
     InvalidLength,
+    /// A raw value is invalid.
     InvalidRawValue(raw_value::DecodeError),
+    /// A string is invalid.
     InvalidString(str::Utf8Error),
+    /// A genotype value is invalid.
+    InvalidGenotypeValue,
+    /// The genotype is invalid.
+    InvalidGenotype(TryFromAllelesError),
 }
 
 impl error::Error for DecodeError {
@@ -349,6 +483,8 @@ impl error::Error for DecodeError {
             Self::InvalidLength => None,
             Self::InvalidRawValue(e) => Some(e),
             Self::InvalidString(e) => Some(e),
+            Self::InvalidGenotypeValue => None,
+            Self::InvalidGenotype(e) => Some(e),
         }
     }
 }
@@ -360,6 +496,8 @@ impl fmt::Display for DecodeError {
             Self::InvalidLength => write!(f, "invalid length"),
             Self::InvalidRawValue(_) => write!(f, "invalid raw value"),
             Self::InvalidString(_) => write!(f, "invalid string"),
+            Self::InvalidGenotypeValue => write!(f, "invalid genotype value"),
+            Self::InvalidGenotype(_) => write!(f, "invalid genotype"),
         }
     }
 }
@@ -368,6 +506,22 @@ impl fmt::Display for DecodeError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_skip_values() {
+        // Int8[2] for 2 samples, followed by an unrelated byte
+        let mut src = &[0x21, 0x02, 0x04, 0x02, 0x02, 0xff][..];
+        assert!(skip_values(&mut src, 2).is_ok());
+        assert_eq!(src, [0xff]);
+
+        let mut src = &[0x21, 0x02, 0x04, 0x02][..];
+        assert_eq!(
+            skip_values(&mut src, 2),
+            Err(DecodeError::InvalidRawValue(
+                raw_value::DecodeError::UnexpectedEof
+            ))
+        );
+    }
+
     #[test]
     fn test_read_values_with_int8_values() {
         let mut src = &[
@@ -544,9 +698,113 @@ mod tests {
         assert_eq!(parse_genotype_values(&[0x04]), "1");
         assert_eq!(parse_genotype_values(&[0x02, 0x04, 0x06]), "0/1/2");
         assert_eq!(parse_genotype_values(&[0x02, 0x04, 0x07]), "0/1|2");
+    }
+
+    #[test]
+    fn test_read_genotype_values() {
+        let mut src = &[
+            0x21, // Some(Type::Int8(2))
+            0x02, 0x04, // "0/1"
+            0x02, 0x81, // "0"
+            0x00, 0x00, // "./."
+            0x80, 0x81, // None
+        ][..];
+
+        assert_eq!(
+            read_genotype_values(&mut src, 4),
+            Ok(vec![
+                Some(Value::from("0/1")),
+                Some(Value::from("0")),
+                Some(Value::from("./.")),
+                None,
+            ])
+        );
+
+        let mut src = &[
+            0x22, // Some(Type::Int16(2))
+            0x02, 0x00, 0x82, 0x00, // "0/64"
+            0x02, 0x00, 0x01, 0x80, // "0"
+        ][..];
+
+        assert_eq!(
+            read_genotype_values(&mut src, 2),
+            Ok(vec![Some(Value::from("0/64")), Some(Value::from("0"))])
+        );
+
+        let mut src = &[
+            0x01, // Some(Type::Int8(0))
+        ][..];
+
+        assert_eq!(read_genotype_values(&mut src, 2), Ok(vec![None, None]));
+
+        let mut src = &[
+            0x21, // Some(Type::Int8(2))
+            0x02, 0x80, // invalid missing value
+        ][..];
+
         assert_eq!(
-            parse_genotype_values(&[0x02, i8::from(Int8::EndOfVector)]),
-            "0"
+            read_genotype_values(&mut src, 1),
+            Err(DecodeError::InvalidGenotypeValue)
         );
     }
+
+    #[test]
+    fn test_read_genotype_field() -> Result<(), Box<dyn std::error::Error>> {
+        fn t(mut src: &[u8], expected: &str) -> Result<(), Box<dyn std::error::Error>> {
+            let expected: Genotype = expected.parse()?;
+            assert_eq!(read_genotype_field(&mut src)?, expected);
+            Ok(())
+        }
+
+        t(&[0x21, 0x02, 0x05], "0|1")?;
+        t(&[0x21, 0x02, 0x02], "0/0")?;
+        t(&[0x21, 0x00, 0x00], "./.")?;
+        t(&[0x21, 0x02, 0x81], "0")?;
+        t(&[0x31, 0x02, 0x04, 0x07], "0/1|2")?;
+        t(&[0x22, 0x02, 0x00, 0x80, 0x00], "0/63")?;
+
+        let mut src = &[0x21, 0x02, 0x80][..];
+        assert_eq!(
+            read_genotype_field(&mut src),
+            Err(DecodeError::InvalidGenotypeValue)
+        );
+
+        let mut src = &[0x11, 0x81][..];
+        assert_eq!(
+            read_genotype_field(&mut src),
+            Err(DecodeError::InvalidGenotype(TryFromAllelesError::Empty))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_genotype_field_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::codec::encoder::genotypes::write_genotype_field;
+
+        for (s, allele_count) in [
+            ("0|1", 2),
+            ("0/0", 2),
+            ("./.", 2),
+            (".|.", 2),
+            ("0/.", 2),
+            ("0", 1),
+            ("0/1/2", 3),
+            ("0|1|2|3", 4),
+            ("0/1|2", 3),
+            ("1/255", 256),
+            ("70000|1", 70001),
+        ] {
+            let genotype: Genotype = s.parse()?;
+
+            let mut buf = Vec::new();
+            write_genotype_field(&mut buf, &genotype, allele_count)?;
+
+            let mut src = &buf[..];
+            assert_eq!(read_genotype_field(&mut src)?, genotype, "{s}");
+            assert!(src.is_empty());
+        }
+
+        Ok(())
+    }
 }
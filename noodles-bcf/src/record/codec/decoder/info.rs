@@ -27,6 +27,33 @@ pub fn read_info(
     Ok(info)
 }
 
+/// Finds the value of the field with the given key.
+///
+/// Only the value of the matching field is decoded. The values of other fields are skipped.
+pub(crate) fn find_value(
+    src: &mut &[u8],
+    infos: &vcf::header::Infos,
+    string_string_map: &StringStringMap,
+    len: usize,
+    key: &vcf::record::info::field::Key,
+) -> Result<Option<Option<vcf::record::info::field::Value>>, DecodeError> {
+    use self::field::{read_key, read_value, skip_value};
+
+    for _ in 0..len {
+        let k = read_key(src, infos, string_string_map).map_err(DecodeError::InvalidField)?;
+
+        if k == key {
+            return read_value(src, infos, k)
+                .map(Some)
+                .map_err(DecodeError::InvalidField);
+        }
+
+        skip_value(src).map_err(DecodeError::InvalidField)?;
+    }
+
+    Ok(None)
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub enum DecodeError {
     InvalidField(field::DecodeError),
@@ -5,7 +5,6 @@ use std::{error, fmt};
 
 use noodles_vcf as vcf;
 
-use self::{key::read_key, value::read_value};
 use crate::header::string_maps::StringStringMap;
 
 pub(crate) fn read_field(
@@ -19,16 +18,42 @@ pub(crate) fn read_field(
     ),
     DecodeError,
 > {
-    let key = read_key(src, infos, string_map).map_err(DecodeError::InvalidKey)?;
-    let info = infos.get(key).ok_or(DecodeError::MissingInfoMapEntry)?;
-    let value = read_value(src, info).map_err(DecodeError::InvalidValue)?;
+    let key = read_key(src, infos, string_map)?;
+    let value = read_value(src, infos, key)?;
     Ok((key.clone(), value))
 }
 
+pub(crate) fn read_key<'h>(
+    src: &mut &[u8],
+    infos: &'h vcf::header::Infos,
+    string_map: &StringStringMap,
+) -> Result<&'h vcf::record::info::field::Key, DecodeError> {
+    key::read_key(src, infos, string_map).map_err(DecodeError::InvalidKey)
+}
+
+pub(crate) fn read_value(
+    src: &mut &[u8],
+    infos: &vcf::header::Infos,
+    key: &vcf::record::info::field::Key,
+) -> Result<Option<vcf::record::info::field::Value>, DecodeError> {
+    let info = infos
+        .get(key)
+        .ok_or_else(|| DecodeError::MissingInfoMapEntry(key.clone()))?;
+    value::read_value(src, info).map_err(DecodeError::InvalidValue)
+}
+
+pub(crate) fn skip_value(src: &mut &[u8]) -> Result<(), DecodeError> {
+    use crate::record::codec::decoder::value::skip_value;
+
+    skip_value(src)
+        .map_err(value::DecodeError::InvalidValue)
+        .map_err(DecodeError::InvalidValue)
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub enum DecodeError {
     InvalidKey(key::DecodeError),
-    MissingInfoMapEntry,
+    MissingInfoMapEntry(vcf::record::info::field::Key),
     InvalidValue(value::DecodeError),
 }
 
@@ -36,7 +61,7 @@ impl error::Error for DecodeError {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
             Self::InvalidKey(e) => Some(e),
-            Self::MissingInfoMapEntry => None,
+            Self::MissingInfoMapEntry(_) => None,
             Self::InvalidValue(e) => Some(e),
         }
     }
@@ -46,7 +71,7 @@ impl fmt::Display for DecodeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::InvalidKey(_) => write!(f, "invalid key"),
-            Self::MissingInfoMapEntry => write!(f, "missing info map entry"),
+            Self::MissingInfoMapEntry(key) => write!(f, "INFO key not in header: {key}"),
             Self::InvalidValue(_) => write!(f, "invalid value"),
         }
     }
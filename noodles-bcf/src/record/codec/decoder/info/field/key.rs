@@ -16,21 +16,21 @@ pub(super) fn read_key<'h>(
         .and_then(|j| {
             string_map
                 .get_index(j)
-                .ok_or(DecodeError::MissingStringMapEntry)
+                .ok_or(DecodeError::MissingStringMapEntry(j))
         })
         .and_then(|raw_key| {
             infos
                 .get_key_value(raw_key)
                 .map(|(k, _)| k)
-                .ok_or(DecodeError::MissingInfoMapEntry)
+                .ok_or_else(|| DecodeError::MissingInfoMapEntry(raw_key.into()))
         })
 }
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum DecodeError {
     InvalidStringMapIndex(crate::record::codec::decoder::string_map::DecodeError),
-    MissingStringMapEntry,
-    MissingInfoMapEntry,
+    MissingStringMapEntry(usize),
+    MissingInfoMapEntry(String),
 }
 
 impl error::Error for DecodeError {
@@ -46,8 +46,10 @@ impl fmt::Display for DecodeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::InvalidStringMapIndex(_) => write!(f, "invalid string map index"),
-            Self::MissingStringMapEntry => write!(f, "missing string map entry"),
-            Self::MissingInfoMapEntry => write!(f, "missing info map entry"),
+            Self::MissingStringMapEntry(i) => {
+                write!(f, "INFO key index {i} not in header string map")
+            }
+            Self::MissingInfoMapEntry(key) => write!(f, "INFO key not in header: {key}"),
         }
     }
 }
@@ -79,4 +81,36 @@ mod tests {
             Ok(&key::SAMPLES_WITH_DATA_COUNT),
         );
     }
+
+    #[test]
+    fn test_read_key_with_missing_entries() {
+        use vcf::{header::record::value::Map, record::info::field::key};
+
+        let infos = [(
+            key::SAMPLES_WITH_DATA_COUNT,
+            Map::from(&key::SAMPLES_WITH_DATA_COUNT),
+        )]
+        .into_iter()
+        .collect();
+
+        let mut string_map = StringStringMap::default();
+        string_map.insert("PASS".into());
+        string_map.insert("ndls".into());
+
+        // Some(Type::Int8(Some(Int8::Value(37))))
+        let mut src = &[0x11, 0x25][..];
+        let result = read_key(&mut src, &infos, &string_map);
+        assert_eq!(result, Err(DecodeError::MissingStringMapEntry(37)));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "INFO key index 37 not in header string map"
+        );
+
+        // Some(Type::Int8(Some(Int8::Value(1))))
+        let mut src = &[0x11, 0x01][..];
+        assert_eq!(
+            read_key(&mut src, &infos, &string_map),
+            Err(DecodeError::MissingInfoMapEntry(String::from("ndls")))
+        );
+    }
 }
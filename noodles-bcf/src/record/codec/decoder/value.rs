@@ -30,6 +30,38 @@ pub fn read_value<'a>(src: &mut &'a [u8]) -> Result<Option<Value<'a>>, DecodeErr
     }
 }
 
+/// Advances past a typed value without decoding it.
+pub fn skip_value(src: &mut &[u8]) -> Result<(), DecodeError> {
+    use super::raw_value::DecodeError as RawValueDecodeError;
+
+    let ty = read_type(src).map_err(DecodeError::InvalidType)?;
+    let len = value_len(ty);
+
+    if src.len() < len {
+        return Err(DecodeError::InvalidRawValue(
+            RawValueDecodeError::UnexpectedEof,
+        ));
+    }
+
+    *src = &src[len..];
+
+    Ok(())
+}
+
+/// Returns the size of the data of a typed value.
+pub(crate) fn value_len(ty: Option<Type>) -> usize {
+    use std::mem;
+
+    match ty {
+        None => 0,
+        Some(Type::Int8(n)) => n * mem::size_of::<i8>(),
+        Some(Type::Int16(n)) => n * mem::size_of::<i16>(),
+        Some(Type::Int32(n)) => n * mem::size_of::<i32>(),
+        Some(Type::Float(n)) => n * mem::size_of::<f32>(),
+        Some(Type::String(n)) => n,
+    }
+}
+
 fn read_i8_value<'a>(src: &mut &'a [u8]) -> Result<Option<Value<'a>>, DecodeError> {
     use super::raw_value::read_i8;
 
@@ -126,6 +158,26 @@ impl fmt::Display for DecodeError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_skip_value() {
+        let mut src = &[
+            0x00, // None
+            0x11, 0x05, // Some(Int8(5))
+            0x32, 0x00, 0x01, 0x08, 0x00, 0x0d, 0x00, // Some(Array(Int16([256, 8, 13])))
+            0x27, b'n', // partial string
+        ][..];
+
+        assert!(skip_value(&mut src).is_ok());
+        assert!(skip_value(&mut src).is_ok());
+        assert!(skip_value(&mut src).is_ok());
+        assert_eq!(src, [0x27, b'n']);
+
+        assert!(matches!(
+            skip_value(&mut src),
+            Err(DecodeError::InvalidRawValue(_))
+        ));
+    }
+
     #[test]
     fn test_read_value() {
         fn t(mut src: &[u8], expected: Option<Value<'_>>) {
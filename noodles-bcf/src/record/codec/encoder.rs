@@ -1,4 +1,6 @@
+//! BCF record encoder.
+
 pub mod genotypes;
-pub mod site;
+pub(crate) mod site;
 mod string_map;
 mod value;
@@ -1,3 +1,5 @@
+//! BCF record genotypes encoder.
+
 use std::{
     cmp,
     io::{self, Write},
@@ -9,11 +11,17 @@ use noodles_vcf::{
     header::record::value::{map::Format, Map},
     record::genotypes::{
         keys::{key, Key},
-        sample::{value::Array, Value},
+        sample::{
+            value::{genotype::allele::Phasing, Array, Genotype},
+            Value,
+        },
     },
 };
 
-use super::{string_map::write_string_map_index, value::write_type};
+use super::{
+    string_map::write_string_map_index,
+    value::{smallest_int_type, smallest_int_type_for_range, write_type, IntType},
+};
 use crate::{
     header::string_maps::StringStringMap,
     lazy::record::value::{Float, Int16, Int32, Int8, Type},
@@ -23,7 +31,7 @@ const NUL: u8 = 0x00;
 const DELIMITER: char = ',';
 const MISSING_VALUE: char = '.';
 
-pub fn write_genotypes<W>(
+pub(crate) fn write_genotypes<W>(
     writer: &mut W,
     header: &vcf::Header,
     string_string_map: &StringStringMap,
@@ -63,7 +71,7 @@ where
     Ok(())
 }
 
-pub fn write_genotype_field_key<W>(
+pub(crate) fn write_genotype_field_key<W>(
     writer: &mut W,
     string_map: &StringStringMap,
     key: &Key,
@@ -82,7 +90,7 @@ where
         .and_then(|i| write_string_map_index(writer, i))
 }
 
-pub fn write_genotype_field_values<W>(
+pub(crate) fn write_genotype_field_values<W>(
     writer: &mut W,
     format: &Map<Format>,
     values: &[Option<&Value>],
@@ -131,27 +139,14 @@ where
         max = cmp::max(max, n);
     }
 
-    if min >= i32::from(Int8::MIN_VALUE) {
-        if max <= i32::from(Int8::MAX_VALUE) {
-            write_genotype_field_int8_values(writer, values)
-        } else if max <= i32::from(Int16::MAX_VALUE) {
-            write_genotype_field_int16_values(writer, values)
-        } else {
-            write_genotype_field_int32_values(writer, values)
-        }
-    } else if min >= i32::from(Int16::MIN_VALUE) {
-        if max <= i32::from(Int16::MAX_VALUE) {
-            write_genotype_field_int16_values(writer, values)
-        } else {
-            write_genotype_field_int32_values(writer, values)
-        }
-    } else if min >= Int32::MIN_VALUE {
-        write_genotype_field_int32_values(writer, values)
-    } else {
-        Err(io::Error::new(
+    match smallest_int_type_for_range(min, max) {
+        Some(IntType::Int8) => write_genotype_field_int8_values(writer, values),
+        Some(IntType::Int16) => write_genotype_field_int16_values(writer, values),
+        Some(IntType::Int32) => write_genotype_field_int32_values(writer, values),
+        None => Err(io::Error::new(
             io::ErrorKind::InvalidInput,
             format!("invalid genotype field integer value: {min}"),
-        ))
+        )),
     }
 }
 
@@ -262,27 +257,14 @@ where
         }
     }
 
-    if min >= i32::from(Int8::MIN_VALUE) {
-        if max <= i32::from(Int8::MAX_VALUE) {
-            write_genotype_field_int8_array_values(writer, values, max_len)
-        } else if max <= i32::from(Int16::MAX_VALUE) {
-            write_genotype_field_int16_array_values(writer, values, max_len)
-        } else {
-            write_genotype_field_int32_array_values(writer, values, max_len)
-        }
-    } else if min >= i32::from(Int16::MIN_VALUE) {
-        if max <= i32::from(Int16::MAX_VALUE) {
-            write_genotype_field_int16_array_values(writer, values, max_len)
-        } else {
-            write_genotype_field_int32_array_values(writer, values, max_len)
-        }
-    } else if min >= Int32::MIN_VALUE {
-        write_genotype_field_int32_array_values(writer, values, max_len)
-    } else {
-        Err(io::Error::new(
+    match smallest_int_type_for_range(min, max) {
+        Some(IntType::Int8) => write_genotype_field_int8_array_values(writer, values, max_len),
+        Some(IntType::Int16) => write_genotype_field_int16_array_values(writer, values, max_len),
+        Some(IntType::Int32) => write_genotype_field_int32_array_values(writer, values, max_len),
+        None => Err(io::Error::new(
             io::ErrorKind::InvalidInput,
             format!("invalid genotype field integer array value: {min}"),
-        ))
+        )),
     }
 }
 
@@ -686,6 +668,53 @@ where
     Ok(())
 }
 
+/// Writes a BCF record genotype field value (`GT`) for a single sample.
+///
+/// Each allele is encoded as `(allele_index + 1) << 1 | phased`, where a missing allele has an
+/// allele index of -1. The integer type is the smallest that can hold the largest possible value
+/// given the number of alleles in the record (the reference allele + alternate alleles).
+///
+/// The phasing bit of the first allele is always unset.
+///
+/// # Errors
+///
+/// An error is returned if an allele position is not less than `allele_count`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bcf::record::codec::encoder::genotypes::write_genotype_field;
+/// use noodles_vcf::record::genotypes::sample::value::Genotype;
+///
+/// let genotype: Genotype = "0|1".parse()?;
+///
+/// let mut buf = Vec::new();
+/// write_genotype_field(&mut buf, &genotype, 2)?;
+///
+/// assert_eq!(buf, [0x21, 0x02, 0x05]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn write_genotype_field<W>(
+    writer: &mut W,
+    genotype: &Genotype,
+    allele_count: usize,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    let values = encode_genotype(genotype, allele_count)?;
+
+    // The largest possible value is a phased allele with an allele index of `allele_count - 1`.
+    let max_value = allele_count
+        .checked_mul(2)
+        .and_then(|n| n.checked_add(1))
+        .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidInput))?;
+
+    let ty = genotype_type(max_value, values.len())?;
+    write_type(writer, Some(ty))?;
+    write_genotype_values(writer, ty, &values)
+}
+
 fn write_genotype_genotype_field_values<W>(
     writer: &mut W,
     values: &[Option<&Value>],
@@ -693,81 +722,174 @@ fn write_genotype_genotype_field_values<W>(
 where
     W: Write,
 {
-    let genotypes: Vec<_> = values
+    // A sample without a value, e.g., when the sample is missing the field, is `None`.
+    let genotypes: Vec<Option<Genotype>> = values
         .iter()
         .map(|v| match v {
-            Some(Value::String(s)) => Ok(s),
-            _ => Err(io::Error::from(io::ErrorKind::InvalidInput)),
+            Some(Value::String(s)) => s
+                .parse()
+                .map(Some)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e)),
+            Some(_) => Err(io::Error::from(io::ErrorKind::InvalidInput)),
+            None => Ok(None),
         })
         .collect::<Result<_, _>>()?;
 
     let mut raw_values = Vec::with_capacity(genotypes.len());
     let mut max_len = 0;
+    let mut max_value = 0;
+
+    for genotype in &genotypes {
+        let raw_value = match genotype {
+            Some(genotype) => {
+                let raw_value = encode_genotype(genotype, usize::MAX)?;
+                max_len = cmp::max(max_len, raw_value.len());
+                max_value = raw_value.iter().copied().fold(max_value, cmp::max);
+                Some(raw_value)
+            }
+            None => {
+                max_len = cmp::max(max_len, 1);
+                None
+            }
+        };
 
-    for genotype in genotypes {
-        let raw_value = encode_genotype_genotype_field_values(genotype)?;
-        max_len = cmp::max(max_len, raw_value.len());
         raw_values.push(raw_value);
     }
 
-    write_type(writer, Some(Type::Int8(max_len)))?;
-
-    for raw_value in raw_values {
-        let len = raw_value.len();
-        let pad = max_len - len;
+    let max_value =
+        usize::try_from(max_value).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let ty = genotype_type(max_value, max_len)?;
 
-        for n in raw_value {
-            let m = u8::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-            writer.write_all(&[m])?;
+    write_type(writer, Some(ty))?;
 
-            for _ in 0..pad {
-                writer.write_all(&[i8::from(Int8::EndOfVector) as u8])?;
-            }
+    for raw_value in raw_values {
+        match raw_value {
+            Some(raw_value) => write_genotype_values(writer, ty, &raw_value)?,
+            None => write_missing_genotype_values(writer, ty)?,
         }
     }
 
     Ok(())
 }
 
-fn encode_genotype_genotype_field_values(genotype: &str) -> io::Result<Vec<i8>> {
-    const MISSING_ALLELE: &str = ".";
+fn genotype_type(max_value: usize, len: usize) -> io::Result<Type> {
+    let invalid_max_value = || {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "invalid genotype allele position",
+        )
+    };
+
+    let n = i32::try_from(max_value).map_err(|_| invalid_max_value())?;
 
-    fn is_phasing(c: char) -> bool {
-        matches!(c, '|' | '/')
+    match smallest_int_type(n) {
+        Some(IntType::Int8) => Ok(Type::Int8(len)),
+        Some(IntType::Int16) => Ok(Type::Int16(len)),
+        Some(IntType::Int32) => Ok(Type::Int32(len)),
+        None => Err(invalid_max_value()),
     }
+}
 
-    fn encode(s: &str, phasing: &str) -> io::Result<i8> {
-        if s == MISSING_ALLELE {
-            return Ok(0);
-        }
+/// Writes encoded genotype values, padding them with end-of-vector values to the length of the
+/// given type.
+fn write_genotype_values<W>(writer: &mut W, ty: Type, values: &[i32]) -> io::Result<()>
+where
+    W: Write,
+{
+    fn invalid_input<E>(e: E) -> io::Error
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        io::Error::new(io::ErrorKind::InvalidInput, e)
+    }
 
-        let j: i8 = s
-            .parse()
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-        let is_phased = phasing == "|";
+    match ty {
+        Type::Int8(len) => {
+            for &n in values {
+                writer.write_i8(i8::try_from(n).map_err(invalid_input)?)?;
+            }
 
-        let mut i = (j + 1) << 1;
+            for _ in values.len()..len {
+                writer.write_i8(i8::from(Int8::EndOfVector))?;
+            }
+        }
+        Type::Int16(len) => {
+            for &n in values {
+                writer.write_i16::<LittleEndian>(i16::try_from(n).map_err(invalid_input)?)?;
+            }
 
-        if is_phased {
-            i |= 0x01;
+            for _ in values.len()..len {
+                writer.write_i16::<LittleEndian>(i16::from(Int16::EndOfVector))?;
+            }
         }
+        Type::Int32(len) => {
+            for &n in values {
+                writer.write_i32::<LittleEndian>(n)?;
+            }
 
-        Ok(i)
+            for _ in values.len()..len {
+                writer.write_i32::<LittleEndian>(i32::from(Int32::EndOfVector))?;
+            }
+        }
+        _ => return Err(invalid_input("invalid genotype type")),
     }
 
-    let mut values = Vec::new();
-    let mut start = 0;
-    let mut last_phasing = "/";
+    Ok(())
+}
 
-    for (end, phasing) in genotype.match_indices(is_phasing) {
-        let i = encode(&genotype[start..end], last_phasing)?;
-        values.push(i);
-        start = end + 1;
-        last_phasing = phasing;
+/// Writes a missing value, padded with end-of-vector values to the length of the given type.
+fn write_missing_genotype_values<W>(writer: &mut W, ty: Type) -> io::Result<()>
+where
+    W: Write,
+{
+    match ty {
+        Type::Int8(len) => {
+            writer.write_i8(i8::from(Int8::Missing))?;
+            write_genotype_values(writer, Type::Int8(len - 1), &[])
+        }
+        Type::Int16(len) => {
+            writer.write_i16::<LittleEndian>(i16::from(Int16::Missing))?;
+            write_genotype_values(writer, Type::Int16(len - 1), &[])
+        }
+        Type::Int32(len) => {
+            writer.write_i32::<LittleEndian>(i32::from(Int32::Missing))?;
+            write_genotype_values(writer, Type::Int32(len - 1), &[])
+        }
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "invalid genotype type",
+        )),
     }
+}
 
-    let i = encode(&genotype[start..], last_phasing)?;
-    values.push(i);
+fn encode_genotype(genotype: &Genotype, allele_count: usize) -> io::Result<Vec<i32>> {
+    let mut values = Vec::with_capacity(genotype.len());
+
+    for (i, allele) in genotype.iter().enumerate() {
+        let j = match allele.position() {
+            Some(position) if position < allele_count => i32::try_from(position)
+                .ok()
+                .and_then(|n| n.checked_add(1))
+                .and_then(|n| n.checked_mul(2))
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "invalid genotype allele position",
+                    )
+                })?,
+            Some(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "genotype allele position out of range",
+                ))
+            }
+            None => 0,
+        };
+
+        let is_phased = i > 0 && allele.phasing() == Phasing::Phased;
+
+        values.push(if is_phased { j | 0x01 } else { j });
+    }
 
     Ok(values)
 }
@@ -1374,20 +1496,164 @@ mod tests {
     }
 
     #[test]
-    fn test_encode_genotype_genotype_field_values() -> io::Result<()> {
-        assert_eq!(encode_genotype_genotype_field_values("0/1")?, [0x02, 0x04]);
-        assert_eq!(encode_genotype_genotype_field_values("0|1")?, [0x02, 0x05]);
-        assert_eq!(encode_genotype_genotype_field_values("./.")?, [0x00, 0x00]);
-        assert_eq!(encode_genotype_genotype_field_values("0")?, [0x02]);
-        assert_eq!(encode_genotype_genotype_field_values("1")?, [0x04]);
-        assert_eq!(
-            encode_genotype_genotype_field_values("0/1/2")?,
-            [0x02, 0x04, 0x06]
-        );
-        assert_eq!(
-            encode_genotype_genotype_field_values("0/1|2")?,
-            [0x02, 0x04, 0x07]
-        );
+    fn test_write_genotype_genotype_field_values_with_polyploid_padding() -> io::Result<()> {
+        let value_0 = Value::from("0/1/2");
+        let value_1 = Value::from("0");
+        let values = [Some(&value_0), Some(&value_1)];
+
+        let mut buf = Vec::new();
+        write_genotype_genotype_field_values(&mut buf, &values)?;
+
+        let expected = [
+            0x31, // Some(Type::Int8(3))
+            0x02, 0x04, 0x06, // "0/1/2"
+            0x02, 0x81, 0x81, // "0"
+        ];
+
+        assert_eq!(buf, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_genotype_genotype_field_values_with_missing_values() -> io::Result<()> {
+        let value_0 = Value::from("0/1");
+        let value_2 = Value::from(".");
+        let values = [Some(&value_0), None, Some(&value_2)];
+
+        let mut buf = Vec::new();
+        write_genotype_genotype_field_values(&mut buf, &values)?;
+
+        let expected = [
+            0x21, // Some(Type::Int8(2))
+            0x02, 0x04, // "0/1"
+            0x80, 0x81, // None
+            0x00, 0x81, // "."
+        ];
+
+        assert_eq!(buf, expected);
+
+        let values = [None, None];
+
+        buf.clear();
+        write_genotype_genotype_field_values(&mut buf, &values)?;
+
+        let expected = [
+            0x11, // Some(Type::Int8(1))
+            0x80, // None
+            0x80, // None
+        ];
+
+        assert_eq!(buf, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_genotypes_round_trip_with_mixed_ploidy() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use vcf::record::{genotypes::Keys, Genotypes};
+
+        use crate::record::codec::decoder::read_genotypes;
+
+        let header: vcf::Header = r#"##fileformat=VCFv4.3
+##FORMAT=<ID=GT,Number=1,Type=String,Description="Genotype">
+##FORMAT=<ID=DP,Number=1,Type=Integer,Description="Read depth">
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0	sample1	sample2	sample3	sample4
+"#
+        .parse()?;
+
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let keys = Keys::try_from(vec![key::GENOTYPE, key::READ_DEPTH])?;
+
+        for gts in [
+            [Some("0/1"), Some("1"), Some("0|1|1"), Some("./."), None],
+            [Some("0"), Some("1/0"), Some("."), None, Some("1")],
+            [Some("0/64"), Some("64"), Some("0|1"), None, Some(".")],
+            [Some("0/70000"), Some("1"), None, None, Some("./.")],
+        ] {
+            let values = gts
+                .iter()
+                .enumerate()
+                .map(|(i, gt)| vec![gt.map(Value::from), Some(Value::from(i as i32))])
+                .collect();
+
+            let genotypes = Genotypes::new(keys.clone(), values);
+
+            let mut buf = Vec::new();
+            write_genotypes(&mut buf, &header, string_maps.strings(), &genotypes)?;
+
+            let mut src = &buf[..];
+            let actual = read_genotypes(
+                &mut src,
+                header.formats(),
+                string_maps.strings(),
+                gts.len(),
+                keys.len(),
+            )?;
+
+            assert_eq!(actual, genotypes, "{gts:?}");
+            assert!(src.is_empty());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_genotype_field() -> Result<(), Box<dyn std::error::Error>> {
+        fn t(
+            genotype: &str,
+            allele_count: usize,
+            expected: &[u8],
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let genotype = genotype.parse()?;
+            let mut buf = Vec::new();
+            write_genotype_field(&mut buf, &genotype, allele_count)?;
+            assert_eq!(buf, expected);
+            Ok(())
+        }
+
+        t("0|1", 2, &[0x21, 0x02, 0x05])?;
+        t("0/0", 2, &[0x21, 0x02, 0x02])?;
+        t("./.", 2, &[0x21, 0x00, 0x00])?;
+        t(".|.", 2, &[0x21, 0x00, 0x01])?;
+        t("0/1/2", 3, &[0x31, 0x02, 0x04, 0x06])?;
+        t("0", 1, &[0x11, 0x02])?;
+
+        // § 6.3.3 "Type encoding": the largest value for 63 alleles (127) fits in an Int8.
+        t("0/62", 63, &[0x21, 0x02, 0x7e])?;
+        t("0/63", 64, &[0x22, 0x02, 0x00, 0x80, 0x00])?;
+        t(
+            "0|1",
+            16384,
+            &[0x23, 0x02, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00],
+        )?;
+
+        let genotype = "0/2".parse()?;
+        let mut buf = Vec::new();
+        assert!(matches!(
+            write_genotype_field(&mut buf, &genotype, 2),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_genotype() -> Result<(), Box<dyn std::error::Error>> {
+        fn t(s: &str) -> Result<Vec<i32>, Box<dyn std::error::Error>> {
+            let genotype = s.parse()?;
+            encode_genotype(&genotype, usize::MAX).map_err(|e| e.into())
+        }
+
+        assert_eq!(t("0/1")?, [0x02, 0x04]);
+        assert_eq!(t("0|1")?, [0x02, 0x05]);
+        assert_eq!(t("./.")?, [0x00, 0x00]);
+        assert_eq!(t("0")?, [0x02]);
+        assert_eq!(t("1")?, [0x04]);
+        assert_eq!(t("0/1/2")?, [0x02, 0x04, 0x06]);
+        assert_eq!(t("0/1|2")?, [0x02, 0x04, 0x07]);
 
         Ok(())
     }
@@ -1,17 +1,17 @@
-use std::{
-    cmp,
-    io::{self, Write},
-};
+use std::io::{self, Write};
 
 use noodles_vcf as vcf;
 
 use crate::{
     header::string_maps::StringStringMap,
     lazy::record::{
-        value::{Array, Float, Int16, Int32, Int8},
+        value::{Array, Float},
         Value,
     },
-    record::codec::encoder::{string_map::write_string_map_index, value::write_value},
+    record::codec::encoder::{
+        string_map::write_string_map_index,
+        value::{encode_integer, encode_integers, write_value},
+    },
 };
 
 const MISSING_VALUE: char = '.';
@@ -92,34 +92,23 @@ where
         Some(field::Value::Array(field::value::Array::String(values))) => {
             write_info_field_string_array_value(writer, values)
         }
-        _ => todo!("unhandled INFO field value: {:?}", value),
+        None => write_info_field_missing_value(writer),
     }
 }
 
+fn write_info_field_missing_value<W>(writer: &mut W) -> io::Result<()>
+where
+    W: Write,
+{
+    write_value(writer, None)
+}
+
 fn write_info_field_integer_value<W>(writer: &mut W, n: i32) -> io::Result<()>
 where
     W: Write,
 {
-    if n >= 0 {
-        if n <= i32::from(Int8::MAX_VALUE) {
-            write_value(writer, Some(Value::Int8(Some(Int8::Value(n as i8)))))
-        } else if n <= i32::from(Int16::MAX_VALUE) {
-            write_value(writer, Some(Value::Int16(Some(Int16::Value(n as i16)))))
-        } else {
-            write_value(writer, Some(Value::Int32(Some(Int32::Value(n)))))
-        }
-    } else if n >= i32::from(Int8::MIN_VALUE) {
-        write_value(writer, Some(Value::Int8(Some(Int8::Value(n as i8)))))
-    } else if n >= i32::from(Int16::MIN_VALUE) {
-        write_value(writer, Some(Value::Int16(Some(Int16::Value(n as i16)))))
-    } else if n >= Int32::MIN_VALUE {
-        write_value(writer, Some(Value::Int32(Some(Int32::Value(n)))))
-    } else {
-        Err(io::Error::new(
-            io::ErrorKind::InvalidInput,
-            format!("invalid info field integer value: {n}"),
-        ))
-    }
+    let value = encode_integer(n)?;
+    write_value(writer, Some(value))
 }
 
 fn write_info_field_float_value<W>(writer: &mut W, n: f32) -> io::Result<()>
@@ -162,105 +151,8 @@ where
         ));
     }
 
-    let (mut min, mut max) = (i32::MAX, i32::MIN);
-
-    for value in values {
-        let n = value.unwrap_or_default();
-        min = cmp::min(min, n);
-        max = cmp::max(max, n);
-    }
-
-    if min >= i32::from(Int8::MIN_VALUE) {
-        if max <= i32::from(Int8::MAX_VALUE) {
-            write_info_field_int8_array_value(writer, values)
-        } else if max <= i32::from(Int16::MAX_VALUE) {
-            write_info_field_int16_array_value(writer, values)
-        } else {
-            write_info_field_int32_array_value(writer, values)
-        }
-    } else if min >= i32::from(Int16::MIN_VALUE) {
-        if max <= i32::from(Int16::MAX_VALUE) {
-            write_info_field_int16_array_value(writer, values)
-        } else {
-            write_info_field_int32_array_value(writer, values)
-        }
-    } else if min >= Int32::MIN_VALUE {
-        write_info_field_int32_array_value(writer, values)
-    } else {
-        Err(io::Error::new(
-            io::ErrorKind::InvalidInput,
-            format!("invalid info field integer array value: {min}"),
-        ))
-    }
-}
-
-fn write_info_field_int8_array_value<W>(writer: &mut W, values: &[Option<i32>]) -> io::Result<()>
-where
-    W: Write,
-{
-    let mut vs = Vec::with_capacity(values.len());
-
-    for value in values {
-        let v = match value {
-            Some(n) => i8::try_from(*n)
-                .map(Int8::from)
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
-            None => Int8::Missing,
-        };
-
-        let n = match v {
-            Int8::Value(n) => n,
-            Int8::Missing => i8::from(v),
-            _ => todo!("unhandled i16 array value: {:?}", v),
-        };
-
-        vs.push(n);
-    }
-
-    write_value(writer, Some(Value::Array(Array::Int8(vs))))
-}
-
-fn write_info_field_int16_array_value<W>(writer: &mut W, values: &[Option<i32>]) -> io::Result<()>
-where
-    W: Write,
-{
-    let mut vs = Vec::with_capacity(values.len());
-
-    for value in values {
-        let v = match value {
-            Some(n) => i16::try_from(*n)
-                .map(Int16::from)
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
-            None => Int16::Missing,
-        };
-
-        let n = match v {
-            Int16::Value(n) => n,
-            Int16::Missing => i16::from(v),
-            _ => todo!("unhandled i16 array value: {:?}", v),
-        };
-
-        vs.push(n);
-    }
-
-    write_value(writer, Some(Value::Array(Array::Int16(vs))))
-}
-
-fn write_info_field_int32_array_value<W>(writer: &mut W, values: &[Option<i32>]) -> io::Result<()>
-where
-    W: Write,
-{
-    let vs = values
-        .iter()
-        .map(|value| value.map(Int32::from).unwrap_or(Int32::Missing))
-        .map(|value| match value {
-            Int32::Value(n) => n,
-            Int32::Missing => i32::from(value),
-            _ => todo!("unhandled i32 array value: {:?}", value),
-        })
-        .collect();
-
-    write_value(writer, Some(Value::Array(Array::Int32(vs))))
+    let value = encode_integers(values)?;
+    write_value(writer, Some(value))
 }
 
 fn write_info_field_float_array_value<W>(writer: &mut W, values: &[Option<f32>]) -> io::Result<()>
@@ -269,13 +161,17 @@ where
 {
     let vs = values
         .iter()
-        .map(|value| value.map(Float::from).unwrap_or(Float::Missing))
-        .map(|value| match value {
-            Float::Value(n) => n,
-            Float::Missing => f32::from(value),
-            _ => todo!("unhandled f32 array value: {:?}", value),
-        })
-        .collect();
+        .map(
+            |value| match value.map(Float::from).unwrap_or(Float::Missing) {
+                Float::Value(n) => Ok(n),
+                v @ Float::Missing => Ok(f32::from(v)),
+                _ => Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid info field float array value: {value:?}"),
+                )),
+            },
+        )
+        .collect::<io::Result<_>>()?;
 
     write_value(writer, Some(Value::Array(Array::Float(vs))))
 }
@@ -329,6 +225,14 @@ where
 mod test {
     use super::*;
 
+    #[test]
+    fn test_write_info_field_value_with_missing_value() -> io::Result<()> {
+        let mut buf = Vec::new();
+        write_info_field_value(&mut buf, None)?;
+        assert_eq!(buf, [0x00]);
+        Ok(())
+    }
+
     #[test]
     fn test_write_info_field_value_with_integer_value() -> io::Result<()> {
         use vcf::record::info::field;
@@ -642,4 +546,45 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_info_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::record::info::field::Value;
+
+        use crate::{header::StringMaps, record::codec::decoder::read_info};
+
+        let header: vcf::Header = r#"##fileformat=VCFv4.3
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples">
+##INFO=<ID=AD,Number=R,Type=Integer,Description="Total read depth for each allele">
+##INFO=<ID=AF,Number=A,Type=Float,Description="Allele frequency">
+##INFO=<ID=CS,Number=.,Type=Character,Description="Characters">
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#
+        .parse()?;
+
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let info: vcf::record::Info = [
+            ("DP".parse()?, None),
+            (
+                "AD".parse()?,
+                Some(Value::from(vec![Some(8), None, Some(40000)])),
+            ),
+            ("AF".parse()?, Some(Value::from(vec![Some(0.25), None]))),
+            ("CS".parse()?, Some(Value::from(vec![Some('n'), None]))),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut buf = Vec::new();
+        write_info(&mut buf, string_maps.strings(), &info)?;
+
+        let mut src = &buf[..];
+        let actual = read_info(&mut src, header.infos(), string_maps.strings(), info.len())?;
+
+        assert_eq!(actual, info);
+        assert!(src.is_empty());
+
+        Ok(())
+    }
 }
@@ -3,7 +3,7 @@ use std::{
     io::{self, Write},
 };
 
-use super::value::write_value;
+use super::value::{smallest_int_type, write_value, IntType};
 use crate::lazy::record::{
     value::{Array, Int16, Int32, Int8},
     Value,
@@ -13,17 +13,17 @@ pub fn write_string_map_index<W>(writer: &mut W, i: usize) -> io::Result<()>
 where
     W: Write,
 {
-    if let Ok(j) = i8::try_from(i) {
-        write_value(writer, Some(Value::Int8(Some(Int8::Value(j)))))
-    } else if let Ok(j) = i16::try_from(i) {
-        write_value(writer, Some(Value::Int16(Some(Int16::Value(j)))))
-    } else if let Ok(j) = i32::try_from(i) {
-        write_value(writer, Some(Value::Int32(Some(Int32::Value(j)))))
-    } else {
-        Err(io::Error::new(
-            io::ErrorKind::InvalidInput,
-            format!("invalid index: {i}"),
-        ))
+    let j = i32::try_from(i)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, format!("invalid index: {i}")))?;
+
+    match smallest_int_type(j) {
+        Some(IntType::Int8) => write_value(writer, Some(Value::Int8(Some(Int8::Value(j as i8))))),
+        Some(IntType::Int16) => {
+            write_value(writer, Some(Value::Int16(Some(Int16::Value(j as i16)))))
+        }
+        Some(IntType::Int32) | None => {
+            write_value(writer, Some(Value::Int32(Some(Int32::Value(j)))))
+        }
     }
 }
 
@@ -46,15 +46,20 @@ where
                 max = cmp::max(max, j);
             }
 
-            if max <= i32::from(Int8::MAX_VALUE) {
-                let is = indices.iter().map(|&i| i as i8).collect();
-                write_value(writer, Some(Value::Array(Array::Int8(is))))
-            } else if max <= i32::from(Int16::MAX_VALUE) {
-                let is = indices.iter().map(|&i| i as i16).collect();
-                write_value(writer, Some(Value::Array(Array::Int16(is))))
-            } else {
-                let is = indices.iter().map(|&i| i as i32).collect();
-                write_value(writer, Some(Value::Array(Array::Int32(is))))
+            match smallest_int_type(max) {
+                Some(IntType::Int8) => {
+                    let is = indices.iter().map(|&i| i as i8).collect();
+                    write_value(writer, Some(Value::Array(Array::Int8(is))))
+                }
+                Some(IntType::Int16) => {
+                    let is = indices.iter().map(|&i| i as i16).collect();
+                    write_value(writer, Some(Value::Array(Array::Int16(is))))
+                }
+                Some(IntType::Int32) => {
+                    let is = indices.iter().map(|&i| i as i32).collect();
+                    write_value(writer, Some(Value::Array(Array::Int32(is))))
+                }
+                None => unreachable!(),
             }
         }
     }
@@ -2,12 +2,110 @@ mod ty;
 
 pub use self::ty::write_type;
 
-use std::io::{self, Write};
+use std::{
+    cmp,
+    io::{self, Write},
+};
 
 use byteorder::{LittleEndian, WriteBytesExt};
 
 use crate::lazy::record::value::{Array, Float, Int16, Int32, Int8, Type, Value};
 
+/// A BCF integer type.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum IntType {
+    Int8,
+    Int16,
+    Int32,
+}
+
+/// Returns the smallest BCF integer type that can represent the given value.
+///
+/// The smallest values of each type are reserved for sentinels (e.g., missing and end of vector)
+/// and cannot represent a value. This returns `None` if the value is in the reserved range of
+/// `Int32`.
+pub fn smallest_int_type(n: i32) -> Option<IntType> {
+    if n >= i32::from(Int8::MIN_VALUE) && n <= i32::from(Int8::MAX_VALUE) {
+        Some(IntType::Int8)
+    } else if n >= i32::from(Int16::MIN_VALUE) && n <= i32::from(Int16::MAX_VALUE) {
+        Some(IntType::Int16)
+    } else if n >= Int32::MIN_VALUE {
+        Some(IntType::Int32)
+    } else {
+        None
+    }
+}
+
+/// Returns the smallest BCF integer type that can represent all values in the given range.
+pub fn smallest_int_type_for_range(min: i32, max: i32) -> Option<IntType> {
+    let min_type = smallest_int_type(min)?;
+    let max_type = smallest_int_type(max)?;
+    Some(cmp::max(min_type, max_type))
+}
+
+/// Encodes an integer using the smallest BCF integer type that can represent it.
+///
+/// An error is returned if the value is in the reserved range of `Int32`.
+pub fn encode_integer(n: i32) -> io::Result<Value<'static>> {
+    match smallest_int_type(n) {
+        Some(IntType::Int8) => Ok(Value::Int8(Some(Int8::Value(n as i8)))),
+        Some(IntType::Int16) => Ok(Value::Int16(Some(Int16::Value(n as i16)))),
+        Some(IntType::Int32) => Ok(Value::Int32(Some(Int32::Value(n)))),
+        None => Err(invalid_integer_value_error(n)),
+    }
+}
+
+/// Encodes integers using the smallest BCF integer type that can represent all values.
+///
+/// Missing values are encoded as the missing sentinel of the type. An error is returned if a value
+/// is in the reserved range of `Int32`.
+pub fn encode_integers(values: &[Option<i32>]) -> io::Result<Value<'static>> {
+    let (min, max) = values
+        .iter()
+        .flatten()
+        .fold((i32::MAX, i32::MIN), |(min, max), &n| {
+            (cmp::min(min, n), cmp::max(max, n))
+        });
+
+    // An array with only missing values uses the smallest type.
+    let ty = if min > max {
+        Some(IntType::Int8)
+    } else {
+        smallest_int_type_for_range(min, max)
+    };
+
+    let array = match ty {
+        Some(IntType::Int8) => Array::Int8(
+            values
+                .iter()
+                .map(|value| value.map(|n| n as i8).unwrap_or(i8::from(Int8::Missing)))
+                .collect(),
+        ),
+        Some(IntType::Int16) => Array::Int16(
+            values
+                .iter()
+                .map(|value| value.map(|n| n as i16).unwrap_or(i16::from(Int16::Missing)))
+                .collect(),
+        ),
+        Some(IntType::Int32) => Array::Int32(
+            values
+                .iter()
+                .map(|value| value.unwrap_or(i32::from(Int32::Missing)))
+                .collect(),
+        ),
+        None => return Err(invalid_integer_value_error(min)),
+    };
+
+    Ok(Value::Array(array))
+}
+
+fn invalid_integer_value_error(n: i32) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("invalid integer value: {n}"),
+    )
+}
+
 pub fn write_value<W>(writer: &mut W, value: Option<Value<'_>>) -> io::Result<()>
 where
     W: Write,
@@ -174,6 +272,100 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_smallest_int_type() {
+        assert_eq!(smallest_int_type(0), Some(IntType::Int8));
+
+        assert_eq!(smallest_int_type(i32::from(i8::MAX)), Some(IntType::Int8));
+        assert_eq!(
+            smallest_int_type(i32::from(i8::MAX) + 1),
+            Some(IntType::Int16)
+        );
+        assert_eq!(smallest_int_type(i32::from(i16::MAX)), Some(IntType::Int16));
+        assert_eq!(
+            smallest_int_type(i32::from(i16::MAX) + 1),
+            Some(IntType::Int32)
+        );
+        assert_eq!(smallest_int_type(i32::MAX), Some(IntType::Int32));
+
+        // -128 (0x80), -127 (0x81), ..., -121 are reserved in Int8.
+        assert_eq!(smallest_int_type(-120), Some(IntType::Int8));
+        assert_eq!(smallest_int_type(-121), Some(IntType::Int16));
+        assert_eq!(smallest_int_type(-128), Some(IntType::Int16));
+
+        // -32768 (0x8000), -32767 (0x8001), ..., -32761 are reserved in Int16.
+        assert_eq!(smallest_int_type(-32760), Some(IntType::Int16));
+        assert_eq!(smallest_int_type(-32761), Some(IntType::Int32));
+
+        // -2147483648 (0x80000000), -2147483647 (0x80000001), ..., -2147483641 are reserved in
+        // Int32.
+        assert_eq!(smallest_int_type(-2147483640), Some(IntType::Int32));
+        assert_eq!(smallest_int_type(-2147483641), None);
+        assert_eq!(smallest_int_type(i32::MIN), None);
+    }
+
+    #[test]
+    fn test_smallest_int_type_for_range() {
+        assert_eq!(smallest_int_type_for_range(0, 0), Some(IntType::Int8));
+        assert_eq!(smallest_int_type_for_range(-120, 127), Some(IntType::Int8));
+        assert_eq!(smallest_int_type_for_range(-121, 127), Some(IntType::Int16));
+        assert_eq!(smallest_int_type_for_range(0, 128), Some(IntType::Int16));
+        assert_eq!(smallest_int_type_for_range(-32761, 0), Some(IntType::Int32));
+        assert_eq!(smallest_int_type_for_range(i32::MIN, 0), None);
+    }
+
+    #[test]
+    fn test_encode_integer() -> io::Result<()> {
+        assert_eq!(encode_integer(0)?, Value::Int8(Some(Int8::Value(0))));
+        assert_eq!(encode_integer(-120)?, Value::Int8(Some(Int8::Value(-120))));
+        assert_eq!(
+            encode_integer(-121)?,
+            Value::Int16(Some(Int16::Value(-121)))
+        );
+        assert_eq!(encode_integer(128)?, Value::Int16(Some(Int16::Value(128))));
+        assert_eq!(
+            encode_integer(32768)?,
+            Value::Int32(Some(Int32::Value(32768)))
+        );
+
+        assert!(matches!(
+            encode_integer(i32::MIN),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_integers() -> io::Result<()> {
+        assert_eq!(
+            encode_integers(&[Some(-120), None, Some(127)])?,
+            Value::Array(Array::Int8(vec![-120, i8::from(Int8::Missing), 127]))
+        );
+        assert_eq!(
+            encode_integers(&[None, Some(128)])?,
+            Value::Array(Array::Int16(vec![i16::from(Int16::Missing), 128]))
+        );
+        assert_eq!(
+            encode_integers(&[Some(-121), Some(32768)])?,
+            Value::Array(Array::Int32(vec![-121, 32768]))
+        );
+        assert_eq!(
+            encode_integers(&[None, None])?,
+            Value::Array(Array::Int8(vec![
+                i8::from(Int8::Missing),
+                i8::from(Int8::Missing)
+            ]))
+        );
+
+        assert!(matches!(
+            encode_integers(&[Some(0), Some(i32::MIN)]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_value() -> io::Result<()> {
         let mut buf = Vec::new();
@@ -157,6 +157,32 @@ where
     pub fn try_finish(&mut self) -> io::Result<()> {
         self.inner.try_finish()
     }
+
+    /// Finishes the output stream and returns the underlying writer.
+    ///
+    /// This flushes any buffered data and writes the BGZF EOF block. Errors are returned, unlike
+    /// when the writer is dropped, which also finishes the stream but ignores any errors. Not
+    /// finishing the stream, e.g., by leaking the writer, results in a truncated BCF file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = bcf::Writer::new(Vec::new());
+    ///
+    /// let header = vcf::Header::default();
+    /// writer.write_header(&header)?;
+    ///
+    /// let data = writer.finish()?;
+    /// assert!(!data.is_empty());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn finish(self) -> io::Result<W> {
+        self.inner.finish()
+    }
 }
 
 impl<W> From<W> for Writer<W> {
@@ -217,4 +243,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_finish() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::Reader;
+
+        let header = vcf::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        let data = writer.finish()?;
+
+        // BGZF EOF block
+        assert!(data.ends_with(&[
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ]));
+
+        let mut reader = Reader::new(&data[..]);
+        let actual = reader.read_header()?;
+        assert_eq!(actual, header);
+
+        Ok(())
+    }
 }
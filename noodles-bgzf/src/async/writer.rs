@@ -133,3 +133,38 @@ where
         Poll::Ready(Ok(()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use super::*;
+    use crate::writer::MAX_BUF_SIZE;
+
+    #[tokio::test]
+    async fn test_write_with_multiple_blocks() -> io::Result<()> {
+        let data: Vec<_> = (0..2 * MAX_BUF_SIZE + 8).map(|i| (i % 251) as u8).collect();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(&data).await?;
+        writer.shutdown().await?;
+
+        let buf = writer.into_inner();
+
+        let mut reader = crate::AsyncReader::new(&buf[..]);
+        let mut actual = Vec::new();
+        reader.read_to_end(&mut actual).await?;
+        assert_eq!(actual, data);
+
+        let mut reader = crate::Reader::new(&buf[..]);
+        let block_sizes: Vec<_> = reader
+            .blocks()
+            .map(|result| result.map(|block| block.data().len()))
+            .collect::<io::Result<_>>()?;
+
+        // The last block is the EOF block.
+        assert_eq!(block_sizes, [MAX_BUF_SIZE, MAX_BUF_SIZE, 8, 0]);
+
+        Ok(())
+    }
+}
@@ -28,12 +28,37 @@ struct Buffer {
 ///
 /// This is a basic multithreaded BGZF reader that uses a thread pool to decompress block data. It
 /// differs from a [`super::Reader`] with > 1 worker by placing the inner reader on its own thread
-/// to read the raw frames asynchronously.
-#[doc(hidden)]
+/// to read the raw frames asynchronously. Decompressed blocks are returned in stream order.
+///
+/// The reader tracks block boundaries, so [`Self::virtual_position`] is valid for the data
+/// returned, e.g., to build an index. Seeking is not supported, as the inner reader is owned by
+/// the reader thread. Use a [`super::Reader`] for random access.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Read, Write};
+/// use std::num::NonZeroUsize;
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let data = writer.finish()?;
+///
+/// let worker_count = NonZeroUsize::try_from(2)?;
+/// let mut reader = bgzf::MultithreadedReader::with_worker_count(worker_count, io::Cursor::new(data));
+///
+/// let mut buf = Vec::new();
+/// reader.read_to_end(&mut buf)?;
+/// assert_eq!(buf, b"noodles");
+///
+/// reader.finish()?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
 pub struct MultithreadedReader {
     reader_handle: Option<JoinHandle<io::Result<()>>>,
     inflater_handles: Vec<JoinHandle<()>>,
-    read_rx: ReadRx,
+    read_rx: Option<ReadRx>,
     recycle_tx: Option<RecycleTx>,
     position: u64,
     buffer: Buffer,
@@ -59,7 +84,7 @@ impl MultithreadedReader {
         Self {
             reader_handle: Some(reader_handle),
             inflater_handles,
-            read_rx,
+            read_rx: Some(read_rx),
             recycle_tx: Some(recycle_tx),
             position: 0,
             buffer: Buffer::default(),
@@ -67,6 +92,8 @@ impl MultithreadedReader {
     }
 
     /// Returns the current position of the stream.
+    ///
+    /// This is the compressed position at the end of the current block.
     pub fn position(&self) -> u64 {
         self.position
     }
@@ -77,8 +104,12 @@ impl MultithreadedReader {
     }
 
     /// Shuts down the reader and inflate workers.
+    ///
+    /// This can be called before the end of the stream is reached. Any error from the reader
+    /// thread that was not already returned by a read is returned here.
     pub fn finish(&mut self) -> io::Result<()> {
         self.recycle_tx.take();
+        self.read_rx.take();
 
         for handle in self.inflater_handles.drain(..) {
             handle.join().unwrap();
@@ -92,12 +123,19 @@ impl MultithreadedReader {
     }
 
     fn recv_buffer(&mut self) -> io::Result<Option<Buffer>> {
-        if let Ok(buffered_rx) = self.read_rx.recv() {
-            if let Ok(buffer) = buffered_rx.recv() {
-                return buffer.map(Some);
+        if let Some(read_rx) = &self.read_rx {
+            if let Ok(buffered_rx) = read_rx.recv() {
+                if let Ok(buffer) = buffered_rx.recv() {
+                    return buffer.map(Some);
+                }
             }
         }
 
+        // The reader thread hung up. Surface its error, if any, rather than treating it as EOF.
+        if let Some(handle) = self.reader_handle.take() {
+            handle.join().unwrap()?;
+        }
+
         Ok(None)
     }
 
@@ -166,8 +204,10 @@ where
 
             let (buffered_tx, buffered_rx) = crossbeam_channel::bounded(1);
 
-            inflate_tx.send((buffer, buffered_tx)).unwrap();
-            read_tx.send(buffered_rx).unwrap();
+            if inflate_tx.send((buffer, buffered_tx)).is_err() || read_tx.send(buffered_rx).is_err()
+            {
+                break;
+            }
         }
 
         Ok(())
@@ -184,9 +224,115 @@ fn spawn_inflaters(worker_count: NonZeroUsize, inflate_rx: InflateRx) -> Vec<Joi
             thread::spawn(move || {
                 while let Ok((mut buffer, buffered_tx)) = inflate_rx.recv() {
                     let result = parse_frame_into(&buffer.buf, &mut buffer.block).map(|_| buffer);
-                    buffered_tx.send(result).unwrap();
+                    buffered_tx.send(result).ok();
                 }
             })
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn build_data(block_count: usize) -> io::Result<Vec<u8>> {
+        let mut writer = crate::Writer::new(Vec::new());
+
+        for i in 0..block_count {
+            writer.write_all(&[b'n'; 8][..=i % 8])?;
+            writer.flush()?;
+        }
+
+        writer.finish()
+    }
+
+    #[test]
+    fn test_read() -> Result<(), Box<dyn std::error::Error>> {
+        let data = build_data(32)?;
+
+        let mut expected_reader = crate::Reader::new(&data[..]);
+        let mut expected = Vec::new();
+        expected_reader.read_to_end(&mut expected)?;
+
+        for worker_count in [1, 2, 5] {
+            let worker_count = NonZeroUsize::try_from(worker_count)?;
+
+            let mut expected_reader = crate::Reader::new(&data[..]);
+            let mut reader =
+                MultithreadedReader::with_worker_count(worker_count, io::Cursor::new(data.clone()));
+
+            let mut actual: Vec<u8> = Vec::new();
+
+            loop {
+                let expected_buf = expected_reader.fill_buf()?.to_vec();
+                let buf = reader.fill_buf()?.to_vec();
+                assert_eq!(buf, expected_buf);
+                assert_eq!(
+                    reader.virtual_position(),
+                    expected_reader.virtual_position()
+                );
+
+                if buf.is_empty() {
+                    break;
+                }
+
+                actual.extend(&buf);
+
+                reader.consume(1);
+                expected_reader.consume(1);
+                assert_eq!(
+                    reader.virtual_position(),
+                    expected_reader.virtual_position()
+                );
+
+                let len = buf.len() - 1;
+                reader.consume(len);
+                expected_reader.consume(len);
+            }
+
+            assert_eq!(actual, expected);
+            assert_eq!(reader.position(), data.len() as u64);
+
+            reader.finish()?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_truncated_stream() -> Result<(), Box<dyn std::error::Error>> {
+        let mut data = build_data(4)?;
+        data.truncate(data.len() - 4);
+
+        let worker_count = NonZeroUsize::try_from(2)?;
+        let mut reader =
+            MultithreadedReader::with_worker_count(worker_count, io::Cursor::new(data));
+
+        let mut buf = Vec::new();
+        assert!(matches!(
+            reader.read_to_end(&mut buf),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_before_eof() -> Result<(), Box<dyn std::error::Error>> {
+        let data = build_data(64)?;
+
+        let worker_count = NonZeroUsize::try_from(2)?;
+        let mut reader =
+            MultithreadedReader::with_worker_count(worker_count, io::Cursor::new(data));
+
+        let mut buf = [0; 1];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(buf, [b'n']);
+
+        reader.finish()?;
+
+        Ok(())
+    }
+}
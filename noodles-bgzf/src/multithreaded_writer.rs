@@ -1,43 +1,82 @@
+//! Multithreaded BGZF writer.
+
+mod builder;
+
+pub use self::builder::Builder;
+
 use std::{
     io::{self, Write},
     num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
     thread::{self, JoinHandle},
 };
 
 use bytes::{BufMut, Bytes, BytesMut};
 use crossbeam_channel::{Receiver, Sender};
 
-use super::gz;
+use super::{
+    gz, gzi,
+    writer::{CompressionLevel, CompressionLevelImpl},
+    VirtualPosition,
+};
 
-type BufferedTx = Sender<io::Result<Vec<u8>>>;
-type BufferedRx = Receiver<io::Result<Vec<u8>>>;
+type BufferedTx = Sender<io::Result<(Vec<u8>, usize)>>;
+type BufferedRx = Receiver<io::Result<(Vec<u8>, usize)>>;
 type DeflateTx = Sender<(Bytes, BufferedTx)>;
 type DeflateRx = Receiver<(Bytes, BufferedTx)>;
-type WriteTx = Sender<BufferedRx>;
-type WriteRx = Receiver<BufferedRx>;
+type WriteTx = Sender<WriteMessage>;
+type WriteRx = Receiver<WriteMessage>;
+
+enum WriteMessage {
+    Block(BufferedRx),
+    // Acknowledged after all preceding blocks are written and the inner writer is flushed.
+    Sync(Sender<()>),
+}
 
 /// A multithreaded BGZF writer.
 ///
 /// This is much more basic than [`super::Writer`] but uses a thread pool to compress block data.
+///
+/// Use a [`Builder`] to set the compression level or to build a gzip index (GZI) of the output.
 pub struct MultithreadedWriter {
-    writer_handle: Option<JoinHandle<io::Result<()>>>,
+    writer_handle: Option<JoinHandle<io::Result<Option<gzi::Index>>>>,
     deflater_handles: Vec<JoinHandle<()>>,
     buf: BytesMut,
     write_tx: Option<WriteTx>,
     deflate_tx: Option<DeflateTx>,
+    gzi_index: Option<gzi::Index>,
+    position: Arc<AtomicU64>,
 }
 
 impl MultithreadedWriter {
     /// Creates a multithreaded BGZF writer.
+    ///
+    /// The compression level is set to the default level. Use a [`Builder`] to change it.
     pub fn with_worker_count<W>(worker_count: NonZeroUsize, inner: W) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        Self::new(worker_count, CompressionLevel::default(), false, inner)
+    }
+
+    fn new<W>(
+        worker_count: NonZeroUsize,
+        compression_level: CompressionLevel,
+        build_gzi_index: bool,
+        inner: W,
+    ) -> Self
     where
         W: Write + Send + 'static,
     {
         let (write_tx, write_rx) = crossbeam_channel::bounded(worker_count.get());
         let (deflate_tx, deflate_rx) = crossbeam_channel::bounded(worker_count.get());
 
-        let writer_handle = spawn_writer(inner, write_rx);
-        let deflater_handles = spawn_deflaters(worker_count, deflate_rx);
+        let position = Arc::new(AtomicU64::new(0));
+        let writer_handle = spawn_writer(inner, build_gzi_index, position.clone(), write_rx);
+        let deflater_handles = spawn_deflaters(worker_count, compression_level.into(), deflate_rx);
 
         Self {
             writer_handle: Some(writer_handle),
@@ -45,9 +84,119 @@ impl MultithreadedWriter {
             buf: BytesMut::new(),
             write_tx: Some(write_tx),
             deflate_tx: Some(deflate_tx),
+            gzi_index: None,
+            position,
         }
     }
 
+    /// Returns the virtual position of the last block boundary written to the inner writer.
+    ///
+    /// Blocks are compressed and written asynchronously, so this only updates at block
+    /// boundaries after a block is written, and the uncompressed offset is always 0. Data that
+    /// is buffered or still being compressed is not included. After [`Self::finish`], this is
+    /// the position of the end of the data, i.e., the start of the EOF block.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the stream wrote >= 256 TiB of compressed data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let worker_count = NonZeroUsize::try_from(2)?;
+    /// let mut writer = bgzf::MultithreadedWriter::with_worker_count(worker_count, io::sink());
+    /// assert_eq!(writer.virtual_position(), bgzf::VirtualPosition::from(0));
+    ///
+    /// writer.write_all(b"noodles")?;
+    /// writer.finish()?;
+    ///
+    /// let virtual_position = writer.virtual_position();
+    /// assert!(virtual_position.compressed() > 0);
+    /// assert_eq!(virtual_position.uncompressed(), 0);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn virtual_position(&self) -> VirtualPosition {
+        let compressed_position = self.position.load(Ordering::Acquire);
+        VirtualPosition::try_from((compressed_position, 0)).unwrap()
+    }
+
+    /// Returns the gzip index (GZI) of the output.
+    ///
+    /// This is only available after the stream is finished and when the writer was built with
+    /// [`Builder::set_build_gzi_index`]. Otherwise, this returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::multithreaded_writer::Builder::default()
+    ///     .set_build_gzi_index(true)
+    ///     .build_with_writer(io::sink());
+    ///
+    /// writer.write_all(b"noodles")?;
+    /// writer.finish()?;
+    ///
+    /// assert_eq!(writer.gzi_index(), Some(&Vec::new()));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn gzi_index(&self) -> Option<&gzi::Index> {
+        self.gzi_index.as_ref()
+    }
+
+    /// Writes any buffered data as a block and waits until it is written to the inner writer.
+    ///
+    /// Unlike [`Write::flush`], which only queues the buffered data for compression, this blocks
+    /// until all queued blocks are written and the inner writer is flushed. Afterward,
+    /// [`Self::virtual_position`] is the start of the next block, e.g., to have a record start
+    /// on a block boundary. If the buffer is empty, no block is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let worker_count = NonZeroUsize::try_from(2)?;
+    /// let mut writer = bgzf::MultithreadedWriter::with_worker_count(worker_count, io::sink());
+    ///
+    /// writer.write_all(b"noodles")?;
+    /// writer.flush_block()?;
+    /// assert!(writer.virtual_position().compressed() > 0);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn flush_block(&mut self) -> io::Result<()> {
+        self.flush()?;
+
+        let (ack_tx, ack_rx) = crossbeam_channel::bounded(1);
+
+        let is_sent = self
+            .write_tx
+            .as_ref()
+            .map(|write_tx| write_tx.send(WriteMessage::Sync(ack_tx)).is_ok())
+            .unwrap_or(false);
+
+        if is_sent && ack_rx.recv().is_ok() {
+            return Ok(());
+        }
+
+        // The writer thread stopped, either because the stream is finished or an error occurred.
+        if let Some(handle) = self.writer_handle.take() {
+            self.gzi_index = handle.join().unwrap()?;
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::BrokenPipe,
+            "writer is finished",
+        ))
+    }
+
     /// Finishes the output stream by flushing any remaining buffers.
     ///
     /// This shuts down the writer and deflater workers and appends the final BGZF EOF block.
@@ -63,7 +212,7 @@ impl MultithreadedWriter {
         self.write_tx.take();
 
         if let Some(handle) = self.writer_handle.take() {
-            handle.join().unwrap()?;
+            self.gzi_index = handle.join().unwrap()?;
         }
 
         Ok(())
@@ -72,7 +221,11 @@ impl MultithreadedWriter {
     fn send(&mut self) -> io::Result<()> {
         let (buffered_tx, buffered_rx) = crossbeam_channel::bounded(1);
 
-        self.write_tx.as_ref().unwrap().send(buffered_rx).unwrap();
+        self.write_tx
+            .as_ref()
+            .unwrap()
+            .send(WriteMessage::Block(buffered_rx))
+            .unwrap();
 
         let src = self.buf.split().freeze();
         let message = (src, buffered_tx);
@@ -115,34 +268,76 @@ impl Write for MultithreadedWriter {
     }
 }
 
-fn spawn_writer<W>(mut writer: W, write_rx: WriteRx) -> JoinHandle<io::Result<()>>
+fn spawn_writer<W>(
+    mut writer: W,
+    build_gzi_index: bool,
+    position: Arc<AtomicU64>,
+    write_rx: WriteRx,
+) -> JoinHandle<io::Result<Option<gzi::Index>>>
 where
     W: Write + Send + 'static,
 {
     use super::writer::BGZF_EOF;
 
     thread::spawn(move || {
-        while let Ok(buffered_rx) = write_rx.recv() {
+        // Blocks are compressed out of order but written in order, so the offsets are only known
+        // here.
+        let mut gzi_index = if build_gzi_index {
+            Some(gzi::Index::new())
+        } else {
+            None
+        };
+
+        let mut compressed_position = 0;
+        let mut uncompressed_position = 0;
+
+        while let Ok(message) = write_rx.recv() {
+            let buffered_rx = match message {
+                WriteMessage::Block(buffered_rx) => buffered_rx,
+                WriteMessage::Sync(ack_tx) => {
+                    writer.flush()?;
+                    ack_tx.send(()).ok();
+                    continue;
+                }
+            };
+
             if let Ok(result) = buffered_rx.recv() {
-                let buf = result?;
+                let (buf, uncompressed_len) = result?;
+
+                // Like bgzip, the start of the first block, i.e., (0, 0), is implied.
+                if let Some(index) = gzi_index.as_mut() {
+                    if compressed_position > 0 {
+                        index.push((compressed_position, uncompressed_position));
+                    }
+                }
+
                 writer.write_all(&buf[..])?;
+
+                compressed_position += buf.len() as u64;
+                uncompressed_position += uncompressed_len as u64;
+
+                position.store(compressed_position, Ordering::Release);
             }
         }
 
         writer.write_all(BGZF_EOF)?;
 
-        Ok(())
+        Ok(gzi_index)
     })
 }
 
-fn spawn_deflaters(worker_count: NonZeroUsize, deflate_rx: DeflateRx) -> Vec<JoinHandle<()>> {
+fn spawn_deflaters(
+    worker_count: NonZeroUsize,
+    compression_level: CompressionLevelImpl,
+    deflate_rx: DeflateRx,
+) -> Vec<JoinHandle<()>> {
     (0..worker_count.get())
         .map(|_| {
             let deflate_rx = deflate_rx.clone();
 
             thread::spawn(move || {
                 while let Ok((src, buffered_tx)) = deflate_rx.recv() {
-                    let result = compress(&src);
+                    let result = compress(&src, compression_level).map(|buf| (buf, src.len()));
                     buffered_tx.send(result).ok();
                 }
             })
@@ -150,12 +345,12 @@ fn spawn_deflaters(worker_count: NonZeroUsize, deflate_rx: DeflateRx) -> Vec<Joi
         .collect()
 }
 
-fn compress(src: &[u8]) -> io::Result<Vec<u8>> {
+fn compress(src: &[u8], compression_level: CompressionLevelImpl) -> io::Result<Vec<u8>> {
     use super::{writer::deflate_data, BGZF_HEADER_SIZE};
 
     let mut dst = Vec::new();
 
-    let (cdata, crc32, _) = deflate_data(src, Default::default())?;
+    let (cdata, crc32, _) = deflate_data(src, compression_level)?;
 
     let block_size = BGZF_HEADER_SIZE + cdata.len() + gz::TRAILER_SIZE;
     put_header(&mut dst, block_size)?;
@@ -210,3 +405,145 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+    use crate::writer::BGZF_EOF;
+
+    fn write(builder: Builder, chunks: &[&[u8]]) -> io::Result<(Vec<u8>, Option<gzi::Index>)> {
+        use std::sync::{Arc, Mutex};
+
+        struct SharedWriter(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let dst = Arc::new(Mutex::new(Vec::new()));
+
+        let mut writer = builder
+            .set_worker_count(NonZeroUsize::new(2).unwrap())
+            .build_with_writer(SharedWriter(dst.clone()));
+
+        for chunk in chunks {
+            writer.write_all(chunk)?;
+            writer.flush()?;
+        }
+
+        writer.finish()?;
+
+        let buf = dst.lock().unwrap().clone();
+        Ok((buf, writer.gzi_index().cloned()))
+    }
+
+    #[test]
+    fn test_write_with_compression_level() -> Result<(), Box<dyn std::error::Error>> {
+        let src: Vec<u8> = b"noodles".iter().copied().cycle().take(1 << 17).collect();
+
+        let write_with_compression_level = |compression_level| {
+            let builder = Builder::default().set_compression_level(compression_level);
+            write(builder, &[&src]).map(|(buf, _)| buf)
+        };
+
+        let none = write_with_compression_level(CompressionLevel::none())?;
+        let fast = write_with_compression_level(CompressionLevel::fast())?;
+        let best = write_with_compression_level(CompressionLevel::best())?;
+
+        assert!(none.len() > src.len());
+        assert!(fast.len() < none.len());
+        assert!(best.len() <= fast.len());
+
+        for buf in [&none, &fast, &best] {
+            assert_eq!(buf[..12], best[..12]);
+            assert!(buf.ends_with(BGZF_EOF));
+
+            let mut reader = crate::Reader::new(&buf[..]);
+            let mut actual = Vec::new();
+            reader.read_to_end(&mut actual)?;
+            assert_eq!(actual, src);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gzi_index() -> io::Result<()> {
+        fn block_size(buf: &[u8]) -> u64 {
+            u64::from(u16::from_le_bytes([buf[16], buf[17]])) + 1
+        }
+
+        let chunks: [&[u8]; 3] = [b"noodles", b"bgzf", b"gzi"];
+
+        let (_, gzi_index) = write(Builder::default(), &chunks)?;
+        assert!(gzi_index.is_none());
+
+        let builder = Builder::default().set_build_gzi_index(true);
+        let (buf, gzi_index) = write(builder, &chunks)?;
+
+        let a = block_size(&buf);
+        let b = a + block_size(&buf[a as usize..]);
+        assert_eq!(gzi_index, Some(vec![(a, 7), (b, 11)]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_virtual_position() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer =
+            MultithreadedWriter::with_worker_count(NonZeroUsize::new(2).unwrap(), Vec::new());
+        assert_eq!(writer.virtual_position(), VirtualPosition::from(0));
+
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"bgzf")?;
+        writer.finish()?;
+
+        let (buf, _) = write(Builder::default(), &[b"noodles", b"bgzf"])?;
+        let compressed_position = (buf.len() - BGZF_EOF.len()) as u64;
+
+        assert_eq!(
+            writer.virtual_position(),
+            VirtualPosition::try_from((compressed_position, 0))?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flush_block() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer =
+            MultithreadedWriter::with_worker_count(NonZeroUsize::new(2).unwrap(), Vec::new());
+
+        writer.flush_block()?;
+        assert_eq!(writer.virtual_position(), VirtualPosition::from(0));
+
+        writer.write_all(b"noodles")?;
+        writer.flush_block()?;
+
+        let (buf, _) = write(Builder::default(), &[b"noodles"])?;
+        let compressed_position = (buf.len() - BGZF_EOF.len()) as u64;
+
+        assert_eq!(
+            writer.virtual_position(),
+            VirtualPosition::try_from((compressed_position, 0))?
+        );
+
+        writer.finish()?;
+
+        assert!(matches!(
+            writer.flush_block(),
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe
+        ));
+
+        Ok(())
+    }
+}
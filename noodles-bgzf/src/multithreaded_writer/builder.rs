@@ -0,0 +1,96 @@
+use std::{io::Write, num::NonZeroUsize, thread};
+
+use super::MultithreadedWriter;
+use crate::writer::CompressionLevel;
+
+/// A multithreaded BGZF writer builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    compression_level: Option<CompressionLevel>,
+    worker_count: Option<NonZeroUsize>,
+    build_gzi_index: bool,
+}
+
+impl Builder {
+    /// Sets a compression level.
+    ///
+    /// By default, the compression level is set to level 6.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, writer::CompressionLevel};
+    ///
+    /// let builder = bgzf::multithreaded_writer::Builder::default()
+    ///     .set_compression_level(CompressionLevel::best());
+    /// ```
+    pub fn set_compression_level(mut self, compression_level: CompressionLevel) -> Self {
+        self.compression_level = Some(compression_level);
+        self
+    }
+
+    /// Sets a worker count.
+    ///
+    /// By default, the worker count is set to the number of available logical CPUs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let worker_count = NonZeroUsize::try_from(1)?;
+    /// let builder = bgzf::multithreaded_writer::Builder::default()
+    ///     .set_worker_count(worker_count);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = Some(worker_count);
+        self
+    }
+
+    /// Sets whether to build a gzip index (GZI) of the output.
+    ///
+    /// The index is available from [`MultithreadedWriter::gzi_index`] after the stream is
+    /// finished. By default, no index is built.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let builder = bgzf::multithreaded_writer::Builder::default().set_build_gzi_index(true);
+    /// ```
+    pub fn set_build_gzi_index(mut self, build_gzi_index: bool) -> Self {
+        self.build_gzi_index = build_gzi_index;
+        self
+    }
+
+    /// Builds a multithreaded BGZF writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let writer = bgzf::multithreaded_writer::Builder::default()
+    ///     .build_with_writer(io::sink());
+    /// ```
+    pub fn build_with_writer<W>(self, writer: W) -> MultithreadedWriter
+    where
+        W: Write + Send + 'static,
+    {
+        let compression_level = self.compression_level.unwrap_or_default();
+
+        let worker_count = self.worker_count.unwrap_or_else(|| {
+            thread::available_parallelism().unwrap_or_else(|_| NonZeroUsize::new(1).unwrap())
+        });
+
+        MultithreadedWriter::new(
+            worker_count,
+            compression_level,
+            self.build_gzi_index,
+            writer,
+        )
+    }
+}
@@ -1,9 +1,11 @@
 //! BGZF reader.
 
 pub(crate) mod block;
+pub mod blocks;
 mod builder;
+mod on_trailing_data;
 
-pub use self::builder::Builder;
+pub use self::{blocks::Blocks, builder::Builder, on_trailing_data::OnTrailingData};
 
 use std::io::{self, BufRead, Read, Seek, SeekFrom};
 
@@ -119,6 +121,43 @@ where
         self.block.virtual_position()
     }
 
+    /// Returns an iterator over the blocks of the stream, starting from the next block.
+    ///
+    /// Each item is a decompressed block, which includes the virtual position at the start of the
+    /// block and the size of the compressed block. Blocks with no uncompressed data, e.g., EOF
+    /// markers, including those between members of concatenated files, are also returned.
+    ///
+    /// Any data remaining in the current block is discarded. After the iterator is exhausted or
+    /// dropped, the reader resumes at the block following the last one returned.
+    ///
+    /// The iterator stops after the first error, e.g., when a block cannot be inflated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// writer.write_all(b"noodles")?;
+    /// let data = writer.finish()?;
+    ///
+    /// let mut reader = bgzf::Reader::new(&data[..]);
+    /// let blocks: Vec<_> = reader.blocks().collect::<io::Result<_>>()?;
+    ///
+    /// assert_eq!(blocks.len(), 2);
+    ///
+    /// assert_eq!(blocks[0].virtual_position(), bgzf::VirtualPosition::from(0));
+    /// assert_eq!(blocks[0].data(), b"noodles");
+    ///
+    /// // EOF block
+    /// assert!(blocks[1].data().is_empty());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn blocks(&mut self) -> Blocks<'_, R> {
+        Blocks::new(self)
+    }
+
     fn read_block(&mut self) -> io::Result<()> {
         while let Some(mut block) = self.inner.next_block()? {
             block.set_position(self.position);
@@ -353,4 +392,175 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_blocks() -> io::Result<()> {
+        #[rustfmt::skip]
+        let data = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+            // EOF block
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // block 1 (b"bgzf")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1f, 0x00, 0x4b, 0x4a, 0xaf, 0x4a, 0x03, 0x00, 0x20, 0x68, 0xf2, 0x8c,
+            0x04, 0x00, 0x00, 0x00,
+            // EOF block
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let mut reader = Reader::new(&data[..]);
+        let actual: Vec<_> = reader
+            .blocks()
+            .map(|result| {
+                result.map(|block| (block.virtual_position(), block.size(), block.into_data()))
+            })
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            (VirtualPosition::from(0), 35, b"noodles".to_vec()),
+            (VirtualPosition::try_from((35, 0)).unwrap(), 28, Vec::new()),
+            (
+                VirtualPosition::try_from((63, 0)).unwrap(),
+                32,
+                b"bgzf".to_vec(),
+            ),
+            (VirtualPosition::try_from((95, 0)).unwrap(), 28, Vec::new()),
+        ];
+
+        assert_eq!(actual, expected);
+        assert_eq!(
+            reader.virtual_position(),
+            VirtualPosition::try_from((123, 0)).unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_blocks_resumes_reader() -> io::Result<()> {
+        #[rustfmt::skip]
+        let data = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+            // block 1 (b"bgzf")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1f, 0x00, 0x4b, 0x4a, 0xaf, 0x4a, 0x03, 0x00, 0x20, 0x68, 0xf2, 0x8c,
+            0x04, 0x00, 0x00, 0x00,
+        ];
+
+        let mut reader = Reader::new(&data[..]);
+        let block = reader.blocks().next().transpose()?;
+        assert_eq!(block.map(|b| b.into_data()), Some(b"noodles".to_vec()));
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"bgzf");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_blocks_with_invalid_block() {
+        #[rustfmt::skip]
+        let data = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+            // block 1 (b"bgzf"; invalid CRC32)
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1f, 0x00, 0x4b, 0x4a, 0xaf, 0x4a, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x04, 0x00, 0x00, 0x00,
+            // EOF block
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let mut reader = Reader::new(&data[..]);
+        let mut blocks = reader.blocks();
+
+        assert!(matches!(blocks.next(), Some(Ok(_))));
+        assert!(matches!(
+            blocks.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(blocks.next().is_none());
+    }
+
+    #[test]
+    fn test_read_with_trailing_data() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use crate::writer::BGZF_EOF;
+
+        #[rustfmt::skip]
+        let block = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+        ];
+
+        fn read(
+            data: &[u8],
+            worker_count: usize,
+            on_trailing_data: OnTrailingData,
+        ) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+            let mut reader = Builder::default()
+                .set_worker_count(NonZeroUsize::try_from(worker_count)?)
+                .set_on_trailing_data(on_trailing_data)
+                .build_from_reader(data);
+
+            let mut buf = Vec::new();
+            reader.read_to_end(&mut buf)?;
+
+            Ok(buf)
+        }
+
+        let short_trailing_data = b"\nnoodles\n".to_vec();
+        let long_trailing_data = vec![0x1f; 64];
+
+        for worker_count in [1, 2] {
+            for trailing_data in [&short_trailing_data, &long_trailing_data] {
+                let mut data = block.to_vec();
+                data.extend(BGZF_EOF);
+                data.extend(trailing_data);
+
+                assert_eq!(
+                    read(&data, worker_count, OnTrailingData::Ignore)?,
+                    b"noodles"
+                );
+
+                let e = read(&data, worker_count, OnTrailingData::Error).unwrap_err();
+                let e = e.downcast_ref::<io::Error>().unwrap();
+                assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+                // trailing data not following an EOF block
+                let mut data = block.to_vec();
+                data.extend(trailing_data);
+                data.extend(BGZF_EOF);
+                assert!(read(&data, worker_count, OnTrailingData::Ignore).is_err());
+            }
+
+            // concatenated files
+            let mut data = block.to_vec();
+            data.extend(BGZF_EOF);
+            data.extend(block);
+            data.extend(BGZF_EOF);
+
+            assert_eq!(
+                read(&data, worker_count, OnTrailingData::Error)?,
+                b"noodlesnoodles"
+            );
+        }
+
+        Ok(())
+    }
 }
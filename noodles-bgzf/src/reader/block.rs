@@ -6,7 +6,8 @@ use std::io::{self, Read};
 use bytes::Buf;
 use flate2::Crc;
 
-use crate::{gz, Block, BGZF_HEADER_SIZE};
+use super::OnTrailingData;
+use crate::{gz, writer::BGZF_EOF, Block, BGZF_HEADER_SIZE};
 
 pub enum Inner<R> {
     Single(single::Reader<R>),
@@ -46,34 +47,30 @@ where
     }
 }
 
-pub(crate) fn read_frame<R>(reader: &mut R) -> io::Result<Option<Vec<u8>>>
+pub(crate) fn read_frame_into<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<Option<()>>
 where
     R: Read,
 {
-    let mut buf = vec![0; BGZF_HEADER_SIZE];
+    buf.resize(BGZF_HEADER_SIZE, 0);
 
-    if read_frame_into(reader, &mut buf)?.is_some() {
-        Ok(Some(buf))
-    } else {
-        Ok(None)
+    match reader.read_exact(buf) {
+        Ok(()) => {}
+        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
     }
+
+    read_frame_body_into(reader, buf)?;
+
+    Ok(Some(()))
 }
 
-pub(crate) fn read_frame_into<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<Option<()>>
+fn read_frame_body_into<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<()>
 where
     R: Read,
 {
     const MIN_FRAME_SIZE: usize = BGZF_HEADER_SIZE + gz::TRAILER_SIZE;
     const BSIZE_POSITION: usize = 16;
 
-    buf.resize(BGZF_HEADER_SIZE, 0);
-
-    match reader.read_exact(buf) {
-        Ok(()) => {}
-        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
-        Err(e) => return Err(e),
-    }
-
     let bsize = (&buf[BSIZE_POSITION..]).get_u16_le();
     let block_size = usize::from(bsize) + 1;
 
@@ -87,7 +84,107 @@ where
     buf.resize(block_size, 0);
     reader.read_exact(&mut buf[BGZF_HEADER_SIZE..])?;
 
-    Ok(Some(()))
+    Ok(())
+}
+
+/// A BGZF frame reader that handles data trailing an EOF block.
+///
+/// A frame following an EOF block is read as usual if it starts with a valid BGZF header, e.g.,
+/// in concatenated files. Otherwise, the remaining input is considered to be trailing data.
+#[derive(Debug, Default)]
+pub(crate) struct FrameReader {
+    on_trailing_data: OnTrailingData,
+    is_after_eof: bool,
+    is_done: bool,
+}
+
+impl FrameReader {
+    pub fn new(on_trailing_data: OnTrailingData) -> Self {
+        Self {
+            on_trailing_data,
+            is_after_eof: false,
+            is_done: false,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.is_after_eof = false;
+        self.is_done = false;
+    }
+
+    pub fn read_frame_into<R>(
+        &mut self,
+        reader: &mut R,
+        buf: &mut Vec<u8>,
+    ) -> io::Result<Option<()>>
+    where
+        R: Read,
+    {
+        if self.is_done {
+            return Ok(None);
+        }
+
+        let result = if self.is_after_eof {
+            self.read_frame_after_eof_into(reader, buf)
+        } else {
+            read_frame_into(reader, buf)
+        };
+
+        match result {
+            Ok(Some(())) => self.is_after_eof = buf[..] == BGZF_EOF[..],
+            Ok(None) => self.is_done = true,
+            Err(_) => {}
+        }
+
+        result
+    }
+
+    fn read_frame_after_eof_into<R>(
+        &self,
+        reader: &mut R,
+        buf: &mut Vec<u8>,
+    ) -> io::Result<Option<()>>
+    where
+        R: Read,
+    {
+        buf.resize(BGZF_HEADER_SIZE, 0);
+
+        let n = read_up_to(reader, buf)?;
+
+        if n == 0 {
+            return Ok(None);
+        } else if n < BGZF_HEADER_SIZE || !is_valid_header(&buf[..]) {
+            return match self.on_trailing_data {
+                OnTrailingData::Ignore => Ok(None),
+                OnTrailingData::Error => Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "unexpected trailing data after EOF block",
+                )),
+            };
+        }
+
+        read_frame_body_into(reader, buf)?;
+
+        Ok(Some(()))
+    }
+}
+
+fn read_up_to<R>(reader: &mut R, mut buf: &mut [u8]) -> io::Result<usize>
+where
+    R: Read,
+{
+    let len = buf.len();
+
+    while !buf.is_empty() {
+        match reader.read(buf) {
+            Ok(0) => break,
+            Ok(n) => buf = &mut buf[n..],
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(len - buf.len())
 }
 
 fn split_frame(buf: &[u8]) -> (&[u8], &[u8], &[u8]) {
@@ -206,7 +303,6 @@ fn inflate(src: &[u8], crc32: u32, dst: &mut [u8]) -> io::Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::writer::BGZF_EOF;
 
     #[test]
     fn test_parse_header() -> io::Result<()> {
@@ -249,15 +345,16 @@ mod tests {
     }
 
     #[test]
-    fn test_read_frame() -> Result<(), Box<dyn std::error::Error>> {
+    fn test_read_frame_into() -> Result<(), Box<dyn std::error::Error>> {
         let mut src = BGZF_EOF;
-        let buf = read_frame(&mut src)?.ok_or("invalid frame")?;
+        let mut buf = Vec::new();
+        read_frame_into(&mut src, &mut buf)?.ok_or("invalid frame")?;
         assert_eq!(buf, BGZF_EOF);
         Ok(())
     }
 
     #[test]
-    fn test_read_frame_with_invalid_block_size() {
+    fn test_read_frame_into_with_invalid_block_size() {
         let data = {
             let mut eof = BGZF_EOF.to_vec();
             // BSIZE = 0
@@ -267,6 +364,7 @@ mod tests {
         };
 
         let mut reader = &data[..];
-        assert!(read_frame(&mut reader).is_err());
+        let mut buf = Vec::new();
+        assert!(read_frame_into(&mut reader, &mut buf).is_err());
     }
 }
@@ -7,7 +7,8 @@ use std::{
 
 use crossbeam_channel::{Receiver, Sender};
 
-use crate::Block;
+use super::FrameReader;
+use crate::{reader::OnTrailingData, Block};
 
 type BufferedTx = Sender<io::Result<Block>>;
 type BufferedRx = Receiver<io::Result<Block>>;
@@ -16,6 +17,7 @@ type InflaterRx = Receiver<(Vec<u8>, BufferedTx)>;
 
 pub struct Reader<R> {
     inner: Option<R>,
+    frame_reader: FrameReader,
     inflater_tx: Option<InflaterTx>,
     inflater_handles: Vec<JoinHandle<()>>,
     queue: VecDeque<BufferedRx>,
@@ -44,7 +46,11 @@ impl<R> Reader<R>
 where
     R: Read,
 {
-    pub(crate) fn with_worker_count(worker_count: NonZeroUsize, inner: R) -> Self {
+    pub(crate) fn with_worker_count(
+        worker_count: NonZeroUsize,
+        inner: R,
+        on_trailing_data: OnTrailingData,
+    ) -> Self {
         let worker_count = worker_count.get();
 
         let (inflater_tx, inflater_rx) = crossbeam_channel::bounded(worker_count);
@@ -52,6 +58,7 @@ where
 
         Self {
             inner: Some(inner),
+            frame_reader: FrameReader::new(on_trailing_data),
             inflater_tx: Some(inflater_tx),
             inflater_handles,
             queue: VecDeque::with_capacity(worker_count),
@@ -66,6 +73,7 @@ where
     pub fn get_mut(&mut self) -> &mut R {
         self.queue.clear();
         self.is_eof = false;
+        self.frame_reader.reset();
         self.inner.as_mut().unwrap()
     }
 
@@ -88,13 +96,15 @@ where
     }
 
     fn fill_queue(&mut self) -> io::Result<()> {
-        use super::read_frame;
+        use crate::BGZF_HEADER_SIZE;
 
         let reader = self.inner.as_mut().unwrap();
 
         while self.queue.len() < self.queue.capacity() && !self.is_eof {
-            match read_frame(reader)? {
-                Some(buf) => {
+            let mut buf = vec![0; BGZF_HEADER_SIZE];
+
+            match self.frame_reader.read_frame_into(reader, &mut buf) {
+                Ok(Some(())) => {
                     let (buffered_tx, buffered_rx) = crossbeam_channel::bounded(1);
 
                     self.inflater_tx
@@ -105,7 +115,14 @@ where
 
                     self.queue.push_back(buffered_rx);
                 }
-                None => self.is_eof = true,
+                Ok(None) => self.is_eof = true,
+                Err(e) => {
+                    // The error is queued so that it is returned after the blocks before it.
+                    let (buffered_tx, buffered_rx) = crossbeam_channel::bounded(1);
+                    buffered_tx.send(Err(e)).unwrap();
+                    self.queue.push_back(buffered_rx);
+                    self.is_eof = true;
+                }
             }
         }
 
@@ -145,7 +162,8 @@ mod tests {
         use crate::writer::BGZF_EOF;
 
         let worker_count = NonZeroUsize::try_from(2)?;
-        let mut reader = Reader::with_worker_count(worker_count, BGZF_EOF);
+        let mut reader =
+            Reader::with_worker_count(worker_count, BGZF_EOF, OnTrailingData::default());
 
         reader.fill_queue()?;
 
@@ -1,9 +1,11 @@
 use std::io::{self, Read};
 
-use crate::Block;
+use super::FrameReader;
+use crate::{reader::OnTrailingData, Block};
 
 pub struct Reader<R> {
     inner: R,
+    frame_reader: FrameReader,
     buf: Vec<u8>,
 }
 
@@ -11,9 +13,10 @@ impl<R> Reader<R>
 where
     R: Read,
 {
-    pub fn new(inner: R) -> Self {
+    pub fn new(inner: R, on_trailing_data: OnTrailingData) -> Self {
         Self {
             inner,
+            frame_reader: FrameReader::new(on_trailing_data),
             buf: Vec::new(),
         }
     }
@@ -23,6 +26,7 @@ where
     }
 
     pub fn get_mut(&mut self) -> &mut R {
+        self.frame_reader.reset();
         &mut self.inner
     }
 
@@ -31,9 +35,13 @@ where
     }
 
     pub fn next_block(&mut self) -> io::Result<Option<Block>> {
-        use super::{parse_frame, read_frame_into};
+        use super::parse_frame;
 
-        if read_frame_into(&mut self.inner, &mut self.buf)?.is_some() {
+        if self
+            .frame_reader
+            .read_frame_into(&mut self.inner, &mut self.buf)?
+            .is_some()
+        {
             parse_frame(&self.buf).map(Some)
         } else {
             Ok(None)
@@ -0,0 +1,110 @@
+//! BGZF reader blocks iterator.
+
+use std::io::{self, Read};
+
+use super::Reader;
+use crate::VirtualPosition;
+
+/// A decompressed BGZF block.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Block {
+    virtual_position: VirtualPosition,
+    size: u64,
+    data: Vec<u8>,
+}
+
+impl Block {
+    /// Returns the virtual position at the start of the block.
+    ///
+    /// The uncompressed position of the start of a block is always 0.
+    pub fn virtual_position(&self) -> VirtualPosition {
+        self.virtual_position
+    }
+
+    /// Returns the size of the compressed block.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the uncompressed data.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the uncompressed data, consuming the block.
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+/// An iterator over blocks of a BGZF reader.
+///
+/// This is created by calling [`Reader::blocks`].
+pub struct Blocks<'r, R> {
+    reader: &'r mut Reader<R>,
+    is_done: bool,
+}
+
+impl<'r, R> Blocks<'r, R>
+where
+    R: Read,
+{
+    pub(super) fn new(reader: &'r mut Reader<R>) -> Self {
+        Self {
+            reader,
+            is_done: false,
+        }
+    }
+}
+
+impl<'r, R> Iterator for Blocks<'r, R>
+where
+    R: Read,
+{
+    type Item = io::Result<Block>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+
+        match self.reader.inner.next_block() {
+            Ok(Some(mut block)) => {
+                let pos = self.reader.position;
+
+                block.set_position(pos);
+                self.reader.position += block.size();
+
+                let data = block.data().as_ref().to_vec();
+                let size = block.size();
+
+                // The block is fully consumed so that the reader resumes at the next block.
+                let len = block.data().len();
+                block.data_mut().set_position(len);
+                self.reader.block = block;
+
+                let virtual_position = match VirtualPosition::try_from((pos, 0)) {
+                    Ok(virtual_position) => virtual_position,
+                    Err(e) => {
+                        self.is_done = true;
+                        return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e)));
+                    }
+                };
+
+                Some(Ok(Block {
+                    virtual_position,
+                    size,
+                    data,
+                }))
+            }
+            Ok(None) => {
+                self.is_done = true;
+                None
+            }
+            Err(e) => {
+                self.is_done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
@@ -5,7 +5,7 @@ use std::{
     path::Path,
 };
 
-use super::{block, Reader};
+use super::{block, OnTrailingData, Reader};
 use crate::Block;
 
 const DEFAULT_WORKER_COUNT: NonZeroUsize = match NonZeroUsize::new(1) {
@@ -17,6 +17,7 @@ const DEFAULT_WORKER_COUNT: NonZeroUsize = match NonZeroUsize::new(1) {
 #[derive(Debug)]
 pub struct Builder {
     worker_count: NonZeroUsize,
+    on_trailing_data: OnTrailingData,
 }
 
 impl Builder {
@@ -40,6 +41,22 @@ impl Builder {
         self
     }
 
+    /// Sets the behavior when data trails an EOF block.
+    ///
+    /// By default, trailing data is ignored, i.e., the EOF block is treated as the end of the
+    /// stream. BGZF blocks following an EOF block, e.g., in concatenated files, are still read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, reader::OnTrailingData};
+    /// let builder = bgzf::reader::Builder::default().set_on_trailing_data(OnTrailingData::Error);
+    /// ```
+    pub fn set_on_trailing_data(mut self, on_trailing_data: OnTrailingData) -> Self {
+        self.on_trailing_data = on_trailing_data;
+        self
+    }
+
     /// Builds a BGZF reader from a path.
     ///
     /// # Examples
@@ -72,11 +89,12 @@ impl Builder {
         R: Read,
     {
         let block_reader = if self.worker_count.get() == 1 {
-            block::Inner::Single(block::single::Reader::new(reader))
+            block::Inner::Single(block::single::Reader::new(reader, self.on_trailing_data))
         } else {
             block::Inner::Multi(block::multi::Reader::with_worker_count(
                 self.worker_count,
                 reader,
+                self.on_trailing_data,
             ))
         };
 
@@ -92,6 +110,7 @@ impl Default for Builder {
     fn default() -> Self {
         Self {
             worker_count: DEFAULT_WORKER_COUNT,
+            on_trailing_data: OnTrailingData::default(),
         }
     }
 }
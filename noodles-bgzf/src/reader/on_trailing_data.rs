@@ -0,0 +1,12 @@
+/// The behavior of a BGZF reader when data trails an EOF block.
+///
+/// Data following an EOF block that is not a BGZF block, e.g., appended by a concatenator, is
+/// trailing data. BGZF blocks following an EOF block, e.g., in concatenated files, are always read.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum OnTrailingData {
+    /// Ignore trailing data and treat the EOF block as the end of the stream.
+    #[default]
+    Ignore,
+    /// Return an error when trailing data is found.
+    Error,
+}
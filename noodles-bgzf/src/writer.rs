@@ -46,9 +46,9 @@ pub(crate) static BGZF_EOF: &[u8] = &[
 ];
 
 #[cfg(feature = "libdeflate")]
-type CompressionLevelImpl = libdeflater::CompressionLvl;
+pub(crate) type CompressionLevelImpl = libdeflater::CompressionLvl;
 #[cfg(not(feature = "libdeflate"))]
-type CompressionLevelImpl = flate2::Compression;
+pub(crate) type CompressionLevelImpl = flate2::Compression;
 
 /// A BZGF writer.
 ///
@@ -151,7 +151,35 @@ where
         VirtualPosition::try_from((self.position, uncompressed_position)).unwrap()
     }
 
-    fn flush_block(&mut self) -> io::Result<()> {
+    /// Writes any buffered data as a block and flushes the inner writer.
+    ///
+    /// Afterward, [`Self::virtual_position`] is the start of the next block, e.g., to have a
+    /// record start on a block boundary. If the buffer is empty, no block is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// writer.write_all(b"noodles")?;
+    /// writer.flush_block()?;
+    ///
+    /// let virtual_position = writer.virtual_position();
+    /// assert_eq!(virtual_position.compressed(), writer.get_ref().len() as u64);
+    /// assert_eq!(virtual_position.uncompressed(), 0);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn flush_block(&mut self) -> io::Result<()> {
+        if !self.buf.is_empty() {
+            self.write_block()?;
+        }
+
+        self.inner.as_mut().unwrap().flush()
+    }
+
+    fn write_block(&mut self) -> io::Result<()> {
         use self::frame::{write_header, write_trailer};
 
         let (cdata, crc32, r#isize) = deflate_data(&self.buf, self.compression_level)?;
@@ -251,7 +279,7 @@ where
         if self.buf.is_empty() {
             Ok(())
         } else {
-            self.flush_block()
+            self.write_block()
         }
     }
 }
@@ -324,6 +352,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_flush_block() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+
+        writer.flush_block()?;
+        assert!(writer.get_ref().is_empty());
+
+        writer.write_all(b"noodles")?;
+        writer.flush_block()?;
+
+        let position = writer.get_ref().len() as u64;
+        assert!(position > 0);
+        assert_eq!(
+            writer.virtual_position(),
+            VirtualPosition::from(position << 16)
+        );
+
+        writer.flush_block()?;
+        assert_eq!(writer.get_ref().len() as u64, position);
+
+        Ok(())
+    }
+
     #[test]
     fn test_finish() -> io::Result<()> {
         let mut writer = Writer::new(Vec::new());
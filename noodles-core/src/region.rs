@@ -1,6 +1,7 @@
 //! Genomic region.
 
 pub mod interval;
+pub mod name;
 
 pub use self::interval::Interval;
 
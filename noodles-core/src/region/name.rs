@@ -0,0 +1,153 @@
+//! Region reference sequence name normalization.
+//!
+//! Reference sequence names for the same assembly are commonly written with or without a `chr`
+//! prefix, e.g., `chr1` and `1`, and the mitochondrial sequence has several aliases, e.g., `chrM`
+//! and `MT`. These functions allow callers to opt in to treating such names as equivalent, e.g.,
+//! when resolving a region against a header or merging headers from different sources.
+//!
+//! Name comparisons elsewhere in noodles are exact.
+
+use std::borrow::Cow;
+
+const PREFIX: &str = "chr";
+const MITOCHONDRIAL_ALIASES: [&str; 2] = ["M", "MT"];
+const MITOCHONDRIAL_NAME: &str = "MT";
+
+/// Normalizes a reference sequence name.
+///
+/// This removes a `chr` prefix (case-insensitive) and maps the mitochondrial aliases (`M` and
+/// `MT`) to `MT`. Other names are returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::region::name::normalize;
+///
+/// assert_eq!(normalize("chr1"), "1");
+/// assert_eq!(normalize("1"), "1");
+/// assert_eq!(normalize("chrM"), "MT");
+/// assert_eq!(normalize("MT"), "MT");
+/// assert_eq!(normalize("sq0"), "sq0");
+/// ```
+pub fn normalize(name: &str) -> Cow<'_, str> {
+    let unprefixed = strip_prefix(name);
+
+    if MITOCHONDRIAL_ALIASES
+        .iter()
+        .any(|alias| unprefixed.eq_ignore_ascii_case(alias))
+    {
+        Cow::Owned(MITOCHONDRIAL_NAME.into())
+    } else {
+        Cow::Borrowed(unprefixed)
+    }
+}
+
+/// Returns whether two reference sequence names are equivalent after normalization.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::region::name::is_equivalent;
+///
+/// assert!(is_equivalent("chr1", "1"));
+/// assert!(is_equivalent("chrM", "MT"));
+/// assert!(!is_equivalent("chr1", "chr2"));
+/// ```
+pub fn is_equivalent(a: &str, b: &str) -> bool {
+    normalize(a) == normalize(b)
+}
+
+/// Finds the index of a reference sequence name in a list of names.
+///
+/// An exact match is preferred. Otherwise, the index of the only equivalent name is returned.
+/// If there are no or multiple equivalent names, this returns `None`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::region::name::find;
+///
+/// let names = ["chr1", "chr2", "chrM"];
+///
+/// assert_eq!(find(names, "chr2"), Some(1));
+/// assert_eq!(find(names, "2"), Some(1));
+/// assert_eq!(find(names, "MT"), Some(2));
+/// assert_eq!(find(names, "3"), None);
+/// ```
+pub fn find<'a, I>(names: I, name: &str) -> Option<usize>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let normalized_name = normalize(name);
+
+    let mut candidate = None;
+    let mut is_ambiguous = false;
+
+    for (i, n) in names.into_iter().enumerate() {
+        if n == name {
+            return Some(i);
+        } else if normalize(n) == normalized_name {
+            is_ambiguous = candidate.is_some();
+            candidate = Some(i);
+        }
+    }
+
+    if is_ambiguous {
+        None
+    } else {
+        candidate
+    }
+}
+
+fn strip_prefix(name: &str) -> &str {
+    match name.get(..PREFIX.len()) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(PREFIX) && name.len() > PREFIX.len() => {
+            &name[PREFIX.len()..]
+        }
+        _ => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("chr1"), "1");
+        assert_eq!(normalize("CHR1"), "1");
+        assert_eq!(normalize("1"), "1");
+        assert_eq!(normalize("chrX"), "X");
+        assert_eq!(normalize("chrM"), "MT");
+        assert_eq!(normalize("chrMT"), "MT");
+        assert_eq!(normalize("M"), "MT");
+        assert_eq!(normalize("MT"), "MT");
+        assert_eq!(normalize("chr"), "chr");
+        assert_eq!(normalize("sq0"), "sq0");
+    }
+
+    #[test]
+    fn test_is_equivalent() {
+        assert!(is_equivalent("chr1", "1"));
+        assert!(is_equivalent("1", "chr1"));
+        assert!(is_equivalent("chrM", "MT"));
+        assert!(is_equivalent("MT", "chrM"));
+        assert!(is_equivalent("chrM", "M"));
+
+        assert!(!is_equivalent("chr1", "chr10"));
+        assert!(!is_equivalent("chr1", "2"));
+    }
+
+    #[test]
+    fn test_find() {
+        let names = ["chr1", "chr2", "chrM"];
+        assert_eq!(find(names, "chr1"), Some(0));
+        assert_eq!(find(names, "1"), Some(0));
+        assert_eq!(find(names, "MT"), Some(2));
+        assert_eq!(find(names, "X"), None);
+
+        let names = ["chr1", "1"];
+        assert_eq!(find(names, "1"), Some(1));
+        assert_eq!(find(names, "CHR1"), None);
+    }
+}
@@ -0,0 +1,56 @@
+//! Compares the time to decode all records in a CRAM file to decoding only their alignment
+//! geometry.
+//!
+//! Records are not resolved in either case, so no reference sequence is needed. The reference
+//! spans of both passes are summed to show that they agree.
+
+use std::{env, io, time::Instant};
+
+use noodles_cram as cram;
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let start = Instant::now();
+    let (n, span) = run(&src, |slice, compression_header| {
+        slice.records(compression_header)
+    })?;
+    println!("records\t{n}\t{span}\t{:?}", start.elapsed());
+
+    let start = Instant::now();
+    let (n, span) = run(&src, |slice, compression_header| {
+        slice.geometry_records(compression_header)
+    })?;
+    println!("geometry_records\t{n}\t{span}\t{:?}", start.elapsed());
+
+    Ok(())
+}
+
+fn run<F>(src: &str, f: F) -> io::Result<(usize, usize)>
+where
+    F: Fn(
+        &cram::data_container::Slice,
+        &cram::data_container::CompressionHeader,
+    ) -> io::Result<Vec<cram::Record>>,
+{
+    let mut reader = cram::reader::Builder::default().build_from_path(src)?;
+    reader.read_header()?;
+
+    let mut n = 0;
+    let mut span = 0;
+
+    while let Some(container) = reader.read_data_container()? {
+        for slice in container.slices() {
+            for record in f(slice, container.compression_header())? {
+                if let (Some(start), Some(end)) = (record.alignment_start(), record.alignment_end())
+                {
+                    span += usize::from(end) - usize::from(start) + 1;
+                }
+
+                n += 1;
+            }
+        }
+    }
+
+    Ok((n, span))
+}
@@ -45,7 +45,7 @@ pub fn encode(compression_level: Compression, src: &[u8]) -> io::Result<Vec<u8>>
     let mut dst = vec![0; max_len];
 
     let len = encoder
-        .deflate_compress(src, &mut dst)
+        .gzip_compress(src, &mut dst)
         .map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
 
     dst.resize(len, 0);
@@ -63,3 +63,24 @@ pub fn encode(compression_level: Compression, src: &[u8]) -> io::Result<Vec<u8>>
     encoder.write_all(src)?;
     encoder.finish()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() -> io::Result<()> {
+        const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
+
+        let src = b"noodles";
+        let dst = encode(Compression::default(), src)?;
+
+        assert_eq!(dst[..2], GZIP_MAGIC_NUMBER);
+
+        let mut buf = vec![0; src.len()];
+        decode(&dst, &mut buf)?;
+        assert_eq!(buf, src);
+
+        Ok(())
+    }
+}
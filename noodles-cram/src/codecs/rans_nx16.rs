@@ -4,5 +4,86 @@ pub(crate) mod decode;
 pub(crate) mod encode;
 mod flags;
 
-pub use self::flags::Flags;
 pub(crate) use self::{decode::decode, encode::encode};
+pub use self::{
+    decode::{read_frequencies, Frequencies},
+    flags::Flags,
+};
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+
+    // A linear congruential generator for reproducible pseudorandom test data.
+    fn build_data(len: usize, alphabet_size: u32, mut seed: u32) -> Vec<u8> {
+        (0..len)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                ((seed >> 16) % alphabet_size) as u8
+            })
+            .collect()
+    }
+
+    fn build_inputs() -> Vec<Vec<u8>> {
+        let mut inputs = vec![
+            Vec::new(),
+            b"n".to_vec(),
+            b"noodles".to_vec(),
+            b"noooooooodles".to_vec(),
+            vec![0; 1024],
+            (0..=255).collect(),
+            b"ACGT".repeat(512),
+        ];
+
+        for (i, &alphabet_size) in [1, 2, 4, 16, 17, 256].iter().enumerate() {
+            for &len in &[3, 31, 33, 1000, 70000] {
+                inputs.push(build_data(len, alphabet_size, i as u32));
+            }
+        }
+
+        inputs
+    }
+
+    #[test]
+    fn test_self() -> io::Result<()> {
+        let flag_sets = [
+            Flags::empty(),
+            Flags::ORDER,
+            Flags::N32,
+            Flags::ORDER | Flags::N32,
+            Flags::STRIPE,
+            Flags::ORDER | Flags::STRIPE,
+            Flags::NO_SIZE,
+            Flags::ORDER | Flags::NO_SIZE,
+            Flags::CAT,
+            Flags::RLE,
+            Flags::ORDER | Flags::RLE,
+            Flags::PACK,
+            Flags::ORDER | Flags::PACK,
+            Flags::PACK | Flags::RLE,
+            Flags::ORDER | Flags::N32 | Flags::PACK | Flags::RLE,
+            Flags::CAT | Flags::PACK | Flags::RLE,
+        ];
+
+        for data in build_inputs() {
+            for &flags in &flag_sets {
+                let compressed_data = encode(flags, &data)?;
+
+                let mut reader = &compressed_data[..];
+                let decompressed_data = decode(&mut reader, data.len())?;
+
+                assert_eq!(
+                    decompressed_data,
+                    data,
+                    "flags = {flags:?}, len = {}",
+                    data.len()
+                );
+                assert!(reader.is_empty());
+            }
+        }
+
+        Ok(())
+    }
+}
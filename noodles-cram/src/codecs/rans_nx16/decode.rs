@@ -3,8 +3,10 @@ mod order_1;
 pub mod pack;
 mod rle;
 
+pub use self::order_0::{read_frequencies, Frequencies};
+
 use std::{
-    io::{self, Cursor, Read},
+    io::{self, Cursor, Read, Write},
     num::NonZeroUsize,
 };
 
@@ -13,9 +15,27 @@ use byteorder::{LittleEndian, ReadBytesExt};
 use super::Flags;
 use crate::reader::num::read_uint7;
 
-pub fn decode<R>(reader: &mut R, mut len: usize) -> io::Result<Vec<u8>>
+// The size of the buffer used to write interleaved stripes.
+const STRIPE_CHUNK_SIZE: usize = 1 << 16;
+
+pub fn decode<R>(reader: &mut R, len: usize) -> io::Result<Vec<u8>>
+where
+    R: Read,
+{
+    let mut dst = Vec::new();
+    decode_into(reader, &mut dst, len)?;
+    Ok(dst)
+}
+
+/// Decodes rANS Nx16-encoded data and writes the decoded data to the given writer.
+///
+/// Unlike [`decode`], this does not hold the entire output in memory when the data is either
+/// uncompressed (`CAT`) or striped (`STRIPE`). Uncompressed data is copied directly to the writer,
+/// and the stripes of striped data are interleaved and written in chunks.
+pub fn decode_into<R, W>(reader: &mut R, writer: &mut W, mut len: usize) -> io::Result<()>
 where
     R: Read,
+    W: Write,
 {
     let flags = reader.read_u8().map(Flags::from)?;
 
@@ -28,7 +48,11 @@ where
     let n = if flags.contains(Flags::N32) { 32 } else { 4 };
 
     if flags.contains(Flags::STRIPE) {
-        return rans_decode_stripe(reader, len, n);
+        return rans_decode_stripe(reader, writer, len);
+    }
+
+    if flags.contains(Flags::CAT) && !flags.contains(Flags::PACK) && !flags.contains(Flags::RLE) {
+        return copy_uncompressed(reader, writer, len);
     }
 
     let mut p = None;
@@ -75,7 +99,24 @@ where
         data = pack::decode(&data, &p, n_sym, pack_len)?;
     }
 
-    Ok(data)
+    writer.write_all(&data)
+}
+
+fn copy_uncompressed<R, W>(reader: &mut R, writer: &mut W, len: usize) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+{
+    let expected_len =
+        u64::try_from(len).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let actual_len = io::copy(&mut reader.take(expected_len), writer)?;
+
+    if actual_len == expected_len {
+        Ok(())
+    } else {
+        Err(io::Error::from(io::ErrorKind::UnexpectedEof))
+    }
 }
 
 fn read_alphabet<R>(reader: &mut R) -> io::Result<[bool; 256]>
@@ -141,46 +182,84 @@ where
     Ok(r)
 }
 
-fn rans_decode_stripe<R>(reader: &mut R, len: usize, n: u32) -> io::Result<Vec<u8>>
+// Renormalizes the state at index `j` after decoding the symbol at output offset `i`.
+//
+// A renormalization failure is typically caused by a truncated stream, so the error includes
+// which interleaved state failed and where.
+fn rans_renorm_nx16_at<R>(reader: &mut R, r: u32, j: usize, i: usize) -> io::Result<u32>
 where
     R: Read,
+{
+    rans_renorm_nx16(reader, r).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("rANS state {j} failed to renormalize at output offset {i}: {e}"),
+        )
+    })
+}
+
+fn rans_decode_stripe<R, W>(reader: &mut R, writer: &mut W, len: usize) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
 {
     let x = reader.read_u8().map(usize::from)?;
-    let mut clens = Vec::with_capacity(x);
 
-    for _ in 0..x {
-        let clen = read_uint7(reader).and_then(|n| {
-            usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-        })?;
+    if x == 0 {
+        return if len == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid stripe count",
+            ))
+        };
+    }
 
-        clens.push(clen);
+    for _ in 0..x {
+        // clen
+        read_uint7(reader)?;
     }
 
-    let mut ulens = Vec::with_capacity(x);
     let mut t = Vec::with_capacity(x);
 
     for j in 0..x {
         let mut ulen = len / x;
 
-        if len % (n as usize) > j {
+        if len % x > j {
             ulen += 1;
         }
 
         let chunk = decode(reader, ulen)?;
 
-        ulens.push(ulen);
+        if chunk.len() != ulen {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "stripe length mismatch",
+            ));
+        }
+
         t.push(chunk);
     }
 
-    let mut dst = vec![0; len];
+    // The stripes are interleaved, i.e., `dst[i * x + j] = t[j][i]`. Rather than materializing
+    // the entire output, the interleaved data is written in chunks.
+    let mut buf = Vec::with_capacity(STRIPE_CHUNK_SIZE.min(len));
 
-    for j in 0..x {
-        for i in 0..ulens[j] {
-            dst[i * (n as usize) + j] = t[j][i];
+    for i in 0..t[0].len() {
+        for stripe in &t {
+            if let Some(&b) = stripe.get(i) {
+                buf.push(b);
+            }
+        }
+
+        if buf.len() >= STRIPE_CHUNK_SIZE {
+            writer.write_all(&buf)?;
+            buf.clear();
         }
     }
 
-    Ok(dst)
+    writer.write_all(&buf)
 }
 
 fn decode_rle_meta<R>(reader: &mut R, n: u32) -> io::Result<([bool; 256], Cursor<Vec<u8>>, usize)>
@@ -311,6 +390,66 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_decode_into() -> io::Result<()> {
+        // STRIPE
+        let data = [
+            0x08, 0x07, 0x04, 0x17, 0x17, 0x17, 0x15, 0x00, 0x02, 0x6c, 0x6e, 0x00, 0x01, 0x01,
+            0x00, 0x08, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x80,
+            0x00, 0x00, 0x00, 0x02, 0x65, 0x6f, 0x00, 0x01, 0x01, 0x00, 0x08, 0x01, 0x00, 0x00,
+            0x00, 0x01, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x02, 0x6f,
+            0x73, 0x00, 0x01, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x08, 0x01, 0x00, 0x00, 0x80,
+            0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x01, 0x64, 0x00, 0x01, 0x00, 0x80, 0x00,
+            0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+            0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x00, 0x81, 0x11, 0x01, 0x7f,
+            0x00,
+        ];
+
+        let mut reader = &data[..];
+        let mut dst = Vec::new();
+        decode_into(&mut reader, &mut dst, 0)?;
+        assert_eq!(dst, b"noodles");
+
+        // CAT
+        let data = [0x20, 0x07, 0x6e, 0x6f, 0x6f, 0x64, 0x6c, 0x65, 0x73];
+
+        let mut reader = &data[..];
+        let mut dst = Vec::new();
+        decode_into(&mut reader, &mut dst, 0)?;
+        assert_eq!(dst, b"noodles");
+
+        let mut reader = &data[..6];
+        let mut dst = Vec::new();
+        assert!(matches!(
+            decode_into(&mut reader, &mut dst, 0),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_with_truncated_renormalization() -> io::Result<()> {
+        use crate::codecs::rans_nx16::encode;
+
+        let src: Vec<u8> = (0..1024).map(|i| b"ACGTN"[(i * i + 3 * i) % 5]).collect();
+
+        for flags in [Flags::empty(), Flags::ORDER] {
+            let data = encode(flags, &src)?;
+
+            let mut reader = &data[..];
+            assert_eq!(decode(&mut reader, 0)?, src);
+
+            let mut reader = &data[..data.len() - 2];
+            let e = decode(&mut reader, 0).unwrap_err();
+            assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof);
+            assert!(e.to_string().starts_with("rANS state "));
+            assert!(e.to_string().contains("at output offset"));
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_decode_uncompressed() -> io::Result<()> {
         let data = [
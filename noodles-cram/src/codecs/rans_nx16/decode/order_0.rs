@@ -10,13 +10,12 @@ where
 {
     use super::{
         rans_advance_step_nx16, rans_get_cumulative_freq_nx16, rans_get_symbol_from_freq,
-        rans_renorm_nx16,
+        rans_renorm_nx16_at,
     };
 
-    let mut freqs = [0; 256];
-    let mut cumulative_freqs = [0; 256];
-
-    read_frequencies(reader, &mut freqs, &mut cumulative_freqs)?;
+    let frequencies = read_frequencies(reader)?;
+    let freqs = frequencies.frequencies();
+    let cumulative_freqs = frequencies.cumulative_frequencies();
 
     let mut state = vec![0; n as usize];
 
@@ -28,7 +27,7 @@ where
         let j = i % (n as usize);
 
         let f = rans_get_cumulative_freq_nx16(state[j], 12);
-        let s = rans_get_symbol_from_freq(&cumulative_freqs, f);
+        let s = rans_get_symbol_from_freq(cumulative_freqs, f);
 
         *b = s;
 
@@ -39,7 +38,7 @@ where
             12,
         );
 
-        state[j] = rans_renorm_nx16(reader, state[j])?;
+        state[j] = rans_renorm_nx16_at(reader, state[j], j, i)?;
     }
 
     Ok(())
@@ -64,17 +63,66 @@ pub fn normalize_frequencies(freqs: &mut [u32], bits: u32) {
     }
 }
 
-fn read_frequencies<R>(
-    reader: &mut R,
-    freqs: &mut [u32],
-    cumulative_freqs: &mut [u32],
-) -> io::Result<()>
+/// An rANS Nx16 order-0 frequency table.
+///
+/// This is the table read from an order-0 encoded stream. It is exposed to inspect the symbol
+/// statistics of a stream, e.g., when diagnosing data that fails to decode.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Frequencies {
+    alphabet: [bool; 256],
+    frequencies: [u32; 256],
+    cumulative_frequencies: [u32; 256],
+}
+
+impl Frequencies {
+    /// Returns the alphabet, i.e., whether each symbol has a frequency in the table.
+    pub fn alphabet(&self) -> &[bool; 256] {
+        &self.alphabet
+    }
+
+    /// Returns the frequencies of the symbols, normalized to a total of 4096 (12 bits).
+    pub fn frequencies(&self) -> &[u32; 256] {
+        &self.frequencies
+    }
+
+    /// Returns the cumulative frequencies of the symbols.
+    ///
+    /// The cumulative frequency of a symbol is the sum of the frequencies of all lower symbols.
+    pub fn cumulative_frequencies(&self) -> &[u32; 256] {
+        &self.cumulative_frequencies
+    }
+}
+
+/// Reads an rANS Nx16 order-0 frequency table.
+///
+/// The reader must be positioned at the start of the frequency table, i.e., after the flags and,
+/// if present, the uncompressed length.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::rans_nx16;
+///
+/// // alphabet = {n, o}, freqs = {n: 1, o: 3}
+/// let data = [0x6e, 0x6f, 0x00, 0x00, 0x01, 0x03];
+/// let mut reader = &data[..];
+///
+/// let frequencies = rans_nx16::read_frequencies(&mut reader)?;
+///
+/// assert!(frequencies.alphabet()[usize::from(b'n')]);
+/// assert_eq!(frequencies.frequencies()[usize::from(b'n')], 1024);
+/// assert_eq!(frequencies.frequencies()[usize::from(b'o')], 3072);
+/// assert_eq!(frequencies.cumulative_frequencies()[usize::from(b'o')], 1024);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn read_frequencies<R>(reader: &mut R) -> io::Result<Frequencies>
 where
     R: Read,
 {
     use super::read_alphabet;
 
     let alphabet = read_alphabet(reader)?;
+    let mut freqs = [0; 256];
 
     for i in 0..alphabet.len() {
         if alphabet[i] {
@@ -82,13 +130,61 @@ where
         }
     }
 
-    normalize_frequencies(freqs, 12);
+    normalize_frequencies(&mut freqs, 12);
 
-    cumulative_freqs[0] = 0;
+    let mut cumulative_freqs = [0; 256];
 
     for i in 0..255 {
         cumulative_freqs[i + 1] = cumulative_freqs[i] + freqs[i];
     }
 
-    Ok(())
+    Ok(Frequencies {
+        alphabet,
+        frequencies: freqs,
+        cumulative_frequencies: cumulative_freqs,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_frequencies() -> io::Result<()> {
+        // alphabet = {d, e, l, n, o, s}
+        let data = [
+            0x64, 0x65, 0x00, 0x6c, 0x6e, 0x6f, 0x00, 0x73, 0x00, 0x01, 0x01, 0x01, 0x01, 0x03,
+            0x01,
+        ];
+        let mut reader = &data[..];
+
+        let frequencies = read_frequencies(&mut reader)?;
+
+        let mut expected_alphabet = [false; 256];
+
+        for &sym in b"delnos" {
+            expected_alphabet[usize::from(sym)] = true;
+        }
+
+        assert_eq!(frequencies.alphabet(), &expected_alphabet);
+
+        let freqs = frequencies.frequencies();
+        assert_eq!(freqs.iter().sum::<u32>(), 4096);
+        assert_eq!(freqs[usize::from(b'd')], 512);
+        assert_eq!(freqs[usize::from(b'o')], 1536);
+
+        let cumulative_freqs = frequencies.cumulative_frequencies();
+        assert_eq!(cumulative_freqs[usize::from(b'd')], 0);
+        assert_eq!(cumulative_freqs[usize::from(b'e')], 512);
+        assert_eq!(cumulative_freqs[usize::from(b's')], 3584);
+
+        assert!(reader.is_empty());
+
+        assert!(matches!(
+            read_frequencies(&mut &data[..9]),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
 }
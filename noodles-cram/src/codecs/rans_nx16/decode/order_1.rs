@@ -10,7 +10,7 @@ where
 {
     use super::{
         rans_advance_step_nx16, rans_get_cumulative_freq_nx16, rans_get_symbol_from_freq,
-        rans_renorm_nx16,
+        rans_renorm_nx16_at,
     };
 
     let mut freqs = vec![vec![0; 256]; 256];
@@ -32,7 +32,8 @@ where
             let f = rans_get_cumulative_freq_nx16(state[j], bits);
             let s = rans_get_symbol_from_freq(&cumulative_freqs[last_syms[j]], f);
 
-            output[i + j * (output.len() / (n as usize))] = s;
+            let k = i + j * (output.len() / (n as usize));
+            output[k] = s;
 
             state[j] = rans_advance_step_nx16(
                 state[j],
@@ -41,7 +42,7 @@ where
                 bits,
             );
 
-            state[j] = rans_renorm_nx16(reader, state[j])?;
+            state[j] = rans_renorm_nx16_at(reader, state[j], j, k)?;
 
             last_syms[j] = usize::from(s);
         }
@@ -65,7 +66,7 @@ where
             bits,
         );
 
-        state[m] = rans_renorm_nx16(reader, state[m])?;
+        state[m] = rans_renorm_nx16_at(reader, state[m], m, i)?;
 
         last_syms[m] = usize::from(s);
 
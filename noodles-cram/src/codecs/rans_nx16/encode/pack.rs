@@ -12,6 +12,15 @@ pub fn encode(src: &[u8]) -> io::Result<(Vec<u8>, Vec<u8>)> {
         frequencies[sym] += 1;
     }
 
+    let symbol_count = frequencies.iter().filter(|&&f| f > 0).count();
+
+    if !(1..=16).contains(&symbol_count) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("expected 1..=16 unique symbols, got {symbol_count}"),
+        ));
+    }
+
     let mut lut = [0; 256];
     let mut n = 0;
 
@@ -50,7 +59,7 @@ pub fn encode(src: &[u8]) -> io::Result<(Vec<u8>, Vec<u8>)> {
         }
 
         dst
-    } else if n <= 16 {
+    } else {
         let len = (src.len() / 2) + 1;
         let mut dst = vec![0; len];
 
@@ -63,11 +72,6 @@ pub fn encode(src: &[u8]) -> io::Result<(Vec<u8>, Vec<u8>)> {
         }
 
         dst
-    } else {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidInput,
-            "unique symbols > 16",
-        ));
     };
 
     let mut header = Vec::new();
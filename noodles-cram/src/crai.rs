@@ -3,16 +3,21 @@
 #[cfg(feature = "async")]
 pub mod r#async;
 
+mod builder;
 mod reader;
 pub mod record;
 mod writer;
 
-pub use self::{reader::Reader, record::Record, writer::Writer};
+pub use self::{builder::Builder, reader::Reader, record::Record, writer::Writer};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
 
-use std::{fs::File, io, path::Path};
+use std::{
+    fs::File,
+    io::{self, Write},
+    path::Path,
+};
 
 /// A CRAM index.
 pub type Index = Vec<Record>;
@@ -38,24 +43,154 @@ where
     reader.read_index()
 }
 
-/// Writes a CRAM index to a file.
+/// Writes a CRAM index.
 ///
-/// This is a convenience function and is equivalent to creating the file at the given path and
-/// writing the index.
+/// This is a convenience function and is equivalent to wrapping the given writer in a
+/// [`Writer`], writing the index, and finishing the gzip stream.
 ///
 /// # Examples
 ///
 /// ```no_run
-/// # use std::io;
+/// # use std::{fs::File, io};
 /// use noodles_cram::crai;
 /// let index = crai::Index::default();
-/// crai::write("sample.cram.crai", &index)?;
+/// let mut writer = File::create("sample.cram.crai")?;
+/// crai::write(&mut writer, &index)?;
 /// # Ok::<(), io::Error>(())
 /// ```
-pub fn write<P>(dst: P, index: &[Record]) -> io::Result<()>
+pub fn write<W>(writer: &mut W, index: &[Record]) -> io::Result<()>
 where
-    P: AsRef<Path>,
+    W: Write,
 {
-    let mut writer = File::open(dst).map(Writer::new)?;
-    writer.write_index(index)
+    let mut writer = Writer::new(writer);
+    writer.write_index(index)?;
+    writer.finish()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn test_write() -> Result<(), Box<dyn std::error::Error>> {
+        let index = vec![Record::new(
+            Some(0),
+            Position::new(10946),
+            6765,
+            17711,
+            233,
+            317811,
+        )];
+
+        let mut buf = Vec::new();
+        write(&mut buf, &index)?;
+
+        let mut reader = Reader::new(&buf[..]);
+        let actual = reader.read_index()?;
+
+        assert_eq!(actual, index);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_index_built_by_writer() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{io::Read, num::NonZeroUsize};
+
+        use flate2::read::GzDecoder;
+        use noodles_fasta as fasta;
+        use noodles_sam::{
+            self as sam,
+            header::record::value::{map::ReferenceSequence, Map},
+            record::Flags,
+        };
+
+        use crate::{writer, Reader};
+
+        let repository = fasta::Repository::new(vec![
+            fasta::Record::new(
+                fasta::record::Definition::new("sq0", None),
+                fasta::record::Sequence::from(b"ACGTACGT".to_vec()),
+            ),
+            fasta::Record::new(
+                fasta::record::Definition::new("sq1", None),
+                fasta::record::Sequence::from(b"TTTTGGGG".to_vec()),
+            ),
+        ]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let alignment_records = [
+            sam::alignment::Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(2)?)
+                .set_cigar("4M".parse()?)
+                .set_sequence("CGTA".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .build(),
+            sam::alignment::Record::builder()
+                .set_read_name("r1".parse()?)
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::try_from(3)?)
+                .set_cigar("5M".parse()?)
+                .set_sequence("TTGGG".parse()?)
+                .set_quality_scores("NDLSN".parse()?)
+                .build(),
+        ];
+
+        let mut writer = writer::Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_with_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for alignment_record in &alignment_records {
+            let record = crate::Record::try_from_alignment_record(&header, alignment_record)?;
+            writer.write_record(&header, record)?;
+        }
+
+        let index = writer.finish_with_index(&header)?;
+        let data = writer.get_ref();
+
+        let mut reader = Reader::new(io::Cursor::new(&data[..]));
+        reader.read_header()?;
+        let offset = reader.position()?;
+        let (container_header, _) = reader
+            .read_data_container_with_container_header()?
+            .ok_or("missing data container")?;
+        let landmark = container_header.landmarks()[0];
+        let slice_length = container_header.len() - landmark;
+
+        let mut buf = Vec::new();
+        write(&mut buf, &index)?;
+
+        let mut actual = String::new();
+        GzDecoder::new(&buf[..]).read_to_string(&mut actual)?;
+
+        // A multi-reference slice has a line for each reference sequence, as written by htslib
+        // (`samtools index`).
+        let expected = format!(
+            "0\t2\t4\t{offset}\t{landmark}\t{slice_length}\n\
+             1\t3\t5\t{offset}\t{landmark}\t{slice_length}\n"
+        );
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,239 @@
+//! CRAM index builder.
+
+use std::{cmp, collections::HashMap, io};
+
+use noodles_core::Position;
+
+use super::{Index, Record};
+use crate::{
+    data_container::{self, slice, CompressionHeader, Slice},
+    DataContainer,
+};
+
+/// A CRAM index builder.
+///
+/// This accumulates index records from slices as they are written.
+#[derive(Debug, Default)]
+pub struct Builder {
+    index: Index,
+}
+
+impl Builder {
+    /// Adds index records for a slice.
+    ///
+    /// A single-reference or unmapped slice adds one record. A multi-reference slice adds a record
+    /// for each reference sequence in the slice, which requires decoding its records.
+    ///
+    /// `container_position` is the position of the container in the CRAM stream, `landmark` is
+    /// the offset of the slice from the start of the container data, and `slice_length` is the
+    /// size of the slice.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the records of a multi-reference slice fail to decode or a mapped
+    /// record in it has no alignment start or end.
+    pub fn add_slice(
+        &mut self,
+        compression_header: &CompressionHeader,
+        slice: &Slice,
+        container_position: u64,
+        landmark: u64,
+        slice_length: u64,
+    ) -> io::Result<()> {
+        push_index_records(
+            &mut self.index,
+            compression_header,
+            slice,
+            container_position,
+            landmark,
+            slice_length,
+        )
+    }
+
+    /// Adds index records for each slice in a data container.
+    pub(crate) fn add_data_container(
+        &mut self,
+        container_header: &data_container::Header,
+        data_container: &DataContainer,
+        container_position: u64,
+    ) -> io::Result<()> {
+        let container_len = container_header.len();
+
+        let landmarks = container_header.landmarks();
+        let slice_count = landmarks.len();
+
+        for (i, slice) in data_container.slices().iter().enumerate() {
+            let landmark = landmarks[i];
+
+            let slice_length = if i < slice_count - 1 {
+                landmarks[i + 1] - landmark
+            } else {
+                container_len - landmark
+            };
+
+            self.add_slice(
+                data_container.compression_header(),
+                slice,
+                container_position,
+                landmark as u64,
+                slice_length as u64,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Builds a CRAM index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::crai;
+    /// let index = crai::Builder::default().build();
+    /// assert!(index.is_empty());
+    /// ```
+    pub fn build(self) -> Index {
+        self.index
+    }
+}
+
+fn push_index_records(
+    index: &mut Index,
+    compression_header: &CompressionHeader,
+    slice: &Slice,
+    container_position: u64,
+    landmark: u64,
+    slice_length: u64,
+) -> io::Result<()> {
+    if slice.header().reference_sequence_context().is_many() {
+        push_index_records_for_multi_reference_slice(
+            index,
+            compression_header,
+            slice,
+            container_position,
+            landmark,
+            slice_length,
+        )
+    } else {
+        push_index_record_for_single_reference_slice(
+            index,
+            slice.header(),
+            container_position,
+            landmark,
+            slice_length,
+        )
+    }
+}
+
+#[derive(Debug)]
+struct SliceReferenceSequenceAlignmentRangeInclusive {
+    start: Option<Position>,
+    end: Option<Position>,
+}
+
+impl Default for SliceReferenceSequenceAlignmentRangeInclusive {
+    fn default() -> Self {
+        Self {
+            start: Position::new(usize::MAX),
+            end: None,
+        }
+    }
+}
+
+fn push_index_records_for_multi_reference_slice(
+    index: &mut Index,
+    compression_header: &CompressionHeader,
+    slice: &Slice,
+    container_position: u64,
+    landmark: u64,
+    slice_length: u64,
+) -> io::Result<()> {
+    let mut reference_sequence_ids: HashMap<
+        Option<usize>,
+        SliceReferenceSequenceAlignmentRangeInclusive,
+    > = HashMap::new();
+
+    for record in slice.records(compression_header)? {
+        let reference_sequence_id = record.reference_sequence_id();
+
+        let range = reference_sequence_ids
+            .entry(reference_sequence_id)
+            .or_default();
+
+        let alignment_start = record.alignment_start();
+        range.start = cmp::min(range.start, alignment_start);
+
+        let alignment_end = record.alignment_end();
+        range.end = cmp::max(range.end, alignment_end);
+    }
+
+    let mut sorted_reference_sequence_ids: Vec<_> =
+        reference_sequence_ids.keys().copied().collect();
+    sorted_reference_sequence_ids.sort_unstable();
+
+    for reference_sequence_id in sorted_reference_sequence_ids {
+        let (alignment_start, alignment_span) = if reference_sequence_id.is_some() {
+            let range = &reference_sequence_ids[&reference_sequence_id];
+
+            if let (Some(start), Some(end)) = (range.start, range.end) {
+                let span = usize::from(end) - usize::from(start) + 1;
+                (Some(start), span)
+            } else {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid alignment range: {range:?}"),
+                ));
+            }
+        } else {
+            (None, 0)
+        };
+
+        let record = Record::new(
+            reference_sequence_id,
+            alignment_start,
+            alignment_span,
+            container_position,
+            landmark,
+            slice_length,
+        );
+
+        index.push(record);
+    }
+
+    Ok(())
+}
+
+fn push_index_record_for_single_reference_slice(
+    index: &mut Index,
+    slice_header: &slice::Header,
+    container_position: u64,
+    landmark: u64,
+    slice_length: u64,
+) -> io::Result<()> {
+    use crate::data_container::ReferenceSequenceContext;
+
+    let (reference_sequence_id, alignment_start, alignment_span) =
+        match slice_header.reference_sequence_context() {
+            ReferenceSequenceContext::Some(context) => {
+                let reference_sequence_id = Some(context.reference_sequence_id());
+                let alignment_start = Some(context.alignment_start());
+                let alignment_span = context.alignment_span();
+                (reference_sequence_id, alignment_start, alignment_span)
+            }
+            ReferenceSequenceContext::None => (None, None, 0),
+            ReferenceSequenceContext::Many => unreachable!(),
+        };
+
+    let record = Record::new(
+        reference_sequence_id,
+        alignment_start,
+        alignment_span,
+        container_position,
+        landmark,
+        slice_length,
+    );
+
+    index.push(record);
+
+    Ok(())
+}
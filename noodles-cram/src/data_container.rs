@@ -3,13 +3,14 @@
 pub mod block_content_encoder_map;
 pub(crate) mod builder;
 pub mod compression_header;
+mod container_stats;
 mod header;
 mod reference_sequence_context;
 pub(crate) mod slice;
 
 pub use self::{
     block_content_encoder_map::BlockContentEncoderMap, compression_header::CompressionHeader,
-    slice::Slice,
+    container_stats::ContainerStats, slice::Slice,
 };
 pub(crate) use self::{
     builder::Builder, header::Header, reference_sequence_context::ReferenceSequenceContext,
@@ -42,4 +43,125 @@ impl DataContainer {
     pub fn slices(&self) -> &[Slice] {
         &self.slices
     }
+
+    /// Returns record statistics calculated from the slice headers.
+    ///
+    /// This does not decode any records, but slice headers only carry the number of records and
+    /// their placement. Records in slices placed on a single reference sequence are counted as
+    /// mapped, and records in unmapped slices are counted as unmapped. Records in
+    /// multi-reference slices are only counted in the total.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// while let Some(data_container) = reader.read_data_container()? {
+    ///     let stats = data_container.record_stats();
+    ///     println!("{:?}\t{}", stats.reference_sequence_id(), stats.total_records());
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn record_stats(&self) -> ContainerStats {
+        let mut total_records = 0;
+        let mut mapped_records = 0;
+        let mut unmapped_records = 0;
+        let mut reference_sequence_ids = Vec::new();
+
+        for slice in &self.slices {
+            let header = slice.header();
+            let record_count = header.record_count();
+
+            total_records += record_count;
+
+            match header.reference_sequence_context() {
+                ReferenceSequenceContext::Some(context) => {
+                    mapped_records += record_count;
+                    reference_sequence_ids.push(Some(context.reference_sequence_id()));
+                }
+                ReferenceSequenceContext::None => {
+                    unmapped_records += record_count;
+                    reference_sequence_ids.push(None);
+                }
+                ReferenceSequenceContext::Many => reference_sequence_ids.push(None),
+            }
+        }
+
+        reference_sequence_ids.dedup();
+
+        let reference_sequence_id = match reference_sequence_ids[..] {
+            [id] => id,
+            _ => None,
+        };
+
+        ContainerStats::new(
+            total_records,
+            mapped_records,
+            unmapped_records,
+            reference_sequence_id,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::container::{block::ContentType, Block};
+
+    fn build_slice(
+        reference_sequence_context: ReferenceSequenceContext,
+        record_count: usize,
+    ) -> Slice {
+        let header = slice::Header::builder()
+            .set_reference_sequence_context(reference_sequence_context)
+            .set_record_count(record_count)
+            .build();
+
+        let core_data_block = Block::builder()
+            .set_content_type(ContentType::CoreData)
+            .build();
+
+        Slice::new(header, core_data_block, Vec::new())
+    }
+
+    #[test]
+    fn test_record_stats() -> Result<(), noodles_core::position::TryFromIntError> {
+        let compression_header = CompressionHeader::builder().build();
+
+        let context =
+            ReferenceSequenceContext::some(2, Position::try_from(8)?, Position::try_from(13)?);
+
+        let data_container = DataContainer::new(
+            compression_header.clone(),
+            vec![build_slice(context, 3), build_slice(context, 5)],
+        );
+        assert_eq!(
+            data_container.record_stats(),
+            ContainerStats::new(8, 8, 0, Some(2))
+        );
+
+        let data_container = DataContainer::new(
+            compression_header.clone(),
+            vec![
+                build_slice(context, 3),
+                build_slice(ReferenceSequenceContext::None, 2),
+                build_slice(ReferenceSequenceContext::Many, 1),
+            ],
+        );
+        assert_eq!(
+            data_container.record_stats(),
+            ContainerStats::new(6, 3, 2, None)
+        );
+
+        let data_container = DataContainer::new(compression_header, Vec::new());
+        assert_eq!(data_container.record_stats(), ContainerStats::default());
+
+        Ok(())
+    }
 }
@@ -6,7 +6,10 @@ pub use self::builder::Builder;
 
 use std::collections::HashMap;
 
-use crate::{codecs::Encoder, container::block};
+use crate::{
+    codecs::Encoder, container::block,
+    data_container::compression_header::data_series_encoding_map::DataSeries,
+};
 
 /// A CRAM data container block content-encoder map.
 #[derive(Clone, Debug)]
@@ -14,6 +17,7 @@ pub struct BlockContentEncoderMap {
     core_data_encoder: Option<Encoder>,
     data_series_encoders: Vec<Option<Encoder>>,
     tag_values_encoders: HashMap<block::ContentId, Option<Encoder>>,
+    default_tag_values_encoder: Option<Encoder>,
 }
 
 impl BlockContentEncoderMap {
@@ -45,6 +49,15 @@ impl BlockContentEncoderMap {
         self.data_series_encoders.get(i).map(|e| e.as_ref())
     }
 
+    pub(crate) fn set_data_series_encoder(
+        &mut self,
+        data_series: DataSeries,
+        encoder: Option<Encoder>,
+    ) {
+        let i = (i32::from(block::ContentId::from(data_series)) as usize) - 1;
+        self.data_series_encoders[i] = encoder;
+    }
+
     pub(crate) fn tag_values_encoders(&self) -> &HashMap<block::ContentId, Option<Encoder>> {
         &self.tag_values_encoders
     }
@@ -57,6 +70,10 @@ impl BlockContentEncoderMap {
             .get(&block_content_id)
             .map(|e| e.as_ref())
     }
+
+    pub(crate) fn default_tag_values_encoder(&self) -> Option<&Encoder> {
+        self.default_tag_values_encoder.as_ref()
+    }
 }
 
 impl Default for BlockContentEncoderMap {
@@ -15,6 +15,7 @@ pub struct Builder {
     core_data_encoder: Option<Encoder>,
     data_series_encoders: Vec<Option<Encoder>>,
     tag_values_encoders: HashMap<block::ContentId, Option<Encoder>>,
+    default_tag_values_encoder: Option<Encoder>,
 }
 
 impl Builder {
@@ -79,6 +80,21 @@ impl Builder {
         self
     }
 
+    /// Sets the encoder for tag values that do not have an encoder set.
+    ///
+    /// The default is gzip with the default compression level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::BlockContentEncoderMap;
+    /// let builder = BlockContentEncoderMap::builder().set_default_tag_values_encoder(None);
+    /// ```
+    pub fn set_default_tag_values_encoder(mut self, encoder: Option<Encoder>) -> Self {
+        self.default_tag_values_encoder = encoder;
+        self
+    }
+
     /// Builds a block content-encoder map.
     ///
     /// # Examples
@@ -92,6 +108,7 @@ impl Builder {
             core_data_encoder: self.core_data_encoder,
             data_series_encoders: self.data_series_encoders,
             tag_values_encoders: self.tag_values_encoders,
+            default_tag_values_encoder: self.default_tag_values_encoder,
         }
     }
 }
@@ -111,6 +128,7 @@ impl Default for Builder {
                 STANDARD_DATA_SERIES.len()
             ],
             tag_values_encoders: HashMap::new(),
+            default_tag_values_encoder: Some(Encoder::Gzip(compression_level)),
         }
     }
 }
@@ -0,0 +1,53 @@
+/// CRAM data container record statistics.
+///
+/// These are calculated from the slice headers, without decoding records. See
+/// [`super::DataContainer::record_stats`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ContainerStats {
+    total_records: usize,
+    mapped_records: usize,
+    unmapped_records: usize,
+    reference_sequence_id: Option<usize>,
+}
+
+impl ContainerStats {
+    pub(crate) fn new(
+        total_records: usize,
+        mapped_records: usize,
+        unmapped_records: usize,
+        reference_sequence_id: Option<usize>,
+    ) -> Self {
+        Self {
+            total_records,
+            mapped_records,
+            unmapped_records,
+            reference_sequence_id,
+        }
+    }
+
+    /// Returns the total number of records.
+    pub fn total_records(&self) -> usize {
+        self.total_records
+    }
+
+    /// Returns the number of records in slices that are placed on a single reference sequence.
+    ///
+    /// Slices only record the placement of their records, so this includes placed, unmapped
+    /// records, i.e., unmapped records that are assigned the position of their mates.
+    pub fn mapped_records(&self) -> usize {
+        self.mapped_records
+    }
+
+    /// Returns the number of records in unmapped slices.
+    ///
+    /// This only includes unplaced, unmapped records.
+    pub fn unmapped_records(&self) -> usize {
+        self.unmapped_records
+    }
+
+    /// Returns the reference sequence ID, if all slices are placed on the same reference
+    /// sequence.
+    pub fn reference_sequence_id(&self) -> Option<usize> {
+        self.reference_sequence_id
+    }
+}
@@ -13,6 +13,7 @@ use super::{CompressionHeader, ReferenceSequenceContext};
 use crate::{
     container::Block,
     io::BitReader,
+    reader::record::Projection,
     record::{resolve, Features},
     Record,
 };
@@ -74,6 +75,55 @@ impl Slice {
     /// # Ok::<_, io::Error>(())
     /// ```
     pub fn records(&self, compression_header: &CompressionHeader) -> io::Result<Vec<Record>> {
+        self.read_records(compression_header, Projection::default())
+    }
+
+    /// Reads and returns a list of raw records in this slice, only decoding their alignment
+    /// geometry.
+    ///
+    /// This decodes the fields needed to calculate the reference sequence ID, flags, alignment
+    /// start, alignment end, and CIGAR of each record. Read names, data, bases, and quality
+    /// scores are skipped when their data series are stored in external blocks that are not
+    /// shared with other data series. These blocks are not decompressed, which makes this
+    /// considerably faster than [`Self::records`] for, e.g., coverage calculations.
+    ///
+    /// Skipped fields are left empty, and the bases and quality scores of read features are
+    /// replaced with placeholders. The records are not meant to be resolved.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_cram as cram;
+    ///
+    /// let data = [];
+    /// let mut reader = cram::Reader::new(&data[..]);
+    /// reader.read_header()?;
+    ///
+    /// while let Some(container) = reader.read_data_container()? {
+    ///     for slice in container.slices() {
+    ///         let records = slice.geometry_records(container.compression_header())?;
+    ///
+    ///         for record in records {
+    ///             let _ = (record.alignment_start(), record.alignment_end());
+    ///         }
+    ///     }
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn geometry_records(
+        &self,
+        compression_header: &CompressionHeader,
+    ) -> io::Result<Vec<Record>> {
+        let projection = Projection::alignment_geometry(compression_header);
+        self.read_records(compression_header, projection)
+    }
+
+    fn read_records(
+        &self,
+        compression_header: &CompressionHeader,
+        projection: Projection,
+    ) -> io::Result<Vec<Record>> {
         use crate::reader::record::ExternalDataReaders;
 
         let core_data_reader = self
@@ -84,6 +134,10 @@ impl Slice {
         let mut external_data_readers = ExternalDataReaders::new();
 
         for block in self.external_blocks() {
+            if projection.skips_block(block.content_id()) {
+                continue;
+            }
+
             let reader = block.decompressed_data()?;
             external_data_readers.insert(block.content_id(), reader);
         }
@@ -95,6 +149,8 @@ impl Slice {
             self.header.reference_sequence_context(),
         );
 
+        record_reader.set_projection(projection);
+
         let record_count = self.header().record_count();
 
         let mut records = vec![Record::default(); record_count];
@@ -120,6 +176,23 @@ impl Slice {
         header: &sam::Header,
         compression_header: &CompressionHeader,
         records: &mut [Record],
+    ) -> io::Result<()> {
+        self.resolve_records_with_validation(
+            reference_sequence_repository,
+            header,
+            compression_header,
+            records,
+            true,
+        )
+    }
+
+    pub(crate) fn resolve_records_with_validation(
+        &self,
+        reference_sequence_repository: &fasta::Repository,
+        header: &sam::Header,
+        compression_header: &CompressionHeader,
+        records: &mut [Record],
+        validate_reference_md5: bool,
     ) -> io::Result<()> {
         resolve_mates(records)?;
 
@@ -129,6 +202,7 @@ impl Slice {
             compression_header,
             self,
             records,
+            validate_reference_md5,
         )?;
 
         resolve_quality_scores(records);
@@ -292,6 +366,7 @@ fn resolve_bases(
     compression_header: &CompressionHeader,
     slice: &Slice,
     records: &mut [Record],
+    validate_reference_md5: bool,
 ) -> io::Result<()> {
     enum SliceReferenceSequence {
         External(usize, fasta::record::Sequence),
@@ -320,19 +395,22 @@ fn resolve_bases(
             // § 11 "Reference sequences" (2021-11-15): "All CRAM reader implementations are
             // expected to check for reference MD5 checksums and report any missing or
             // mismatching entries."
-            let start = context.alignment_start();
-            let end = context.alignment_end();
+            if validate_reference_md5 {
+                let start = context.alignment_start();
+                let end = context.alignment_end();
 
-            let actual_md5 = builder::calculate_normalized_sequence_digest(&sequence[start..=end]);
-            let expected_md5 = slice.header().reference_md5();
+                let actual_md5 =
+                    builder::calculate_normalized_sequence_digest(&sequence[start..=end]);
+                let expected_md5 = slice.header().reference_md5();
 
-            if actual_md5 != expected_md5 {
-                return Err(io::Error::new(
+                if actual_md5 != expected_md5 {
+                    return Err(io::Error::new(
                         io::ErrorKind::InvalidData,
                         format!(
                             "reference sequence checksum mismatch: expected {expected_md5:?}, got {actual_md5:?}"
                         ),
                     ));
+                }
             }
 
             Some(SliceReferenceSequence::External(
@@ -374,20 +452,33 @@ fn resolve_bases(
                 if record.reference_sequence_id() == Some(*reference_sequence_id) {
                     Some(sequence.clone())
                 } else {
-                    // An invalid state?
-                    todo!();
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "record reference sequence ID does not match slice reference sequence ID",
+                    ));
                 }
             } else {
+                // Records in a multi-reference slice each carry their own reference sequence ID.
                 let reference_sequence_name = record
                     .reference_sequence(header.reference_sequences())
                     .transpose()?
                     .map(|(name, _)| name)
-                    .expect("invalid reference sequence ID");
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            "missing record reference sequence ID",
+                        )
+                    })?;
 
                 let sequence = reference_sequence_repository
                     .get(reference_sequence_name)
                     .transpose()?
-                    .expect("invalid reference sequence name");
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("missing reference sequence: {reference_sequence_name}"),
+                        )
+                    })?;
 
                 Some(sequence)
             }
@@ -645,6 +736,7 @@ mod tests {
             &compression_header,
             &slice,
             &mut records,
+            true,
         )?;
 
         let actual: Vec<_> = records.into_iter().map(|r| r.bases).collect();
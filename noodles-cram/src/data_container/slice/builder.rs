@@ -226,7 +226,11 @@ fn write_records(
             {
                 set_block_data(builder, buf, encoder)?
             } else {
-                set_block_data(builder, buf, Some(&Encoder::Gzip(Default::default())))?
+                set_block_data(
+                    builder,
+                    buf,
+                    block_content_encoder_map.default_tag_values_encoder(),
+                )?
             };
 
             Ok(builder.build())
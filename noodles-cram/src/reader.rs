@@ -3,15 +3,24 @@
 mod builder;
 pub(crate) mod container;
 pub(crate) mod data_container;
+mod geometry_records;
 pub(crate) mod header_container;
 pub(crate) mod num;
+mod options;
 mod query;
 pub(crate) mod record;
 mod records;
 
-pub use self::{builder::Builder, query::Query, records::Records};
+pub use self::{
+    builder::Builder, geometry_records::GeometryRecords, query::Query, records::Records,
+};
 
-use std::io::{self, Read, Seek, SeekFrom};
+pub(crate) use self::options::Options;
+
+use std::{
+    collections::HashSet,
+    io::{self, Read, Seek, SeekFrom},
+};
 
 use bytes::BytesMut;
 use noodles_core::Region;
@@ -46,6 +55,8 @@ use crate::data_container::DataContainer;
 pub struct Reader<R> {
     inner: R,
     reference_sequence_repository: fasta::Repository,
+    options: Options,
+    validated_reference_sequence_ids: HashSet<usize>,
     buf: BytesMut,
 }
 
@@ -113,6 +124,81 @@ where
         &self.reference_sequence_repository
     }
 
+    pub(crate) fn options(&self) -> &Options {
+        &self.options
+    }
+
+    // § 11 "Reference sequences" (2021-11-15): "All CRAM reader implementations are expected to
+    // check for reference MD5 checksums and report any missing or mismatching entries."
+    //
+    // This compares the checksum of each external reference sequence used by the data container
+    // with its `M5` field in the SAM header, if set. Each reference sequence is only checked once.
+    pub(crate) fn validate_reference_sequence_md5_checksums(
+        &mut self,
+        header: &sam::Header,
+        data_container: &DataContainer,
+    ) -> io::Result<()> {
+        use sam::header::record::value::map::reference_sequence::Md5Checksum;
+
+        use crate::data_container::{
+            slice::builder::calculate_normalized_sequence_digest, ReferenceSequenceContext,
+        };
+
+        if !self.options.validate_reference_md5
+            || !data_container
+                .compression_header()
+                .preservation_map()
+                .is_reference_required()
+        {
+            return Ok(());
+        }
+
+        for slice in data_container.slices() {
+            let reference_sequence_id = match slice.header().reference_sequence_context() {
+                ReferenceSequenceContext::Some(context) => context.reference_sequence_id(),
+                _ => continue,
+            };
+
+            if self
+                .validated_reference_sequence_ids
+                .contains(&reference_sequence_id)
+            {
+                continue;
+            }
+
+            let (name, reference_sequence) = header
+                .reference_sequences()
+                .get_index(reference_sequence_id)
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "invalid slice reference sequence ID",
+                    )
+                })?;
+
+            if let Some(expected_md5) = reference_sequence.md5_checksum() {
+                if let Some(sequence) = self.reference_sequence_repository.get(name).transpose()? {
+                    let actual_md5 =
+                        Md5Checksum::from(calculate_normalized_sequence_digest(&sequence[..]));
+
+                    if actual_md5 != expected_md5 {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!(
+                                "MD5 mismatch for reference sequence {name}: expected {expected_md5}, got {actual_md5}"
+                            ),
+                        ));
+                    }
+                }
+            }
+
+            self.validated_reference_sequence_ids
+                .insert(reference_sequence_id);
+        }
+
+        Ok(())
+    }
+
     /// Reads the CRAM file definition.
     ///
     /// The CRAM magic number is also checked.
@@ -235,6 +321,35 @@ where
     pub fn records<'r>(&'r mut self, header: &'r sam::Header) -> Records<'r, R> {
         Records::new(self, header)
     }
+
+    /// Returns an iterator over records that only decodes their alignment geometry.
+    ///
+    /// The stream is expected to be at the start of a data container.
+    ///
+    /// Only the fields needed to calculate the reference sequence ID, flags, alignment start,
+    /// alignment end, and CIGAR of each record are guaranteed to be decoded. See
+    /// [`crate::data_container::Slice::geometry_records`] for details.
+    ///
+    /// Records are not resolved, so no reference sequence repository is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// for result in reader.geometry_records() {
+    ///     let record = result?;
+    ///     let _ = (record.reference_sequence_id(), record.alignment_end());
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn geometry_records(&mut self) -> GeometryRecords<'_, R> {
+        GeometryRecords::new(self)
+    }
 }
 
 impl<R> Reader<R>
@@ -403,6 +518,303 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_records_with_mismatched_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_core::Position;
+        use sam::{
+            header::record::value::{map::ReferenceSequence, Map},
+            record::Flags,
+        };
+
+        use crate::{writer, Record};
+
+        fn build_repository(sequence: &[u8]) -> fasta::Repository {
+            fasta::Repository::new(vec![fasta::Record::new(
+                fasta::record::Definition::new("sq0", None),
+                fasta::record::Sequence::from(sequence.to_vec()),
+            )])
+        }
+
+        fn read_records(
+            data: &[u8],
+            builder: Builder,
+        ) -> Result<Vec<Record>, Box<dyn std::error::Error>> {
+            let mut reader = builder.build_from_reader(data);
+            let header = reader.read_header()?;
+            let records = reader.records(&header).collect::<io::Result<_>>()?;
+            Ok(records)
+        }
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let alignment_record = sam::alignment::Record::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar("4M".parse()?)
+            .set_sequence("ACGT".parse()?)
+            .set_quality_scores("NDLS".parse()?)
+            .build();
+
+        let mut writer = writer::Builder::default()
+            .set_reference_sequence_repository(build_repository(b"ACGTACGT"))
+            .build_with_writer(Vec::new());
+
+        writer.write_header(&header)?;
+        let record = Record::try_from_alignment_record(&header, &alignment_record)?;
+        writer.write_record(&header, record)?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let builder =
+            Builder::default().set_reference_sequence_repository(build_repository(b"ACGTACGT"));
+        assert_eq!(read_records(data, builder)?.len(), 1);
+
+        let builder =
+            Builder::default().set_reference_sequence_repository(build_repository(b"TTTTTTTT"));
+        let e = read_records(data, builder)
+            .unwrap_err()
+            .downcast::<io::Error>()?;
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert!(e.to_string().starts_with("MD5 mismatch"));
+
+        let builder = Builder::default()
+            .set_reference_sequence_repository(build_repository(b"TTTTTTTT"))
+            .validate_reference_md5(false);
+        assert_eq!(read_records(data, builder)?.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_multi_reference_container() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{io::Cursor, num::NonZeroUsize};
+
+        use noodles_core::Position;
+        use sam::{
+            header::record::value::{map::ReferenceSequence, Map},
+            record::Flags,
+        };
+
+        use crate::{data_container::ReferenceSequenceContext, writer, Record};
+
+        let repository = fasta::Repository::new(vec![
+            fasta::Record::new(
+                fasta::record::Definition::new("sq0", None),
+                fasta::record::Sequence::from(b"ACGTACGT".to_vec()),
+            ),
+            fasta::Record::new(
+                fasta::record::Definition::new("sq1", None),
+                fasta::record::Sequence::from(b"TTTTGGGG".to_vec()),
+            ),
+        ]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let alignment_records = [
+            sam::alignment::Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(2)?)
+                .set_cigar("4M".parse()?)
+                .set_sequence("CGTA".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .build(),
+            sam::alignment::Record::builder()
+                .set_read_name("r1".parse()?)
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::try_from(3)?)
+                .set_cigar("4M".parse()?)
+                .set_sequence("TTGA".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .build(),
+            sam::alignment::Record::builder()
+                .set_read_name("r2".parse()?)
+                .set_flags(Flags::UNMAPPED)
+                .set_sequence("ACGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .build(),
+        ];
+
+        let mut writer = writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_with_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for alignment_record in &alignment_records {
+            let record = Record::try_from_alignment_record(&header, alignment_record)?;
+            writer.write_record(&header, record)?;
+        }
+
+        let index = writer.finish_with_index(&header)?;
+        let data = writer.get_ref();
+
+        let mut reader = Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_from_reader(&data[..]);
+        reader.read_header()?;
+
+        let data_container = reader
+            .read_data_container()?
+            .ok_or("missing data container")?;
+        assert!(data_container.slices().iter().all(|slice| matches!(
+            slice.header().reference_sequence_context(),
+            ReferenceSequenceContext::Many
+        )));
+
+        let mut reader = Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_from_reader(&data[..]);
+        reader.read_header()?;
+
+        let actual: Vec<_> = reader
+            .records(&header)
+            .map(|result| result.and_then(|r| r.try_into_alignment_record(&header)))
+            .collect::<io::Result<_>>()?;
+        assert_eq!(actual, alignment_records);
+
+        let mut reader = Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_reader(Cursor::new(&data[..]));
+        reader.read_header()?;
+
+        let region = Region::new("sq1", Position::MIN..=Position::try_from(8)?);
+        let actual: Vec<_> = reader
+            .query(&header, &index, &region)?
+            .map(|result| result.and_then(|r| r.try_into_alignment_record(&header)))
+            .collect::<io::Result<_>>()?;
+        assert_eq!(actual, &alignment_records[1..2]);
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGTACGT".to_vec()),
+        )]);
+
+        let mut reader = Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_reader(&data[..]);
+        reader.read_header()?;
+
+        assert!(matches!(
+            reader.records(&header).next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_geometry_records() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_core::Position;
+        use sam::{
+            header::record::value::{map::ReferenceSequence, Map},
+            record::{Flags, MappingQuality},
+        };
+
+        use crate::{writer, Record};
+
+        let reference_sequence_repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGTACGTACGTACGTACGTACGT".to_vec()),
+        )]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(24)?),
+            )
+            .build();
+
+        let alignment_records = [
+            sam::alignment::Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(2)?)
+                .set_mapping_quality(MappingQuality::try_from(13)?)
+                .set_cigar("2S3M1I2M3D2M".parse()?)
+                .set_sequence("TTCGTTACCG".parse()?)
+                .set_quality_scores("NDLSNDLSND".parse()?)
+                .set_data("NH:i:1".parse()?)
+                .build(),
+            sam::alignment::Record::builder()
+                .set_read_name("r1".parse()?)
+                .set_flags(Flags::UNMAPPED)
+                .set_sequence("ACGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .build(),
+        ];
+
+        let mut writer = writer::Builder::default()
+            .set_reference_sequence_repository(reference_sequence_repository.clone())
+            .build_with_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for alignment_record in &alignment_records {
+            let record = Record::try_from_alignment_record(&header, alignment_record)?;
+            writer.write_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let mut reader = Builder::default()
+            .set_reference_sequence_repository(reference_sequence_repository)
+            .build_from_reader(&data[..]);
+        reader.read_header()?;
+        let records: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_header()?;
+        let geometry_records: Vec<_> = reader.geometry_records().collect::<io::Result<_>>()?;
+
+        assert_eq!(geometry_records.len(), records.len());
+
+        for (actual, expected) in geometry_records.iter().zip(&records) {
+            assert_eq!(actual.flags(), expected.flags());
+            assert_eq!(
+                actual.reference_sequence_id(),
+                expected.reference_sequence_id()
+            );
+            assert_eq!(actual.alignment_start(), expected.alignment_start());
+            assert_eq!(actual.alignment_end(), expected.alignment_end());
+            assert_eq!(actual.read_length(), expected.read_length());
+            assert!(actual.data().is_empty());
+            assert!(actual.quality_scores().is_empty());
+        }
+
+        assert_eq!(
+            geometry_records[0].alignment_end(),
+            Some(Position::try_from(11)?)
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_magic_number() {
         let data = b"CRAM";
@@ -1,4 +1,5 @@
 use std::{
+    collections::HashSet,
     fs::File,
     io::{self, Read},
     path::Path,
@@ -7,12 +8,13 @@ use std::{
 use bytes::BytesMut;
 use noodles_fasta as fasta;
 
-use super::Reader;
+use super::{Options, Reader};
 
 /// A CRAM reader builder.
 #[derive(Debug, Default)]
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
+    options: Options,
 }
 
 impl Builder {
@@ -37,6 +39,25 @@ impl Builder {
         self
     }
 
+    /// Sets whether to validate reference sequence MD5 checksums.
+    ///
+    /// If `true`, the checksums of external reference sequences are compared with the `M5`
+    /// fields of the SAM header reference sequences and with the reference MD5 checksums of
+    /// slices. A mismatch is an error.
+    ///
+    /// The default is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::reader::Builder::default().validate_reference_md5(false);
+    /// ```
+    pub fn validate_reference_md5(mut self, value: bool) -> Self {
+        self.options.validate_reference_md5 = value;
+        self
+    }
+
     /// Builds a CRAM reader from a path.
     ///
     /// # Examples
@@ -70,6 +91,8 @@ impl Builder {
         Reader {
             inner: reader,
             reference_sequence_repository: self.reference_sequence_repository,
+            options: self.options,
+            validated_reference_sequence_ids: HashSet::new(),
             buf: BytesMut::new(),
         }
     }
@@ -1,6 +1,6 @@
 mod data_series_encoding_map;
 mod encoding;
-mod preservation_map;
+pub(crate) mod preservation_map;
 mod tag_encoding_map;
 
 use std::io;
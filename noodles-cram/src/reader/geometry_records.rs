@@ -0,0 +1,69 @@
+use std::{
+    io::{self, Read},
+    vec,
+};
+
+use super::Reader;
+use crate::Record;
+
+/// An iterator over records of a CRAM reader that only decodes their alignment geometry.
+///
+/// This is created by calling [`Reader::geometry_records`].
+pub struct GeometryRecords<'a, R>
+where
+    R: Read,
+{
+    reader: &'a mut Reader<R>,
+    records: vec::IntoIter<Record>,
+}
+
+impl<'a, R> GeometryRecords<'a, R>
+where
+    R: Read,
+{
+    pub(crate) fn new(reader: &'a mut Reader<R>) -> Self {
+        Self {
+            reader,
+            records: Vec::new().into_iter(),
+        }
+    }
+
+    fn read_container_records(&mut self) -> io::Result<bool> {
+        let container = match self.reader.read_data_container()? {
+            Some(c) => c,
+            None => return Ok(true),
+        };
+
+        self.records = container
+            .slices()
+            .iter()
+            .map(|slice| slice.geometry_records(container.compression_header()))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        Ok(false)
+    }
+}
+
+impl<'a, R> Iterator for GeometryRecords<'a, R>
+where
+    R: Read,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.records.next() {
+                Some(r) => return Some(Ok(r)),
+                None => match self.read_container_records() {
+                    Ok(true) => return None,
+                    Ok(false) => {}
+                    Err(e) => return Some(Err(e)),
+                },
+            }
+        }
+    }
+}
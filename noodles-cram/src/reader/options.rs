@@ -0,0 +1,12 @@
+#[derive(Clone, Debug)]
+pub struct Options {
+    pub validate_reference_md5: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            validate_reference_md5: true,
+        }
+    }
+}
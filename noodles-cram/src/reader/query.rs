@@ -25,6 +25,7 @@ where
     reference_sequence_id: usize,
     interval: Interval,
 
+    container_position: Option<u64>,
     records: vec::IntoIter<Record>,
 }
 
@@ -49,6 +50,7 @@ where
             reference_sequence_id,
             interval,
 
+            container_position: None,
             records: Vec::new().into_iter(),
         }
     }
@@ -60,6 +62,14 @@ where
             return Some(Ok(()));
         }
 
+        // A container with multiple slices or a multi-reference slice has an index record for
+        // each slice and reference sequence. All of its records are read at once.
+        if self.container_position == Some(index_record.offset()) {
+            return Some(Ok(()));
+        }
+
+        self.container_position = Some(index_record.offset());
+
         if let Err(e) = self.reader.seek(SeekFrom::Start(index_record.offset())) {
             return Some(Err(e));
         }
@@ -70,6 +80,13 @@ where
             Err(e) => return Some(Err(e)),
         };
 
+        if let Err(e) = self
+            .reader
+            .validate_reference_sequence_md5_checksums(self.header, &container)
+        {
+            return Some(Err(e));
+        }
+
         let records = container
             .slices()
             .iter()
@@ -77,11 +94,12 @@ where
                 let compression_header = container.compression_header();
 
                 slice.records(compression_header).and_then(|mut records| {
-                    slice.resolve_records(
+                    slice.resolve_records_with_validation(
                         self.reader.reference_sequence_repository(),
                         self.header,
                         compression_header,
                         &mut records,
+                        self.reader.options().validate_reference_md5,
                     )?;
 
                     Ok(records)
@@ -114,6 +132,11 @@ where
         loop {
             match self.records.next() {
                 Some(r) => {
+                    // Multi-reference slices can have records on other reference sequences.
+                    if r.reference_sequence_id() != Some(self.reference_sequence_id) {
+                        continue;
+                    }
+
                     if let (Some(start), Some(end)) = (r.alignment_start(), r.alignment_end()) {
                         let alignment_interval = (start..=end).into();
 
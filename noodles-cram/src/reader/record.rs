@@ -1,6 +1,8 @@
 mod external_data_readers;
+mod projection;
 
 pub use external_data_readers::ExternalDataReaders;
+pub(crate) use projection::Projection;
 
 use std::{error, fmt, io};
 
@@ -58,6 +60,7 @@ where
     external_data_readers: ExternalDataReaders<EDR>,
     reference_sequence_context: ReferenceSequenceContext,
     prev_alignment_start: Option<Position>,
+    projection: Projection,
 }
 
 impl<'a, CDR, EDR> Reader<'a, CDR, EDR>
@@ -82,9 +85,14 @@ where
             external_data_readers,
             reference_sequence_context,
             prev_alignment_start: initial_alignment_start,
+            projection: Projection::default(),
         }
     }
 
+    pub(crate) fn set_projection(&mut self, projection: Projection) {
+        self.projection = projection;
+    }
+
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<()> {
         let bam_bit_flags = self.read_bam_bit_flags()?;
         record.bam_bit_flags = bam_bit_flags;
@@ -228,7 +236,7 @@ where
         let preservation_map = self.compression_header.preservation_map();
 
         // Missing read names are generated when resolving mates.
-        if preservation_map.read_names_included() {
+        if preservation_map.read_names_included() && !self.projection.skips(DataSeries::ReadNames) {
             record.read_name = self.read_read_name()?;
         }
 
@@ -280,7 +288,9 @@ where
 
             let preservation_map = self.compression_header.preservation_map();
 
-            if !preservation_map.read_names_included() {
+            if !preservation_map.read_names_included()
+                && !self.projection.skips(DataSeries::ReadNames)
+            {
                 record.read_name = self.read_read_name()?;
             }
 
@@ -387,6 +397,10 @@ where
 
         let tag_line = self.read_tag_line()?;
 
+        if self.projection.skips_tags() {
+            return Ok(sam::record::Data::default());
+        }
+
         let tag_keys = self
             .compression_header
             .preservation_map()
@@ -450,7 +464,9 @@ where
 
         record.mapping_quality = self.read_mapping_quality()?;
 
-        if flags.are_quality_scores_stored_as_array() {
+        if flags.are_quality_scores_stored_as_array()
+            && !self.projection.skips(DataSeries::QualityScores)
+        {
             record.quality_scores = self.read_quality_scores_stored_as_array(read_length)?;
         }
 
@@ -573,6 +589,10 @@ where
     }
 
     fn read_stretches_of_bases(&mut self) -> io::Result<Vec<Base>> {
+        if self.projection.skips(DataSeries::StretchesOfBases) {
+            return Ok(Vec::new());
+        }
+
         let raw_bases = self
             .compression_header
             .data_series_encoding_map()
@@ -592,6 +612,10 @@ where
     }
 
     fn read_stretches_of_quality_scores(&mut self) -> io::Result<Vec<Score>> {
+        if self.projection.skips(DataSeries::StretchesOfQualityScores) {
+            return Ok(Vec::new());
+        }
+
         let scores = self
             .compression_header
             .data_series_encoding_map()
@@ -613,6 +637,10 @@ where
     }
 
     fn read_base(&mut self) -> io::Result<Base> {
+        if self.projection.skips(DataSeries::Bases) {
+            return Ok(Base::N);
+        }
+
         self.compression_header
             .data_series_encoding_map()
             .bases_encoding()
@@ -629,6 +657,10 @@ where
     }
 
     fn read_quality_score(&mut self) -> io::Result<u8> {
+        if self.projection.skips(DataSeries::QualityScores) {
+            return Ok(0);
+        }
+
         self.compression_header
             .data_series_encoding_map()
             .quality_scores_encoding()
@@ -642,6 +674,10 @@ where
     }
 
     fn read_base_substitution_code(&mut self) -> io::Result<substitution::Value> {
+        if self.projection.skips(DataSeries::BaseSubstitutionCodes) {
+            return Ok(substitution::Value::Code(0));
+        }
+
         self.compression_header
             .data_series_encoding_map()
             .base_substitution_codes_encoding()
@@ -780,14 +816,18 @@ where
         flags: Flags,
         read_length: usize,
     ) -> io::Result<()> {
-        record.bases.as_mut().reserve(read_length);
+        if !self.projection.skips(DataSeries::Bases) {
+            record.bases.as_mut().reserve(read_length);
 
-        for _ in 0..read_length {
-            let base = self.read_base()?;
-            record.bases.push(base);
+            for _ in 0..read_length {
+                let base = self.read_base()?;
+                record.bases.push(base);
+            }
         }
 
-        if flags.are_quality_scores_stored_as_array() {
+        if flags.are_quality_scores_stored_as_array()
+            && !self.projection.skips(DataSeries::QualityScores)
+        {
             record.quality_scores = self.read_quality_scores_stored_as_array(read_length)?;
         }
 
@@ -0,0 +1,231 @@
+use std::collections::HashSet;
+
+use crate::{
+    container::block,
+    data_container::{
+        compression_header::{
+            data_series_encoding_map::{data_series::STANDARD_DATA_SERIES, DataSeries},
+            encoding::codec::{Byte, ByteArray, Integer},
+            DataSeriesEncodingMap, Encoding,
+        },
+        CompressionHeader,
+    },
+};
+
+// Data series that do not contribute to the alignment geometry of a record, i.e., its reference
+// sequence ID, flags, alignment start, and alignment span (CIGAR).
+static NON_GEOMETRY_DATA_SERIES: &[DataSeries] = &[
+    DataSeries::ReadNames,
+    DataSeries::StretchesOfBases,
+    DataSeries::StretchesOfQualityScores,
+    DataSeries::BaseSubstitutionCodes,
+    DataSeries::Bases,
+    DataSeries::QualityScores,
+];
+
+/// A set of record fields to skip when reading records.
+///
+/// A field is only skipped when it is stored entirely in external blocks that no decoded field
+/// reads from. Otherwise, skipping it would desynchronize the core data block or a shared external
+/// block.
+#[derive(Debug, Default)]
+pub(crate) struct Projection {
+    skipped_data_series: HashSet<DataSeries>,
+    skips_tags: bool,
+    skipped_content_ids: HashSet<block::ContentId>,
+}
+
+impl Projection {
+    /// Creates a projection that only decodes the alignment geometry of records.
+    ///
+    /// Read names, tags, bases, and quality scores are skipped when possible.
+    pub(crate) fn alignment_geometry(compression_header: &CompressionHeader) -> Self {
+        #[derive(Clone, Copy)]
+        enum Field {
+            DataSeries(DataSeries),
+            Tags,
+        }
+
+        let data_series_encoding_map = compression_header.data_series_encoding_map();
+
+        let mut read_content_ids = HashSet::new();
+        let mut candidates = Vec::new();
+
+        for &data_series in STANDARD_DATA_SERIES {
+            let usage = data_series_block_usage(data_series_encoding_map, data_series);
+
+            if NON_GEOMETRY_DATA_SERIES.contains(&data_series) && !usage.reads_core_data {
+                candidates.push((Field::DataSeries(data_series), usage.content_ids));
+            } else {
+                read_content_ids.extend(usage.content_ids);
+            }
+        }
+
+        let tags_usage = compression_header
+            .tag_encoding_map()
+            .values()
+            .map(|encoding| encoding.get().block_usage())
+            .fold(BlockUsage::default(), BlockUsage::union);
+
+        if tags_usage.reads_core_data {
+            read_content_ids.extend(tags_usage.content_ids);
+        } else {
+            candidates.push((Field::Tags, tags_usage.content_ids));
+        }
+
+        // A candidate that shares a block with a decoded field must also be decoded, which, in
+        // turn, may mark more blocks as read.
+        loop {
+            let mut is_changed = false;
+
+            candidates.retain(|(_, content_ids)| {
+                if content_ids.iter().any(|id| read_content_ids.contains(id)) {
+                    read_content_ids.extend(content_ids.iter().copied());
+                    is_changed = true;
+                    false
+                } else {
+                    true
+                }
+            });
+
+            if !is_changed {
+                break;
+            }
+        }
+
+        let mut projection = Self::default();
+
+        for (field, content_ids) in candidates {
+            match field {
+                Field::DataSeries(data_series) => {
+                    projection.skipped_data_series.insert(data_series);
+                }
+                Field::Tags => projection.skips_tags = true,
+            }
+
+            projection.skipped_content_ids.extend(content_ids);
+        }
+
+        projection
+    }
+
+    pub(crate) fn skips(&self, data_series: DataSeries) -> bool {
+        self.skipped_data_series.contains(&data_series)
+    }
+
+    pub(crate) fn skips_tags(&self) -> bool {
+        self.skips_tags
+    }
+
+    pub(crate) fn skips_block(&self, content_id: block::ContentId) -> bool {
+        self.skipped_content_ids.contains(&content_id)
+    }
+}
+
+#[derive(Debug, Default)]
+struct BlockUsage {
+    reads_core_data: bool,
+    content_ids: Vec<block::ContentId>,
+}
+
+impl BlockUsage {
+    fn external(content_id: block::ContentId) -> Self {
+        Self {
+            reads_core_data: false,
+            content_ids: vec![content_id],
+        }
+    }
+
+    fn core() -> Self {
+        Self {
+            reads_core_data: true,
+            content_ids: Vec::new(),
+        }
+    }
+
+    fn union(mut self, other: Self) -> Self {
+        self.reads_core_data |= other.reads_core_data;
+        self.content_ids.extend(other.content_ids);
+        self
+    }
+}
+
+trait BlockUsageExt {
+    fn block_usage(&self) -> BlockUsage;
+}
+
+impl BlockUsageExt for Integer {
+    fn block_usage(&self) -> BlockUsage {
+        match self {
+            Self::External(block_content_id) => BlockUsage::external(*block_content_id),
+            // A single symbol Huffman code does not read any bits.
+            Self::Huffman(alphabet, _) if alphabet.len() == 1 => BlockUsage::default(),
+            _ => BlockUsage::core(),
+        }
+    }
+}
+
+impl BlockUsageExt for Byte {
+    fn block_usage(&self) -> BlockUsage {
+        match self {
+            Self::External(block_content_id) => BlockUsage::external(*block_content_id),
+            Self::Huffman(alphabet, _) if alphabet.len() == 1 => BlockUsage::default(),
+            Self::Huffman(..) => BlockUsage::core(),
+        }
+    }
+}
+
+impl BlockUsageExt for ByteArray {
+    fn block_usage(&self) -> BlockUsage {
+        match self {
+            Self::ByteArrayLen(len_encoding, value_encoding) => len_encoding
+                .get()
+                .block_usage()
+                .union(value_encoding.get().block_usage()),
+            Self::ByteArrayStop(_, block_content_id) => BlockUsage::external(*block_content_id),
+        }
+    }
+}
+
+fn data_series_block_usage(map: &DataSeriesEncodingMap, data_series: DataSeries) -> BlockUsage {
+    fn usage<C>(encoding: Option<&Encoding<C>>) -> BlockUsage
+    where
+        C: BlockUsageExt,
+    {
+        encoding.map(|e| e.get().block_usage()).unwrap_or_default()
+    }
+
+    match data_series {
+        DataSeries::BamBitFlags => usage(Some(map.bam_bit_flags_encoding())),
+        DataSeries::CramBitFlags => usage(Some(map.cram_bit_flags_encoding())),
+        DataSeries::ReferenceId => usage(map.reference_id_encoding()),
+        DataSeries::ReadLengths => usage(Some(map.read_lengths_encoding())),
+        DataSeries::InSeqPositions => usage(Some(map.in_seq_positions_encoding())),
+        DataSeries::ReadGroups => usage(Some(map.read_groups_encoding())),
+        DataSeries::ReadNames => usage(map.read_names_encoding()),
+        DataSeries::NextMateBitFlags => usage(map.next_mate_bit_flags_encoding()),
+        DataSeries::NextFragmentReferenceSequenceId => {
+            usage(map.next_fragment_reference_sequence_id_encoding())
+        }
+        DataSeries::NextMateAlignmentStart => usage(map.next_mate_alignment_start_encoding()),
+        DataSeries::TemplateSize => usage(map.template_size_encoding()),
+        DataSeries::DistanceToNextFragment => usage(map.distance_to_next_fragment_encoding()),
+        DataSeries::TagIds => usage(Some(map.tag_ids_encoding())),
+        DataSeries::NumberOfReadFeatures => usage(map.number_of_read_features_encoding()),
+        DataSeries::ReadFeaturesCodes => usage(map.read_features_codes_encoding()),
+        DataSeries::InReadPositions => usage(map.in_read_positions_encoding()),
+        DataSeries::DeletionLengths => usage(map.deletion_lengths_encoding()),
+        DataSeries::StretchesOfBases => usage(map.stretches_of_bases_encoding()),
+        DataSeries::StretchesOfQualityScores => usage(map.stretches_of_quality_scores_encoding()),
+        DataSeries::BaseSubstitutionCodes => usage(map.base_substitution_codes_encoding()),
+        DataSeries::Insertion => usage(map.insertion_encoding()),
+        DataSeries::ReferenceSkipLength => usage(map.reference_skip_length_encoding()),
+        DataSeries::Padding => usage(map.padding_encoding()),
+        DataSeries::HardClip => usage(map.hard_clip_encoding()),
+        DataSeries::SoftClip => usage(map.soft_clip_encoding()),
+        DataSeries::MappingQualities => usage(map.mapping_qualities_encoding()),
+        DataSeries::Bases => usage(map.bases_encoding()),
+        DataSeries::QualityScores => usage(map.quality_scores_encoding()),
+        DataSeries::ReservedTc | DataSeries::ReservedTn => BlockUsage::default(),
+    }
+}
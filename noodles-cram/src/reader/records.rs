@@ -38,6 +38,9 @@ where
             None => return Ok(true),
         };
 
+        self.reader
+            .validate_reference_sequence_md5_checksums(self.header, &container)?;
+
         self.records = container
             .slices()
             .iter()
@@ -45,11 +48,12 @@ where
                 let compression_header = container.compression_header();
 
                 slice.records(compression_header).and_then(|mut records| {
-                    slice.resolve_records(
+                    slice.resolve_records_with_validation(
                         self.reader.reference_sequence_repository(),
                         self.header,
                         compression_header,
                         &mut records,
+                        self.reader.options().validate_reference_md5,
                     )?;
 
                     Ok(records)
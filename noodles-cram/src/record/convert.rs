@@ -209,3 +209,96 @@ fn maybe_insert_read_group(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_into_alignment_record_with_oversized_cigar(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_bam as bam;
+        use noodles_core::Position;
+        use sam::{
+            header::record::value::{map::ReferenceSequence, Map},
+            record::{
+                cigar::{op::Kind, Op},
+                data::field::tag,
+                sequence::Base,
+                Flags,
+            },
+        };
+
+        use crate::record::{Feature, Features};
+
+        // BAM stores the number of CIGAR operations as a u16. Records with more operations are
+        // written with a placeholder CIGAR, and the real CIGAR is moved to the `CG` data field.
+        const READ_LENGTH: usize = 1 << 17;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(READ_LENGTH)?),
+            )
+            .build();
+
+        // Every other base is an insertion, i.e., `1M1I1M1I...`.
+        let features: Vec<_> = (2..=READ_LENGTH)
+            .step_by(2)
+            .map(|i| Position::try_from(i).map(|position| Feature::InsertBase(position, Base::A)))
+            .collect::<Result<_, _>>()?;
+
+        let record = Record::builder()
+            .set_bam_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_read_length(READ_LENGTH)
+            .set_bases(vec![Base::A; READ_LENGTH].into())
+            .set_features(Features::from(features))
+            .build();
+
+        let alignment_record = record.try_into_alignment_record(&header)?;
+
+        let expected_cigar: sam::record::Cigar = (0..READ_LENGTH)
+            .map(|i| {
+                let kind = if i % 2 == 0 {
+                    Kind::Match
+                } else {
+                    Kind::Insertion
+                };
+
+                Op::new(kind, 1)
+            })
+            .collect();
+
+        assert_eq!(alignment_record.cigar(), &expected_cigar);
+
+        let mut writer = bam::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_record(&header, &alignment_record)?;
+        writer.try_finish()?;
+
+        let src = writer.get_ref().get_ref().as_slice();
+
+        let mut reader = bam::Reader::new(src);
+        reader.read_header()?;
+
+        let mut lazy_record = bam::lazy::Record::default();
+        reader.read_lazy_record(&mut lazy_record)?;
+
+        assert_eq!(lazy_record.cigar().len(), 2);
+        assert!(lazy_record.data().get(&tag::CIGAR).is_some());
+
+        let mut reader = bam::Reader::new(src);
+        reader.read_header()?;
+
+        let mut actual = sam::alignment::Record::default();
+        reader.read_record(&header, &mut actual)?;
+
+        assert_eq!(actual.cigar(), &expected_cigar);
+
+        Ok(())
+    }
+}
@@ -1,15 +1,19 @@
 //! CRAM writer.
 
 pub(crate) mod builder;
+mod compression_scheme;
 pub(crate) mod container;
 pub(crate) mod data_container;
+mod encoding_profile;
 pub(crate) mod header_container;
 pub(crate) mod num;
 mod options;
 pub(crate) mod record;
 
-pub use self::builder::Builder;
 pub(crate) use self::options::Options;
+pub use self::{
+    builder::Builder, compression_scheme::CompressionScheme, encoding_profile::EncodingProfile,
+};
 
 use std::{
     io::{self, Write},
@@ -19,7 +23,7 @@ use std::{
 use noodles_fasta as fasta;
 use noodles_sam::{self as sam, header::ReferenceSequences};
 
-use super::{file_definition::Version, DataContainer, FileDefinition, Record, MAGIC_NUMBER};
+use super::{crai, file_definition::Version, DataContainer, FileDefinition, Record, MAGIC_NUMBER};
 
 /// A CRAM writer.
 ///
@@ -53,6 +57,8 @@ where
     options: Options,
     data_container_builder: crate::data_container::Builder,
     record_counter: u64,
+    position: u64,
+    index_builder: crai::Builder,
 }
 
 impl<W> Writer<W>
@@ -104,8 +110,42 @@ where
     /// ```
     pub fn try_finish(&mut self, header: &sam::Header) -> io::Result<()> {
         use self::container::write_eof_container;
+
         self.flush(header)?;
-        write_eof_container(&mut self.inner)
+
+        let mut buf = Vec::new();
+        write_eof_container(&mut buf)?;
+        self.write_all(&buf)
+    }
+
+    /// Finishes the output stream and returns an index of the written containers.
+    ///
+    /// This writes any pending containers and a final EOF container, like [`Self::try_finish`].
+    /// The index has a record for each slice written (or for each reference sequence in a
+    /// multi-reference slice), with offsets relative to the start of the output stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram as cram;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut writer = cram::Writer::new(Vec::new());
+    ///
+    /// let header = sam::Header::default();
+    /// writer.write_header(&header)?;
+    ///
+    /// let record = cram::Record::default();
+    /// writer.write_record(&header, record)?;
+    ///
+    /// let index = writer.finish_with_index(&header)?;
+    /// assert_eq!(index.len(), 1);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn finish_with_index(&mut self, header: &sam::Header) -> io::Result<crai::Index> {
+        self.try_finish(header)?;
+        Ok(mem::take(&mut self.index_builder).build())
     }
 
     /// Writes a CRAM file definition.
@@ -134,7 +174,10 @@ where
     /// ```
     pub fn write_file_definition(&mut self) -> io::Result<()> {
         let file_definition = FileDefinition::new(self.options.version, Default::default());
-        write_file_definition(&mut self.inner, &file_definition)
+
+        let mut buf = Vec::new();
+        write_file_definition(&mut buf, &file_definition)?;
+        self.write_all(&buf)
     }
 
     /// Writes a CRAM file header container.
@@ -170,7 +213,9 @@ where
             header.reference_sequences_mut(),
         )?;
 
-        write_header_container(&mut self.inner, &header)
+        let mut buf = Vec::new();
+        write_header_container(&mut buf, &header)?;
+        self.write_all(&buf)
     }
 
     /// Writes a SAM header.
@@ -257,7 +302,23 @@ where
             header,
         )?;
 
-        write_data_container(&mut self.inner, &data_container, base_count)
+        let container_position = self.position;
+
+        let mut buf = Vec::new();
+        let container_header = write_data_container(&mut buf, &data_container, base_count)?;
+        self.write_all(&buf)?;
+
+        self.index_builder.add_data_container(
+            &container_header,
+            &data_container,
+            container_position,
+        )
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.inner.write_all(buf)?;
+        self.position += buf.len() as u64;
+        Ok(())
     }
 }
 
@@ -329,6 +390,138 @@ pub(crate) fn add_missing_reference_sequence_checksums(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_finish_with_index() -> Result<(), Box<dyn std::error::Error>> {
+        use bytes::Bytes;
+
+        use crate::{
+            container::block::ContentType,
+            reader::{container::read_block, data_container::header::read_header},
+        };
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for _ in 0..3 {
+            writer.write_record(&header, Record::default())?;
+        }
+
+        let index = writer.finish_with_index(&header)?;
+        let data = writer.get_ref();
+
+        assert_eq!(index.len(), 1);
+
+        let record = &index[0];
+        assert!(record.reference_sequence_id().is_none());
+        assert!(record.alignment_start().is_none());
+        assert_eq!(record.alignment_span(), 0);
+
+        let offset = usize::try_from(record.offset())?;
+        let mut src = &data[offset..];
+        let container_header = read_header(&mut src)?.ok_or("missing container header")?;
+
+        let container_len = u64::try_from(container_header.len())?;
+        assert_eq!(record.landmark() + record.slice_length(), container_len);
+
+        // The landmark is the start of the slice header block.
+        let landmark = usize::try_from(record.landmark())?;
+        let mut src = Bytes::copy_from_slice(&src[landmark..]);
+        let block = read_block(&mut src)?;
+        assert_eq!(block.content_type(), ContentType::SliceHeader);
+
+        Ok(())
+    }
+
+    // Writes and reads back a single record using the given writer builder. This returns the
+    // written data.
+    fn write_and_read_record(builder: Builder) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        use sam::record::{Flags, QualityScores, Sequence};
+
+        use crate::Reader;
+
+        let header = sam::Header::default();
+
+        let record = sam::alignment::Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_flags(Flags::UNMAPPED)
+            .set_sequence("ACGTACGTAC".parse::<Sequence>()?)
+            .set_quality_scores("NDLSNDLSND".parse::<QualityScores>()?)
+            .set_data("NH:i:1\tCO:Z:noodles".parse()?)
+            .build();
+
+        let mut writer = builder.build_with_writer(Vec::new());
+
+        writer.write_header(&header)?;
+        writer.write_record(
+            &header,
+            Record::try_from_alignment_record(&header, &record)?,
+        )?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref().clone();
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_header()?;
+
+        let records: Vec<_> = reader
+            .records(&header)
+            .map(|result| result.and_then(|r| r.try_into_alignment_record(&header)))
+            .collect::<io::Result<_>>()?;
+        assert_eq!(records, [record]);
+
+        Ok(data)
+    }
+
+    #[test]
+    fn test_write_record_with_compression_scheme() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{container::block::CompressionMethod, Reader};
+
+        for (compression_scheme, expected_compression_method) in [
+            (CompressionScheme::Uncompressed, CompressionMethod::None),
+            (
+                CompressionScheme::Gzip(flate2::Compression::best()),
+                CompressionMethod::Gzip,
+            ),
+            (CompressionScheme::Bzip2, CompressionMethod::Bzip2),
+            (CompressionScheme::RansOrder0, CompressionMethod::RansNx16),
+            (CompressionScheme::RansOrder1, CompressionMethod::RansNx16),
+        ] {
+            let builder = Builder::default().set_compression_scheme(compression_scheme);
+            let data = write_and_read_record(builder)?;
+
+            let mut reader = Reader::new(&data[..]);
+            reader.read_header()?;
+
+            let data_container = reader
+                .read_data_container()?
+                .ok_or("missing data container")?;
+
+            for slice in data_container.slices() {
+                for block in slice.external_blocks() {
+                    assert_eq!(block.compression_method(), expected_compression_method);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_encoding_profile() -> Result<(), Box<dyn std::error::Error>> {
+        for encoding_profile in [
+            EncodingProfile::Fast,
+            EncodingProfile::Balanced,
+            EncodingProfile::Best,
+        ] {
+            let builder = Builder::default().set_encoding_profile(encoding_profile);
+            write_and_read_record(builder)?;
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_add_missing_reference_sequence_checksums() -> Result<(), Box<dyn std::error::Error>> {
         use std::num::NonZeroUsize;
@@ -2,9 +2,9 @@ use std::io::Write;
 
 use noodles_fasta as fasta;
 
-use super::{Options, Writer};
+use super::{CompressionScheme, EncodingProfile, Options, Writer};
 use crate::{
-    codecs::Encoder, data_container::BlockContentEncoderMap, file_definition::Version,
+    codecs::Encoder, crai, data_container::BlockContentEncoderMap, file_definition::Version,
     DataContainer,
 };
 
@@ -87,6 +87,45 @@ impl Builder {
         self
     }
 
+    /// Sets the compression scheme.
+    ///
+    /// This applies a single codec to the core data block and all external data blocks, replacing
+    /// the current block content-encoder map.
+    ///
+    /// The default is gzip with the default compression level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{self as cram, writer::CompressionScheme};
+    /// let builder = cram::writer::Builder::default()
+    ///     .set_compression_scheme(CompressionScheme::RansOrder1);
+    /// ```
+    pub fn set_compression_scheme(mut self, compression_scheme: CompressionScheme) -> Self {
+        self.options.block_content_encoder_map = BlockContentEncoderMap::from(compression_scheme);
+        self
+    }
+
+    /// Sets the encoding profile.
+    ///
+    /// This selects a preset of codecs for the core data block, data series, and tag values,
+    /// replacing the current block content-encoder map. See [`EncodingProfile`] for the codecs
+    /// each profile uses.
+    ///
+    /// The default is [`EncodingProfile::Balanced`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{self as cram, writer::EncodingProfile};
+    /// let builder = cram::writer::Builder::default()
+    ///     .set_encoding_profile(EncodingProfile::Best);
+    /// ```
+    pub fn set_encoding_profile(mut self, encoding_profile: EncodingProfile) -> Self {
+        self.options.block_content_encoder_map = BlockContentEncoderMap::from(encoding_profile);
+        self
+    }
+
     /// Builds a CRAM writer.
     ///
     /// # Examples
@@ -109,6 +148,8 @@ impl Builder {
             options: self.options,
             data_container_builder: DataContainer::builder(0),
             record_counter: 0,
+            position: 0,
+            index_builder: crai::Builder::default(),
         }
     }
 }
@@ -132,6 +173,7 @@ pub fn uses_cram_3_1_codecs(block_content_encoder_map: &BlockContentEncoderMap)
         .iter()
         .chain(block_content_encoder_map.tag_values_encoders().values())
         .flatten()
+        .chain(block_content_encoder_map.default_tag_values_encoder())
         .any(is_cram_3_1_codec)
 }
 
@@ -0,0 +1,86 @@
+use crate::{
+    codecs::{rans_nx16, Encoder},
+    data_container::BlockContentEncoderMap,
+};
+
+/// A CRAM writer compression scheme.
+///
+/// A compression scheme applies a single codec to the core data block and all external data
+/// blocks. Use [`super::Builder::set_block_content_encoder_map`] to set codecs per block instead.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CompressionScheme {
+    /// No compression.
+    Uncompressed,
+    /// gzip.
+    Gzip(flate2::Compression),
+    /// bzip2.
+    Bzip2,
+    /// Order-0 rANS Nx16.
+    RansOrder0,
+    /// Order-1 rANS Nx16.
+    RansOrder1,
+}
+
+impl CompressionScheme {
+    fn encoder(&self) -> Option<Encoder> {
+        match self {
+            Self::Uncompressed => None,
+            Self::Gzip(compression_level) => Some(Encoder::Gzip(*compression_level)),
+            Self::Bzip2 => Some(Encoder::Bzip2(bzip2::Compression::default())),
+            Self::RansOrder0 => Some(Encoder::RansNx16(rans_nx16::Flags::empty())),
+            Self::RansOrder1 => Some(Encoder::RansNx16(rans_nx16::Flags::ORDER)),
+        }
+    }
+}
+
+impl Default for CompressionScheme {
+    fn default() -> Self {
+        Self::Gzip(flate2::Compression::default())
+    }
+}
+
+impl From<CompressionScheme> for BlockContentEncoderMap {
+    fn from(compression_scheme: CompressionScheme) -> Self {
+        use crate::data_container::compression_header::data_series_encoding_map::data_series::STANDARD_DATA_SERIES;
+
+        let encoder = compression_scheme.encoder();
+
+        let mut builder = Self::builder()
+            .set_core_data_encoder(encoder.clone())
+            .set_default_tag_values_encoder(encoder.clone());
+
+        for &data_series in STANDARD_DATA_SERIES {
+            builder = builder.set_data_series_encoder(data_series, encoder.clone());
+        }
+
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_compression_scheme_for_block_content_encoder_map() {
+        let map = BlockContentEncoderMap::from(CompressionScheme::Uncompressed);
+        assert!(map.core_data_encoder().is_none());
+        assert!(map.data_series_encoders().iter().all(Option::is_none));
+        assert!(map.default_tag_values_encoder().is_none());
+
+        fn is_order_1(encoder: Option<&Encoder>) -> bool {
+            match encoder {
+                Some(Encoder::RansNx16(flags)) => *flags == rans_nx16::Flags::ORDER,
+                _ => false,
+            }
+        }
+
+        let map = BlockContentEncoderMap::from(CompressionScheme::RansOrder1);
+        assert!(is_order_1(map.core_data_encoder()));
+        assert!(map
+            .data_series_encoders()
+            .iter()
+            .all(|encoder| is_order_1(encoder.as_ref())));
+        assert!(is_order_1(map.default_tag_values_encoder()));
+    }
+}
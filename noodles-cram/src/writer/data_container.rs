@@ -17,7 +17,7 @@ pub fn write_data_container<W>(
     writer: &mut W,
     data_container: &DataContainer,
     base_count: u64,
-) -> io::Result<()>
+) -> io::Result<Header>
 where
     W: Write,
 {
@@ -31,7 +31,7 @@ where
         write_block(writer, &block)?;
     }
 
-    Ok(())
+    Ok(header)
 }
 
 fn build_container(
@@ -49,9 +49,13 @@ fn build_container(
         .set_data(buf.into())
         .build();
 
-    let mut blocks = vec![block];
+    // Landmarks are the offsets of the slices from the start of the container data, i.e., after
+    // the compression header block.
+    let mut landmark = block.len();
     let mut landmarks = Vec::new();
 
+    let mut blocks = vec![block];
+
     let container_reference_sequence_context =
         build_container_reference_sequence_context(data_container.slices())?;
 
@@ -67,6 +71,8 @@ fn build_container(
 
         container_record_count += slice_header.record_count() as i32;
 
+        landmarks.push(landmark);
+
         let mut slice_len = 0;
 
         let mut slice_header_buf = Vec::new();
@@ -89,9 +95,7 @@ fn build_container(
             slice_len += external_block.len();
         }
 
-        let last_landmark = landmarks.last().copied().unwrap_or(0);
-        let landmark = last_landmark + slice_len;
-        landmarks.push(landmark);
+        landmark += slice_len;
     }
 
     let len = blocks.iter().map(|b| b.len()).sum();
@@ -162,3 +166,34 @@ fn build_container_reference_sequence_context(
 
     Ok(container_reference_sequence_context)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_container() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_fasta as fasta;
+        use noodles_sam as sam;
+
+        use crate::{container::block::ContentType, writer::Options, Record};
+
+        let mut builder = DataContainer::builder(0);
+        assert!(builder.add_record(Record::default()).is_ok());
+
+        let data_container = builder.build(
+            &Options::default(),
+            &fasta::Repository::default(),
+            &sam::Header::default(),
+        )?;
+
+        let (header, blocks) = build_container(&data_container, 0)?;
+
+        // The first slice starts after the compression header block.
+        let compression_header_block_len = blocks[0].len();
+        assert_eq!(header.landmarks(), [compression_header_block_len]);
+        assert_eq!(blocks[1].content_type(), ContentType::SliceHeader);
+
+        Ok(())
+    }
+}
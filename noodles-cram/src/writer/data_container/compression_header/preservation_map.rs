@@ -116,6 +116,211 @@ mod tests {
     use super::*;
     use crate::data_container::compression_header::preservation_map::tag_ids_dictionary::Key;
 
+    #[test]
+    fn test_write_preservation_map() -> io::Result<()> {
+        let preservation_map = PreservationMap::new(
+            false,
+            false,
+            false,
+            SubstitutionMatrix::default(),
+            TagIdsDictionary::from(vec![vec![Key::new(tag::COMMENT, Type::String)]]),
+        );
+
+        let mut buf = Vec::new();
+        write_preservation_map(&mut buf, &preservation_map)?;
+
+        let expected = [
+            0x18, // data.len = 24
+            0x05, // map.len = 5
+            0x52, 0x4e, // key = "RN"
+            0x00, // map["RN"] = false
+            0x41, 0x50, // key = "AP"
+            0x00, // map["AP"] = false
+            0x52, 0x52, // key = "RR"
+            0x00, // map["RR"] = false
+            0x53, 0x4d, // key = "SM"
+            // [[C, G, T, N], [A, G, T, N], [A, C, T, N], [A, C, G, N], [A, C, G, T]]
+            0x1b, 0x1b, 0x1b, 0x1b, 0x1b, // substitution matrix
+            0x54, 0x44, // key = "TD"
+            0x04, 0x43, 0x4f, 0x5a, 0x00, // tag IDs dictionary = [[CO:Z]]
+        ];
+
+        assert_eq!(buf, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_preservation_map_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        use bytes::Bytes;
+
+        use crate::reader::data_container::compression_header::preservation_map::get_preservation_map;
+
+        let substitution_matrix = SubstitutionMatrix::try_from([0xe4, 0x1b, 0x93, 0x6c, 0x4e])?;
+        assert_ne!(substitution_matrix, SubstitutionMatrix::default());
+
+        let tag_ids_dictionary = TagIdsDictionary::from(vec![
+            vec![],
+            vec![
+                Key::new(tag::ALIGNMENT_HIT_COUNT, Type::Int8),
+                Key::new(tag::COMMENT, Type::String),
+            ],
+        ]);
+
+        for read_names_included in [false, true] {
+            for ap_data_series_delta in [false, true] {
+                for reference_required in [false, true] {
+                    let preservation_map = PreservationMap::new(
+                        read_names_included,
+                        ap_data_series_delta,
+                        reference_required,
+                        substitution_matrix.clone(),
+                        tag_ids_dictionary.clone(),
+                    );
+
+                    let mut buf = Vec::new();
+                    write_preservation_map(&mut buf, &preservation_map)?;
+
+                    let mut src = Bytes::from(buf);
+                    let actual = get_preservation_map(&mut src)?;
+
+                    assert_eq!(actual, preservation_map);
+                    assert!(src.is_empty());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_preservation_map_with_bytes_from_cram_file(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_core::Position;
+        use noodles_fasta as fasta;
+        use noodles_sam::{
+            self as sam,
+            header::record::value::{map::ReferenceSequence, Map},
+            record::Flags,
+        };
+
+        use crate::{
+            container::block::ContentType,
+            reader::{
+                container::read_block,
+                data_container::{
+                    compression_header::preservation_map::get_preservation_map, header::read_header,
+                },
+            },
+            writer, Reader,
+        };
+
+        // Writes a CRAM file with read names, reference mismatches, and tags, and extracts the
+        // raw preservation map from its first compression header block.
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGTACGT".to_vec()),
+        )]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let alignment_records = [
+            sam::alignment::Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(1)?)
+                .set_cigar("4M".parse()?)
+                .set_sequence("ATGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .set_data("NH:i:1".parse()?)
+                .build(),
+            sam::alignment::Record::builder()
+                .set_read_name("r1".parse()?)
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(3)?)
+                .set_cigar("4M".parse()?)
+                .set_sequence("GTTC".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .set_data("NH:i:1\tCO:Z:noodles".parse()?)
+                .build(),
+        ];
+
+        let mut writer = writer::Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_with_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for alignment_record in &alignment_records {
+            let record = crate::Record::try_from_alignment_record(&header, alignment_record)?;
+            writer.write_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+
+        let inner = reader.get_mut();
+        read_header(inner)?.ok_or("missing data container")?;
+        let mut src = bytes::Bytes::copy_from_slice(inner);
+        let block = read_block(&mut src)?;
+        assert_eq!(block.content_type(), ContentType::CompressionHeader);
+
+        let data = block.decompressed_data()?;
+        let mut src = data.clone();
+        let preservation_map = get_preservation_map(&mut src)?;
+        let actual = &data[..data.len() - src.len()];
+
+        let expected = [
+            0x1f, // data.len = 31
+            0x05, // map.len = 5
+            0x52, 0x4e, // key = "RN"
+            0x01, // map["RN"] = true
+            0x41, 0x50, // key = "AP"
+            0x01, // map["AP"] = true
+            0x52, 0x52, // key = "RR"
+            0x01, // map["RR"] = true
+            0x53, 0x4d, // key = "SM"
+            // [[C, G, T, N], [A, G, T, N], [A, C, T, N], [A, C, G, N], [A, C, G, T]]
+            0x1b, 0x1b, 0x1b, 0x1b, 0x1b, // substitution matrix
+            0x54, 0x44, // key = "TD"
+            0x0b, // tag IDs dictionary.len = 11
+            0x4e, 0x48, 0x43, 0x00, // [NH:C]
+            0x4e, 0x48, 0x43, 0x43, 0x4f, 0x5a, 0x00, // [NH:C, CO:Z]
+        ];
+
+        assert_eq!(actual, expected);
+
+        let mut buf = Vec::new();
+        write_preservation_map(&mut buf, &preservation_map)?;
+        assert_eq!(buf, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_bool() -> io::Result<()> {
+        let mut buf = Vec::new();
+        write_bool(&mut buf, false)?;
+        assert_eq!(buf, [0x00]);
+
+        buf.clear();
+        write_bool(&mut buf, true)?;
+        assert_eq!(buf, [0x01]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_tag_ids_dictionary() -> io::Result<()> {
         let mut buf = Vec::new();
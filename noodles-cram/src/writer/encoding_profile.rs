@@ -0,0 +1,114 @@
+use super::CompressionScheme;
+use crate::{
+    codecs::{rans_nx16, Encoder},
+    data_container::{
+        compression_header::data_series_encoding_map::DataSeries, BlockContentEncoderMap,
+    },
+};
+
+/// A CRAM writer encoding profile.
+///
+/// An encoding profile is a preset of codecs for the core data block, data series, and tag
+/// values. It trades compression ratio for encoding speed. Use
+/// [`super::Builder::set_block_content_encoder_map`] to choose codecs per block instead.
+///
+/// Actual ratios and speeds depend on the input, e.g., read lengths, quality score binning, and
+/// the number of tags, so it is worth measuring with representative data.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum EncodingProfile {
+    /// Favor encoding speed over compression ratio.
+    ///
+    /// All blocks are compressed using gzip with the fastest compression level.
+    Fast,
+    /// Balance encoding speed and compression ratio.
+    ///
+    /// All blocks are compressed using gzip with the default compression level. This is the same
+    /// as the default block content-encoder map.
+    #[default]
+    Balanced,
+    /// Favor compression ratio over encoding speed.
+    ///
+    /// Bases are compressed using order-1 rANS Nx16, quality scores using bzip2, and all other
+    /// blocks using gzip with the best compression level. Using rANS Nx16 requires CRAM 3.1.
+    Best,
+}
+
+impl From<EncodingProfile> for BlockContentEncoderMap {
+    fn from(encoding_profile: EncodingProfile) -> Self {
+        let compression_level = match encoding_profile {
+            EncodingProfile::Fast => flate2::Compression::fast(),
+            EncodingProfile::Balanced => flate2::Compression::default(),
+            EncodingProfile::Best => flate2::Compression::best(),
+        };
+
+        let mut map = Self::from(CompressionScheme::Gzip(compression_level));
+
+        if encoding_profile == EncodingProfile::Best {
+            map.set_data_series_encoder(
+                DataSeries::Bases,
+                Some(Encoder::RansNx16(rans_nx16::Flags::ORDER)),
+            );
+
+            map.set_data_series_encoder(
+                DataSeries::QualityScores,
+                Some(Encoder::Bzip2(bzip2::Compression::best())),
+            );
+        }
+
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container::block;
+
+    #[test]
+    fn test_from_encoding_profile_for_block_content_encoder_map() {
+        fn get_data_series_encoder(
+            map: &BlockContentEncoderMap,
+            data_series: DataSeries,
+        ) -> Option<&Encoder> {
+            map.get_data_series_encoder(block::ContentId::from(data_series))
+                .flatten()
+        }
+
+        fn is_gzip(encoder: Option<&Encoder>, compression_level: flate2::Compression) -> bool {
+            matches!(encoder, Some(Encoder::Gzip(level)) if *level == compression_level)
+        }
+
+        let map = BlockContentEncoderMap::from(EncodingProfile::Fast);
+        let compression_level = flate2::Compression::fast();
+        assert!(is_gzip(map.core_data_encoder(), compression_level));
+        assert!(map
+            .data_series_encoders()
+            .iter()
+            .all(|encoder| is_gzip(encoder.as_ref(), compression_level)));
+        assert!(is_gzip(map.default_tag_values_encoder(), compression_level));
+
+        let map = BlockContentEncoderMap::from(EncodingProfile::Balanced);
+        let compression_level = flate2::Compression::default();
+        assert!(is_gzip(map.core_data_encoder(), compression_level));
+        assert!(map
+            .data_series_encoders()
+            .iter()
+            .all(|encoder| is_gzip(encoder.as_ref(), compression_level)));
+
+        let map = BlockContentEncoderMap::from(EncodingProfile::Best);
+        let compression_level = flate2::Compression::best();
+        assert!(is_gzip(map.core_data_encoder(), compression_level));
+        assert!(is_gzip(
+            get_data_series_encoder(&map, DataSeries::ReadNames),
+            compression_level
+        ));
+        assert!(matches!(
+            get_data_series_encoder(&map, DataSeries::Bases),
+            Some(Encoder::RansNx16(flags)) if *flags == rans_nx16::Flags::ORDER
+        ));
+        assert!(matches!(
+            get_data_series_encoder(&map, DataSeries::QualityScores),
+            Some(Encoder::Bzip2(_))
+        ));
+    }
+}
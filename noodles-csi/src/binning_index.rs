@@ -1,6 +1,7 @@
 //! Binning index utilities.
 
 use noodles_bgzf as bgzf;
+use noodles_core::Position;
 
 use super::index::reference_sequence::bin::Chunk;
 
@@ -102,6 +103,47 @@ pub fn optimize_chunks(chunks: &[Chunk], min_offset: bgzf::VirtualPosition) -> V
     merged_chunks
 }
 
+/// Returns the IDs of all bins that overlap the given interval.
+///
+/// This includes the bins at every level of the binning scheme, from the root (bin 0) to the
+/// leaves. Bin IDs are in ascending order. The interval is 1-based and inclusive, and the end
+/// must be <= the maximum position of the binning scheme, i.e., 2^(`min_shift` + `depth` * 3) - 1.
+///
+/// This is `reg2bins` as described in `CSIv1.pdf` (2020-07-21). Using a `min_shift` of 14 and a
+/// `depth` of 5 is compatible with the BAM index (BAI).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_csi::binning_index::reg2bins;
+///
+/// let start = Position::try_from(8)?;
+/// let end = Position::try_from(13)?;
+/// assert_eq!(reg2bins(start, end, 14, 5), [0, 1, 9, 73, 585, 4681]);
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+pub fn reg2bins(start: Position, end: Position, min_shift: u8, depth: u8) -> Vec<usize> {
+    // [beg, end), 0-based
+    let beg = usize::from(start) - 1;
+    let end = usize::from(end) - 1;
+
+    let mut bin_ids = Vec::new();
+    let mut t = 0;
+
+    for l in 0..=depth {
+        let s = u32::from(min_shift) + u32::from(depth - l) * 3;
+
+        let b = t + (beg >> s);
+        let e = t + (end >> s);
+        bin_ids.extend(b..=e);
+
+        t += 1 << (u32::from(l) * 3);
+    }
+
+    bin_ids
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,4 +225,44 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_reg2bins() -> Result<(), noodles_core::position::TryFromIntError> {
+        const MIN_SHIFT: u8 = 14;
+        const DEPTH: u8 = 5;
+
+        let start = Position::try_from(1)?;
+        let end = Position::try_from(1)?;
+        assert_eq!(
+            reg2bins(start, end, MIN_SHIFT, DEPTH),
+            [0, 1, 9, 73, 585, 4681]
+        );
+
+        let end = Position::try_from(16384)?;
+        assert_eq!(
+            reg2bins(start, end, MIN_SHIFT, DEPTH),
+            [0, 1, 9, 73, 585, 4681]
+        );
+
+        let end = Position::try_from(16385)?;
+        assert_eq!(
+            reg2bins(start, end, MIN_SHIFT, DEPTH),
+            [0, 1, 9, 73, 585, 4681, 4682]
+        );
+
+        let start = Position::try_from(131073)?;
+        let end = Position::try_from(131073)?;
+        assert_eq!(
+            reg2bins(start, end, MIN_SHIFT, DEPTH),
+            [0, 1, 9, 73, 586, 4689]
+        );
+
+        let start = Position::try_from(1)?;
+        let end = Position::try_from(536870911)?;
+        let bin_ids = reg2bins(start, end, MIN_SHIFT, DEPTH);
+        assert_eq!(bin_ids.len(), 37449);
+        assert!(bin_ids.iter().copied().eq(0..37449));
+
+        Ok(())
+    }
 }
@@ -4,9 +4,16 @@ mod builder;
 pub mod header;
 mod indexer;
 pub mod reference_sequence;
+mod stats;
+mod validate;
 
 pub use self::{
-    builder::Builder, header::Header, indexer::Indexer, reference_sequence::ReferenceSequence,
+    builder::Builder,
+    header::Header,
+    indexer::Indexer,
+    reference_sequence::ReferenceSequence,
+    stats::{IndexStats, ReferenceSequenceStats},
+    validate::ValidationError,
 };
 
 use std::io;
@@ -104,40 +111,390 @@ impl Index {
         self.unplaced_unmapped_record_count
     }
 
+    /// Returns the record counts of the reference sequence with the given ID.
+    ///
+    /// This returns `None` if the reference sequence does not exist or does not have metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::{
+    ///     self as csi,
+    ///     index::{reference_sequence::Metadata, ReferenceSequence},
+    /// };
+    ///
+    /// let metadata = Metadata::new(
+    ///     bgzf::VirtualPosition::from(610),
+    ///     bgzf::VirtualPosition::from(1597),
+    ///     55,
+    ///     0,
+    /// );
+    ///
+    /// let index = csi::Index::builder()
+    ///     .set_reference_sequences(vec![ReferenceSequence::new(
+    ///         Default::default(),
+    ///         Vec::new(),
+    ///         Some(metadata),
+    ///     )])
+    ///     .build();
+    ///
+    /// let stats = index.reference_sequence_stats(0).unwrap();
+    /// assert_eq!(stats.mapped_record_count(), 55);
+    /// assert_eq!(stats.unmapped_record_count(), 0);
+    ///
+    /// assert!(index.reference_sequence_stats(1).is_none());
+    /// ```
+    pub fn reference_sequence_stats(
+        &self,
+        reference_sequence_id: usize,
+    ) -> Option<ReferenceSequenceStats> {
+        self.reference_sequences()
+            .get(reference_sequence_id)
+            .and_then(|reference_sequence| reference_sequence.metadata())
+            .map(|metadata| {
+                ReferenceSequenceStats::new(
+                    metadata.mapped_record_count(),
+                    metadata.unmapped_record_count(),
+                )
+            })
+    }
+
+    /// Returns the record counts of all reference sequences.
+    ///
+    /// This sums the record counts in the metadata of each reference sequence. Reference
+    /// sequences without metadata are not counted. The number of unplaced, unmapped records is
+    /// the same as [`Self::unplaced_unmapped_record_count`].
+    ///
+    /// Together with [`Self::reference_sequence_stats`], this can produce the same output as
+    /// `samtools idxstats` without reading the records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    ///
+    /// let index = csi::Index::builder()
+    ///     .set_unplaced_unmapped_record_count(8)
+    ///     .build();
+    ///
+    /// let stats = index.total_stats();
+    /// assert_eq!(stats.mapped_record_count(), 0);
+    /// assert_eq!(stats.unmapped_record_count(), 0);
+    /// assert_eq!(stats.unplaced_unmapped_record_count(), Some(8));
+    /// ```
+    pub fn total_stats(&self) -> IndexStats {
+        let (mapped_record_count, unmapped_record_count) = self
+            .reference_sequences()
+            .iter()
+            .filter_map(|reference_sequence| reference_sequence.metadata())
+            .fold((0, 0), |(mapped, unmapped), metadata| {
+                (
+                    mapped + metadata.mapped_record_count(),
+                    unmapped + metadata.unmapped_record_count(),
+                )
+            });
+
+        IndexStats::new(
+            mapped_record_count,
+            unmapped_record_count,
+            self.unplaced_unmapped_record_count(),
+        )
+    }
+
     /// Returns the chunks that overlap with the given region.
+    ///
+    /// The chunks of the bins that overlap the interval are merged, sorted, and trimmed to start
+    /// no earlier than the minimum offset in the linear index for the start of the interval.
+    /// Adjacent and overlapping chunks are merged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::{
+    ///     self as csi,
+    ///     index::{
+    ///         reference_sequence::{bin::Chunk, Bin},
+    ///         ReferenceSequence,
+    ///     },
+    /// };
+    ///
+    /// const LEAF_BIN_ID: usize = 4681;
+    ///
+    /// let chunk = Chunk::new(
+    ///     bgzf::VirtualPosition::from(8),
+    ///     bgzf::VirtualPosition::from(13),
+    /// );
+    ///
+    /// let bins = [(LEAF_BIN_ID, Bin::new(bgzf::VirtualPosition::default(), vec![chunk]))]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let index = csi::Index::builder()
+    ///     .set_reference_sequences(vec![ReferenceSequence::new(bins, Vec::new(), None)])
+    ///     .build();
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    /// assert_eq!(index.query(0, start..=end)?, [chunk]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
     pub fn query<I>(&self, reference_sequence_id: usize, interval: I) -> io::Result<Vec<Chunk>>
     where
         I: Into<Interval>,
     {
-        use super::binning_index::optimize_chunks;
+        self.query_regions(&[(reference_sequence_id, interval.into())])
+    }
+
+    /// Returns the chunks that overlap with any of the given regions.
+    ///
+    /// Each region is a reference sequence ID and an interval. The chunks of each region are
+    /// resolved as in [`Self::query`], and the union of them is returned sorted and merged, i.e.,
+    /// overlapping and adjacent chunks are combined. Regions can be on different reference
+    /// sequences and can overlap.
+    ///
+    /// This is similar to `hts_itr_multi_querys` in htslib.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::{
+    ///     self as csi,
+    ///     index::{
+    ///         reference_sequence::{bin::Chunk, Bin},
+    ///         ReferenceSequence,
+    ///     },
+    /// };
+    ///
+    /// fn chunk(start: u64, end: u64) -> Chunk {
+    ///     Chunk::new(
+    ///         bgzf::VirtualPosition::from(start),
+    ///         bgzf::VirtualPosition::from(end),
+    ///     )
+    /// }
+    ///
+    /// let bins = [
+    ///     (4681, Bin::new(bgzf::VirtualPosition::default(), vec![chunk(8, 13)])),
+    ///     (4682, Bin::new(bgzf::VirtualPosition::default(), vec![chunk(13, 21)])),
+    ///     (4683, Bin::new(bgzf::VirtualPosition::default(), vec![chunk(34, 55)])),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let index = csi::Index::builder()
+    ///     .set_reference_sequences(vec![ReferenceSequence::new(bins, Vec::new(), None)])
+    ///     .build();
+    ///
+    /// let regions = [
+    ///     (0, (Position::try_from(1)?..=Position::try_from(8)?).into()),
+    ///     (0, (Position::try_from(16385)?..=Position::try_from(16393)?).into()),
+    /// ];
+    ///
+    /// assert_eq!(index.query_regions(&regions)?, [chunk(8, 21)]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions(&self, regions: &[(usize, Interval)]) -> io::Result<Vec<Chunk>> {
+        use super::binning_index::{merge_chunks, optimize_chunks};
 
-        let interval = interval.into();
+        let mut chunks = Vec::new();
 
-        let reference_sequence = self
-            .reference_sequences()
-            .get(reference_sequence_id)
-            .ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    format!("invalid reference sequence ID: {reference_sequence_id}"),
-                )
-            })?;
+        for &(reference_sequence_id, interval) in regions {
+            let reference_sequence = self
+                .reference_sequences()
+                .get(reference_sequence_id)
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("invalid reference sequence ID: {reference_sequence_id}"),
+                    )
+                })?;
 
-        let query_bins = reference_sequence
-            .query(self.min_shift(), self.depth(), interval)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+            let query_bins = reference_sequence
+                .query(self.min_shift(), self.depth(), interval)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
-        let chunks: Vec<_> = query_bins
-            .iter()
-            .flat_map(|bin| bin.chunks())
-            .copied()
-            .collect();
+            let region_chunks: Vec<_> = query_bins
+                .iter()
+                .flat_map(|bin| bin.chunks())
+                .copied()
+                .collect();
+
+            let (start, _) = resolve_interval(self.min_shift(), self.depth(), interval)?;
+            let min_offset = reference_sequence.min_offset(self.min_shift(), self.depth(), start);
+
+            chunks.extend(
+                optimize_chunks(&region_chunks, min_offset)
+                    .into_iter()
+                    .map(|chunk| {
+                        if chunk.start() < min_offset {
+                            Chunk::new(min_offset, chunk.end())
+                        } else {
+                            chunk
+                        }
+                    }),
+            );
+        }
+
+        Ok(merge_chunks(&chunks))
+    }
+
+    /// Validates the bins of the index.
+    ///
+    /// This checks that, for each reference sequence,
+    ///
+    ///   * bin IDs are in range for the index depth;
+    ///   * chunk start positions are <= their end positions;
+    ///   * chunks in a bin are sorted by start position; and
+    ///   * bin linear offsets are >= the linear offset of their parent bin.
+    ///
+    /// A corrupt index can otherwise cause queries to return wrong results or panic.
+    ///
+    /// # Errors
+    ///
+    /// The first violation is returned, in reference sequence and bin ID order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let index = csi::Index::default();
+    /// assert!(index.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validate::validate(self)
+    }
+
+    /// Merges another index into this index.
+    ///
+    /// This is used to combine indices of separately indexed parts of a file, e.g., shards that
+    /// are concatenated after indexing. `offset` is the position in the concatenated file of the
+    /// start of the part indexed by `other`. It must be at the start of a block, i.e., its
+    /// uncompressed position must be 0. The records indexed by `other` are assumed to come after
+    /// the records indexed by this index.
+    ///
+    /// The virtual positions of `other` are shifted by the compressed position of `offset`. Bins,
+    /// linear indices, and metadata are merged per reference sequence, and record counts are
+    /// summed.
+    ///
+    /// This returns an error if the indices have different min shifts, depths, headers, or
+    /// numbers of reference sequences. On error, this index is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::{
+    ///     self as csi,
+    ///     index::{
+    ///         reference_sequence::{bin::Chunk, Bin},
+    ///         ReferenceSequence,
+    ///     },
+    /// };
+    ///
+    /// const LEAF_BIN_ID: usize = 4681;
+    ///
+    /// fn build_index(start: u64, end: u64) -> csi::Index {
+    ///     let chunk = Chunk::new(
+    ///         bgzf::VirtualPosition::from(start),
+    ///         bgzf::VirtualPosition::from(end),
+    ///     );
+    ///
+    ///     let bins = [(LEAF_BIN_ID, Bin::new(chunk.start(), vec![chunk]))]
+    ///         .into_iter()
+    ///         .collect();
+    ///
+    ///     csi::Index::builder()
+    ///         .set_reference_sequences(vec![ReferenceSequence::new(bins, Vec::new(), None)])
+    ///         .build()
+    /// }
+    ///
+    /// let mut index = build_index(0, 8);
+    /// let other = build_index(0, 13);
+    ///
+    /// let offset = bgzf::VirtualPosition::try_from((21, 0))?;
+    /// index.try_merge(&other, offset)?;
+    ///
+    /// let bin = &index.reference_sequences()[0].bins()[&LEAF_BIN_ID];
+    ///
+    /// assert_eq!(bin.chunks(), [
+    ///     Chunk::new(
+    ///         bgzf::VirtualPosition::try_from((0, 0))?,
+    ///         bgzf::VirtualPosition::try_from((0, 8))?,
+    ///     ),
+    ///     Chunk::new(
+    ///         bgzf::VirtualPosition::try_from((21, 0))?,
+    ///         bgzf::VirtualPosition::try_from((21, 13))?,
+    ///     ),
+    /// ]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_merge(&mut self, other: &Index, offset: bgzf::VirtualPosition) -> io::Result<()> {
+        if other.min_shift() != self.min_shift() || other.depth() != self.depth() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "min shift and depth mismatch: expected ({}, {}), got ({}, {})",
+                    self.min_shift(),
+                    self.depth(),
+                    other.min_shift(),
+                    other.depth()
+                ),
+            ));
+        }
+
+        if other.header() != self.header() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "header mismatch",
+            ));
+        }
+
+        if other.reference_sequences().len() != self.reference_sequences().len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "reference sequence count mismatch: expected {}, got {}",
+                    self.reference_sequences().len(),
+                    other.reference_sequences().len()
+                ),
+            ));
+        }
+
+        if offset.uncompressed() != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "offset is not at the start of a block",
+            ));
+        }
+
+        let offset = offset.compressed();
 
-        let (start, _) = resolve_interval(self.min_shift(), self.depth(), interval)?;
-        let min_offset = reference_sequence.min_offset(self.min_shift(), self.depth(), start);
-        let merged_chunks = optimize_chunks(&chunks, min_offset);
+        let mut reference_sequences = self.reference_sequences.clone();
 
-        Ok(merged_chunks)
+        for (reference_sequence, other_reference_sequence) in reference_sequences
+            .iter_mut()
+            .zip(other.reference_sequences())
+        {
+            reference_sequence.merge(other_reference_sequence, offset)?;
+        }
+
+        let unplaced_unmapped_record_count = match (
+            self.unplaced_unmapped_record_count(),
+            other.unplaced_unmapped_record_count(),
+        ) {
+            (Some(m), Some(n)) => Some(m + n),
+            (Some(n), None) | (None, Some(n)) => Some(n),
+            (None, None) => None,
+        };
+
+        self.reference_sequences = reference_sequences;
+        self.unplaced_unmapped_record_count = unplaced_unmapped_record_count;
+
+        Ok(())
     }
 
     /// Returns the start position of the first record in the last linear bin.
@@ -186,3 +543,282 @@ where
         Ok((start, end))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::index::reference_sequence::Bin;
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        fn chunk(start: u64, end: u64) -> Chunk {
+            Chunk::new(
+                bgzf::VirtualPosition::from(start),
+                bgzf::VirtualPosition::from(end),
+            )
+        }
+
+        let bins: HashMap<_, _> = [
+            (
+                0,
+                Bin::new(bgzf::VirtualPosition::default(), vec![chunk(5, 12)]),
+            ),
+            (
+                4681,
+                Bin::new(bgzf::VirtualPosition::default(), vec![chunk(10, 20)]),
+            ),
+            (
+                4682,
+                Bin::new(bgzf::VirtualPosition::default(), vec![chunk(20, 30)]),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let linear_index = vec![
+            bgzf::VirtualPosition::from(8),
+            bgzf::VirtualPosition::from(20),
+        ];
+
+        let index = Index::builder()
+            .set_reference_sequences(vec![ReferenceSequence::new(bins, linear_index, None)])
+            .build();
+
+        let start = Position::try_from(1)?;
+        let end = Position::try_from(10)?;
+        assert_eq!(index.query(0, start..=end)?, [chunk(8, 20)]);
+
+        let start = Position::try_from(16385)?;
+        let end = Position::try_from(20000)?;
+        assert_eq!(index.query(0, start..=end)?, [chunk(20, 30)]);
+
+        let start = Position::try_from(1)?;
+        let end = Position::try_from(20000)?;
+        assert_eq!(index.query(0, start..=end)?, [chunk(8, 30)]);
+
+        assert!(matches!(
+            index.query(1, ..),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_total_stats() {
+        use super::reference_sequence::Metadata;
+
+        fn build_reference_sequence(metadata: Option<Metadata>) -> ReferenceSequence {
+            ReferenceSequence::new(HashMap::new(), Vec::new(), metadata)
+        }
+
+        let index = Index::builder()
+            .set_reference_sequences(vec![
+                build_reference_sequence(Some(Metadata::new(
+                    bgzf::VirtualPosition::from(8),
+                    bgzf::VirtualPosition::from(13),
+                    5,
+                    2,
+                ))),
+                build_reference_sequence(None),
+                build_reference_sequence(Some(Metadata::new(
+                    bgzf::VirtualPosition::from(21),
+                    bgzf::VirtualPosition::from(34),
+                    3,
+                    1,
+                ))),
+            ])
+            .set_unplaced_unmapped_record_count(13)
+            .build();
+
+        assert_eq!(
+            index.reference_sequence_stats(2),
+            Some(ReferenceSequenceStats::new(3, 1))
+        );
+        assert!(index.reference_sequence_stats(1).is_none());
+        assert_eq!(index.total_stats(), IndexStats::new(8, 3, Some(13)));
+    }
+
+    #[test]
+    fn test_query_regions() -> Result<(), Box<dyn std::error::Error>> {
+        fn chunk(start: u64, end: u64) -> Chunk {
+            Chunk::new(
+                bgzf::VirtualPosition::from(start),
+                bgzf::VirtualPosition::from(end),
+            )
+        }
+
+        fn interval(start: usize, end: usize) -> Result<Interval, Box<dyn std::error::Error>> {
+            Ok((Position::try_from(start)?..=Position::try_from(end)?).into())
+        }
+
+        let bins_0: HashMap<_, _> = [
+            (
+                4681,
+                Bin::new(bgzf::VirtualPosition::default(), vec![chunk(5, 13)]),
+            ),
+            (
+                4683,
+                Bin::new(bgzf::VirtualPosition::default(), vec![chunk(21, 34)]),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let bins_1: HashMap<_, _> = [(
+            4681,
+            Bin::new(bgzf::VirtualPosition::default(), vec![chunk(34, 55)]),
+        )]
+        .into_iter()
+        .collect();
+
+        let index = Index::builder()
+            .set_reference_sequences(vec![
+                ReferenceSequence::new(bins_0, Vec::new(), None),
+                ReferenceSequence::new(bins_1, Vec::new(), None),
+            ])
+            .build();
+
+        assert!(index.query_regions(&[])?.is_empty());
+
+        let regions = [
+            (0, interval(1, 8)?),
+            (0, interval(5, 13)?),
+            (0, interval(32769, 32777)?),
+        ];
+        assert_eq!(
+            index.query_regions(&regions)?,
+            [chunk(5, 13), chunk(21, 34)]
+        );
+
+        let regions = [(1, interval(1, 8)?), (0, interval(32769, 32777)?)];
+        assert_eq!(index.query_regions(&regions)?, [chunk(21, 55)]);
+
+        let regions = [(0, interval(1, 8)?), (2, interval(1, 8)?)];
+        assert!(matches!(
+            index.query_regions(&regions),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_merge() -> Result<(), Box<dyn std::error::Error>> {
+        type Record = (Option<(usize, Position, Position, bool)>, Chunk);
+
+        fn chunk(start: (u64, u16), end: (u64, u16)) -> Result<Chunk, Box<dyn std::error::Error>> {
+            Ok(Chunk::new(
+                bgzf::VirtualPosition::try_from(start)?,
+                bgzf::VirtualPosition::try_from(end)?,
+            ))
+        }
+
+        fn build_index(records: &[Record]) -> io::Result<Index> {
+            let mut indexer = Indexer::default();
+
+            for (alignment_context, chunk) in records {
+                indexer.add_record(*alignment_context, *chunk)?;
+            }
+
+            Ok(indexer.build(2))
+        }
+
+        let records_0 = [
+            (
+                Some((0, Position::try_from(1)?, Position::try_from(10)?, true)),
+                chunk((0, 0), (0, 100))?,
+            ),
+            (
+                Some((
+                    0,
+                    Position::try_from(20000)?,
+                    Position::try_from(20010)?,
+                    false,
+                )),
+                chunk((0, 100), (0, 200))?,
+            ),
+        ];
+
+        let records_1 = [
+            (
+                Some((
+                    0,
+                    Position::try_from(40000)?,
+                    Position::try_from(40010)?,
+                    true,
+                )),
+                chunk((0, 30), (0, 50))?,
+            ),
+            (
+                Some((1, Position::try_from(5)?, Position::try_from(8)?, true)),
+                chunk((0, 50), (0, 90))?,
+            ),
+            (None, chunk((0, 90), (0, 120))?),
+        ];
+
+        // The second shard starts at compressed position 21 in the concatenated file.
+        let concatenated_records = [
+            records_0[0],
+            records_0[1],
+            (records_1[0].0, chunk((21, 30), (21, 50))?),
+            (records_1[1].0, chunk((21, 50), (21, 90))?),
+            (records_1[2].0, chunk((21, 90), (21, 120))?),
+        ];
+
+        let mut index = build_index(&records_0)?;
+        let other = build_index(&records_1)?;
+        index.try_merge(&other, bgzf::VirtualPosition::try_from((21, 0))?)?;
+
+        assert_eq!(index, build_index(&concatenated_records)?);
+
+        let metadata = index.reference_sequences()[0]
+            .metadata()
+            .ok_or("missing metadata")?;
+        assert_eq!(metadata.mapped_record_count(), 2);
+        assert_eq!(metadata.unmapped_record_count(), 1);
+
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_merge_with_incompatible_indices() {
+        let mut index = Index::default();
+
+        let other = Index::builder().set_min_shift(12).build();
+        assert!(matches!(
+            index.try_merge(&other, bgzf::VirtualPosition::default()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let other = Index::builder().set_depth(6).build();
+        assert!(matches!(
+            index.try_merge(&other, bgzf::VirtualPosition::default()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let other = Index::builder()
+            .set_reference_sequences(vec![ReferenceSequence::new(
+                HashMap::new(),
+                Vec::new(),
+                None,
+            )])
+            .build();
+        assert!(matches!(
+            index.try_merge(&other, bgzf::VirtualPosition::default()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let other = Index::default();
+        assert!(matches!(
+            index.try_merge(&other, bgzf::VirtualPosition::from(1)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert_eq!(index, Index::default());
+    }
+}
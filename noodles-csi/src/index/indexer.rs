@@ -132,6 +132,8 @@ impl Indexer {
         // SAFETY: `reference_sequence_count` is > 0.
         let last_reference_sequence_id = reference_sequence_count - 1;
         self.add_reference_sequences_builders_until(last_reference_sequence_id);
+        self.reference_sequences
+            .push(self.reference_sequence_builder.build());
 
         let mut builder = Index::builder()
             .set_reference_sequences(self.reference_sequences)
@@ -184,4 +186,29 @@ mod tests {
         assert!(indexer.reference_sequences.is_empty());
         assert_eq!(indexer.unplaced_unmapped_record_count, 0);
     }
+
+    #[test]
+    fn test_build() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_bgzf as bgzf;
+
+        let mut indexer = Indexer::default();
+
+        let chunk = Chunk::new(
+            bgzf::VirtualPosition::from(144),
+            bgzf::VirtualPosition::from(233),
+        );
+
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+        indexer.add_record(Some((1, start, end, true)), chunk)?;
+
+        let index = indexer.build(2);
+        let reference_sequences = index.reference_sequences();
+
+        assert_eq!(reference_sequences.len(), 2);
+        assert!(reference_sequences[0].bins().is_empty());
+        assert_eq!(reference_sequences[1].bins().len(), 1);
+
+        Ok(())
+    }
 }
@@ -14,10 +14,6 @@ use noodles_core::{region::Interval, Position};
 
 use super::resolve_interval;
 
-// _Sequence Alignment/Map Format Specification_ (2022-08-22) § 5.1.2 "Combining with linear
-// index": "...each tiling 16384bp window..."
-const LINEAR_INDEX_WINDOW_SIZE: usize = 1 << 14;
-
 /// A CSI reference sequence.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ReferenceSequence {
@@ -189,7 +185,7 @@ impl ReferenceSequence {
 
             bgzf::VirtualPosition::default()
         } else {
-            let i = (usize::from(start) - 1) / LINEAR_INDEX_WINDOW_SIZE;
+            let i = (usize::from(start) - 1) / linear_index_window_size(min_shift);
             self.linear_index.get(i).copied().unwrap_or_default()
         }
     }
@@ -246,6 +242,122 @@ impl ReferenceSequence {
             self.linear_index().last().copied()
         }
     }
+
+    /// Merges another reference sequence into this one.
+    ///
+    /// The virtual positions of `other` are shifted by the compressed position `offset`. All
+    /// records of `other` are assumed to come after the records of this reference sequence.
+    pub(super) fn merge(&mut self, other: &Self, offset: u64) -> io::Result<()> {
+        use std::collections::hash_map::Entry;
+
+        for (&id, bin) in other.bins() {
+            let loffset = shift_virtual_position(bin.loffset(), offset)?;
+
+            let chunks = bin
+                .chunks()
+                .iter()
+                .map(|chunk| {
+                    let start = shift_virtual_position(chunk.start(), offset)?;
+                    let end = shift_virtual_position(chunk.end(), offset)?;
+                    Ok(bin::Chunk::new(start, end))
+                })
+                .collect::<io::Result<Vec<_>>>()?;
+
+            match self.bins.entry(id) {
+                Entry::Occupied(mut entry) => {
+                    let bin = entry.get();
+
+                    let mut merged_chunks = bin.chunks().to_vec();
+                    merged_chunks.extend(chunks);
+
+                    let merged_bin = Bin::new(bin.loffset().min(loffset), merged_chunks);
+                    entry.insert(merged_bin);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(Bin::new(loffset, chunks));
+                }
+            }
+        }
+
+        self.propagate_loffsets();
+
+        if other.linear_index.len() > self.linear_index.len() {
+            self.linear_index
+                .resize(other.linear_index.len(), bgzf::VirtualPosition::default());
+        }
+
+        // An empty window is stored as the default virtual position, which is also the most
+        // conservative lower bound. Such windows in `other` are not shifted.
+        for (position, &other_position) in self.linear_index.iter_mut().zip(&other.linear_index) {
+            if *position == bgzf::VirtualPosition::default()
+                && other_position != bgzf::VirtualPosition::default()
+            {
+                *position = shift_virtual_position(other_position, offset)?;
+            }
+        }
+
+        if let Some(other_metadata) = other.metadata() {
+            let start_position = shift_virtual_position(other_metadata.start_position(), offset)?;
+            let end_position = shift_virtual_position(other_metadata.end_position(), offset)?;
+
+            let metadata = match self.metadata.take() {
+                Some(metadata) => Metadata::new(
+                    metadata.start_position().min(start_position),
+                    metadata.end_position().max(end_position),
+                    metadata.mapped_record_count() + other_metadata.mapped_record_count(),
+                    metadata.unmapped_record_count() + other_metadata.unmapped_record_count(),
+                ),
+                None => Metadata::new(
+                    start_position,
+                    end_position,
+                    other_metadata.mapped_record_count(),
+                    other_metadata.unmapped_record_count(),
+                ),
+            };
+
+            self.metadata = Some(metadata);
+        }
+
+        Ok(())
+    }
+
+    // A bin's linear offset is the smallest linear offset of itself and all of its descendants.
+    fn propagate_loffsets(&mut self) {
+        let loffsets: Vec<_> = self
+            .bins
+            .iter()
+            .map(|(&id, bin)| (id, bin.loffset()))
+            .collect();
+
+        for (mut id, loffset) in loffsets {
+            while let Some(pid) = parent_id(id) {
+                if let Some(parent) = self.bins.get_mut(&pid) {
+                    if loffset < parent.loffset() {
+                        *parent = Bin::new(loffset, parent.chunks().to_vec());
+                    }
+                }
+
+                id = pid;
+            }
+        }
+    }
+}
+
+fn shift_virtual_position(
+    position: bgzf::VirtualPosition,
+    offset: u64,
+) -> io::Result<bgzf::VirtualPosition> {
+    let (compressed_pos, uncompressed_pos) = position.into();
+
+    compressed_pos
+        .checked_add(offset)
+        .and_then(|pos| bgzf::VirtualPosition::try_from((pos, uncompressed_pos)).ok())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "shifted virtual position overflows",
+            )
+        })
 }
 
 const M: usize = match NonZeroUsize::new(8) {
@@ -254,7 +366,7 @@ const M: usize = match NonZeroUsize::new(8) {
 };
 
 // parent of i = floor((i - 1) / M)
-fn parent_id(id: usize) -> Option<usize> {
+pub(crate) fn parent_id(id: usize) -> Option<usize> {
     // See <https://github.com/rust-lang/rust-clippy/issues/9422>.
     #[allow(clippy::unnecessary_lazy_evaluations)]
     (id > 0).then(|| (id - 1) / M)
@@ -284,32 +396,19 @@ fn reg2bin(start: Position, end: Position, min_shift: u8, depth: u8) -> usize {
     0
 }
 
-// `CSIv1.pdf` (2020-07-21)
-#[allow(clippy::many_single_char_names)]
 fn reg2bins(start: Position, end: Position, min_shift: u8, depth: u8, bins: &mut BitVec) {
-    // [beg, end), 0-based
-    let beg = usize::from(start) - 1;
-    let end = usize::from(end);
-
-    let end = end - 1;
-    let mut l = 0;
-    let mut t = 0;
-    let mut s = i32::from(min_shift) + i32::from(depth) * 3;
-
-    while l <= depth {
-        let b = t + (beg >> s);
-        let e = t + (end >> s);
-
-        for i in b..=e {
-            bins.set(i, true);
-        }
-
-        s -= 3;
-        t += 1 << (l * 3);
-        l += 1;
+    for bin_id in crate::binning_index::reg2bins(start, end, min_shift, depth) {
+        bins.set(bin_id, true);
     }
 }
 
+// _Sequence Alignment/Map Format Specification_ (2022-08-22) § 5.1.2 "Combining with linear
+// index": "...each tiling 16384bp window..." This is the size of the smallest bin, i.e., 2^14 for
+// a BAI and 2^min_shift for a CSI.
+fn linear_index_window_size(min_shift: u8) -> usize {
+    1 << min_shift
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
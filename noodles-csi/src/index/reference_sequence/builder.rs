@@ -31,10 +31,94 @@ impl Builder {
         chunk: Chunk,
     ) {
         self.update_bins(min_shift, depth, start, end, chunk);
-        self.update_linear_index(start, end, chunk);
+        self.update_linear_index(min_shift, start, end, chunk);
         self.update_metadata(is_mapped, chunk);
     }
 
+    /// Returns the number of mapped records added so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::index::reference_sequence::{bin::Chunk, Builder};
+    ///
+    /// let mut builder = Builder::default();
+    /// assert_eq!(builder.mapped_record_count(), 0);
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    /// let chunk = Chunk::new(
+    ///     bgzf::VirtualPosition::from(144),
+    ///     bgzf::VirtualPosition::from(233),
+    /// );
+    /// builder.add_record(14, 5, start, end, true, chunk);
+    ///
+    /// assert_eq!(builder.mapped_record_count(), 1);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn mapped_record_count(&self) -> u64 {
+        self.mapped_record_count
+    }
+
+    /// Returns the number of unmapped records added so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::index::reference_sequence::{bin::Chunk, Builder};
+    ///
+    /// let mut builder = Builder::default();
+    /// assert_eq!(builder.unmapped_record_count(), 0);
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    /// let chunk = Chunk::new(
+    ///     bgzf::VirtualPosition::from(144),
+    ///     bgzf::VirtualPosition::from(233),
+    /// );
+    /// builder.add_record(14, 5, start, end, false, chunk);
+    ///
+    /// assert_eq!(builder.unmapped_record_count(), 1);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn unmapped_record_count(&self) -> u64 {
+        self.unmapped_record_count
+    }
+
+    /// Returns the number of records added so far.
+    ///
+    /// This is the sum of the mapped and unmapped record counts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::index::reference_sequence::{bin::Chunk, Builder};
+    ///
+    /// let mut builder = Builder::default();
+    /// assert_eq!(builder.record_count(), 0);
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    /// let chunk = Chunk::new(
+    ///     bgzf::VirtualPosition::from(144),
+    ///     bgzf::VirtualPosition::from(233),
+    /// );
+    /// builder.add_record(14, 5, start, end, true, chunk);
+    /// builder.add_record(14, 5, start, end, false, chunk);
+    ///
+    /// assert_eq!(builder.record_count(), 2);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn record_count(&self) -> u64 {
+        self.mapped_record_count + self.unmapped_record_count
+    }
+
     /// Builds a CSI reference sequence.
     pub fn build(mut self) -> ReferenceSequence {
         use super::parent_id;
@@ -98,11 +182,12 @@ impl Builder {
         builder.add_chunk(chunk);
     }
 
-    fn update_linear_index(&mut self, start: Position, end: Position, chunk: Chunk) {
-        use super::LINEAR_INDEX_WINDOW_SIZE;
+    fn update_linear_index(&mut self, min_shift: u8, start: Position, end: Position, chunk: Chunk) {
+        use super::linear_index_window_size;
 
-        let linear_index_start_offset = (usize::from(start) - 1) / LINEAR_INDEX_WINDOW_SIZE;
-        let linear_index_end_offset = (usize::from(end) - 1) / LINEAR_INDEX_WINDOW_SIZE;
+        let window_size = linear_index_window_size(min_shift);
+        let linear_index_start_offset = (usize::from(start) - 1) / window_size;
+        let linear_index_end_offset = (usize::from(end) - 1) / window_size;
 
         if linear_index_end_offset >= self.linear_index.len() {
             self.linear_index.resize(linear_index_end_offset + 1, None);
@@ -173,6 +258,10 @@ mod tests {
             ),
         );
 
+        assert_eq!(builder.mapped_record_count(), 1);
+        assert_eq!(builder.unmapped_record_count(), 1);
+        assert_eq!(builder.record_count(), 2);
+
         let actual = builder.build();
 
         let expected = {
@@ -236,4 +325,58 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_build_with_min_shift() -> Result<(), Box<dyn std::error::Error>> {
+        const MIN_SHIFT: u8 = 12;
+        const DEPTH: u8 = 6;
+
+        let mut builder = Builder::default();
+
+        // [4095, 8192] overlaps windows 0, 1, and 2.
+        builder.add_record(
+            MIN_SHIFT,
+            DEPTH,
+            Position::try_from(4096)?,
+            Position::try_from(8193)?,
+            true,
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(9),
+            ),
+        );
+
+        // [16384, 16384] is in window 4.
+        builder.add_record(
+            MIN_SHIFT,
+            DEPTH,
+            Position::try_from(16385)?,
+            Position::try_from(16385)?,
+            true,
+            Chunk::new(
+                bgzf::VirtualPosition::from(9),
+                bgzf::VirtualPosition::from(21),
+            ),
+        );
+
+        let actual = builder.build();
+
+        let expected = [
+            bgzf::VirtualPosition::from(0),
+            bgzf::VirtualPosition::from(0),
+            bgzf::VirtualPosition::from(0),
+            bgzf::VirtualPosition::default(),
+            bgzf::VirtualPosition::from(9),
+        ];
+
+        assert_eq!(actual.linear_index(), expected);
+
+        let start = Position::try_from(16385)?;
+        assert_eq!(
+            actual.min_offset(MIN_SHIFT, DEPTH, start),
+            bgzf::VirtualPosition::from(9)
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,70 @@
+/// Record counts of a reference sequence in an index.
+///
+/// These are read from the metadata pseudo-bin of the reference sequence. See
+/// [`super::Index::reference_sequence_stats`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ReferenceSequenceStats {
+    mapped_record_count: u64,
+    unmapped_record_count: u64,
+}
+
+impl ReferenceSequenceStats {
+    pub(crate) fn new(mapped_record_count: u64, unmapped_record_count: u64) -> Self {
+        Self {
+            mapped_record_count,
+            unmapped_record_count,
+        }
+    }
+
+    /// Returns the number of mapped records.
+    pub fn mapped_record_count(&self) -> u64 {
+        self.mapped_record_count
+    }
+
+    /// Returns the number of unmapped records.
+    ///
+    /// These are placed, unmapped records, i.e., unmapped records that are assigned the position
+    /// of their mates.
+    pub fn unmapped_record_count(&self) -> u64 {
+        self.unmapped_record_count
+    }
+}
+
+/// Record counts of all reference sequences in an index.
+///
+/// See [`super::Index::total_stats`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct IndexStats {
+    mapped_record_count: u64,
+    unmapped_record_count: u64,
+    unplaced_unmapped_record_count: Option<u64>,
+}
+
+impl IndexStats {
+    pub(crate) fn new(
+        mapped_record_count: u64,
+        unmapped_record_count: u64,
+        unplaced_unmapped_record_count: Option<u64>,
+    ) -> Self {
+        Self {
+            mapped_record_count,
+            unmapped_record_count,
+            unplaced_unmapped_record_count,
+        }
+    }
+
+    /// Returns the number of mapped records in all reference sequences.
+    pub fn mapped_record_count(&self) -> u64 {
+        self.mapped_record_count
+    }
+
+    /// Returns the number of placed, unmapped records in all reference sequences.
+    pub fn unmapped_record_count(&self) -> u64 {
+        self.unmapped_record_count
+    }
+
+    /// Returns the number of unplaced, unmapped records, if set in the index.
+    pub fn unplaced_unmapped_record_count(&self) -> Option<u64> {
+        self.unplaced_unmapped_record_count
+    }
+}
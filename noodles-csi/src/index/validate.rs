@@ -0,0 +1,223 @@
+use std::{error, fmt};
+
+use super::{
+    reference_sequence::{parent_id, Bin},
+    Index,
+};
+
+/// An error returned when a coordinate-sorted index is invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// A bin ID is out of range for the index depth.
+    InvalidBinId {
+        /// The reference sequence ID.
+        reference_sequence_id: usize,
+        /// The bin ID.
+        bin_id: usize,
+    },
+    /// A chunk start position is greater than its end position.
+    InvalidChunk {
+        /// The reference sequence ID.
+        reference_sequence_id: usize,
+        /// The bin ID.
+        bin_id: usize,
+    },
+    /// The chunks in a bin are not sorted by start position.
+    UnsortedChunks {
+        /// The reference sequence ID.
+        reference_sequence_id: usize,
+        /// The bin ID.
+        bin_id: usize,
+    },
+    /// A bin linear offset is less than the linear offset of its parent bin.
+    InvalidLinearOffset {
+        /// The reference sequence ID.
+        reference_sequence_id: usize,
+        /// The bin ID.
+        bin_id: usize,
+    },
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidBinId {
+                reference_sequence_id,
+                bin_id,
+            } => write!(
+                f,
+                "invalid bin ID: reference sequence {reference_sequence_id}: bin {bin_id}"
+            ),
+            Self::InvalidChunk {
+                reference_sequence_id,
+                bin_id,
+            } => write!(
+                f,
+                "invalid chunk: reference sequence {reference_sequence_id}: bin {bin_id}"
+            ),
+            Self::UnsortedChunks {
+                reference_sequence_id,
+                bin_id,
+            } => write!(
+                f,
+                "unsorted chunks: reference sequence {reference_sequence_id}: bin {bin_id}"
+            ),
+            Self::InvalidLinearOffset {
+                reference_sequence_id,
+                bin_id,
+            } => write!(
+                f,
+                "invalid linear offset: reference sequence {reference_sequence_id}: bin {bin_id}"
+            ),
+        }
+    }
+}
+
+pub(super) fn validate(index: &Index) -> Result<(), ValidationError> {
+    let max_bin_id = Bin::max_id(index.depth());
+
+    for (reference_sequence_id, reference_sequence) in
+        index.reference_sequences().iter().enumerate()
+    {
+        let bins = reference_sequence.bins();
+
+        // Bins are stored in a hash map. Visit them in ID order to consistently report the
+        // first error.
+        let mut bin_ids: Vec<_> = bins.keys().copied().collect();
+        bin_ids.sort_unstable();
+
+        for bin_id in bin_ids {
+            let bin = &bins[&bin_id];
+
+            if bin_id >= max_bin_id {
+                return Err(ValidationError::InvalidBinId {
+                    reference_sequence_id,
+                    bin_id,
+                });
+            }
+
+            if bin.chunks().iter().any(|chunk| chunk.start() > chunk.end()) {
+                return Err(ValidationError::InvalidChunk {
+                    reference_sequence_id,
+                    bin_id,
+                });
+            }
+
+            if bin
+                .chunks()
+                .windows(2)
+                .any(|chunks| chunks[0].start() > chunks[1].start())
+            {
+                return Err(ValidationError::UnsortedChunks {
+                    reference_sequence_id,
+                    bin_id,
+                });
+            }
+
+            if let Some(parent) = parent_id(bin_id).and_then(|id| bins.get(&id)) {
+                if parent.loffset() > bin.loffset() {
+                    return Err(ValidationError::InvalidLinearOffset {
+                        reference_sequence_id,
+                        bin_id,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+    use crate::index::{reference_sequence::bin::Chunk, ReferenceSequence};
+
+    fn chunk(start: u64, end: u64) -> Chunk {
+        Chunk::new(
+            bgzf::VirtualPosition::from(start),
+            bgzf::VirtualPosition::from(end),
+        )
+    }
+
+    fn build_index(bins: Vec<(usize, Bin)>) -> Index {
+        let bins = bins.into_iter().collect();
+
+        Index::builder()
+            .set_reference_sequences(vec![ReferenceSequence::new(bins, Vec::new(), None)])
+            .build()
+    }
+
+    #[test]
+    fn test_validate() {
+        let index = build_index(vec![
+            (
+                0,
+                Bin::new(bgzf::VirtualPosition::from(5), vec![chunk(5, 8)]),
+            ),
+            (
+                4681,
+                Bin::new(
+                    bgzf::VirtualPosition::from(13),
+                    vec![chunk(13, 21), chunk(34, 55)],
+                ),
+            ),
+        ]);
+        assert_eq!(validate(&index), Ok(()));
+
+        let index = build_index(vec![(
+            37449,
+            Bin::new(bgzf::VirtualPosition::default(), Vec::new()),
+        )]);
+        assert_eq!(
+            validate(&index),
+            Err(ValidationError::InvalidBinId {
+                reference_sequence_id: 0,
+                bin_id: 37449
+            })
+        );
+
+        let index = build_index(vec![(
+            4681,
+            Bin::new(bgzf::VirtualPosition::default(), vec![chunk(21, 13)]),
+        )]);
+        assert_eq!(
+            validate(&index),
+            Err(ValidationError::InvalidChunk {
+                reference_sequence_id: 0,
+                bin_id: 4681
+            })
+        );
+
+        let index = build_index(vec![(
+            4681,
+            Bin::new(
+                bgzf::VirtualPosition::default(),
+                vec![chunk(34, 55), chunk(13, 21)],
+            ),
+        )]);
+        assert_eq!(
+            validate(&index),
+            Err(ValidationError::UnsortedChunks {
+                reference_sequence_id: 0,
+                bin_id: 4681
+            })
+        );
+
+        let index = build_index(vec![
+            (585, Bin::new(bgzf::VirtualPosition::from(13), Vec::new())),
+            (4681, Bin::new(bgzf::VirtualPosition::from(8), Vec::new())),
+        ]);
+        assert_eq!(
+            validate(&index),
+            Err(ValidationError::InvalidLinearOffset {
+                reference_sequence_id: 0,
+                bin_id: 4681
+            })
+        );
+    }
+}
@@ -1,15 +1,20 @@
-use std::io::{self, Read, Seek};
+use std::{
+    collections::HashMap,
+    io::{self, Read, Seek},
+};
 
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
 
-use super::{indexed_records::Record, Query};
-use crate::Index;
+use super::{indexed_records::Record, IndexedRecord, Query};
+use crate::{index::header::ReferenceSequenceNames, Index};
 
 /// An indexed reader.
 pub struct IndexedReader<R> {
     inner: R,
     index: Index,
+    sequence_name_aliases: HashMap<String, String>,
+    normalize_sequence_names: bool,
 }
 
 impl<R> IndexedReader<bgzf::Reader<R>>
@@ -21,6 +26,8 @@ where
         Self {
             inner: bgzf::Reader::new(inner),
             index,
+            sequence_name_aliases: HashMap::new(),
+            normalize_sequence_names: false,
         }
     }
 
@@ -43,6 +50,24 @@ where
     pub fn index(&self) -> &Index {
         &self.index
     }
+
+    /// Sets reference sequence name aliases.
+    ///
+    /// Each alias maps a name to another name for the same reference sequence, e.g., `chr1` to
+    /// `1`. Aliases are applied in both directions when resolving a region name in a query, so an
+    /// index with `1` can be queried using `chr1`, and vice versa.
+    pub fn set_sequence_name_aliases(&mut self, aliases: HashMap<String, String>) {
+        self.sequence_name_aliases = aliases;
+    }
+
+    /// Sets whether to match region names to equivalent reference sequence names.
+    ///
+    /// When enabled, a region name that is not in the index or the aliases is matched after
+    /// normalization (see [`noodles_core::region::name`]), e.g., `chr1` matches `1`, and `MT`
+    /// matches `chrM`. This is disabled by default.
+    pub fn set_normalize_sequence_names(&mut self, normalize_sequence_names: bool) {
+        self.normalize_sequence_names = normalize_sequence_names;
+    }
 }
 
 impl<R> IndexedReader<bgzf::Reader<R>>
@@ -59,9 +84,25 @@ where
             .header()
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index header"))?;
 
-        let reference_sequence_id = header
-            .reference_sequence_names()
-            .get_index_of(region.name())
+        let reference_sequence_names = header.reference_sequence_names();
+
+        let reference_sequence_id = resolve_reference_sequence_id(
+            reference_sequence_names,
+            &self.sequence_name_aliases,
+            self.normalize_sequence_names,
+            region.name(),
+        )
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "missing reference sequence name",
+            )
+        })?;
+
+        // The region name may be an alias, so records are filtered by the name in the index.
+        let reference_sequence_name = reference_sequence_names
+            .get_index(reference_sequence_id)
+            .cloned()
             .ok_or_else(|| {
                 io::Error::new(
                     io::ErrorKind::InvalidInput,
@@ -69,10 +110,215 @@ where
                 )
             })?;
 
-        let chunks = self.index.query(reference_sequence_id, region.interval())?;
+        let interval = region.interval();
+
+        let chunks = self.index.query(reference_sequence_id, interval)?;
 
         Ok(Query::new(&mut self.inner, chunks)
             .indexed_records(header)
-            .filter_by_region(region))
+            .filter(move |result| match result {
+                Ok(record) => {
+                    record.indexed_reference_sequence_name() == reference_sequence_name
+                        && record.indexed_interval().intersects(interval)
+                }
+                Err(_) => true,
+            }))
+    }
+}
+
+fn resolve_reference_sequence_id(
+    reference_sequence_names: &ReferenceSequenceNames,
+    aliases: &HashMap<String, String>,
+    normalize_names: bool,
+    name: &str,
+) -> Option<usize> {
+    use noodles_core::region::name::find;
+
+    if let Some(i) = reference_sequence_names.get_index_of(name) {
+        return Some(i);
+    }
+
+    if let Some(i) = aliases
+        .get(name)
+        .and_then(|alias| reference_sequence_names.get_index_of(alias))
+    {
+        return Some(i);
+    }
+
+    // Several names can map to the same alias, so the reverse lookup is done in index order.
+    if let Some(i) = reference_sequence_names
+        .iter()
+        .position(|candidate| aliases.get(candidate).map(|alias| alias.as_str()) == Some(name))
+    {
+        return Some(i);
+    }
+
+    if normalize_names {
+        find(reference_sequence_names.iter().map(|n| n.as_str()), name)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::index::{self, reference_sequence::bin::Chunk, Indexer};
+
+    #[test]
+    fn test_resolve_reference_sequence_id() {
+        fn build_names(names: &[&str]) -> ReferenceSequenceNames {
+            names.iter().map(|s| s.to_string()).collect()
+        }
+
+        let aliases = [("chr1", "1"), ("chr2", "2"), ("chrM", "MT"), ("M", "MT")]
+            .into_iter()
+            .map(|(a, b)| (a.into(), b.into()))
+            .collect();
+
+        // Ensembl names in the index
+        let names = build_names(&["1", "2", "MT"]);
+        assert_eq!(
+            resolve_reference_sequence_id(&names, &aliases, false, "1"),
+            Some(0)
+        );
+        assert_eq!(
+            resolve_reference_sequence_id(&names, &aliases, false, "chr1"),
+            Some(0)
+        );
+        assert_eq!(
+            resolve_reference_sequence_id(&names, &aliases, false, "chrM"),
+            Some(2)
+        );
+        assert!(resolve_reference_sequence_id(&names, &aliases, false, "chr3").is_none());
+
+        // UCSC names in the index
+        let names = build_names(&["chr1", "chr2", "chrM"]);
+        assert_eq!(
+            resolve_reference_sequence_id(&names, &aliases, false, "chr2"),
+            Some(1)
+        );
+        assert_eq!(
+            resolve_reference_sequence_id(&names, &aliases, false, "2"),
+            Some(1)
+        );
+        assert_eq!(
+            resolve_reference_sequence_id(&names, &aliases, false, "MT"),
+            Some(2)
+        );
+        assert!(resolve_reference_sequence_id(&names, &aliases, false, "3").is_none());
+
+        // Two names in the index map to the same alias
+        let names = build_names(&["M", "chrM"]);
+        assert_eq!(
+            resolve_reference_sequence_id(&names, &aliases, false, "MT"),
+            Some(0)
+        );
+
+        assert!(resolve_reference_sequence_id(&build_names(&[]), &aliases, false, "1").is_none());
+
+        // Normalized names
+        let aliases = HashMap::new();
+
+        let names = build_names(&["1", "2", "MT"]);
+        assert!(resolve_reference_sequence_id(&names, &aliases, false, "chr1").is_none());
+        assert_eq!(
+            resolve_reference_sequence_id(&names, &aliases, true, "chr1"),
+            Some(0)
+        );
+        assert_eq!(
+            resolve_reference_sequence_id(&names, &aliases, true, "chrM"),
+            Some(2)
+        );
+
+        let names = build_names(&["chr1", "chr2", "chrM"]);
+        assert_eq!(
+            resolve_reference_sequence_id(&names, &aliases, true, "2"),
+            Some(1)
+        );
+        assert_eq!(
+            resolve_reference_sequence_id(&names, &aliases, true, "MT"),
+            Some(2)
+        );
+        assert!(resolve_reference_sequence_id(&names, &aliases, true, "3").is_none());
+
+        // An exact match is preferred over an equivalent name.
+        let names = build_names(&["chr1", "1"]);
+        assert_eq!(
+            resolve_reference_sequence_id(&names, &aliases, true, "1"),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_query_with_sequence_name_aliases() -> Result<(), Box<dyn std::error::Error>> {
+        // BED records
+        let records = [("1", 7, 13), ("1", 20, 34), ("2", 4, 8)];
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        let mut indexer = Indexer::default();
+
+        for (name, start, end) in records {
+            let start_position = writer.virtual_position();
+            writeln!(writer, "{name}\t{start}\t{end}")?;
+            let end_position = writer.virtual_position();
+
+            let reference_sequence_id = if name == "1" { 0 } else { 1 };
+
+            indexer.add_record(
+                Some((
+                    reference_sequence_id,
+                    Position::try_from(start + 1)?,
+                    Position::try_from(end)?,
+                    true,
+                )),
+                Chunk::new(start_position, end_position),
+            )?;
+        }
+
+        let data = writer.finish()?;
+
+        let header = index::header::Builder::bed()
+            .set_reference_sequence_names(["1", "2"].into_iter().map(String::from).collect())
+            .build();
+
+        let index = indexer.set_header(header).build(2);
+
+        let mut reader = IndexedReader::new(io::Cursor::new(data), index);
+
+        let region = "chr1:10-20".parse()?;
+        assert!(reader.query(&region).is_err());
+
+        let aliases = [("chr1", "1"), ("chr2", "2")]
+            .into_iter()
+            .map(|(a, b)| (a.into(), b.into()))
+            .collect();
+
+        reader.set_sequence_name_aliases(aliases);
+
+        let records: Vec<_> = reader.query(&region)?.collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].indexed_reference_sequence_name(), "1");
+
+        let region = "2".parse()?;
+        let records: Vec<_> = reader.query(&region)?.collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+
+        reader.set_sequence_name_aliases(HashMap::new());
+
+        let region = "chr2".parse()?;
+        assert!(reader.query(&region).is_err());
+
+        reader.set_normalize_sequence_names(true);
+
+        let records: Vec<_> = reader.query(&region)?.collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].indexed_reference_sequence_name(), "2");
+
+        Ok(())
     }
 }
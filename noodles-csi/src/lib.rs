@@ -11,7 +11,7 @@ pub mod io;
 mod reader;
 mod writer;
 
-pub use self::{index::Index, reader::Reader, writer::Writer};
+pub use self::{binning_index::reg2bins, index::Index, reader::Reader, writer::Writer};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
@@ -0,0 +1,50 @@
+//! Counts the number of unmapped records in a SAM file, comparing full and lazy record reading.
+//!
+//! Lazy records only parse the flags field, so fields such as the sequence and quality scores are
+//! never parsed.
+//!
+//! The result matches the output of `samtools view --count --require-flags 4 <src>`.
+
+use std::{env, io, time::Instant};
+
+use noodles_sam::{self as sam, lazy};
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = sam::reader::Builder.build_from_path(&src)?;
+    let header = reader.read_header()?;
+
+    let start = Instant::now();
+    let mut n = 0;
+
+    for result in reader.records(&header) {
+        let record = result?;
+
+        if record.flags().is_unmapped() {
+            n += 1;
+        }
+    }
+
+    eprintln!("records: {:?}", start.elapsed());
+
+    let mut reader = sam::reader::Builder.build_from_path(src)?;
+    reader.read_header()?;
+
+    let start = Instant::now();
+    let mut record = lazy::Record::default();
+    let mut m = 0;
+
+    while reader.read_lazy_record(&mut record)? != 0 {
+        if record.flags()?.is_unmapped() {
+            m += 1;
+        }
+    }
+
+    eprintln!("lazy records: {:?}", start.elapsed());
+
+    assert_eq!(n, m);
+    println!("{n}");
+
+    Ok(())
+}
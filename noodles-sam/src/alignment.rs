@@ -1,6 +1,8 @@
 //! Alignment record and fields.
 
+pub mod consensus;
 pub mod iter;
+mod nm;
 pub mod record;
 
-pub use self::record::Record;
+pub use self::{nm::calculate_nm, record::Record};
@@ -0,0 +1,355 @@
+//! Alignment record consensus.
+
+use std::{error, fmt};
+
+use noodles_core::Position;
+
+use super::Record;
+use crate::record::{
+    quality_scores::Score,
+    sequence::{Base, Sequence},
+    QualityScores,
+};
+
+const BASES: [Base; 4] = [Base::A, Base::C, Base::G, Base::T];
+
+// The error probability of an observation is clamped to this range to avoid infinite log
+// likelihoods for very high or zero quality scores.
+const MIN_ERROR_PROBABILITY: f64 = 1e-10;
+const MAX_ERROR_PROBABILITY: f64 = 0.75;
+
+/// A consensus of alignment records.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Consensus {
+    reference_sequence_id: usize,
+    alignment_start: Position,
+    sequence: Sequence,
+    quality_scores: QualityScores,
+}
+
+impl Consensus {
+    /// Returns the reference sequence ID.
+    pub fn reference_sequence_id(&self) -> usize {
+        self.reference_sequence_id
+    }
+
+    /// Returns the alignment start.
+    pub fn alignment_start(&self) -> Position {
+        self.alignment_start
+    }
+
+    /// Returns the consensus sequence.
+    ///
+    /// The sequence has one base per reference position, starting at the alignment start.
+    pub fn sequence(&self) -> &Sequence {
+        &self.sequence
+    }
+
+    /// Returns the consensus quality scores.
+    pub fn quality_scores(&self) -> &QualityScores {
+        &self.quality_scores
+    }
+}
+
+/// An error returned when a consensus fails to be called.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CallError {
+    /// There are no mapped records.
+    Empty,
+    /// The records are mapped to different reference sequences.
+    MixedReferenceSequences,
+}
+
+impl error::Error for CallError {}
+
+impl fmt::Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::MixedReferenceSequences => f.write_str("mixed reference sequences"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Pileup {
+    log_likelihoods: [f64; 4],
+    observation_count: usize,
+    is_masked: bool,
+}
+
+/// Calls a consensus from a group of alignment records.
+///
+/// The records are typically reads of the same molecule, e.g., reads that share a unique
+/// molecular identifier (UMI). Each read base is placed at its reference position using
+/// [`Record::aligned_pairs`]. At each position, the consensus base is the base with the highest
+/// likelihood given the observed bases and their quality scores, and its quality score is the
+/// Phred-scaled probability that the consensus base is wrong.
+///
+/// Indels are handled conservatively: a reference position that is deleted or skipped in any
+/// record is masked (`N` with a quality score of 0), and inserted bases are not included in the
+/// consensus. Positions without observations and positions where bases are tied are masked as
+/// well.
+///
+/// Unmapped records are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{consensus, Record};
+///
+/// let build_record = |sequence: &str| -> Result<Record, Box<dyn std::error::Error>> {
+///     Ok(Record::builder()
+///         .set_reference_sequence_id(0)
+///         .set_alignment_start(Position::MIN)
+///         .set_cigar("4M".parse()?)
+///         .set_sequence(sequence.parse()?)
+///         .set_quality_scores("????".parse()?)
+///         .build())
+/// };
+///
+/// let records = [build_record("ACGT")?, build_record("ACGT")?, build_record("ACTT")?];
+/// let consensus = consensus::call(&records)?;
+///
+/// assert_eq!(consensus.alignment_start(), Position::MIN);
+/// assert_eq!(consensus.sequence().to_string(), "ACGT");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn call(records: &[Record]) -> Result<Consensus, CallError> {
+    let mut reference_sequence_id = None;
+    let mut start = usize::MAX;
+    let mut end = 0;
+
+    for record in records {
+        let (alignment_start, alignment_end) =
+            match (record.alignment_start(), record.alignment_end()) {
+                (Some(s), Some(e)) => (usize::from(s), usize::from(e)),
+                _ => continue,
+            };
+
+        match (reference_sequence_id, record.reference_sequence_id()) {
+            (None, id) => reference_sequence_id = Some(id),
+            (Some(a), b) if a == b => {}
+            _ => return Err(CallError::MixedReferenceSequences),
+        }
+
+        start = start.min(alignment_start);
+        end = end.max(alignment_end);
+    }
+
+    let (reference_sequence_id, alignment_start) =
+        match (reference_sequence_id.flatten(), Position::new(start)) {
+            (Some(id), Some(position)) if start <= end => (id, position),
+            _ => return Err(CallError::Empty),
+        };
+
+    let mut pileups = vec![Pileup::default(); end - start + 1];
+
+    for record in records {
+        let aligned_pairs = match record.aligned_pairs() {
+            Some(aligned_pairs) => aligned_pairs,
+            None => continue,
+        };
+
+        for (read_position, reference_position) in aligned_pairs {
+            let reference_position = match reference_position {
+                Some(position) => usize::from(position),
+                None => continue,
+            };
+
+            let pileup = &mut pileups[reference_position - start];
+
+            match read_position {
+                Some(read_position) => {
+                    let base = record.sequence().get(read_position).copied();
+                    let score = record.quality_scores().get(read_position).copied();
+
+                    if let Some(base) = base {
+                        add_observation(pileup, base, score);
+                    }
+                }
+                None => pileup.is_masked = true,
+            }
+        }
+    }
+
+    let mut sequence = Vec::with_capacity(pileups.len());
+    let mut quality_scores = Vec::with_capacity(pileups.len());
+
+    for pileup in &pileups {
+        let (base, score) = call_base(pileup);
+        sequence.push(base);
+        quality_scores.push(score);
+    }
+
+    Ok(Consensus {
+        reference_sequence_id,
+        alignment_start,
+        sequence: Sequence::from(sequence),
+        quality_scores: QualityScores::from(quality_scores),
+    })
+}
+
+fn add_observation(pileup: &mut Pileup, base: Base, score: Option<Score>) {
+    if !BASES.contains(&base) {
+        return;
+    }
+
+    let q = score.map(|s| f64::from(s.get())).unwrap_or_default();
+    let error_probability = 10f64
+        .powf(-q / 10.0)
+        .clamp(MIN_ERROR_PROBABILITY, MAX_ERROR_PROBABILITY);
+
+    for (candidate, log_likelihood) in BASES.iter().zip(&mut pileup.log_likelihoods) {
+        *log_likelihood += if *candidate == base {
+            (1.0 - error_probability).log10()
+        } else {
+            (error_probability / 3.0).log10()
+        };
+    }
+
+    pileup.observation_count += 1;
+}
+
+fn call_base(pileup: &Pileup) -> (Base, Score) {
+    const MASKED: (Base, Score) = (Base::N, Score::MIN);
+
+    if pileup.is_masked || pileup.observation_count == 0 {
+        return MASKED;
+    }
+
+    let (best_index, best_log_likelihood) =
+        pileup.log_likelihoods.iter().copied().enumerate().fold(
+            (0, f64::NEG_INFINITY),
+            |(i, max), (j, n)| {
+                if n > max {
+                    (j, n)
+                } else {
+                    (i, max)
+                }
+            },
+        );
+
+    let mut total = 0.0;
+    let mut other = 0.0;
+
+    for (i, &log_likelihood) in pileup.log_likelihoods.iter().enumerate() {
+        let likelihood = 10f64.powf(log_likelihood - best_log_likelihood);
+
+        total += likelihood;
+
+        if i != best_index {
+            if likelihood >= 1.0 {
+                return MASKED;
+            }
+
+            other += likelihood;
+        }
+    }
+
+    let error_probability = other / total;
+    let max = f64::from(Score::MAX.get());
+
+    let q = if error_probability > 0.0 {
+        (-10.0 * error_probability.log10()).round().clamp(0.0, max)
+    } else {
+        max
+    };
+
+    let score = Score::new(q as u8).unwrap_or(Score::MAX);
+
+    (BASES[best_index], score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_record(
+        alignment_start: usize,
+        cigar: &str,
+        sequence: &str,
+        quality_scores: &str,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        Ok(Record::builder()
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(alignment_start)?)
+            .set_cigar(cigar.parse()?)
+            .set_sequence(sequence.parse()?)
+            .set_quality_scores(quality_scores.parse()?)
+            .build())
+    }
+
+    #[test]
+    fn test_call() -> Result<(), Box<dyn std::error::Error>> {
+        // Q30 = '?'
+        let records = [
+            build_record(8, "8M", "ACGTACGT", "????????")?,
+            build_record(8, "8M", "ACGTACGT", "????????")?,
+            build_record(8, "8M", "ACGAACGT", "????????")?,
+            build_record(10, "6M", "GTACGT", "??????")?,
+        ];
+
+        let consensus = call(&records)?;
+
+        assert_eq!(consensus.reference_sequence_id(), 0);
+        assert_eq!(consensus.alignment_start(), Position::try_from(8)?);
+        assert_eq!(consensus.sequence().to_string(), "ACGTACGT");
+
+        let scores: Vec<_> = consensus
+            .quality_scores()
+            .as_ref()
+            .iter()
+            .map(|score| score.get())
+            .collect();
+        assert_eq!(scores, [93, 93, 93, 70, 93, 93, 93, 93]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_call_with_indels() -> Result<(), Box<dyn std::error::Error>> {
+        let records = [
+            build_record(1, "8M", "ACGTACGT", "????????")?,
+            build_record(1, "3M1D4M", "ACGACGT", "???????")?,
+            build_record(1, "4M2I4M", "ACGTTTACGT", "??????????")?,
+        ];
+
+        let consensus = call(&records)?;
+
+        assert_eq!(consensus.sequence().to_string(), "ACGNACGT");
+        assert_eq!(
+            consensus.quality_scores()[Position::try_from(4)?],
+            Score::MIN
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_call_with_tie() -> Result<(), Box<dyn std::error::Error>> {
+        let records = [
+            build_record(1, "4M", "ACGT", "????")?,
+            build_record(1, "4M", "ACTT", "????")?,
+        ];
+
+        let consensus = call(&records)?;
+        assert_eq!(consensus.sequence().to_string(), "ACNT");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_call_with_invalid_records() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(call(&[]), Err(CallError::Empty));
+        assert_eq!(call(&[Record::default()]), Err(CallError::Empty));
+
+        let mut record = build_record(1, "4M", "ACGT", "????")?;
+        *record.reference_sequence_id_mut() = Some(1);
+        let records = [build_record(1, "4M", "ACGT", "????")?, record];
+        assert_eq!(call(&records), Err(CallError::MixedReferenceSequences));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use crate::record::{
+    cigar::op::Kind,
+    sequence::{Base, Sequence},
+    Cigar,
+};
+
+/// Calculates the edit distance (`NM`) of an alignment.
+///
+/// This follows the definition used by `samtools calmd`: the number of mismatched bases in
+/// alignment matches (`M`, `=`, and `X`), plus the number of inserted (`I`) and deleted (`D`)
+/// bases.
+///
+/// `ref_seq` is the reference sequence starting at the alignment start. Bases are compared
+/// case-insensitively. An ambiguous base (`N`) in either the read or reference is counted as a
+/// mismatch, and a read base of `=` is counted as a match. Alignment matches that fall outside the
+/// read or reference sequence are not compared.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{
+///     alignment::calculate_nm,
+///     record::{Cigar, Sequence},
+/// };
+///
+/// let cigar: Cigar = "2S3M1I2M1D2M".parse()?;
+/// let read_seq: Sequence = "TTACGTACAC".parse()?;
+/// let ref_seq = b"ACCACTAC";
+///
+/// assert_eq!(calculate_nm(&cigar, &read_seq, ref_seq), 3);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn calculate_nm(cigar: &Cigar, read_seq: &Sequence, ref_seq: &[u8]) -> u32 {
+    let read_bases = read_seq.as_ref();
+
+    let mut read_position = 0;
+    let mut reference_position = 0;
+    let mut nm = 0;
+
+    for op in cigar.iter() {
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                let read_end = read_position + len;
+                let reference_end = reference_position + len;
+
+                let read_bases = read_bases
+                    .get(read_position..read_end.min(read_bases.len()))
+                    .unwrap_or_default();
+                let reference_bases = ref_seq
+                    .get(reference_position..reference_end.min(ref_seq.len()))
+                    .unwrap_or_default();
+
+                let mismatch_count = read_bases
+                    .iter()
+                    .zip(reference_bases)
+                    .filter(|(&read_base, &reference_base)| !bases_eq(read_base, reference_base))
+                    .count();
+
+                nm += mismatch_count as u32;
+
+                read_position = read_end;
+                reference_position = reference_end;
+            }
+            Kind::Insertion => {
+                nm += len as u32;
+                read_position += len;
+            }
+            Kind::Deletion => {
+                nm += len as u32;
+                reference_position += len;
+            }
+            Kind::SoftClip => read_position += len,
+            Kind::Skip => reference_position += len,
+            Kind::HardClip | Kind::Pad => {}
+        }
+    }
+
+    nm
+}
+
+fn bases_eq(read_base: Base, reference_base: u8) -> bool {
+    if read_base == Base::Eq {
+        return true;
+    }
+
+    let read_base = u8::from(read_base);
+    let reference_base = reference_base.to_ascii_uppercase();
+
+    read_base == reference_base && read_base != b'N'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_nm() {
+        fn t(cigar: &str, read_seq: &str, ref_seq: &[u8], expected: u32) {
+            let cigar = cigar.parse().unwrap();
+            let read_seq = read_seq.parse().unwrap();
+            assert_eq!(calculate_nm(&cigar, &read_seq, ref_seq), expected);
+        }
+
+        t("4M", "ACGT", b"ACGT", 0);
+        t("4M", "ACGT", b"acgt", 0);
+        t("4M", "ACGT", b"AGGA", 2);
+        t("2=1X1=", "ACGT", b"ACTT", 1);
+
+        // soft clips
+        t("2S4M2S", "TTACGTTT", b"ACGT", 0);
+        t("2S4M2S", "TTACGATT", b"ACGT", 1);
+        t("2H4M", "ACGT", b"ACGT", 0);
+
+        // insertions and deletions
+        t("2M2I2M", "ACTTGT", b"ACGT", 2);
+        t("2M2D2M", "ACGT", b"ACCCGT", 2);
+        t("2S3M1I2M1D2M", "TTACGTACAC", b"ACCACTAC", 3);
+        t("2M10N2M", "ACGT", b"ACNNNNNNNNNNGT", 0);
+
+        // ambiguous bases
+        t("4M", "ACNT", b"ACGT", 1);
+        t("4M", "ACGT", b"ACNT", 1);
+        t("4M", "ACNT", b"ACNT", 1);
+        t("4M", "ACRT", b"ACAT", 1);
+        t("4M", "AC=T", b"ACGT", 0);
+
+        // missing sequence
+        let cigar = "4M".parse().unwrap();
+        assert_eq!(calculate_nm(&cigar, &Sequence::default(), b"ACGT"), 0);
+    }
+}
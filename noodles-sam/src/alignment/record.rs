@@ -1,8 +1,9 @@
 //! Alignment record.
 
-mod builder;
+mod aligned_pairs;
+pub mod builder;
 
-pub use self::builder::Builder;
+pub use self::{aligned_pairs::AlignedPairs, builder::Builder};
 
 use std::io;
 
@@ -16,7 +17,10 @@ use crate::{
         },
         ReferenceSequences,
     },
-    record::{Cigar, Data, Flags, MappingQuality, QualityScores, ReadName, Sequence},
+    record::{
+        cigar::AlignmentBlock, Cigar, Data, Flags, MappingQuality, QualityScores, ReadName,
+        Sequence,
+    },
     Header,
 };
 
@@ -483,6 +487,82 @@ impl Record {
             Position::new(end)
         })
     }
+
+    /// Returns an iterator over aligned read and reference positions.
+    ///
+    /// Each item is a pair of a read position and a reference position. A read position is
+    /// missing for a deletion or skipped region, and a reference position is missing for an
+    /// insertion or soft clip. Hard clips and padding are not included.
+    ///
+    /// This returns `None` if the record is unmapped, i.e., it has no alignment start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam as sam;
+    ///
+    /// let record = sam::alignment::Record::builder()
+    ///     .set_alignment_start(Position::try_from(8)?)
+    ///     .set_cigar("1M1I1D1M".parse()?)
+    ///     .build();
+    ///
+    /// let aligned_pairs: Vec<_> = record.aligned_pairs().unwrap().collect();
+    ///
+    /// assert_eq!(aligned_pairs, [
+    ///     (Position::new(1), Position::new(8)),
+    ///     (Position::new(2), None),
+    ///     (None, Position::new(9)),
+    ///     (Position::new(3), Position::new(10)),
+    /// ]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn aligned_pairs(&self) -> Option<AlignedPairs<'_>> {
+        self.alignment_start()
+            .map(|alignment_start| AlignedPairs::new(self.cigar(), alignment_start))
+    }
+
+    /// Returns an iterator over the alignment blocks.
+    ///
+    /// This is similar to [`Cigar::alignment_blocks`], but the reference start of each block is
+    /// the 0-based position in the reference sequence. Soft-clipped bases are not included in any
+    /// block.
+    ///
+    /// The iterator is empty if the record is unmapped, i.e., it has no alignment start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::{self as sam, record::cigar::AlignmentBlock};
+    ///
+    /// let record = sam::alignment::Record::builder()
+    ///     .set_alignment_start(Position::try_from(8)?)
+    ///     .set_cigar("2S4M2D4M".parse()?)
+    ///     .build();
+    ///
+    /// let blocks: Vec<_> = record.alignment_blocks().collect();
+    /// assert_eq!(blocks, [AlignmentBlock::new(2, 7, 4), AlignmentBlock::new(6, 13, 4)]);
+    ///
+    /// let record = sam::alignment::Record::default();
+    /// assert_eq!(record.alignment_blocks().count(), 0);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn alignment_blocks(&self) -> impl Iterator<Item = AlignmentBlock> + '_ {
+        self.alignment_start()
+            .into_iter()
+            .flat_map(move |alignment_start| {
+                let offset = usize::from(alignment_start) - 1;
+
+                self.cigar().alignment_blocks().map(move |block| {
+                    AlignmentBlock::new(
+                        block.read_start(),
+                        offset + block.reference_start(),
+                        block.len(),
+                    )
+                })
+            })
+    }
 }
 
 impl Default for Record {
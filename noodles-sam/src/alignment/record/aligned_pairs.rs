@@ -0,0 +1,99 @@
+use std::slice;
+
+use noodles_core::Position;
+
+use crate::record::cigar::{op::Kind, Op};
+
+/// An iterator over aligned read and reference positions.
+///
+/// This is created by calling [`super::Record::aligned_pairs`].
+pub struct AlignedPairs<'a> {
+    ops: slice::Iter<'a, Op>,
+    kind: Kind,
+    remaining: usize,
+    read_position: usize,
+    reference_position: usize,
+}
+
+impl<'a> AlignedPairs<'a> {
+    pub(super) fn new(ops: &'a [Op], alignment_start: Position) -> Self {
+        Self {
+            ops: ops.iter(),
+            kind: Kind::HardClip,
+            remaining: 0,
+            read_position: 1,
+            reference_position: usize::from(alignment_start),
+        }
+    }
+}
+
+impl<'a> Iterator for AlignedPairs<'a> {
+    type Item = (Option<Position>, Option<Position>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining == 0 {
+            let op = self.ops.next()?;
+            self.kind = op.kind();
+            self.remaining = op.len();
+
+            if matches!(self.kind, Kind::HardClip | Kind::Pad) {
+                self.remaining = 0;
+            }
+        }
+
+        self.remaining -= 1;
+
+        let read_position = if self.kind.consumes_read() {
+            let position = Position::new(self.read_position);
+            self.read_position += 1;
+            position
+        } else {
+            None
+        };
+
+        let reference_position = if self.kind.consumes_reference() {
+            let position = Position::new(self.reference_position);
+            self.reference_position += 1;
+            position
+        } else {
+            None
+        };
+
+        Some((read_position, reference_position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let cigar: crate::record::Cigar = "1H2S2M1I1D1N1M".parse()?;
+        let aligned_pairs = AlignedPairs::new(&cigar, Position::try_from(8)?);
+
+        let actual: Vec<_> = aligned_pairs
+            .map(|(read_position, reference_position)| {
+                (
+                    read_position.map(usize::from),
+                    reference_position.map(usize::from),
+                )
+            })
+            .collect();
+
+        let expected = [
+            (Some(1), None),
+            (Some(2), None),
+            (Some(3), Some(8)),
+            (Some(4), Some(9)),
+            (Some(5), None),
+            (None, Some(10)),
+            (None, Some(11)),
+            (Some(6), Some(12)),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -1,3 +1,11 @@
+//! SAM alignment record builder.
+
+mod validations;
+
+pub use self::validations::Validations;
+
+use std::{cmp::Ordering, error, fmt};
+
 use noodles_core::Position;
 
 use super::Record;
@@ -18,6 +26,42 @@ pub struct Builder {
     sequence: Sequence,
     quality_scores: QualityScores,
     data: Data,
+    validations: Validations,
+}
+
+/// An error returned when an alignment record fails validation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// The record is unmapped but has a nonzero mapping quality.
+    UnmappedWithMappingQuality,
+    /// The record is unmapped but has CIGAR operations.
+    UnmappedWithCigar,
+    /// The record is not segmented but has segment-specific flags set.
+    InvalidSegmentFlags,
+    /// The record is segmented with a mapped mate, but the mate reference sequence ID is missing.
+    MissingMateReferenceSequenceId,
+    /// The template length is set on a record that cannot have one.
+    UnexpectedTemplateLength,
+    /// The sign of the template length does not match the position of the record relative to
+    /// its mate.
+    InvalidTemplateLengthSign,
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnmappedWithMappingQuality => f.write_str("unmapped with mapping quality"),
+            Self::UnmappedWithCigar => f.write_str("unmapped with CIGAR"),
+            Self::InvalidSegmentFlags => f.write_str("invalid segment flags"),
+            Self::MissingMateReferenceSequenceId => {
+                f.write_str("missing mate reference sequence ID")
+            }
+            Self::UnexpectedTemplateLength => f.write_str("unexpected template length"),
+            Self::InvalidTemplateLengthSign => f.write_str("invalid template length sign"),
+        }
+    }
 }
 
 impl Builder {
@@ -254,6 +298,80 @@ impl Builder {
         self
     }
 
+    /// Sets the validations to run when using [`Self::build_validated`].
+    ///
+    /// By default, all validations are enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::record::builder::Validations, record::Flags};
+    ///
+    /// let result = sam::alignment::Record::builder()
+    ///     .set_flags(Flags::UNMAPPED)
+    ///     .set_cigar("4M".parse()?)
+    ///     .set_validations(Validations::empty())
+    ///     .build_validated();
+    ///
+    /// assert!(result.is_ok());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_validations(mut self, validations: Validations) -> Self {
+        self.validations = validations;
+        self
+    }
+
+    /// Builds the alignment record after checking it for consistency.
+    ///
+    /// The checks are configured by [`Self::set_validations`]. Unlike [`Self::build`], this
+    /// catches records that violate invariants described in the SAM specification, e.g., an
+    /// unmapped record with a nonzero mapping quality.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::record::builder::ValidationError,
+    ///     record::{Flags, MappingQuality},
+    /// };
+    ///
+    /// let result = sam::alignment::Record::builder()
+    ///     .set_flags(Flags::UNMAPPED)
+    ///     .set_mapping_quality(MappingQuality::try_from(8)?)
+    ///     .build_validated();
+    ///
+    /// assert_eq!(result, Err(ValidationError::UnmappedWithMappingQuality));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build_validated(self) -> Result<Record, ValidationError> {
+        self.validate()?;
+        Ok(self.build())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        if self.validations.contains(Validations::UNMAPPED) {
+            validate_unmapped(self.flags, self.mapping_quality, &self.cigar)?;
+        }
+
+        if self.validations.contains(Validations::SEGMENTS) {
+            validate_segments(self.flags, self.mate_reference_sequence_id)?;
+        }
+
+        if self.validations.contains(Validations::TEMPLATE_LENGTH) {
+            validate_template_length(
+                self.flags,
+                self.reference_sequence_id,
+                self.alignment_start,
+                self.mate_reference_sequence_id,
+                self.mate_alignment_start,
+                self.template_length,
+            )?;
+        }
+
+        Ok(())
+    }
+
     /// Builds the alignment record.
     ///
     /// # Examples
@@ -296,10 +414,87 @@ impl Default for Builder {
             sequence: Sequence::default(),
             quality_scores: QualityScores::default(),
             data: Data::default(),
+            validations: Validations::default(),
         }
     }
 }
 
+fn validate_unmapped(
+    flags: Flags,
+    mapping_quality: Option<MappingQuality>,
+    cigar: &Cigar,
+) -> Result<(), ValidationError> {
+    if !flags.is_unmapped() {
+        return Ok(());
+    }
+
+    if mapping_quality
+        .map(|mapq| mapq != MappingQuality::MIN)
+        .unwrap_or(false)
+    {
+        return Err(ValidationError::UnmappedWithMappingQuality);
+    }
+
+    if !cigar.is_empty() {
+        return Err(ValidationError::UnmappedWithCigar);
+    }
+
+    Ok(())
+}
+
+fn validate_segments(
+    flags: Flags,
+    mate_reference_sequence_id: Option<usize>,
+) -> Result<(), ValidationError> {
+    use crate::record::flags::FlagError;
+
+    flags
+        .validate_segments(mate_reference_sequence_id)
+        .map_err(|e| match e {
+            FlagError::MissingMateReferenceSequenceId => {
+                ValidationError::MissingMateReferenceSequenceId
+            }
+            _ => ValidationError::InvalidSegmentFlags,
+        })
+}
+
+fn validate_template_length(
+    flags: Flags,
+    reference_sequence_id: Option<usize>,
+    alignment_start: Option<Position>,
+    mate_reference_sequence_id: Option<usize>,
+    mate_alignment_start: Option<Position>,
+    template_length: i32,
+) -> Result<(), ValidationError> {
+    if template_length == 0 {
+        return Ok(());
+    }
+
+    if !flags.is_segmented() || flags.is_unmapped() || flags.is_mate_unmapped() {
+        return Err(ValidationError::UnexpectedTemplateLength);
+    }
+
+    if reference_sequence_id != mate_reference_sequence_id {
+        return Err(ValidationError::UnexpectedTemplateLength);
+    }
+
+    // § 1.4 "The alignment section: mandatory fields" (2023-05-24): "The leftmost segment has a
+    // plus sign and the rightmost has a minus sign."
+    if let (Some(start), Some(mate_start)) = (alignment_start, mate_alignment_start) {
+        let is_valid = match start.cmp(&mate_start) {
+            Ordering::Less => template_length > 0,
+            Ordering::Greater => template_length < 0,
+            Ordering::Equal => true,
+        };
+
+        if !is_valid {
+            return Err(ValidationError::InvalidTemplateLengthSign);
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -320,5 +515,76 @@ mod tests {
         assert!(builder.sequence.is_empty());
         assert!(builder.quality_scores.is_empty());
         assert!(builder.data.is_empty());
+        assert_eq!(builder.validations, Validations::all());
+    }
+
+    #[test]
+    fn test_build_validated() -> Result<(), Box<dyn std::error::Error>> {
+        assert!(Builder::default().build_validated().is_ok());
+
+        let result = Builder::default()
+            .set_flags(Flags::UNMAPPED)
+            .set_mapping_quality(MappingQuality::MIN)
+            .build_validated();
+        assert!(result.is_ok());
+
+        let result = Builder::default()
+            .set_flags(Flags::UNMAPPED)
+            .set_mapping_quality(MappingQuality::try_from(8)?)
+            .build_validated();
+        assert_eq!(result, Err(ValidationError::UnmappedWithMappingQuality));
+
+        let result = Builder::default()
+            .set_flags(Flags::UNMAPPED)
+            .set_cigar("4M".parse()?)
+            .build_validated();
+        assert_eq!(result, Err(ValidationError::UnmappedWithCigar));
+
+        let result = Builder::default()
+            .set_flags(Flags::FIRST_SEGMENT)
+            .build_validated();
+        assert_eq!(result, Err(ValidationError::InvalidSegmentFlags));
+
+        let result = Builder::default()
+            .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT)
+            .build_validated();
+        assert_eq!(result, Err(ValidationError::MissingMateReferenceSequenceId));
+
+        let result = Builder::default()
+            .set_flags(Flags::SEGMENTED | Flags::MATE_UNMAPPED | Flags::FIRST_SEGMENT)
+            .build_validated();
+        assert!(result.is_ok());
+
+        let result = Builder::default()
+            .set_flags(Flags::empty())
+            .set_template_length(8)
+            .build_validated();
+        assert_eq!(result, Err(ValidationError::UnexpectedTemplateLength));
+
+        let build_pair = |template_length| -> Result<_, Box<dyn std::error::Error>> {
+            Ok(Builder::default()
+                .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(8)?)
+                .set_mate_reference_sequence_id(0)
+                .set_mate_alignment_start(Position::try_from(13)?)
+                .set_template_length(template_length)
+                .build_validated())
+        };
+
+        assert!(build_pair(10)?.is_ok());
+        assert_eq!(
+            build_pair(-10)?,
+            Err(ValidationError::InvalidTemplateLengthSign)
+        );
+
+        let result = Builder::default()
+            .set_flags(Flags::UNMAPPED)
+            .set_cigar("4M".parse()?)
+            .set_validations(Validations::SEGMENTS | Validations::TEMPLATE_LENGTH)
+            .build_validated();
+        assert!(result.is_ok());
+
+        Ok(())
     }
 }
@@ -0,0 +1,20 @@
+bitflags::bitflags! {
+    /// Alignment record validations.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub struct Validations: u8 {
+        /// Unmapped records have no mapping quality (or `0`) and no CIGAR operations.
+        const UNMAPPED = 0x01;
+        /// Segment flags are only set on segmented records, and segmented records with a mapped
+        /// mate have a mate reference sequence ID.
+        const SEGMENTS = 0x02;
+        /// The template length is only set on segmented records with both segments mapped to the
+        /// same reference sequence, and its sign matches the leftmost segment.
+        const TEMPLATE_LENGTH = 0x04;
+    }
+}
+
+impl Default for Validations {
+    fn default() -> Self {
+        Self::all()
+    }
+}
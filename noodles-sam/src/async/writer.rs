@@ -141,10 +141,10 @@ where
         header: &Header,
         record: &Record,
     ) -> io::Result<()> {
-        use crate::writer::write_record;
+        use crate::writer::{write_record, LineEnding};
 
         let mut buf = Vec::new();
-        write_record(&mut buf, header, record)?;
+        write_record(&mut buf, header, record, LineEnding::Lf)?;
         self.inner.write_all(&buf).await
     }
 }
@@ -72,11 +72,13 @@
 //! ```
 
 mod builder;
+mod merge;
 mod parser;
 pub mod record;
 
 pub use self::{
     builder::Builder,
+    merge::MergeError,
     parser::{ParseError, Parser},
     record::Record,
 };
@@ -398,6 +400,201 @@ impl Header {
         self.programs.clear();
         self.comments.clear();
     }
+
+    /// Appends a program to the program chain.
+    ///
+    /// This adds a program (`PG`) record for a tool that processed the data described by this
+    /// header. The previous program ID (`PP`) is set to the ID of the last program in the chain,
+    /// i.e., the last program that is not the previous program of another program. If the given
+    /// ID is already used, a numeric suffix (e.g., `.1`) is appended to it.
+    ///
+    /// This returns the ID and map of the appended program.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    ///
+    /// let mut header = sam::Header::default();
+    ///
+    /// header.append_program("noodles", "noodles", "0.1.0", "noodles view");
+    ///
+    /// let (id, program) = header.append_program("noodles", "noodles", "0.1.0", "noodles sort");
+    /// assert_eq!(id, "noodles.1");
+    /// assert_eq!(program.previous_id(), Some("noodles"));
+    /// assert_eq!(program.command_line(), Some("noodles sort"));
+    /// ```
+    pub fn append_program(
+        &mut self,
+        id: &str,
+        name: &str,
+        version: &str,
+        command_line: &str,
+    ) -> (&str, &Map<Program>) {
+        let previous_id = self
+            .programs
+            .keys()
+            .rev()
+            .find(|candidate| {
+                !self
+                    .programs
+                    .values()
+                    .any(|program| program.previous_id() == Some(candidate.as_str()))
+            })
+            .cloned();
+
+        let id = if self.programs.contains_key(id) {
+            merge::next_unique_id(id, |candidate| self.programs.contains_key(candidate))
+        } else {
+            id.into()
+        };
+
+        let mut program = Map::<Program>::default();
+        program.inner.name = Some(name.into());
+        program.inner.version = Some(version.into());
+        program.inner.command_line = Some(command_line.into());
+        program.inner.previous_id = previous_id;
+
+        let (i, _) = self.programs.insert_full(id, program);
+
+        // SAFETY: `i` is the index of the inserted program.
+        let (id, program) = self.programs.get_index(i).unwrap();
+
+        (id, program)
+    }
+
+    /// Merges another header into this header.
+    ///
+    /// This is used to combine headers from multiple files, e.g., when concatenating SAM/BAM
+    /// files. The header (`HD`) record of this header is kept if it is set. Reference sequences,
+    /// read groups, and comments are unioned in order.
+    ///
+    /// Programs (`PG`) of the other header are appended, except those identical to an existing
+    /// program. If a program ID is already used, a numeric suffix (e.g., `.1`) is appended to the
+    /// ID, and the previous program IDs (`PP`) of the other header are updated to keep its program
+    /// chain intact.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if a reference sequence has a different length or a read group has
+    /// different fields in the other header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::{map::{Program, ReferenceSequence}, Map},
+    /// };
+    ///
+    /// let a = sam::Header::builder()
+    ///     .add_reference_sequence(
+    ///         "sq0".parse()?,
+    ///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+    ///     )
+    ///     .add_program("pg0", Map::<Program>::default())
+    ///     .build();
+    ///
+    /// let b = sam::Header::builder()
+    ///     .add_reference_sequence(
+    ///         "sq1".parse()?,
+    ///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+    ///     )
+    ///     .add_program("pg0", Map::<Program>::builder().set_name("noodles").build()?)
+    ///     .build();
+    ///
+    /// let header = a.merge(b)?;
+    ///
+    /// assert_eq!(header.reference_sequences().len(), 2);
+    /// assert!(header.programs().contains_key("pg0"));
+    /// assert!(header.programs().contains_key("pg0.1"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn merge(self, other: Self) -> Result<Self, MergeError> {
+        merge::merge(self, other, false)
+    }
+
+    /// Merges another header into this one, matching reference sequences by equivalent names.
+    ///
+    /// This is the same as [`Self::merge`], except that reference sequence names are compared
+    /// after normalization (see [`noodles_core::region::name`]), e.g., `chr1` and `1`, or `chrM`
+    /// and `MT`, are the same reference sequence. The name in this header is kept.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if a reference sequence has a different length in the other header,
+    /// or if a read group has different fields in the other header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::{map::ReferenceSequence, Map},
+    /// };
+    ///
+    /// let a = sam::Header::builder()
+    ///     .add_reference_sequence(
+    ///         "chr1".parse()?,
+    ///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+    ///     )
+    ///     .build();
+    ///
+    /// let b = sam::Header::builder()
+    ///     .add_reference_sequence(
+    ///         "1".parse()?,
+    ///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+    ///     )
+    ///     .add_reference_sequence(
+    ///         "MT".parse()?,
+    ///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+    ///     )
+    ///     .build();
+    ///
+    /// let header = a.merge_with_normalized_reference_sequence_names(b)?;
+    ///
+    /// let names: Vec<_> = header.reference_sequences().keys().map(|name| name.as_str()).collect();
+    /// assert_eq!(names, ["chr1", "MT"]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn merge_with_normalized_reference_sequence_names(
+        self,
+        other: Self,
+    ) -> Result<Self, MergeError> {
+        merge::merge(self, other, true)
+    }
+
+    /// Merges a list of headers.
+    ///
+    /// The headers are merged in order using [`Self::merge`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    ///
+    /// let headers = [
+    ///     sam::Header::builder().add_comment("ndls").build(),
+    ///     sam::Header::builder().add_comment("ndls").build(),
+    ///     sam::Header::builder().add_comment("noodles").build(),
+    /// ];
+    ///
+    /// let header = sam::Header::merge_from_iter(headers)?;
+    /// assert_eq!(header.comments(), ["ndls", "noodles"]);
+    /// # Ok::<_, sam::header::MergeError>(())
+    /// ```
+    pub fn merge_from_iter<I>(headers: I) -> Result<Self, MergeError>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        headers
+            .into_iter()
+            .try_fold(Self::default(), |dst, src| dst.merge(src))
+    }
 }
 
 impl fmt::Display for Header {
@@ -512,4 +709,39 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_append_program() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header = Header::builder()
+            .add_program("pg0", Map::<Program>::default())
+            .add_program(
+                "pg1",
+                Map::<Program>::builder().set_previous_id("pg0").build()?,
+            )
+            .add_program("pg2", Map::<Program>::default())
+            .add_program(
+                "pg3",
+                Map::<Program>::builder().set_previous_id("pg1").build()?,
+            )
+            .build();
+
+        let (id, program) = header.append_program("pg1", "noodles", "0.1.0", "noodles view");
+
+        assert_eq!(id, "pg1.1");
+        assert_eq!(program.name(), Some("noodles"));
+        assert_eq!(program.version(), Some("0.1.0"));
+        assert_eq!(program.command_line(), Some("noodles view"));
+        assert_eq!(program.previous_id(), Some("pg3"));
+
+        let (id, program) = header.append_program("pg4", "noodles", "0.1.0", "noodles sort");
+        assert_eq!(id, "pg4");
+        assert_eq!(program.previous_id(), Some("pg1.1"));
+
+        let mut header = Header::default();
+        let (id, program) = header.append_program("pg0", "noodles", "0.1.0", "noodles view");
+        assert_eq!(id, "pg0");
+        assert!(program.previous_id().is_none());
+
+        Ok(())
+    }
 }
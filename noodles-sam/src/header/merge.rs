@@ -0,0 +1,390 @@
+use std::{collections::HashMap, error, fmt, num::NonZeroUsize};
+
+use super::{record::value::map::reference_sequence::Name, Header};
+
+/// An error returned when SAM headers fail to merge.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MergeError {
+    /// A reference sequence has different lengths in the headers.
+    ConflictingReferenceSequenceLength {
+        /// The reference sequence name.
+        name: Name,
+        /// The length in the first header.
+        len_a: NonZeroUsize,
+        /// The length in the second header.
+        len_b: NonZeroUsize,
+    },
+    /// A read group has different samples in the headers.
+    ConflictingReadGroupSample {
+        /// The read group ID.
+        id: String,
+    },
+    /// A read group has different fields in the headers.
+    ConflictingReadGroup {
+        /// The read group ID.
+        id: String,
+    },
+}
+
+impl error::Error for MergeError {}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConflictingReferenceSequenceLength { name, len_a, len_b } => write!(
+                f,
+                "conflicting reference sequence length: {name}: expected {len_a}, got {len_b}"
+            ),
+            Self::ConflictingReadGroupSample { id } => {
+                write!(f, "conflicting read group sample: {id}")
+            }
+            Self::ConflictingReadGroup { id } => write!(f, "conflicting read group: {id}"),
+        }
+    }
+}
+
+pub(super) fn merge(
+    mut dst: Header,
+    src: Header,
+    normalize_names: bool,
+) -> Result<Header, MergeError> {
+    use noodles_core::region::name::find;
+
+    if dst.header.is_none() {
+        dst.header = src.header;
+    }
+
+    for (name, reference_sequence) in src.reference_sequences {
+        let i = if normalize_names {
+            find(dst.reference_sequences.keys().map(|n| n.as_str()), &name)
+        } else {
+            dst.reference_sequences.get_index_of(&name)
+        };
+
+        if let Some((_, prev_reference_sequence)) =
+            i.and_then(|i| dst.reference_sequences.get_index(i))
+        {
+            let len_a = prev_reference_sequence.length();
+            let len_b = reference_sequence.length();
+
+            if len_a != len_b {
+                return Err(MergeError::ConflictingReferenceSequenceLength { name, len_a, len_b });
+            }
+        } else {
+            dst.reference_sequences.insert(name, reference_sequence);
+        }
+    }
+
+    for (id, read_group) in src.read_groups {
+        if let Some(prev_read_group) = dst.read_groups.get(&id) {
+            if prev_read_group.sample() != read_group.sample() {
+                return Err(MergeError::ConflictingReadGroupSample { id });
+            } else if *prev_read_group != read_group {
+                return Err(MergeError::ConflictingReadGroup { id });
+            }
+        } else {
+            dst.read_groups.insert(id, read_group);
+        }
+    }
+
+    merge_programs(&mut dst, src.programs);
+
+    for comment in src.comments {
+        if !dst.comments.contains(&comment) {
+            dst.comments.push(comment);
+        }
+    }
+
+    Ok(dst)
+}
+
+fn merge_programs(dst: &mut Header, src: super::Programs) {
+    let mut ids = HashMap::new();
+    let mut programs = Vec::new();
+
+    for (id, program) in src {
+        if dst.programs.get(&id) == Some(&program) {
+            ids.insert(id.clone(), id);
+            continue;
+        }
+
+        let is_taken = |candidate: &str| {
+            dst.programs.contains_key(candidate) || ids.values().any(|id| id == candidate)
+        };
+
+        let new_id = if is_taken(&id) {
+            next_unique_id(&id, is_taken)
+        } else {
+            id.clone()
+        };
+
+        ids.insert(id, new_id.clone());
+        programs.push((new_id, program));
+    }
+
+    for (id, mut program) in programs {
+        if let Some(previous_id) = program.inner.previous_id.as_mut() {
+            if let Some(new_previous_id) = ids.get(previous_id) {
+                *previous_id = new_previous_id.clone();
+            }
+        }
+
+        dst.programs.insert(id, program);
+    }
+}
+
+pub(super) fn next_unique_id<F>(id: &str, is_taken: F) -> String
+where
+    F: Fn(&str) -> bool,
+{
+    let mut i = 1;
+
+    loop {
+        let candidate = format!("{id}.{i}");
+
+        if !is_taken(&candidate) {
+            return candidate;
+        }
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::record::value::{
+        map::{Program, ReadGroup, ReferenceSequence},
+        Map,
+    };
+
+    #[test]
+    fn test_merge() -> Result<(), Box<dyn std::error::Error>> {
+        let a = Header::builder()
+            .set_header(Default::default())
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_read_group("rg0", Map::<ReadGroup>::default())
+            .add_comment("ndls")
+            .build();
+
+        let b = Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .add_read_group("rg0", Map::<ReadGroup>::default())
+            .add_read_group("rg1", Map::<ReadGroup>::default())
+            .add_comment("ndls")
+            .add_comment("noodles")
+            .build();
+
+        let actual = merge(a, b, false)?;
+
+        let expected = Header::builder()
+            .set_header(Default::default())
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .add_read_group("rg0", Map::<ReadGroup>::default())
+            .add_read_group("rg1", Map::<ReadGroup>::default())
+            .add_comment("ndls")
+            .add_comment("noodles")
+            .build();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_conflicts() -> Result<(), Box<dyn std::error::Error>> {
+        let a = Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let b = Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        assert_eq!(
+            merge(a, b, false),
+            Err(MergeError::ConflictingReferenceSequenceLength {
+                name: "sq0".parse()?,
+                len_a: NonZeroUsize::try_from(8)?,
+                len_b: NonZeroUsize::try_from(13)?,
+            })
+        );
+
+        let a = Header::builder()
+            .add_read_group(
+                "rg0",
+                Map::<ReadGroup>::builder().set_sample("sm0").build()?,
+            )
+            .build();
+
+        let b = Header::builder()
+            .add_read_group(
+                "rg0",
+                Map::<ReadGroup>::builder().set_sample("sm1").build()?,
+            )
+            .build();
+
+        assert_eq!(
+            merge(a.clone(), b, false),
+            Err(MergeError::ConflictingReadGroupSample {
+                id: String::from("rg0")
+            })
+        );
+
+        let b = Header::builder()
+            .add_read_group(
+                "rg0",
+                Map::<ReadGroup>::builder()
+                    .set_sample("sm0")
+                    .set_library("lb0")
+                    .build()?,
+            )
+            .build();
+
+        assert_eq!(
+            merge(a, b, false),
+            Err(MergeError::ConflictingReadGroup {
+                id: String::from("rg0")
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_normalized_names() -> Result<(), Box<dyn std::error::Error>> {
+        let build_header = |names: &[(&str, usize)]| -> Result<Header, Box<dyn std::error::Error>> {
+            let mut builder = Header::builder();
+
+            for &(name, len) in names {
+                builder = builder.add_reference_sequence(
+                    name.parse()?,
+                    Map::<ReferenceSequence>::new(NonZeroUsize::try_from(len)?),
+                );
+            }
+
+            Ok(builder.build())
+        };
+
+        let a = build_header(&[("chr1", 8), ("chrM", 13)])?;
+        let b = build_header(&[("1", 8), ("MT", 13), ("2", 21)])?;
+
+        let actual = merge(a.clone(), b.clone(), true)?;
+        let expected = build_header(&[("chr1", 8), ("chrM", 13), ("2", 21)])?;
+        assert_eq!(actual, expected);
+
+        // Names are compared exactly unless normalization is enabled.
+        let actual = merge(a, b, false)?;
+        let expected = build_header(&[("chr1", 8), ("chrM", 13), ("1", 8), ("MT", 13), ("2", 21)])?;
+        assert_eq!(actual, expected);
+
+        let a = build_header(&[("chr1", 8)])?;
+        let b = build_header(&[("1", 13)])?;
+
+        assert_eq!(
+            merge(a, b, true),
+            Err(MergeError::ConflictingReferenceSequenceLength {
+                name: "1".parse()?,
+                len_a: NonZeroUsize::try_from(8)?,
+                len_b: NonZeroUsize::try_from(13)?,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_programs() -> Result<(), Box<dyn std::error::Error>> {
+        let a = Header::builder()
+            .add_program(
+                "pg0",
+                Map::<Program>::builder().set_name("aligner").build()?,
+            )
+            .add_program(
+                "pg1",
+                Map::<Program>::builder()
+                    .set_name("sorter")
+                    .set_previous_id("pg0")
+                    .build()?,
+            )
+            .build();
+
+        let b = Header::builder()
+            .add_program(
+                "pg0",
+                Map::<Program>::builder().set_name("aligner").build()?,
+            )
+            .add_program(
+                "pg1",
+                Map::<Program>::builder()
+                    .set_name("marker")
+                    .set_previous_id("pg0")
+                    .build()?,
+            )
+            .add_program(
+                "pg1.1",
+                Map::<Program>::builder()
+                    .set_name("caller")
+                    .set_previous_id("pg1")
+                    .build()?,
+            )
+            .build();
+
+        let actual = merge(a, b, false)?;
+
+        let expected = Header::builder()
+            .add_program(
+                "pg0",
+                Map::<Program>::builder().set_name("aligner").build()?,
+            )
+            .add_program(
+                "pg1",
+                Map::<Program>::builder()
+                    .set_name("sorter")
+                    .set_previous_id("pg0")
+                    .build()?,
+            )
+            .add_program(
+                "pg1.1",
+                Map::<Program>::builder()
+                    .set_name("marker")
+                    .set_previous_id("pg0")
+                    .build()?,
+            )
+            .add_program(
+                "pg1.1.1",
+                Map::<Program>::builder()
+                    .set_name("caller")
+                    .set_previous_id("pg1.1")
+                    .build()?,
+            )
+            .build();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -285,3 +285,42 @@ impl Default for Record {
         Self { buf, bounds }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fields() -> Result<(), Box<dyn std::error::Error>> {
+        // The template length (`ndls`) is invalid.
+        let src = &b"r0\t99\tsq0\t8\t13\t4M\t=\t21\tndls\tACGT\tNDLS\n"[..];
+        let mut reader = crate::Reader::new(src);
+
+        let mut record = Record::default();
+        reader.read_lazy_record(&mut record)?;
+
+        assert_eq!(
+            record.flags()?,
+            Flags::SEGMENTED
+                | Flags::PROPERLY_ALIGNED
+                | Flags::MATE_REVERSE_COMPLEMENTED
+                | Flags::FIRST_SEGMENT
+        );
+        assert_eq!(
+            record
+                .reference_sequence_name()
+                .as_ref()
+                .map(|name| name.as_ref()),
+            Some(&b"sq0"[..])
+        );
+        assert_eq!(record.alignment_start()?, Position::new(8));
+        assert_eq!(record.mapping_quality()?, MappingQuality::new(13));
+
+        assert!(matches!(
+            record.template_length(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
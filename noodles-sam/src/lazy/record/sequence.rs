@@ -1,5 +1,7 @@
 use std::io;
 
+use crate::record::sequence::{base, Base};
+
 /// A raw SAM record sequence.
 #[derive(Debug, Eq, PartialEq)]
 pub struct Sequence<'a>(&'a [u8]);
@@ -18,6 +20,25 @@ impl<'a> Sequence<'a> {
     pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    /// Returns an iterator over bases.
+    ///
+    /// Bases are parsed as they are iterated.
+    pub fn iter(&self) -> impl Iterator<Item = Result<Base, base::TryFromCharError>> + '_ {
+        self.0.iter().map(|&b| Base::try_from(b))
+    }
+
+    /// Returns the reverse complement of the sequence.
+    ///
+    /// See [`Base::complement`] for how each base is complemented.
+    pub fn reverse_complement(&self) -> Result<crate::record::Sequence, base::TryFromCharError> {
+        self.0
+            .iter()
+            .rev()
+            .map(|&b| Base::try_from(b).map(Base::complement))
+            .collect::<Result<Vec<_>, _>>()
+            .map(crate::record::Sequence::from)
+    }
 }
 
 impl<'a> AsRef<[u8]> for Sequence<'a> {
@@ -26,19 +47,80 @@ impl<'a> AsRef<[u8]> for Sequence<'a> {
     }
 }
 
-impl<'a> TryInto<crate::record::Sequence> for Sequence<'a> {
+impl<'a> TryFrom<Sequence<'a>> for crate::record::Sequence {
     type Error = io::Error;
 
-    fn try_into(self) -> Result<crate::record::Sequence, Self::Error> {
+    fn try_from(Sequence(src): Sequence<'a>) -> Result<Self, Self::Error> {
         use crate::reader::record::parse_sequence;
 
         let mut sequence = crate::record::Sequence::default();
 
-        if !self.is_empty() {
-            parse_sequence(self.as_ref(), &mut sequence)
+        if !src.is_empty() {
+            parse_sequence(src, &mut sequence)
                 .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
         }
 
         Ok(sequence)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iter() -> Result<(), base::TryFromCharError> {
+        let sequence = Sequence::new(b"");
+        assert!(sequence.iter().next().is_none());
+
+        let sequence = Sequence::new(b"ACGT");
+        let actual: Vec<_> = sequence.iter().collect::<Result<_, _>>()?;
+        let expected = [Base::A, Base::C, Base::G, Base::T];
+        assert_eq!(actual, expected);
+
+        let sequence = Sequence::new(b"AC!T");
+        let mut iter = sequence.iter();
+        assert_eq!(iter.next(), Some(Ok(Base::A)));
+        assert_eq!(iter.next(), Some(Ok(Base::C)));
+        assert!(matches!(iter.next(), Some(Err(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reverse_complement() -> Result<(), base::TryFromCharError> {
+        let sequence = Sequence::new(b"");
+        assert!(sequence.reverse_complement()?.is_empty());
+
+        let sequence = Sequence::new(b"ACGTRN");
+        let actual = sequence.reverse_complement()?;
+        let expected = crate::record::Sequence::from(vec![
+            Base::N,
+            Base::Y,
+            Base::A,
+            Base::C,
+            Base::G,
+            Base::T,
+        ]);
+        assert_eq!(actual, expected);
+
+        let sequence = Sequence::new(b"AC!T");
+        assert!(sequence.reverse_complement().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_sequence_for_sam_record_sequence() -> io::Result<()> {
+        let sequence = Sequence::new(b"");
+        let actual = crate::record::Sequence::try_from(sequence)?;
+        assert!(actual.is_empty());
+
+        let sequence = Sequence::new(b"ACGT");
+        let actual = crate::record::Sequence::try_from(sequence)?;
+        let expected = crate::record::Sequence::from(vec![Base::A, Base::C, Base::G, Base::T]);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
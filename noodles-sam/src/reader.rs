@@ -2,11 +2,12 @@
 
 mod builder;
 mod header;
+mod parallel_reader;
 mod query;
 pub(crate) mod record;
 mod records;
 
-pub use self::{builder::Builder, records::Records};
+pub use self::{builder::Builder, parallel_reader::ParallelReader, records::Records};
 
 use std::io::{self, BufRead, Read, Seek};
 
@@ -0,0 +1,306 @@
+use std::{
+    io::{self, BufRead},
+    mem,
+    num::NonZeroUsize,
+    sync::Arc,
+    thread::{self, JoinHandle},
+    vec,
+};
+
+use crossbeam_channel::{Receiver, Sender};
+
+use super::read_line;
+use crate::{alignment::Record, Header};
+
+const BATCH_SIZE: usize = 1024;
+
+type Batch = Vec<Vec<u8>>;
+type ParsedTx = Sender<Vec<io::Result<Record>>>;
+type ParsedRx = Receiver<Vec<io::Result<Record>>>;
+type ParseTx = Sender<(Batch, ParsedTx)>;
+type ParseRx = Receiver<(Batch, ParsedTx)>;
+type ReadTx = Sender<ParsedRx>;
+type ReadRx = Receiver<ParsedRx>;
+
+/// A parallel SAM reader.
+///
+/// This reads raw lines into batches on a reader thread and parses the batches into records using
+/// a thread pool. Records are yielded in input order.
+///
+/// The number of batches in flight is bounded by the worker count, i.e., the input is not read
+/// ahead of the consumer by more than a few batches.
+///
+/// The header must be read before creating a parallel reader, e.g., using [`super::Reader`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::{io, num::NonZeroUsize};
+/// use noodles_sam::{self as sam, reader::ParallelReader};
+///
+/// let data = b"@HD\tVN:1.6
+/// *\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*
+/// ";
+///
+/// let mut reader = sam::Reader::new(&data[..]);
+/// let header = reader.read_header()?;
+///
+/// let worker_count = NonZeroUsize::try_from(2).unwrap();
+/// let reader = ParallelReader::with_worker_count(worker_count, reader.into_inner(), header);
+///
+/// for result in reader {
+///     let record = result?;
+///     // ...
+/// }
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct ParallelReader {
+    reader_handle: Option<JoinHandle<()>>,
+    parser_handles: Vec<JoinHandle<()>>,
+    read_rx: Option<ReadRx>,
+    records: vec::IntoIter<io::Result<Record>>,
+}
+
+impl ParallelReader {
+    /// Creates a parallel SAM reader.
+    ///
+    /// The given reader is expected to be positioned at the start of the first record, i.e.,
+    /// directly after the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_sam::{self as sam, reader::ParallelReader};
+    ///
+    /// let worker_count = NonZeroUsize::try_from(2).unwrap();
+    /// let reader = ParallelReader::with_worker_count(worker_count, &[][..], sam::Header::default());
+    /// ```
+    pub fn with_worker_count<R>(worker_count: NonZeroUsize, inner: R, header: Header) -> Self
+    where
+        R: BufRead + Send + 'static,
+    {
+        let (parse_tx, parse_rx) = crossbeam_channel::bounded(worker_count.get());
+        let (read_tx, read_rx) = crossbeam_channel::bounded(worker_count.get());
+
+        let reader_handle = spawn_reader(inner, parse_tx, read_tx);
+        let parser_handles = spawn_parsers(worker_count, Arc::new(header), parse_rx);
+
+        Self {
+            reader_handle: Some(reader_handle),
+            parser_handles,
+            read_rx: Some(read_rx),
+            records: Vec::new().into_iter(),
+        }
+    }
+
+    /// Shuts down the reader and parse workers.
+    ///
+    /// This is called when the reader is dropped.
+    pub fn finish(&mut self) {
+        self.read_rx.take();
+
+        if let Some(handle) = self.reader_handle.take() {
+            handle.join().unwrap();
+        }
+
+        for handle in self.parser_handles.drain(..) {
+            handle.join().unwrap();
+        }
+    }
+
+    fn recv_records(&mut self) -> Option<Vec<io::Result<Record>>> {
+        let read_rx = self.read_rx.as_ref()?;
+        let parsed_rx = read_rx.recv().ok()?;
+        parsed_rx.recv().ok()
+    }
+}
+
+impl Drop for ParallelReader {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+impl Iterator for ParallelReader {
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.records.next() {
+                Some(Ok(record)) => return Some(Ok(record)),
+                Some(Err(e)) => {
+                    self.records = Vec::new().into_iter();
+                    self.finish();
+                    return Some(Err(e));
+                }
+                None => self.records = self.recv_records()?.into_iter(),
+            }
+        }
+    }
+}
+
+fn spawn_reader<R>(mut reader: R, parse_tx: ParseTx, read_tx: ReadTx) -> JoinHandle<()>
+where
+    R: BufRead + Send + 'static,
+{
+    thread::spawn(move || loop {
+        let (parsed_tx, parsed_rx) = crossbeam_channel::bounded(1);
+
+        match read_batch(&mut reader) {
+            Ok(batch) if batch.is_empty() => break,
+            Ok(batch) => {
+                if read_tx.send(parsed_rx).is_err() || parse_tx.send((batch, parsed_tx)).is_err() {
+                    break;
+                }
+            }
+            Err(e) => {
+                parsed_tx.send(vec![Err(e)]).ok();
+                read_tx.send(parsed_rx).ok();
+                break;
+            }
+        }
+    })
+}
+
+fn read_batch<R>(reader: &mut R) -> io::Result<Batch>
+where
+    R: BufRead,
+{
+    let mut batch = Vec::with_capacity(BATCH_SIZE);
+    let mut buf = Vec::new();
+
+    while batch.len() < BATCH_SIZE {
+        if read_line(reader, &mut buf)? == 0 {
+            break;
+        }
+
+        batch.push(mem::take(&mut buf));
+    }
+
+    Ok(batch)
+}
+
+fn spawn_parsers(
+    worker_count: NonZeroUsize,
+    header: Arc<Header>,
+    parse_rx: ParseRx,
+) -> Vec<JoinHandle<()>> {
+    (0..worker_count.get())
+        .map(|_| {
+            let header = header.clone();
+            let parse_rx = parse_rx.clone();
+
+            thread::spawn(move || {
+                while let Ok((batch, parsed_tx)) = parse_rx.recv() {
+                    let result = parse_batch(&header, &batch);
+                    parsed_tx.send(result).ok();
+                }
+            })
+        })
+        .collect()
+}
+
+fn parse_batch(header: &Header, batch: &[Vec<u8>]) -> Vec<io::Result<Record>> {
+    use super::record::parse_record;
+
+    let mut records = Vec::with_capacity(batch.len());
+
+    for line in batch {
+        let mut record = Record::default();
+
+        match parse_record(line, header, &mut record) {
+            Ok(()) => records.push(Ok(record)),
+            Err(e) => {
+                records.push(Err(io::Error::new(io::ErrorKind::InvalidData, e)));
+                break;
+            }
+        }
+    }
+
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_data(record_count: usize) -> Vec<u8> {
+        let mut data = b"@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:1000\n".to_vec();
+
+        for i in 0..record_count {
+            let line = format!(
+                "r{i}\t0\tsq0\t{}\t60\t4M\t*\t0\t0\tACGT\tNDLS\n",
+                i % 997 + 1
+            );
+            data.extend_from_slice(line.as_bytes());
+        }
+
+        data
+    }
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let data = build_data(BATCH_SIZE * 5 + 3);
+
+        let mut reader = super::super::Reader::new(&data[..]);
+        let header = reader.read_header()?;
+        let expected: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+
+        let mut reader = super::super::Reader::new(io::Cursor::new(data));
+        let header = reader.read_header()?;
+
+        let worker_count = NonZeroUsize::try_from(4).unwrap();
+        let parallel_reader =
+            ParallelReader::with_worker_count(worker_count, reader.into_inner(), header);
+        let actual: Vec<_> = parallel_reader.collect::<io::Result<_>>()?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_invalid_record() -> io::Result<()> {
+        let mut data = build_data(BATCH_SIZE + 1);
+        data.extend_from_slice(b"r\tnoodles\n");
+        data.extend_from_slice(b"r\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS\n");
+
+        let mut reader = super::super::Reader::new(io::Cursor::new(data));
+        let header = reader.read_header()?;
+
+        let worker_count = NonZeroUsize::try_from(2).unwrap();
+        let mut parallel_reader =
+            ParallelReader::with_worker_count(worker_count, reader.into_inner(), header);
+
+        for _ in 0..BATCH_SIZE + 1 {
+            assert!(parallel_reader.next().transpose()?.is_some());
+        }
+
+        assert!(matches!(
+            parallel_reader.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(parallel_reader.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_drop_before_end() -> io::Result<()> {
+        let data = build_data(BATCH_SIZE * 16);
+
+        let mut reader = super::super::Reader::new(io::Cursor::new(data));
+        let header = reader.read_header()?;
+
+        let worker_count = NonZeroUsize::try_from(2).unwrap();
+        let mut parallel_reader =
+            ParallelReader::with_worker_count(worker_count, reader.into_inner(), header);
+
+        assert!(parallel_reader.next().transpose()?.is_some());
+        drop(parallel_reader);
+
+        Ok(())
+    }
+}
@@ -2,7 +2,8 @@
 
 pub mod cigar;
 pub mod data;
-mod flags;
+pub mod filter;
+pub mod flags;
 pub mod mapping_quality;
 pub mod quality_scores;
 pub mod read_name;
@@ -11,7 +12,7 @@ pub mod sequence;
 pub mod template_length;
 
 pub use self::{
-    cigar::Cigar, data::Data, flags::Flags, mapping_quality::MappingQuality,
+    cigar::Cigar, data::Data, filter::Filter, flags::Flags, mapping_quality::MappingQuality,
     quality_scores::QualityScores, read_name::ReadName,
     reference_sequence_name::ReferenceSequenceName, sequence::Sequence,
     template_length::TemplateLength,
@@ -1,10 +1,14 @@
 //! SAM CIGAR and operations.
 
+pub mod alignment_blocks;
 pub mod op;
 
 use std::{error, fmt, ops::Deref, str::FromStr};
 
-pub use self::op::Op;
+pub use self::{
+    alignment_blocks::{AlignmentBlock, AlignmentBlocks},
+    op::Op,
+};
 
 /// A SAM record CIGAR.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -83,6 +87,72 @@ impl Cigar {
             .filter_map(|op| op.kind().consumes_read().then_some(op.len()))
             .sum()
     }
+
+    /// Calculates the number of reference bases covered by the CIGAR operations.
+    ///
+    /// This sums the lengths of the operations that consume the reference sequence: alignment
+    /// matches (`M`), deletions (`D`), skipped regions (`N`), sequence matches (`=`), and
+    /// sequence mismatches (`X`). Insertions (`I`), soft clips (`S`), hard clips (`H`), and
+    /// padding (`P`) do not contribute.
+    ///
+    /// This is the same as [`Self::alignment_span`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Cigar;
+    /// let cigar: Cigar = "2S8M4I4M3D1N2H".parse()?;
+    /// assert_eq!(cigar.reference_len(), 16);
+    /// # Ok::<_, noodles_sam::record::cigar::ParseError>(())
+    /// ```
+    pub fn reference_len(&self) -> usize {
+        self.alignment_span()
+    }
+
+    /// Calculates the number of query (read) bases covered by the CIGAR operations.
+    ///
+    /// This sums the lengths of the operations that consume the query sequence: alignment
+    /// matches (`M`), insertions (`I`), soft clips (`S`), sequence matches (`=`), and sequence
+    /// mismatches (`X`). Deletions (`D`), skipped regions (`N`), hard clips (`H`), and padding
+    /// (`P`) do not contribute.
+    ///
+    /// This is the same as [`Self::read_length`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Cigar;
+    /// let cigar: Cigar = "2S8M4I4M3D1N2H".parse()?;
+    /// assert_eq!(cigar.query_len(), 18);
+    /// # Ok::<_, noodles_sam::record::cigar::ParseError>(())
+    /// ```
+    pub fn query_len(&self) -> usize {
+        self.read_length()
+    }
+
+    /// Returns an iterator over the alignment blocks.
+    ///
+    /// An alignment block is an alignment match (`M`), sequence match (`=`), or sequence mismatch
+    /// (`X`) operation. Each block has a 0-based read start, a 0-based reference start relative to
+    /// the alignment start, and a length. Adjacent operations are not merged.
+    ///
+    /// Soft-clipped bases are not included in any block, but they are counted in the read start
+    /// since they are part of the read sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{cigar::AlignmentBlock, Cigar};
+    ///
+    /// let cigar: Cigar = "2S4M2D4M".parse()?;
+    /// let blocks: Vec<_> = cigar.alignment_blocks().collect();
+    ///
+    /// assert_eq!(blocks, [AlignmentBlock::new(2, 0, 4), AlignmentBlock::new(6, 6, 4)]);
+    /// # Ok::<_, noodles_sam::record::cigar::ParseError>(())
+    /// ```
+    pub fn alignment_blocks(&self) -> AlignmentBlocks<'_> {
+        AlignmentBlocks::new(&self.0)
+    }
 }
 
 impl Deref for Cigar {
@@ -207,6 +277,32 @@ mod tests {
         assert!(!cigar.is_empty());
     }
 
+    #[test]
+    fn test_reference_len() -> Result<(), ParseError> {
+        assert_eq!(Cigar::default().reference_len(), 0);
+
+        let cigar: Cigar = "8M".parse()?;
+        assert_eq!(cigar.reference_len(), 8);
+
+        let cigar: Cigar = "1H2S3M4I5D6N7P8=9X".parse()?;
+        assert_eq!(cigar.reference_len(), 31);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_len() -> Result<(), ParseError> {
+        assert_eq!(Cigar::default().query_len(), 0);
+
+        let cigar: Cigar = "8M".parse()?;
+        assert_eq!(cigar.query_len(), 8);
+
+        let cigar: Cigar = "1H2S3M4I5D6N7P8=9X".parse()?;
+        assert_eq!(cigar.query_len(), 26);
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() {
         let cigar = Cigar::default();
@@ -0,0 +1,152 @@
+//! SAM record CIGAR alignment blocks.
+
+use std::slice;
+
+use super::{op::Kind, Op};
+
+/// An aligned block of a read.
+///
+/// An alignment block is a run of read bases that are aligned to reference bases, i.e., an
+/// alignment match (`M`), sequence match (`=`), or sequence mismatch (`X`) operation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AlignmentBlock {
+    read_start: usize,
+    reference_start: usize,
+    len: usize,
+}
+
+impl AlignmentBlock {
+    /// Creates an alignment block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::cigar::AlignmentBlock;
+    /// let block = AlignmentBlock::new(2, 0, 8);
+    /// ```
+    pub fn new(read_start: usize, reference_start: usize, len: usize) -> Self {
+        Self {
+            read_start,
+            reference_start,
+            len,
+        }
+    }
+
+    /// Returns the 0-based start of the block in the read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::cigar::AlignmentBlock;
+    /// let block = AlignmentBlock::new(2, 0, 8);
+    /// assert_eq!(block.read_start(), 2);
+    /// ```
+    pub fn read_start(&self) -> usize {
+        self.read_start
+    }
+
+    /// Returns the 0-based start of the block in the reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::cigar::AlignmentBlock;
+    /// let block = AlignmentBlock::new(2, 0, 8);
+    /// assert_eq!(block.reference_start(), 0);
+    /// ```
+    pub fn reference_start(&self) -> usize {
+        self.reference_start
+    }
+
+    /// Returns the length of the block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::cigar::AlignmentBlock;
+    /// let block = AlignmentBlock::new(2, 0, 8);
+    /// assert_eq!(block.len(), 8);
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// An iterator over the alignment blocks of a CIGAR.
+///
+/// This is created by calling [`super::Cigar::alignment_blocks`].
+pub struct AlignmentBlocks<'a> {
+    ops: slice::Iter<'a, Op>,
+    read_position: usize,
+    reference_position: usize,
+}
+
+impl<'a> AlignmentBlocks<'a> {
+    pub(super) fn new(ops: &'a [Op]) -> Self {
+        Self {
+            ops: ops.iter(),
+            read_position: 0,
+            reference_position: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for AlignmentBlocks<'a> {
+    type Item = AlignmentBlock;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for op in self.ops.by_ref() {
+            let kind = op.kind();
+            let len = op.len();
+
+            let block = match kind {
+                Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => Some(
+                    AlignmentBlock::new(self.read_position, self.reference_position, len),
+                ),
+                _ => None,
+            };
+
+            if kind.consumes_read() {
+                self.read_position += len;
+            }
+
+            if kind.consumes_reference() {
+                self.reference_position += len;
+            }
+
+            if block.is_some() {
+                return block;
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::Cigar;
+
+    #[test]
+    fn test_next() -> Result<(), super::super::ParseError> {
+        let cigar: Cigar = "2H2S4M2I3=1X2D3M1N2M2S".parse()?;
+        let actual: Vec<_> = cigar.alignment_blocks().collect();
+
+        let expected = [
+            AlignmentBlock::new(2, 0, 4),
+            AlignmentBlock::new(8, 4, 3),
+            AlignmentBlock::new(11, 7, 1),
+            AlignmentBlock::new(12, 10, 3),
+            AlignmentBlock::new(15, 14, 2),
+        ];
+
+        assert_eq!(actual, expected);
+
+        let cigar: Cigar = "4S".parse()?;
+        assert_eq!(cigar.alignment_blocks().next(), None);
+
+        Ok(())
+    }
+}
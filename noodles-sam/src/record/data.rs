@@ -320,12 +320,68 @@ impl FromStr for Data {
     }
 }
 
+/// Calculates the edit distance (`NM`) from a mismatched positions string (`MD`) and CIGAR.
+///
+/// This is useful when the reference sequence is not available. Mismatched and deleted bases are
+/// counted from the `MD` string, and inserted bases (`I`) are counted from the CIGAR.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::record::{data::nm_from_md, Cigar};
+/// let cigar: Cigar = "2S3M1I2M1D2M".parse()?;
+/// assert_eq!(nm_from_md("2C2^T2", &cigar), 3);
+/// # Ok::<_, noodles_sam::record::cigar::ParseError>(())
+/// ```
+pub fn nm_from_md(md: &str, cigar: &super::Cigar) -> u32 {
+    use super::cigar::op::Kind;
+
+    let mismatch_and_deletion_count = md.bytes().filter(|b| b.is_ascii_alphabetic()).count();
+
+    let insertion_count: usize = cigar
+        .iter()
+        .filter(|op| op.kind() == Kind::Insertion)
+        .map(|op| op.len())
+        .sum();
+
+    (mismatch_and_deletion_count + insertion_count) as u32
+}
+
 #[cfg(test)]
 mod tests {
     use super::field::tag;
 
     use super::*;
 
+    #[test]
+    fn test_nm_from_md() -> Result<(), crate::record::cigar::ParseError> {
+        use crate::record::Cigar;
+
+        let cigar: Cigar = "4M".parse()?;
+        assert_eq!(nm_from_md("4", &cigar), 0);
+        assert_eq!(nm_from_md("1A0C1", &cigar), 2);
+
+        // soft clips
+        let cigar: Cigar = "2S4M2S".parse()?;
+        assert_eq!(nm_from_md("3T0", &cigar), 1);
+
+        // insertions and deletions
+        let cigar: Cigar = "2M2I2M".parse()?;
+        assert_eq!(nm_from_md("4", &cigar), 2);
+
+        let cigar: Cigar = "2M2D2M".parse()?;
+        assert_eq!(nm_from_md("2^CC2", &cigar), 2);
+
+        let cigar: Cigar = "2S3M1I2M1D2M".parse()?;
+        assert_eq!(nm_from_md("2C2^T2", &cigar), 3);
+
+        // ambiguous bases
+        let cigar: Cigar = "4M".parse()?;
+        assert_eq!(nm_from_md("2N1", &cigar), 1);
+
+        Ok(())
+    }
+
     #[test]
     fn test_remove_with_multiple_removes() -> Result<(), field::tag::ParseError> {
         let zz = "zz".parse()?;
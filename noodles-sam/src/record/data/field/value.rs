@@ -313,6 +313,48 @@ impl Value {
     pub fn is_array(&self) -> bool {
         matches!(self, Self::Array(_))
     }
+
+    /// Returns an iterator over the values as 64-bit integers if the value is an integer array.
+    ///
+    /// This is a convenience method that converts the values of any integer array subtype to
+    /// `i64`. See [`Array::as_i64_iter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::data::field::{value::Array, Value};
+    ///
+    /// let value = Value::Array(Array::Int8(vec![-1, 2]));
+    /// let values: Option<Vec<_>> = value.as_i64_iter().map(|iter| iter.collect());
+    /// assert_eq!(values, Some(vec![-1, 2]));
+    ///
+    /// assert!(Value::Array(Array::Float(vec![0.0])).as_i64_iter().is_none());
+    /// assert!(Value::Int32(0).as_i64_iter().is_none());
+    /// ```
+    pub fn as_i64_iter(&self) -> Option<Box<dyn Iterator<Item = i64> + '_>> {
+        self.as_array().and_then(|array| array.as_i64_iter())
+    }
+
+    /// Returns an iterator over the values as double-precision floating-points if the value is an
+    /// array.
+    ///
+    /// This is a convenience method that converts the values of any array subtype to `f64`. See
+    /// [`Array::as_f64_iter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::data::field::{value::Array, Value};
+    ///
+    /// let value = Value::Array(Array::UInt8(vec![1, 2]));
+    /// let values: Option<Vec<_>> = value.as_f64_iter().map(|iter| iter.collect());
+    /// assert_eq!(values, Some(vec![1.0, 2.0]));
+    ///
+    /// assert!(Value::Float(0.0).as_f64_iter().is_none());
+    /// ```
+    pub fn as_f64_iter(&self) -> Option<Box<dyn Iterator<Item = f64> + '_>> {
+        self.as_array().map(|array| array.as_f64_iter())
+    }
 }
 
 impl From<i8> for Value {
@@ -45,6 +45,61 @@ impl Array {
             Self::Float(_) => Subtype::Float,
         }
     }
+
+    /// Returns an iterator over the values as 64-bit integers if the array is an integer array.
+    ///
+    /// This converts the values of any integer array subtype to `i64`, which captures the entire
+    /// range of all integer array values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::data::field::value::Array;
+    ///
+    /// let array = Array::UInt16(vec![8, 13]);
+    /// let values: Option<Vec<_>> = array.as_i64_iter().map(|iter| iter.collect());
+    /// assert_eq!(values, Some(vec![8, 13]));
+    ///
+    /// assert!(Array::Float(vec![0.0]).as_i64_iter().is_none());
+    /// ```
+    pub fn as_i64_iter(&self) -> Option<Box<dyn Iterator<Item = i64> + '_>> {
+        match self {
+            Self::Int8(values) => Some(Box::new(values.iter().copied().map(i64::from))),
+            Self::UInt8(values) => Some(Box::new(values.iter().copied().map(i64::from))),
+            Self::Int16(values) => Some(Box::new(values.iter().copied().map(i64::from))),
+            Self::UInt16(values) => Some(Box::new(values.iter().copied().map(i64::from))),
+            Self::Int32(values) => Some(Box::new(values.iter().copied().map(i64::from))),
+            Self::UInt32(values) => Some(Box::new(values.iter().copied().map(i64::from))),
+            Self::Float(_) => None,
+        }
+    }
+
+    /// Returns an iterator over the values as double-precision floating-points.
+    ///
+    /// Values of all array subtypes, including integers, are losslessly converted to `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::data::field::value::Array;
+    ///
+    /// let values: Vec<_> = Array::Float(vec![0.5, 1.0]).as_f64_iter().collect();
+    /// assert_eq!(values, [0.5, 1.0]);
+    ///
+    /// let values: Vec<_> = Array::Int8(vec![-1, 2]).as_f64_iter().collect();
+    /// assert_eq!(values, [-1.0, 2.0]);
+    /// ```
+    pub fn as_f64_iter(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+        match self {
+            Self::Int8(values) => Box::new(values.iter().copied().map(f64::from)),
+            Self::UInt8(values) => Box::new(values.iter().copied().map(f64::from)),
+            Self::Int16(values) => Box::new(values.iter().copied().map(f64::from)),
+            Self::UInt16(values) => Box::new(values.iter().copied().map(f64::from)),
+            Self::Int32(values) => Box::new(values.iter().copied().map(f64::from)),
+            Self::UInt32(values) => Box::new(values.iter().copied().map(f64::from)),
+            Self::Float(values) => Box::new(values.iter().copied().map(f64::from)),
+        }
+    }
 }
 
 impl fmt::Display for Array {
@@ -132,6 +187,43 @@ mod tests {
         assert_eq!(Array::Float(vec![0.0]).subtype(), Subtype::Float);
     }
 
+    #[test]
+    fn test_as_i64_iter() {
+        fn collect(array: &Array) -> Option<Vec<i64>> {
+            array.as_i64_iter().map(|iter| iter.collect())
+        }
+
+        assert_eq!(collect(&Array::Int8(vec![i8::MIN, 0])), Some(vec![-128, 0]));
+        assert_eq!(collect(&Array::UInt8(vec![u8::MAX])), Some(vec![255]));
+        assert_eq!(collect(&Array::Int16(vec![i16::MIN])), Some(vec![-32768]));
+        assert_eq!(collect(&Array::UInt16(vec![u16::MAX])), Some(vec![65535]));
+        assert_eq!(
+            collect(&Array::Int32(vec![i32::MIN])),
+            Some(vec![-2147483648])
+        );
+        assert_eq!(
+            collect(&Array::UInt32(vec![u32::MAX])),
+            Some(vec![4294967295])
+        );
+        assert_eq!(collect(&Array::Int32(Vec::new())), Some(Vec::new()));
+        assert_eq!(collect(&Array::Float(vec![0.0])), None);
+    }
+
+    #[test]
+    fn test_as_f64_iter() {
+        fn collect(array: &Array) -> Vec<f64> {
+            array.as_f64_iter().collect()
+        }
+
+        assert_eq!(collect(&Array::Int8(vec![-1])), [-1.0]);
+        assert_eq!(collect(&Array::UInt8(vec![2])), [2.0]);
+        assert_eq!(collect(&Array::Int16(vec![-3])), [-3.0]);
+        assert_eq!(collect(&Array::UInt16(vec![5])), [5.0]);
+        assert_eq!(collect(&Array::Int32(vec![-8])), [-8.0]);
+        assert_eq!(collect(&Array::UInt32(vec![u32::MAX])), [4294967295.0]);
+        assert_eq!(collect(&Array::Float(vec![0.5])), [0.5]);
+    }
+
     #[test]
     fn test_fmt() {
         assert_eq!(Array::Int8(Vec::new()).to_string(), "c");
@@ -2,10 +2,12 @@
 
 pub mod group;
 mod parser;
+mod resolver;
 
-pub use self::group::Group;
+pub use self::{group::Group, resolver::ResolveError};
 
-use crate::record::Sequence;
+use self::group::{Modification, UnmodifiedBase};
+use crate::record::{sequence::Base, Sequence};
 
 /// Base modifications.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -51,6 +53,127 @@ impl BaseModifications {
     ) -> Result<Self, parser::ParseError> {
         parser::parse(s, is_reverse_complemented, sequence)
     }
+
+    /// Pairs each modified base with its probability.
+    ///
+    /// `probabilities` are the raw values of the base modification probabilities (`ML`) data
+    /// field. Each resolved modification is a tuple of the 0-based position on the sequence, the
+    /// modification, and the probability. They are in the order of the groups and, within a
+    /// group, in the order of the positions, as in the `ML` data field.
+    ///
+    /// Bases of a group's unmodified base type that are not listed are included with a
+    /// probability of 0 if the group has an implicit status (`.` or none). They are omitted if
+    /// the status is explicit (`?`), as there is no information about them.
+    ///
+    /// The sequence and whether it is reverse complemented must be the same as those given when
+    /// parsing the base modifications.
+    ///
+    /// This returns an error if the number of probabilities does not match the total number of
+    /// modifications.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::data::field::value::{
+    ///     base_modifications::group::modification, BaseModifications,
+    /// };
+    ///
+    /// let is_reverse_complemented = false;
+    /// let sequence = "CACCCGATGACCGGCT".parse()?;
+    /// let base_modifications = BaseModifications::parse(
+    ///     "C+m?,1,3,0;",
+    ///     is_reverse_complemented,
+    ///     &sequence,
+    /// )?;
+    ///
+    /// let modifications = base_modifications.resolve(
+    ///     is_reverse_complemented,
+    ///     &sequence,
+    ///     &[8, 13, 21],
+    /// )?;
+    ///
+    /// assert_eq!(modifications, [
+    ///     (2, modification::FIVE_METHYLCYTOSINE, 8),
+    ///     (11, modification::FIVE_METHYLCYTOSINE, 13),
+    ///     (14, modification::FIVE_METHYLCYTOSINE, 21),
+    /// ]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn resolve(
+        &self,
+        is_reverse_complemented: bool,
+        sequence: &Sequence,
+        probabilities: &[u8],
+    ) -> Result<Vec<(usize, Modification, u8)>, ResolveError> {
+        resolver::resolve(self, is_reverse_complemented, sequence, probabilities)
+    }
+
+    /// Returns the probability of a modification at a position on the sequence.
+    ///
+    /// `probabilities` are the raw values of the base modification probabilities (`ML`) data
+    /// field. A raw value `N` is the probability range [N/256, (N + 1)/256), and this returns its
+    /// lower bound, i.e., N/256.
+    ///
+    /// If the position is not listed for the modification, the probability is 0 if the base is of
+    /// the unmodified base type of a group with an implicit status (`.` or none). Otherwise, the
+    /// position is not annotated, and this returns `None`.
+    ///
+    /// The sequence and whether it is reverse complemented must be the same as those given when
+    /// parsing the base modifications.
+    ///
+    /// This returns an error if the number of probabilities does not match the total number of
+    /// modifications.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::data::field::value::{
+    ///     base_modifications::group::{modification, Modification},
+    ///     BaseModifications,
+    /// };
+    ///
+    /// let is_reverse_complemented = false;
+    /// let sequence = "CACCCGATGACCGGCT".parse()?;
+    /// let base_modifications = BaseModifications::parse(
+    ///     "C+m.,1,3;A+28871?,2;",
+    ///     is_reverse_complemented,
+    ///     &sequence,
+    /// )?;
+    /// let probabilities = [64, 128, 192];
+    ///
+    /// let p = |position, modification| {
+    ///     base_modifications.probability_at(
+    ///         is_reverse_complemented,
+    ///         &sequence,
+    ///         &probabilities,
+    ///         position,
+    ///         modification,
+    ///     )
+    /// };
+    ///
+    /// assert_eq!(p(2, modification::FIVE_METHYLCYTOSINE)?, Some(0.25));
+    /// assert_eq!(p(3, modification::FIVE_METHYLCYTOSINE)?, Some(0.0));
+    /// assert_eq!(p(9, Modification::ChebiId(28871))?, Some(0.75));
+    /// assert_eq!(p(1, Modification::ChebiId(28871))?, None);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn probability_at(
+        &self,
+        is_reverse_complemented: bool,
+        sequence: &Sequence,
+        probabilities: &[u8],
+        position: usize,
+        modification: Modification,
+    ) -> Result<Option<f32>, ResolveError> {
+        resolver::probability_at(
+            self,
+            is_reverse_complemented,
+            sequence,
+            probabilities,
+            position,
+            modification,
+        )
+    }
 }
 
 impl AsRef<[Group]> for BaseModifications {
@@ -76,3 +199,30 @@ impl From<BaseModifications> for Vec<Group> {
         base_modifications.0
     }
 }
+
+// Returns the base on the sequence that a group's positions refer to.
+fn candidate_base(unmodified_base: UnmodifiedBase, is_reverse_complemented: bool) -> Base {
+    if is_reverse_complemented {
+        Base::from(unmodified_base.complement())
+    } else {
+        Base::from(unmodified_base)
+    }
+}
+
+// Returns the positions of the sequence that a group's skip counts refer to, in the order they are
+// counted. If the record is reverse complemented, these are the complemented bases, counted from
+// the end of the sequence.
+fn candidate_positions(
+    unmodified_base: UnmodifiedBase,
+    is_reverse_complemented: bool,
+    sequence: &Sequence,
+) -> Box<dyn Iterator<Item = usize> + '_> {
+    let bases = sequence.as_ref().iter().enumerate();
+    let base = candidate_base(unmodified_base, is_reverse_complemented);
+
+    if is_reverse_complemented {
+        Box::new(bases.rev().filter(move |(_, &b)| b == base).map(|(i, _)| i))
+    } else {
+        Box::new(bases.filter(move |(_, &b)| b == base).map(|(i, _)| i))
+    }
+}
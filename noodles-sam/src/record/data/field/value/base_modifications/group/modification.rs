@@ -41,6 +41,15 @@ pub enum Modification {
     ChebiId(u32),
 }
 
+impl fmt::Display for Modification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Code(b) => write!(f, "{}", char::from(*b)),
+            Self::ChebiId(id) => write!(f, "{id}"),
+        }
+    }
+}
+
 /// An error returned when a base modifications group modification fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
@@ -78,6 +87,13 @@ fn is_ambiguity_code(b: u8) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fmt() {
+        assert_eq!(FIVE_METHYLCYTOSINE.to_string(), "m");
+        assert_eq!(Modification::Code(b'N').to_string(), "N");
+        assert_eq!(Modification::ChebiId(76792).to_string(), "76792");
+    }
+
     #[test]
     fn test_try_from_u8_for_modification() {
         fn t(b: u8, expected: Modification) {
@@ -133,34 +133,10 @@ fn decode_positions(
     sequence: &Sequence,
     unmodified_base: UnmodifiedBase,
 ) -> Result<Vec<usize>, ParseError> {
-    use crate::record::sequence::Base;
+    use crate::record::data::field::value::base_modifications::candidate_positions;
 
     let mut positions = Vec::with_capacity(skip_counts.len());
-
-    let mut iter: Box<dyn Iterator<Item = usize>> = if is_reverse_complemented {
-        let unmodified_base = Base::from(unmodified_base.complement());
-
-        Box::new(
-            sequence
-                .as_ref()
-                .iter()
-                .enumerate()
-                .rev()
-                .filter(move |(_, &base)| base == unmodified_base)
-                .map(|(i, _)| i),
-        )
-    } else {
-        let unmodified_base = Base::from(unmodified_base);
-
-        Box::new(
-            sequence
-                .as_ref()
-                .iter()
-                .enumerate()
-                .filter(move |(_, &base)| base == unmodified_base)
-                .map(|(i, _)| i),
-        )
-    };
+    let mut iter = candidate_positions(unmodified_base, is_reverse_complemented, sequence);
 
     for &count in skip_counts {
         let i = iter.nth(count).unwrap();
@@ -205,6 +181,17 @@ mod tests {
         );
         assert_eq!(actual, Ok(expected));
 
+        let mut src = &b"C+76792?,1;"[..];
+        let actual = parse_group(&mut src, is_reverse_complemented, &sequence);
+        let expected = Group::new(
+            UnmodifiedBase::C,
+            Strand::Forward,
+            vec![modification::Modification::ChebiId(76792)],
+            Some(Status::Explicit),
+            vec![2],
+        );
+        assert_eq!(actual, Ok(expected));
+
         let mut src = &b"C+m,1,0,0;"[..];
         let actual = parse_group(&mut src, true, &sequence);
         let expected = Group::new(
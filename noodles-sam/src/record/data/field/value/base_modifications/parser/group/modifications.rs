@@ -7,6 +7,10 @@ use crate::record::data::field::value::base_modifications::group::Modification;
 pub enum ParseError {
     /// The input is invalid.
     Invalid,
+    /// Modification codes and a ChEBI ID are mixed.
+    ///
+    /// A group has either one or more modification codes or a single ChEBI ID.
+    Mixed,
 }
 
 impl error::Error for ParseError {}
@@ -15,17 +19,23 @@ impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Invalid => write!(f, "invalid input"),
+            Self::Mixed => write!(f, "mixed modification codes and ChEBI ID"),
         }
     }
 }
 
 pub fn parse_modifications(src: &mut &[u8]) -> Result<Vec<Modification>, ParseError> {
-    if let Some(modifications) = parse_short_codes(src)? {
-        Ok(modifications)
+    let modifications = if let Some(modifications) = parse_short_codes(src)? {
+        modifications
     } else if let Some(modification) = parse_chebi_id(src)? {
-        Ok(vec![modification])
+        vec![modification]
     } else {
-        Err(ParseError::Invalid)
+        return Err(ParseError::Invalid);
+    };
+
+    match src.first() {
+        Some(b) if b.is_ascii_lowercase() || b.is_ascii_digit() => Err(ParseError::Mixed),
+        _ => Ok(modifications),
     }
 }
 
@@ -68,7 +78,11 @@ where
             *src = rest;
             token
         }
-        None => src,
+        None => {
+            let token = *src;
+            *src = &[];
+            token
+        }
     }
 }
 
@@ -101,7 +115,20 @@ mod tests {
             Ok(vec![Modification::ChebiId(27551)])
         );
 
+        let mut src = &b"76792.,"[..];
+        assert_eq!(
+            parse_modifications(&mut src),
+            Ok(vec![Modification::ChebiId(76792)])
+        );
+        assert_eq!(src, b".,");
+
         let mut src = &b""[..];
         assert_eq!(parse_modifications(&mut src), Err(ParseError::Invalid));
+
+        let mut src = &b"m27551"[..];
+        assert_eq!(parse_modifications(&mut src), Err(ParseError::Mixed));
+
+        let mut src = &b"27551m"[..];
+        assert_eq!(parse_modifications(&mut src), Err(ParseError::Mixed));
     }
 }
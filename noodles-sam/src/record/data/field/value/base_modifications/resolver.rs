@@ -0,0 +1,368 @@
+use std::{error, fmt};
+
+use super::{
+    candidate_base, candidate_positions,
+    group::{Modification, Status},
+    BaseModifications, Group,
+};
+use crate::record::Sequence;
+
+/// An error returned when base modifications fail to resolve.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ResolveError {
+    /// The number of probabilities does not match the number of modifications.
+    LengthMismatch {
+        /// The actual length.
+        actual: usize,
+        /// The expected length.
+        expected: usize,
+    },
+    /// A position is not a base of the group's unmodified base type.
+    InvalidPosition(usize),
+}
+
+impl error::Error for ResolveError {}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthMismatch { actual, expected } => {
+                write!(f, "length mismatch: expected {expected}, got {actual}")
+            }
+            Self::InvalidPosition(i) => write!(f, "invalid position: {i}"),
+        }
+    }
+}
+
+pub(super) fn resolve(
+    base_modifications: &BaseModifications,
+    is_reverse_complemented: bool,
+    sequence: &Sequence,
+    probabilities: &[u8],
+) -> Result<Vec<(usize, Modification, u8)>, ResolveError> {
+    let groups = base_modifications.as_ref();
+    let expected = validate_probabilities_len(groups, probabilities)?;
+
+    let mut probabilities = probabilities.iter().copied();
+    let mut resolved_modifications = Vec::with_capacity(expected);
+
+    for group in groups {
+        resolve_group(
+            group,
+            is_reverse_complemented,
+            sequence,
+            &mut probabilities,
+            &mut resolved_modifications,
+        )?;
+    }
+
+    Ok(resolved_modifications)
+}
+
+pub(super) fn probability_at(
+    base_modifications: &BaseModifications,
+    is_reverse_complemented: bool,
+    sequence: &Sequence,
+    probabilities: &[u8],
+    position: usize,
+    modification: Modification,
+) -> Result<Option<f32>, ResolveError> {
+    let groups = base_modifications.as_ref();
+    validate_probabilities_len(groups, probabilities)?;
+
+    let mut offset = 0;
+    let mut implicit_probability = None;
+
+    for group in groups {
+        let modifications = group.modifications();
+
+        if let Some(j) = modifications.iter().position(|&m| m == modification) {
+            if let Some(k) = group.positions().iter().position(|&i| i == position) {
+                let probability = probabilities[offset + k * modifications.len() + j];
+                return Ok(Some(f32::from(probability) / 256.0));
+            }
+
+            // § 1.7 "Base modifications" (2023-05-24): "If [the status] is omitted, the default
+            // is '.'."
+            let status = group.status().unwrap_or_default();
+
+            if status == Status::Implicit
+                && sequence.as_ref().get(position)
+                    == Some(&candidate_base(
+                        group.unmodified_base(),
+                        is_reverse_complemented,
+                    ))
+            {
+                implicit_probability = Some(0.0);
+            }
+        }
+
+        offset += group.positions().len() * modifications.len();
+    }
+
+    Ok(implicit_probability)
+}
+
+fn validate_probabilities_len(
+    groups: &[Group],
+    probabilities: &[u8],
+) -> Result<usize, ResolveError> {
+    let expected = groups
+        .iter()
+        .map(|group| group.positions().len() * group.modifications().len())
+        .sum();
+
+    if probabilities.len() == expected {
+        Ok(expected)
+    } else {
+        Err(ResolveError::LengthMismatch {
+            actual: probabilities.len(),
+            expected,
+        })
+    }
+}
+
+fn resolve_group<I>(
+    group: &Group,
+    is_reverse_complemented: bool,
+    sequence: &Sequence,
+    probabilities: &mut I,
+    dst: &mut Vec<(usize, Modification, u8)>,
+) -> Result<(), ResolveError>
+where
+    I: Iterator<Item = u8>,
+{
+    // § 1.7 "Base modifications" (2023-05-24): "If [the status] is omitted, the default is '.'."
+    let status = group.status().unwrap_or_default();
+
+    let mut positions = group.positions().iter().copied().peekable();
+
+    for i in candidate_positions(group.unmodified_base(), is_reverse_complemented, sequence) {
+        if positions.next_if_eq(&i).is_some() {
+            for &modification in group.modifications() {
+                // The number of probabilities was checked before resolving any group.
+                let probability = probabilities.next().expect("missing probability");
+                dst.push((i, modification, probability));
+            }
+        } else if status == Status::Implicit {
+            // Skipped bases are unmodified, i.e., have a modification probability of 0.
+            for &modification in group.modifications() {
+                dst.push((i, modification, 0));
+            }
+        }
+    }
+
+    match positions.next() {
+        Some(i) => Err(ResolveError::InvalidPosition(i)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::data::field::value::base_modifications::group::{
+        modification, Strand, UnmodifiedBase,
+    };
+
+    #[test]
+    fn test_resolve() -> Result<(), Box<dyn std::error::Error>> {
+        let sequence = "CACCCGATGACCGGCT".parse()?;
+
+        let base_modifications = BaseModifications::from(vec![Group::new(
+            UnmodifiedBase::C,
+            Strand::Forward,
+            vec![
+                modification::FIVE_METHYLCYTOSINE,
+                modification::FIVE_HYDROXYMETHYLCYTOSINE,
+            ],
+            Some(Status::Explicit),
+            vec![2, 11],
+        )]);
+
+        let actual = resolve(&base_modifications, false, &sequence, &[8, 13, 21, 34])?;
+        let expected = [
+            (2, modification::FIVE_METHYLCYTOSINE, 8),
+            (2, modification::FIVE_HYDROXYMETHYLCYTOSINE, 13),
+            (11, modification::FIVE_METHYLCYTOSINE, 21),
+            (11, modification::FIVE_HYDROXYMETHYLCYTOSINE, 34),
+        ];
+        assert_eq!(actual, expected);
+
+        assert_eq!(
+            resolve(&base_modifications, false, &sequence, &[8, 13, 21]),
+            Err(ResolveError::LengthMismatch {
+                actual: 3,
+                expected: 4
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_with_implicit_status() -> Result<(), Box<dyn std::error::Error>> {
+        let sequence = "GCACG".parse()?;
+
+        let base_modifications = BaseModifications::from(vec![Group::new(
+            UnmodifiedBase::C,
+            Strand::Forward,
+            vec![modification::FIVE_METHYLCYTOSINE],
+            None,
+            vec![3],
+        )]);
+
+        let actual = resolve(&base_modifications, false, &sequence, &[200])?;
+        let expected = [
+            (1, modification::FIVE_METHYLCYTOSINE, 0),
+            (3, modification::FIVE_METHYLCYTOSINE, 200),
+        ];
+        assert_eq!(actual, expected);
+
+        // The sequence is reverse complemented, so G bases are candidates, last to first.
+        let base_modifications = BaseModifications::from(vec![Group::new(
+            UnmodifiedBase::C,
+            Strand::Forward,
+            vec![modification::FIVE_METHYLCYTOSINE],
+            Some(Status::Implicit),
+            vec![4],
+        )]);
+
+        let actual = resolve(&base_modifications, true, &sequence, &[200])?;
+        let expected = [
+            (4, modification::FIVE_METHYLCYTOSINE, 200),
+            (0, modification::FIVE_METHYLCYTOSINE, 0),
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_probability_at() -> Result<(), Box<dyn std::error::Error>> {
+        let sequence = "CACCCGATGACCGGCT".parse()?;
+
+        let base_modifications = BaseModifications::from(vec![
+            Group::new(
+                UnmodifiedBase::C,
+                Strand::Forward,
+                vec![
+                    modification::FIVE_METHYLCYTOSINE,
+                    modification::FIVE_HYDROXYMETHYLCYTOSINE,
+                ],
+                Some(Status::Explicit),
+                vec![2, 11],
+            ),
+            Group::new(
+                UnmodifiedBase::A,
+                Strand::Forward,
+                vec![Modification::ChebiId(28871)],
+                None,
+                vec![9],
+            ),
+        ]);
+
+        let probabilities = [64, 128, 192, 255, 32];
+
+        let t = |position, modification| {
+            probability_at(
+                &base_modifications,
+                false,
+                &sequence,
+                &probabilities,
+                position,
+                modification,
+            )
+        };
+
+        assert_eq!(t(2, modification::FIVE_METHYLCYTOSINE), Ok(Some(0.25)));
+        assert_eq!(
+            t(2, modification::FIVE_HYDROXYMETHYLCYTOSINE),
+            Ok(Some(0.5))
+        );
+        assert_eq!(t(11, modification::FIVE_METHYLCYTOSINE), Ok(Some(0.75)));
+        assert_eq!(
+            t(11, modification::FIVE_HYDROXYMETHYLCYTOSINE),
+            Ok(Some(255.0 / 256.0))
+        );
+        assert_eq!(t(9, Modification::ChebiId(28871)), Ok(Some(0.125)));
+
+        // explicit status: a skipped C has no information
+        assert_eq!(t(0, modification::FIVE_METHYLCYTOSINE), Ok(None));
+
+        // implicit status: a skipped A is unmodified
+        assert_eq!(t(1, Modification::ChebiId(28871)), Ok(Some(0.0)));
+        // ...but a base of another type is not annotated
+        assert_eq!(t(0, Modification::ChebiId(28871)), Ok(None));
+
+        assert_eq!(t(2, modification::SIX_METHYLADENINE), Ok(None));
+
+        assert_eq!(
+            probability_at(
+                &base_modifications,
+                false,
+                &sequence,
+                &probabilities[..4],
+                2,
+                modification::FIVE_METHYLCYTOSINE,
+            ),
+            Err(ResolveError::LengthMismatch {
+                actual: 4,
+                expected: 5
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_probability_at_with_reverse_complemented_sequence(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let sequence = "GCACG".parse()?;
+
+        let base_modifications = BaseModifications::from(vec![Group::new(
+            UnmodifiedBase::C,
+            Strand::Forward,
+            vec![modification::FIVE_METHYLCYTOSINE],
+            None,
+            vec![4],
+        )]);
+
+        let t = |position| {
+            probability_at(
+                &base_modifications,
+                true,
+                &sequence,
+                &[128],
+                position,
+                modification::FIVE_METHYLCYTOSINE,
+            )
+        };
+
+        assert_eq!(t(4), Ok(Some(0.5)));
+        assert_eq!(t(0), Ok(Some(0.0)));
+        assert_eq!(t(1), Ok(None));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_with_invalid_position() -> Result<(), Box<dyn std::error::Error>> {
+        let sequence = "GCACG".parse()?;
+
+        let base_modifications = BaseModifications::from(vec![Group::new(
+            UnmodifiedBase::C,
+            Strand::Forward,
+            vec![modification::FIVE_METHYLCYTOSINE],
+            Some(Status::Explicit),
+            vec![2],
+        )]);
+
+        assert_eq!(
+            resolve(&base_modifications, false, &sequence, &[200]),
+            Err(ResolveError::InvalidPosition(2))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,364 @@
+//! SAM record filter.
+
+use std::{collections::BTreeSet, error, fmt};
+
+use super::{Flags, MappingQuality};
+use crate::{alignment::Record, Header};
+
+/// A SAM record filter.
+///
+/// A filter is a reusable predicate over records that checks flags, the mapping quality, and the
+/// reference sequence. Filters can be combined using [`Self::and`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{alignment::Record, record::{Filter, Flags}};
+///
+/// let filter = Filter::default()
+///     .forbid(Flags::SECONDARY | Flags::SUPPLEMENTARY)
+///     .min_mapping_quality(20);
+///
+/// let record = Record::builder().set_flags(Flags::SECONDARY).build();
+/// assert!(!filter.matches(&record));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Filter {
+    required_flags: Flags,
+    forbidden_flags: Flags,
+    min_mapping_quality: Option<u8>,
+    reference_sequence_ids: Option<BTreeSet<usize>>,
+}
+
+impl Filter {
+    /// Requires records to have all of the given flags set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{Filter, Flags};
+    /// let filter = Filter::default().require(Flags::SEGMENTED);
+    /// assert!(filter.matches_fields(Flags::SEGMENTED | Flags::PROPERLY_ALIGNED, None, None));
+    /// assert!(!filter.matches_fields(Flags::empty(), None, None));
+    /// ```
+    pub fn require(mut self, flags: Flags) -> Self {
+        self.required_flags.insert(flags);
+        self
+    }
+
+    /// Requires records to have none of the given flags set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{Filter, Flags};
+    /// let filter = Filter::default().forbid(Flags::DUPLICATE);
+    /// assert!(filter.matches_fields(Flags::empty(), None, None));
+    /// assert!(!filter.matches_fields(Flags::DUPLICATE, None, None));
+    /// ```
+    pub fn forbid(mut self, flags: Flags) -> Self {
+        self.forbidden_flags.insert(flags);
+        self
+    }
+
+    /// Requires records to have a mapping quality of at least the given value.
+    ///
+    /// As with `samtools view -q`, a missing mapping quality is treated as 255.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{Filter, Flags, MappingQuality};
+    /// let filter = Filter::default().min_mapping_quality(20);
+    /// assert!(filter.matches_fields(Flags::empty(), MappingQuality::new(20), None));
+    /// assert!(!filter.matches_fields(Flags::empty(), MappingQuality::new(19), None));
+    /// assert!(filter.matches_fields(Flags::empty(), None, None));
+    /// ```
+    pub fn min_mapping_quality(mut self, min_mapping_quality: u8) -> Self {
+        self.min_mapping_quality = Some(min_mapping_quality);
+        self
+    }
+
+    /// Requires records to be placed on one of the given reference sequences.
+    ///
+    /// The reference sequence names are resolved to reference sequence IDs using the given
+    /// header. If this is called more than once, records must be placed on a reference sequence
+    /// in each list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::{map::ReferenceSequence, Map},
+    ///     record::{Filter, Flags},
+    /// };
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence(
+    ///         "sq0".parse()?,
+    ///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+    ///     )
+    ///     .add_reference_sequence(
+    ///         "sq1".parse()?,
+    ///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+    ///     )
+    ///     .build();
+    ///
+    /// let filter = Filter::default().allow_reference_sequences(&header, ["sq1"])?;
+    /// assert!(filter.matches_fields(Flags::empty(), None, Some(1)));
+    /// assert!(!filter.matches_fields(Flags::empty(), None, Some(0)));
+    /// assert!(!filter.matches_fields(Flags::empty(), None, None));
+    ///
+    /// assert!(Filter::default().allow_reference_sequences(&header, ["sq2"]).is_err());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn allow_reference_sequences<I, N>(
+        mut self,
+        header: &Header,
+        names: I,
+    ) -> Result<Self, MissingReferenceSequenceError>
+    where
+        I: IntoIterator<Item = N>,
+        N: AsRef<str>,
+    {
+        let reference_sequences = header.reference_sequences();
+
+        let ids = names
+            .into_iter()
+            .map(|name| {
+                let name = name.as_ref();
+
+                reference_sequences
+                    .get_index_of(name)
+                    .ok_or_else(|| MissingReferenceSequenceError(name.into()))
+            })
+            .collect::<Result<BTreeSet<_>, _>>()?;
+
+        self.reference_sequence_ids = Some(match self.reference_sequence_ids.take() {
+            Some(prev_ids) => prev_ids.intersection(&ids).copied().collect(),
+            None => ids,
+        });
+
+        Ok(self)
+    }
+
+    /// Combines this filter with another.
+    ///
+    /// Records must match both filters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{Filter, Flags, MappingQuality};
+    ///
+    /// let primary = Filter::default().forbid(Flags::SECONDARY | Flags::SUPPLEMENTARY);
+    /// let high_quality = Filter::default().forbid(Flags::QC_FAIL).min_mapping_quality(20);
+    /// let filter = primary.and(high_quality);
+    ///
+    /// let mapping_quality = MappingQuality::new(30);
+    /// assert!(filter.matches_fields(Flags::empty(), mapping_quality, None));
+    /// assert!(!filter.matches_fields(Flags::SECONDARY, mapping_quality, None));
+    /// assert!(!filter.matches_fields(Flags::QC_FAIL, mapping_quality, None));
+    /// assert!(!filter.matches_fields(Flags::empty(), MappingQuality::new(10), None));
+    /// ```
+    pub fn and(mut self, other: Self) -> Self {
+        self.required_flags.insert(other.required_flags);
+        self.forbidden_flags.insert(other.forbidden_flags);
+
+        self.min_mapping_quality = match (self.min_mapping_quality, other.min_mapping_quality) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+
+        self.reference_sequence_ids =
+            match (self.reference_sequence_ids, other.reference_sequence_ids) {
+                (Some(a), Some(b)) => Some(a.intersection(&b).copied().collect()),
+                (a, b) => a.or(b),
+            };
+
+        self
+    }
+
+    /// Returns whether the given fields pass the filter.
+    ///
+    /// This is used to apply the filter to other record types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{Filter, Flags, MappingQuality};
+    ///
+    /// let filter = Filter::default().forbid(Flags::UNMAPPED).min_mapping_quality(20);
+    ///
+    /// assert!(filter.matches_fields(Flags::empty(), MappingQuality::new(30), Some(0)));
+    /// assert!(!filter.matches_fields(Flags::empty(), MappingQuality::new(10), Some(0)));
+    /// assert!(!filter.matches_fields(Flags::UNMAPPED, MappingQuality::new(30), Some(0)));
+    /// ```
+    pub fn matches_fields(
+        &self,
+        flags: Flags,
+        mapping_quality: Option<MappingQuality>,
+        reference_sequence_id: Option<usize>,
+    ) -> bool {
+        use super::mapping_quality::MISSING;
+
+        if !flags.contains(self.required_flags) || flags.intersects(self.forbidden_flags) {
+            return false;
+        }
+
+        if let Some(min_mapping_quality) = self.min_mapping_quality {
+            let mapping_quality = mapping_quality.map(u8::from).unwrap_or(MISSING);
+
+            if mapping_quality < min_mapping_quality {
+                return false;
+            }
+        }
+
+        if let Some(ids) = &self.reference_sequence_ids {
+            match reference_sequence_id {
+                Some(id) if ids.contains(&id) => {}
+                _ => return false,
+            }
+        }
+
+        true
+    }
+
+    /// Returns whether the given record passes the filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{alignment::Record, record::{Filter, Flags}};
+    ///
+    /// let filter = Filter::default().forbid(Flags::DUPLICATE);
+    ///
+    /// assert!(filter.matches(&Record::default()));
+    ///
+    /// let record = Record::builder().set_flags(Flags::DUPLICATE).build();
+    /// assert!(!filter.matches(&record));
+    /// ```
+    pub fn matches(&self, record: &Record) -> bool {
+        self.matches_fields(
+            record.flags(),
+            record.mapping_quality(),
+            record.reference_sequence_id(),
+        )
+    }
+
+    /// Converts the filter into a predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{alignment::Record, record::{Filter, Flags}};
+    ///
+    /// let records = [
+    ///     Record::default(),
+    ///     Record::builder().set_flags(Flags::SECONDARY).build(),
+    /// ];
+    ///
+    /// let predicate = Filter::default().forbid(Flags::SECONDARY).into_predicate();
+    /// assert_eq!(records.iter().filter(|record| predicate(record)).count(), 1);
+    /// ```
+    pub fn into_predicate(self) -> impl Fn(&Record) -> bool {
+        move |record| self.matches(record)
+    }
+}
+
+/// An error returned when a reference sequence name is not in the header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MissingReferenceSequenceError(String);
+
+impl error::Error for MissingReferenceSequenceError {}
+
+impl fmt::Display for MissingReferenceSequenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing reference sequence: {}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::*;
+
+    #[test]
+    fn test_matches() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::record::value::{map::ReferenceSequence, Map};
+
+        let header = Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        let filter = Filter::default()
+            .require(Flags::SEGMENTED)
+            .forbid(Flags::SECONDARY | Flags::SUPPLEMENTARY | Flags::DUPLICATE)
+            .min_mapping_quality(20)
+            .allow_reference_sequences(&header, ["sq1"])?;
+
+        let record = Record::builder()
+            .set_flags(Flags::SEGMENTED)
+            .set_reference_sequence_id(1)
+            .set_mapping_quality(MappingQuality::try_from(30)?)
+            .build();
+        assert!(filter.matches(&record));
+
+        let mut r = record.clone();
+        *r.flags_mut() = Flags::empty();
+        assert!(!filter.matches(&r));
+
+        let mut r = record.clone();
+        r.flags_mut().insert(Flags::DUPLICATE);
+        assert!(!filter.matches(&r));
+
+        let mut r = record.clone();
+        *r.mapping_quality_mut() = MappingQuality::new(10);
+        assert!(!filter.matches(&r));
+
+        let mut r = record.clone();
+        *r.mapping_quality_mut() = None;
+        assert!(filter.matches(&r));
+
+        let mut r = record.clone();
+        *r.reference_sequence_id_mut() = Some(0);
+        assert!(!filter.matches(&r));
+
+        let mut r = record;
+        *r.reference_sequence_id_mut() = None;
+        assert!(!filter.matches(&r));
+
+        assert!(Filter::default().matches(&Record::default()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_and() {
+        let a = Filter::default()
+            .require(Flags::SEGMENTED)
+            .min_mapping_quality(30);
+        let b = Filter::default()
+            .forbid(Flags::SECONDARY)
+            .min_mapping_quality(20);
+
+        let actual = a.and(b);
+        let expected = Filter::default()
+            .require(Flags::SEGMENTED)
+            .forbid(Flags::SECONDARY)
+            .min_mapping_quality(30);
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,3 +1,9 @@
+//! SAM record flags.
+
+use std::{error, fmt};
+
+use crate::alignment::Record;
+
 bitflags::bitflags! {
     /// SAM record flags.
     #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -188,6 +194,127 @@ impl Flags {
     pub fn is_supplementary(self) -> bool {
         self.contains(Self::SUPPLEMENTARY)
     }
+
+    /// Checks the flags for consistency with each other and the given record.
+    ///
+    /// This returns the first violated constraint, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     alignment::Record,
+    ///     record::{flags::FlagError, Flags},
+    /// };
+    ///
+    /// let record = Record::default();
+    /// assert!(Flags::UNMAPPED.validate(&record).is_ok());
+    ///
+    /// let flags = Flags::UNMAPPED | Flags::FIRST_SEGMENT;
+    /// assert_eq!(flags.validate(&record), Err(FlagError::FirstSegmentWithoutSegmented));
+    /// ```
+    pub fn validate(self, record: &Record) -> Result<(), FlagError> {
+        self.validate_segments(record.mate_reference_sequence_id())?;
+
+        if self.is_secondary() && self.is_supplementary() {
+            return Err(FlagError::SecondaryAndSupplementary);
+        }
+
+        // § 1.4 "The alignment section: mandatory fields" (2023-05-24): "Bit 0x4 is the only
+        // reliable place to tell whether the read is unmapped."
+        if !self.is_unmapped() {
+            if record.reference_sequence_id().is_none() {
+                return Err(FlagError::MissingReferenceSequenceId);
+            } else if record.alignment_start().is_none() {
+                return Err(FlagError::MissingAlignmentStart);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the segment flags for consistency with the segmented flag and the mate reference
+    /// sequence ID.
+    pub(crate) fn validate_segments(
+        self,
+        mate_reference_sequence_id: Option<usize>,
+    ) -> Result<(), FlagError> {
+        // § 1.4 "The alignment section: mandatory fields" (2023-05-24): "If 0x1 is unset, no
+        // assumptions can be made about 0x2, 0x8, 0x20, 0x40 and 0x80."
+        if !self.is_segmented() {
+            if self.is_properly_aligned() {
+                return Err(FlagError::ProperlyAlignedWithoutSegmented);
+            } else if self.is_mate_unmapped() {
+                return Err(FlagError::MateUnmappedWithoutSegmented);
+            } else if self.is_mate_reverse_complemented() {
+                return Err(FlagError::MateReverseComplementedWithoutSegmented);
+            } else if self.is_first_segment() {
+                return Err(FlagError::FirstSegmentWithoutSegmented);
+            } else if self.is_last_segment() {
+                return Err(FlagError::LastSegmentWithoutSegmented);
+            }
+        } else if !self.is_mate_unmapped() && mate_reference_sequence_id.is_none() {
+            return Err(FlagError::MissingMateReferenceSequenceId);
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when SAM record flags are inconsistent.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FlagError {
+    /// The properly aligned flag (`0x02`) is set without the segmented flag (`0x01`).
+    ProperlyAlignedWithoutSegmented,
+    /// The mate unmapped flag (`0x08`) is set without the segmented flag (`0x01`).
+    MateUnmappedWithoutSegmented,
+    /// The mate reverse complemented flag (`0x20`) is set without the segmented flag (`0x01`).
+    MateReverseComplementedWithoutSegmented,
+    /// The first segment flag (`0x40`) is set without the segmented flag (`0x01`).
+    FirstSegmentWithoutSegmented,
+    /// The last segment flag (`0x80`) is set without the segmented flag (`0x01`).
+    LastSegmentWithoutSegmented,
+    /// The record is segmented and the mate is mapped, but the mate reference sequence ID is
+    /// missing.
+    MissingMateReferenceSequenceId,
+    /// Both the secondary (`0x100`) and supplementary (`0x800`) flags are set.
+    SecondaryAndSupplementary,
+    /// The record is mapped, but the reference sequence ID is missing.
+    MissingReferenceSequenceId,
+    /// The record is mapped, but the alignment start is missing.
+    MissingAlignmentStart,
+}
+
+impl error::Error for FlagError {}
+
+impl fmt::Display for FlagError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ProperlyAlignedWithoutSegmented => {
+                f.write_str("properly aligned flag set without segmented flag")
+            }
+            Self::MateUnmappedWithoutSegmented => {
+                f.write_str("mate unmapped flag set without segmented flag")
+            }
+            Self::MateReverseComplementedWithoutSegmented => {
+                f.write_str("mate reverse complemented flag set without segmented flag")
+            }
+            Self::FirstSegmentWithoutSegmented => {
+                f.write_str("first segment flag set without segmented flag")
+            }
+            Self::LastSegmentWithoutSegmented => {
+                f.write_str("last segment flag set without segmented flag")
+            }
+            Self::MissingMateReferenceSequenceId => {
+                f.write_str("missing mate reference sequence ID")
+            }
+            Self::SecondaryAndSupplementary => {
+                f.write_str("both secondary and supplementary flags set")
+            }
+            Self::MissingReferenceSequenceId => f.write_str("missing reference sequence ID"),
+            Self::MissingAlignmentStart => f.write_str("missing alignment start"),
+        }
+    }
 }
 
 impl From<u16> for Flags {
@@ -242,6 +369,73 @@ mod tests {
         assert!(Flags::SUPPLEMENTARY.is_supplementary());
     }
 
+    #[test]
+    fn test_validate() -> Result<(), noodles_core::position::TryFromIntError> {
+        use noodles_core::Position;
+
+        let unmapped = Record::default();
+
+        assert_eq!(Flags::UNMAPPED.validate(&unmapped), Ok(()));
+
+        for (flag, expected) in [
+            (
+                Flags::PROPERLY_ALIGNED,
+                FlagError::ProperlyAlignedWithoutSegmented,
+            ),
+            (
+                Flags::MATE_UNMAPPED,
+                FlagError::MateUnmappedWithoutSegmented,
+            ),
+            (
+                Flags::MATE_REVERSE_COMPLEMENTED,
+                FlagError::MateReverseComplementedWithoutSegmented,
+            ),
+            (
+                Flags::FIRST_SEGMENT,
+                FlagError::FirstSegmentWithoutSegmented,
+            ),
+            (Flags::LAST_SEGMENT, FlagError::LastSegmentWithoutSegmented),
+        ] {
+            assert_eq!((Flags::UNMAPPED | flag).validate(&unmapped), Err(expected));
+        }
+
+        let flags = Flags::UNMAPPED | Flags::SEGMENTED | Flags::FIRST_SEGMENT;
+        assert_eq!(
+            flags.validate(&unmapped),
+            Err(FlagError::MissingMateReferenceSequenceId)
+        );
+
+        let flags = Flags::UNMAPPED | Flags::SEGMENTED | Flags::MATE_UNMAPPED;
+        assert_eq!(flags.validate(&unmapped), Ok(()));
+
+        let flags = Flags::UNMAPPED | Flags::SECONDARY | Flags::SUPPLEMENTARY;
+        assert_eq!(
+            flags.validate(&unmapped),
+            Err(FlagError::SecondaryAndSupplementary)
+        );
+
+        assert_eq!(
+            Flags::empty().validate(&unmapped),
+            Err(FlagError::MissingReferenceSequenceId)
+        );
+
+        let record = Record::builder().set_reference_sequence_id(0).build();
+        assert_eq!(
+            Flags::empty().validate(&record),
+            Err(FlagError::MissingAlignmentStart)
+        );
+
+        let record = Record::builder()
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_mate_reference_sequence_id(0)
+            .build();
+        let flags = Flags::SEGMENTED | Flags::PROPERLY_ALIGNED | Flags::FIRST_SEGMENT;
+        assert_eq!(flags.validate(&record), Ok(()));
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_u16_for_flags() {
         assert_eq!(Flags::from(0x40), Flags::FIRST_SEGMENT);
@@ -128,6 +128,120 @@ impl QualityScores {
     pub fn push(&mut self, score: Score) {
         self.0.push(score);
     }
+
+    /// Returns the sum of the scores.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::QualityScores;
+    /// let quality_scores: QualityScores = "NDLS".parse()?;
+    /// assert_eq!(quality_scores.sum(), 173);
+    /// # Ok::<_, noodles_sam::record::quality_scores::ParseError>(())
+    /// ```
+    pub fn sum(&self) -> u64 {
+        self.0.iter().map(|score| u64::from(score.get())).sum()
+    }
+
+    /// Returns the arithmetic mean of the scores.
+    ///
+    /// This returns `None` if there are no scores.
+    ///
+    /// # Examples
+    ///
+    /// Filter reads with a mean quality less than 20:
+    ///
+    /// ```
+    /// use noodles_sam::{alignment::Record, record::QualityScores};
+    ///
+    /// const MIN_MEAN_QUALITY: f64 = 20.0;
+    ///
+    /// let records = [
+    ///     Record::builder().set_quality_scores("NDLS".parse()?).build(),
+    ///     Record::builder().set_quality_scores("####".parse()?).build(),
+    ///     Record::default(),
+    /// ];
+    ///
+    /// let passing_record_count = records
+    ///     .iter()
+    ///     .filter(|record| {
+    ///         record
+    ///             .quality_scores()
+    ///             .mean()
+    ///             .map(|mean| mean >= MIN_MEAN_QUALITY)
+    ///             .unwrap_or(false)
+    ///     })
+    ///     .count();
+    ///
+    /// assert_eq!(passing_record_count, 1);
+    /// # Ok::<_, noodles_sam::record::quality_scores::ParseError>(())
+    /// ```
+    pub fn mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.sum() as f64 / self.len() as f64)
+        }
+    }
+
+    /// Returns the population standard deviation of the scores.
+    ///
+    /// This returns `None` if there are no scores.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::QualityScores;
+    /// let quality_scores: QualityScores = "+5".parse()?; // [10, 20]
+    /// assert_eq!(quality_scores.std_dev(), Some(5.0));
+    /// # Ok::<_, noodles_sam::record::quality_scores::ParseError>(())
+    /// ```
+    pub fn std_dev(&self) -> Option<f64> {
+        let mean = self.mean()?;
+
+        let sum_of_squares: f64 = self
+            .0
+            .iter()
+            .map(|score| {
+                let d = f64::from(score.get()) - mean;
+                d * d
+            })
+            .sum();
+
+        Some((sum_of_squares / self.len() as f64).sqrt())
+    }
+
+    /// Returns the minimum score.
+    ///
+    /// This returns `None` if there are no scores.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{quality_scores::Score, QualityScores};
+    /// let quality_scores: QualityScores = "NDLS".parse()?;
+    /// assert_eq!(quality_scores.min_score(), Some(Score::try_from('D')?));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn min_score(&self) -> Option<Score> {
+        self.0.iter().copied().min()
+    }
+
+    /// Returns the maximum score.
+    ///
+    /// This returns `None` if there are no scores.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{quality_scores::Score, QualityScores};
+    /// let quality_scores: QualityScores = "NDLS".parse()?;
+    /// assert_eq!(quality_scores.max_score(), Some(Score::try_from('S')?));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn max_score(&self) -> Option<Score> {
+        self.0.iter().copied().max()
+    }
 }
 
 impl AsRef<[Score]> for QualityScores {
@@ -267,6 +381,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_statistics() -> Result<(), ParseError> {
+        let quality_scores = QualityScores::default();
+        assert_eq!(quality_scores.sum(), 0);
+        assert!(quality_scores.mean().is_none());
+        assert!(quality_scores.std_dev().is_none());
+        assert!(quality_scores.min_score().is_none());
+        assert!(quality_scores.max_score().is_none());
+
+        // [10, 20, 30, 40]
+        let quality_scores: QualityScores = "+5?I".parse()?;
+        assert_eq!(quality_scores.sum(), 100);
+        assert_eq!(quality_scores.mean(), Some(25.0));
+        assert_eq!(quality_scores.std_dev(), Some(125f64.sqrt()));
+        assert_eq!(quality_scores.min_score().map(|s| s.get()), Some(10));
+        assert_eq!(quality_scores.max_score().map(|s| s.get()), Some(40));
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), ParseError> {
         let quality_scores = QualityScores::default();
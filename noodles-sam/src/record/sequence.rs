@@ -128,6 +128,25 @@ impl Sequence {
     pub fn push(&mut self, base: Base) {
         self.0.push(base);
     }
+
+    /// Returns the reverse complement of the sequence.
+    ///
+    /// See [`Base::complement`] for how each base is complemented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Sequence;
+    ///
+    /// let sequence: Sequence = "ACGTRN".parse()?;
+    /// let expected: Sequence = "NYACGT".parse()?;
+    ///
+    /// assert_eq!(sequence.reverse_complement(), expected);
+    /// # Ok::<_, noodles_sam::record::sequence::ParseError>(())
+    /// ```
+    pub fn reverse_complement(&self) -> Self {
+        Self(self.0.iter().rev().map(|base| base.complement()).collect())
+    }
 }
 
 impl AsRef<[Base]> for Sequence {
@@ -239,6 +258,16 @@ impl From<Sequence> for Vec<Base> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_reverse_complement() {
+        let sequence = Sequence::default();
+        assert!(sequence.reverse_complement().is_empty());
+
+        let sequence = Sequence::from(vec![Base::A, Base::A, Base::C, Base::K, Base::S]);
+        let expected = Sequence::from(vec![Base::S, Base::M, Base::G, Base::T, Base::T]);
+        assert_eq!(sequence.reverse_complement(), expected);
+    }
+
     #[test]
     fn test_fmt() {
         let sequence = Sequence::from(vec![Base::A, Base::T, Base::C, Base::G]);
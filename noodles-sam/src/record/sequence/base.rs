@@ -64,6 +64,41 @@ pub enum Base {
     Eq,
 }
 
+impl Base {
+    /// Returns the complement of the base.
+    ///
+    /// IUPAC ambiguity codes are complemented to the code representing the complementary set of
+    /// bases, e.g., `R` (A or G) is complemented to `Y` (C or T). Uracil (`U`) is complemented to
+    /// adenine (`A`). Self-complementary codes (`S`, `W`, and `N`), undefined bases, and `=` are
+    /// returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::sequence::Base;
+    /// assert_eq!(Base::A.complement(), Base::T);
+    /// assert_eq!(Base::R.complement(), Base::Y);
+    /// assert_eq!(Base::N.complement(), Base::N);
+    /// ```
+    pub fn complement(self) -> Self {
+        match self {
+            Self::A => Self::T,
+            Self::T | Self::U => Self::A,
+            Self::C => Self::G,
+            Self::G => Self::C,
+            Self::R => Self::Y,
+            Self::Y => Self::R,
+            Self::K => Self::M,
+            Self::M => Self::K,
+            Self::B => Self::V,
+            Self::V => Self::B,
+            Self::D => Self::H,
+            Self::H => Self::D,
+            base => base,
+        }
+    }
+}
+
 impl fmt::Display for Base {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_char(char::from(*self))
@@ -208,6 +243,29 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_complement() {
+        let pairs = [
+            (Base::A, Base::T),
+            (Base::C, Base::G),
+            (Base::R, Base::Y),
+            (Base::K, Base::M),
+            (Base::B, Base::V),
+            (Base::D, Base::H),
+        ];
+
+        for (a, b) in pairs {
+            assert_eq!(a.complement(), b);
+            assert_eq!(b.complement(), a);
+        }
+
+        assert_eq!(Base::U.complement(), Base::A);
+
+        for base in [Base::S, Base::W, Base::N, Base::X, Base::Eq] {
+            assert_eq!(base.complement(), base);
+        }
+    }
+
     #[test]
     fn test_try_from_char_for_base() {
         for (c, &expected) in ('A'..='Z').zip(ALPHA_BASES) {
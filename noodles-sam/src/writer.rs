@@ -1,9 +1,15 @@
+//! SAM writer.
+
+mod builder;
+mod line_ending;
 mod num;
 mod record;
 
 use std::io::{self, Write};
 
-pub(crate) use self::record::write_record;
+pub use self::{builder::Builder, line_ending::LineEnding};
+
+pub(crate) use self::record::{write_record, write_record_with_read_group};
 use super::{alignment::Record, AlignmentWriter, Header};
 
 /// A SAM writer.
@@ -41,6 +47,8 @@ where
     W: Write,
 {
     inner: W,
+    line_ending: LineEnding,
+    default_read_group: Option<String>,
 }
 
 impl<W> Writer<W>
@@ -56,7 +64,11 @@ where
     /// let writer = sam::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            line_ending: LineEnding::default(),
+            default_read_group: None,
+        }
     }
 
     /// Returns a reference to the underlying writer.
@@ -98,6 +110,38 @@ where
         self.inner
     }
 
+    /// Sets a read group ID to add to records that do not have one.
+    ///
+    /// Records that already have a read group (`RG`) data field are written as is. The read group
+    /// must be in the header given when writing a record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::Record,
+    ///     header::record::value::{map::ReadGroup, Map},
+    /// };
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_read_group("rg0", Map::<ReadGroup>::default())
+    ///     .build();
+    ///
+    /// let mut writer = sam::Writer::new(Vec::new());
+    /// writer.set_default_read_group(Some(String::from("rg0")));
+    ///
+    /// let record = Record::default();
+    /// writer.write_record(&header, &record)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0\n");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn set_default_read_group(&mut self, read_group_id: Option<String>) {
+        self.default_read_group = read_group_id;
+    }
+
     /// Writes a SAM header.
     ///
     /// The SAM header is optional, though recommended to include. A call to this method can be
@@ -115,7 +159,13 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
-        write!(self.inner, "{header}")
+        match self.line_ending {
+            LineEnding::Lf => write!(self.inner, "{header}"),
+            LineEnding::CrLf => {
+                let s = header.to_string().replace('\n', "\r\n");
+                self.inner.write_all(s.as_bytes())
+            }
+        }
     }
 
     /// Writes a SAM record.
@@ -136,7 +186,16 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        let read_group_id =
+            resolve_default_read_group(header, self.default_read_group.as_deref(), record)?;
+
+        write_record_with_read_group(
+            &mut self.inner,
+            header,
+            record,
+            read_group_id,
+            self.line_ending,
+        )
     }
 }
 
@@ -149,10 +208,103 @@ where
     }
 
     fn write_alignment_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        self.write_record(header, record)
     }
 
     fn finish(&mut self, _: &Header) -> io::Result<()> {
         Ok(())
     }
 }
+
+/// Returns the default read group ID to add to the given record.
+///
+/// This is `None` if there is no default read group or if the record already has a read group
+/// (`RG`) data field. An error is returned if the default read group is not in the header.
+#[doc(hidden)]
+pub fn resolve_default_read_group<'a>(
+    header: &Header,
+    read_group_id: Option<&'a str>,
+    record: &Record,
+) -> io::Result<Option<&'a str>> {
+    use crate::record::data::field::tag;
+
+    let id = match read_group_id {
+        Some(id) => id,
+        None => return Ok(None),
+    };
+
+    if !header.read_groups().contains_key(id) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("default read group not in header: {id}"),
+        ));
+    }
+
+    if record.data().get(&tag::READ_GROUP).is_some() {
+        Ok(None)
+    } else {
+        Ok(Some(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_record_with_default_read_group() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::record::value::{map::ReadGroup, Map},
+            record::data::field::{tag, Value},
+        };
+
+        let header = Header::builder()
+            .add_read_group("rg0", Map::<ReadGroup>::default())
+            .add_read_group("rg1", Map::<ReadGroup>::default())
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.set_default_read_group(Some(String::from("rg0")));
+
+        writer.write_record(&header, &Record::default())?;
+
+        let record = Record::builder()
+            .set_data(
+                [(tag::READ_GROUP, Value::String(String::from("rg1")))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+        writer.write_record(&header, &record)?;
+
+        let expected = b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0
+*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg1
+";
+        assert_eq!(writer.get_ref(), expected);
+
+        writer.set_default_read_group(Some(String::from("rg2")));
+        assert!(matches!(
+            writer.write_record(&header, &Record::default()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_crlf_line_ending() -> io::Result<()> {
+        let header = Header::builder().add_comment("noodles-sam").build();
+
+        let mut writer = Builder::default()
+            .set_line_ending(LineEnding::CrLf)
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+        writer.write_record(&header, &Record::default())?;
+
+        let expected = b"@CO\tnoodles-sam\r\n*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\r\n";
+        assert_eq!(writer.get_ref(), expected);
+
+        Ok(())
+    }
+}
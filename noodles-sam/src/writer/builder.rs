@@ -0,0 +1,78 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use noodles_bgzf as bgzf;
+
+use super::{LineEnding, Writer};
+
+/// A SAM writer builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    line_ending: LineEnding,
+}
+
+impl Builder {
+    /// Sets the line ending.
+    ///
+    /// By default, lines end with a line feed (`\n`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::writer::{Builder, LineEnding};
+    /// let builder = Builder::default().set_line_ending(LineEnding::CrLf);
+    /// ```
+    pub fn set_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Builds a SAM writer from a path.
+    ///
+    /// If the extension of the path is `gz` or `bgz`, the output is BGZF-compressed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_sam as sam;
+    /// let writer = sam::writer::Builder::default().build_from_path("out.sam")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, dst: P) -> io::Result<Writer<Box<dyn Write>>>
+    where
+        P: AsRef<Path>,
+    {
+        let dst = dst.as_ref();
+
+        let file = File::create(dst)?;
+
+        let writer: Box<dyn Write> = match dst.extension().and_then(|ext| ext.to_str()) {
+            Some("gz" | "bgz") => Box::new(bgzf::Writer::new(file)),
+            _ => Box::new(BufWriter::new(file)),
+        };
+
+        Ok(self.build_from_writer(writer))
+    }
+
+    /// Builds a SAM writer from a writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// let writer = sam::writer::Builder::default().build_from_writer(Vec::new());
+    /// ```
+    pub fn build_from_writer<W>(self, writer: W) -> Writer<W>
+    where
+        W: Write,
+    {
+        Writer {
+            inner: writer,
+            line_ending: self.line_ending,
+            default_read_group: None,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+/// A SAM writer line ending.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum LineEnding {
+    /// Line feed (`\n`).
+    #[default]
+    Lf,
+    /// Carriage return and line feed (`\r\n`).
+    CrLf,
+}
+
+impl AsRef<[u8]> for LineEnding {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::Lf => b"\n",
+            Self::CrLf => b"\r\n",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(LineEnding::default(), LineEnding::Lf);
+    }
+
+    #[test]
+    fn test_as_ref() {
+        assert_eq!(LineEnding::Lf.as_ref(), b"\n");
+        assert_eq!(LineEnding::CrLf.as_ref(), b"\r\n");
+    }
+}
@@ -11,11 +11,31 @@ pub use self::{
 
 use std::io::{self, Write};
 
+use super::LineEnding;
 use crate::{alignment::Record, Header};
 
 const MISSING: u8 = b'*';
 
-pub fn write_record<W>(writer: &mut W, header: &Header, record: &Record) -> io::Result<()>
+pub fn write_record<W>(
+    writer: &mut W,
+    header: &Header,
+    record: &Record,
+    line_ending: LineEnding,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    write_record_with_read_group(writer, header, record, None, line_ending)
+}
+
+// Writes a record with an additional read group (`RG`) data field, if given.
+pub fn write_record_with_read_group<W>(
+    writer: &mut W,
+    header: &Header,
+    record: &Record,
+    read_group_id: Option<&str>,
+    line_ending: LineEnding,
+) -> io::Result<()>
 where
     W: Write,
 {
@@ -89,7 +109,12 @@ where
 
     write_data(writer, record.data())?;
 
-    writeln!(writer)?;
+    if let Some(id) = read_group_id {
+        writer.write_all(b"\tRG:Z:")?;
+        writer.write_all(id.as_bytes())?;
+    }
+
+    writer.write_all(line_ending.as_ref())?;
 
     Ok(())
 }
@@ -111,7 +136,7 @@ mod tests {
             .collect();
         let record = Record::builder().set_data(data).build();
 
-        write_record(&mut buf, &header, &record)?;
+        write_record(&mut buf, &header, &record, LineEnding::Lf)?;
 
         let expected = b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0\n";
         assert_eq!(buf, expected);
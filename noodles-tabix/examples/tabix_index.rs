@@ -0,0 +1,39 @@
+//! Builds and writes a tabix index from a bgzipped BED, GFF, or VCF file.
+//!
+//! The preset is one of `bed`, `gff`, or `vcf`.
+//!
+//! This writes the output to `stdout` rather than `<src>.tbi`.
+//!
+//! The output is similar to the output of `tabix --preset <preset> <src>`.
+
+use std::{env, fs::File, io};
+
+use noodles_csi as csi;
+use noodles_tabix as tabix;
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+    let src = args.next().expect("missing src");
+    let preset = args.next().expect("missing preset");
+
+    let builder = match preset.as_str() {
+        "bed" => csi::index::header::Builder::bed(),
+        "gff" => csi::index::header::Builder::gff(),
+        "vcf" => csi::index::header::Builder::vcf(),
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid preset: {preset}"),
+            ))
+        }
+    };
+
+    let reader = File::open(src)?;
+    let index = tabix::index_from_bgzf(reader, builder.build())?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = tabix::Writer::new(stdout);
+    writer.write_index(&index)?;
+
+    Ok(())
+}
@@ -1,7 +1,8 @@
 //! Tabix index.
 
+mod from_bgzf;
 mod indexer;
 
-pub use self::indexer::Indexer;
+pub use self::{from_bgzf::index_from_bgzf, indexer::Indexer};
 
 pub(crate) const DEPTH: u8 = 5;
@@ -0,0 +1,402 @@
+use std::{
+    collections::HashSet,
+    io::{self, BufRead, Read},
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::{
+    index::{
+        header::{format::CoordinateSystem, Format},
+        reference_sequence::bin::Chunk,
+        Header,
+    },
+    Index,
+};
+
+use super::Indexer;
+
+/// Builds a tabix index from a bgzipped, tab-delimited file.
+///
+/// The index header describes the input: its format and coordinate system, the reference
+/// sequence name, start position, and end position columns, the line comment prefix, and the
+/// number of lines to skip. Use the presets [`csi::index::header::Builder::bed`],
+/// [`csi::index::header::Builder::gff`], or [`csi::index::header::Builder::vcf`] for common
+/// formats. The reference sequence names of the header are replaced by the ones in the input.
+///
+/// The input must be grouped by reference sequence name and sorted by start position.
+///
+/// For VCF, the end position is the value of the `END` INFO field, if present; the start
+/// position plus the largest absolute `SVLEN` INFO field value of the `<DEL>`, `<DUP>`, `<INV>`,
+/// and `<CNV>` alternate alleles; or calculated using the reference bases length. This is the
+/// same as `vcf::Record::end_position`. SAM is not supported.
+///
+/// This is equivalent to `tabix --preset <format> <src>`.
+///
+/// [`csi::index::header::Builder::bed`]: noodles_csi::index::header::Builder::bed
+/// [`csi::index::header::Builder::gff`]: noodles_csi::index::header::Builder::gff
+/// [`csi::index::header::Builder::vcf`]: noodles_csi::index::header::Builder::vcf
+///
+/// # Errors
+///
+/// An error is returned if a record is invalid or out of order.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::fs::File;
+/// use noodles_csi as csi;
+/// use noodles_tabix as tabix;
+///
+/// let reader = File::open("sample.bed.gz")?;
+/// let header = csi::index::header::Builder::bed().build();
+/// let index = tabix::index_from_bgzf(reader, header)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn index_from_bgzf<R>(reader: R, header: Header) -> io::Result<Index>
+where
+    R: Read,
+{
+    if header.format() == Format::Sam {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "indexing SAM is not supported",
+        ));
+    }
+
+    let mut reader = bgzf::Reader::new(reader);
+    let mut indexer = Indexer::default();
+
+    let mut buf = String::new();
+    let mut line_count = 0;
+    let mut reference_sequence_names = HashSet::new();
+    let mut last_sort_key: Option<(String, Position)> = None;
+
+    loop {
+        let start_position = reader.virtual_position();
+
+        buf.clear();
+
+        if reader.read_line(&mut buf)? == 0 {
+            break;
+        }
+
+        let end_position = reader.virtual_position();
+
+        line_count += 1;
+
+        if line_count <= header.line_skip_count()
+            || buf.as_bytes().first() == Some(&header.line_comment_prefix())
+        {
+            continue;
+        }
+
+        let line = buf.trim_end_matches(['\n', '\r']);
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let (reference_sequence_name, start, end) = parse_record(line, &header)?;
+
+        if let Some((last_reference_sequence_name, last_start)) = &last_sort_key {
+            if reference_sequence_name == last_reference_sequence_name {
+                if start < *last_start {
+                    return Err(unsorted_error(line_count));
+                }
+            } else if reference_sequence_names.contains(reference_sequence_name) {
+                return Err(unsorted_error(line_count));
+            }
+        }
+
+        let chunk = Chunk::new(start_position, end_position);
+        indexer.add_record(reference_sequence_name, start, end, chunk)?;
+
+        reference_sequence_names.insert(reference_sequence_name.to_string());
+        last_sort_key = Some((reference_sequence_name.into(), start));
+    }
+
+    indexer.set_header(header);
+
+    Ok(indexer.build())
+}
+
+fn unsorted_error(line_number: u32) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("record is out of order at line {line_number}: records must be coordinate-sorted"),
+    )
+}
+
+// Returns the reference sequence name and 1-based, inclusive start and end positions.
+fn parse_record<'a>(line: &'a str, header: &Header) -> io::Result<(&'a str, Position, Position)> {
+    const DELIMITER: char = '\t';
+
+    let fields: Vec<_> = line.split(DELIMITER).collect();
+
+    let get_field = |i: usize, name: &str| {
+        fields.get(i).copied().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("missing {name} field"))
+        })
+    };
+
+    let reference_sequence_name = get_field(
+        header.reference_sequence_name_index(),
+        "reference sequence name",
+    )?;
+
+    let raw_start = get_field(header.start_position_index(), "start position")?;
+    let start = parse_position(raw_start)?;
+
+    let start = match header.format().coordinate_system() {
+        CoordinateSystem::Gff => Position::try_from(start),
+        CoordinateSystem::Bed => Position::try_from(start + 1),
+    }
+    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let end = if header.format() == Format::Vcf {
+        resolve_vcf_end(&fields, start)?
+    } else if let Some(i) = header.end_position_index() {
+        // In both coordinate systems, the end position is the same as a 1-based, inclusive end.
+        let raw_end = get_field(i, "end position")?;
+        parse_position(raw_end)?
+    } else {
+        usize::from(start)
+    };
+
+    // A zero-length interval, e.g., a BED insertion, is indexed as a single position.
+    let end = Position::try_from(end)
+        .ok()
+        .filter(|&end| end >= start)
+        .unwrap_or(start);
+
+    Ok((reference_sequence_name, start, end))
+}
+
+// This uses the same rule as `vcf::Record::end_position`, so that records are binned the same
+// way as by the VCF indexed writer and queried the same way by the VCF indexed reader.
+fn resolve_vcf_end(fields: &[&str], start: Position) -> io::Result<usize> {
+    const REFERENCE_BASES_INDEX: usize = 3;
+    const ALTERNATE_BASES_INDEX: usize = 4;
+    const INFO_INDEX: usize = 7;
+
+    let info = fields.get(INFO_INDEX).copied().unwrap_or_default();
+
+    if let Some(raw_end) = get_info_value(info, "END") {
+        return parse_position(raw_end);
+    }
+
+    if let Some(raw_sv_lengths) = get_info_value(info, "SVLEN") {
+        let alternate_bases = fields
+            .get(ALTERNATE_BASES_INDEX)
+            .copied()
+            .unwrap_or_default();
+
+        if let Some(len) = max_sv_length(alternate_bases, raw_sv_lengths)? {
+            return usize::from(start)
+                .checked_add(len)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid end position"));
+        }
+    }
+
+    let reference_bases = fields.get(REFERENCE_BASES_INDEX).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, "missing reference bases field")
+    })?;
+
+    let len = reference_bases.len().max(1);
+
+    usize::from(start)
+        .checked_add(len - 1)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid end position"))
+}
+
+fn get_info_value<'a>(info: &'a str, key: &str) -> Option<&'a str> {
+    info.split(';')
+        .find_map(|field| field.strip_prefix(key).and_then(|s| s.strip_prefix('=')))
+}
+
+// Returns the largest absolute `SVLEN` value of the deletion (`<DEL>`), duplication (`<DUP>`),
+// inversion (`<INV>`), and copy number variation (`<CNV>`) symbolic alternate alleles.
+fn max_sv_length(alternate_bases: &str, raw_sv_lengths: &str) -> io::Result<Option<usize>> {
+    const MISSING: &str = ".";
+
+    let mut max_len = None;
+
+    for (allele, raw_sv_length) in alternate_bases.split(',').zip(raw_sv_lengths.split(',')) {
+        if !spans_reference(allele) || raw_sv_length == MISSING {
+            continue;
+        }
+
+        let sv_length: i32 = raw_sv_length
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let len = usize::try_from(sv_length.unsigned_abs())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        max_len = max_len.max(Some(len));
+    }
+
+    Ok(max_len)
+}
+
+fn spans_reference(allele: &str) -> bool {
+    allele
+        .strip_prefix('<')
+        .and_then(|s| s.strip_suffix('>'))
+        .and_then(|symbol| symbol.split(':').next())
+        .map(|ty| matches!(ty, "DEL" | "DUP" | "INV" | "CNV"))
+        .unwrap_or(false)
+}
+
+fn parse_position(s: &str) -> io::Result<usize> {
+    s.parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use noodles_core::Region;
+    use noodles_csi::index::header;
+
+    use super::*;
+
+    fn compress(data: &[u8]) -> io::Result<Vec<u8>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(data)?;
+        writer.finish()
+    }
+
+    fn query_line_count(
+        index: &Index,
+        data: &[u8],
+        region: &str,
+    ) -> Result<usize, Box<dyn std::error::Error>> {
+        let region: Region = region.parse()?;
+
+        let reference_sequence_id = index
+            .header()
+            .and_then(|header| {
+                header
+                    .reference_sequence_names()
+                    .get_index_of(region.name())
+            })
+            .ok_or("missing reference sequence")?;
+
+        let chunks = index.query(reference_sequence_id, region.interval())?;
+
+        let mut reader = bgzf::Reader::new(io::Cursor::new(data));
+        let mut n = 0;
+
+        for chunk in chunks {
+            reader.seek(chunk.start())?;
+
+            while reader.virtual_position() < chunk.end() {
+                let mut line = String::new();
+                reader.read_line(&mut line)?;
+                n += 1;
+            }
+        }
+
+        Ok(n)
+    }
+
+    #[test]
+    fn test_index_from_bgzf_with_bed() -> Result<(), Box<dyn std::error::Error>> {
+        let data = compress(b"#comment\nsq0\t7\t13\nsq0\t20\t21\nsq1\t0\t8\n")?;
+
+        let index = index_from_bgzf(&data[..], header::Builder::bed().build())?;
+
+        let header = index.header().ok_or("missing header")?;
+        assert_eq!(header.format(), Format::Generic(CoordinateSystem::Bed));
+        assert_eq!(
+            header.reference_sequence_names().iter().collect::<Vec<_>>(),
+            ["sq0", "sq1"]
+        );
+
+        assert_eq!(query_line_count(&index, &data, "sq0:8-8")?, 2);
+        assert_eq!(query_line_count(&index, &data, "sq1")?, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_from_bgzf_with_vcf() -> Result<(), Box<dyn std::error::Error>> {
+        let data = compress(
+            b"##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t<DEL>\t.\t.\tSVTYPE=DEL;END=21
+sq0\t8\t.\tACGT\tA\t.\t.\t.
+",
+        )?;
+
+        let index = index_from_bgzf(&data[..], header::Builder::vcf().build())?;
+        assert_eq!(index.reference_sequences().len(), 1);
+
+        let metadata = index.reference_sequences()[0]
+            .metadata()
+            .ok_or("missing metadata")?;
+        assert_eq!(metadata.mapped_record_count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_from_bgzf_with_vcf_sv_lengths() -> Result<(), Box<dyn std::error::Error>> {
+        let data = compress(
+            b"##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t<DEL>\t.\t.\tSVLEN=-20000
+",
+        )?;
+
+        let index = index_from_bgzf(&data[..], header::Builder::vcf().build())?;
+        assert_eq!(query_line_count(&index, &data, "sq0:20001-20001")?, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_vcf_end() -> Result<(), Box<dyn std::error::Error>> {
+        fn t(line: &str) -> io::Result<usize> {
+            let fields: Vec<_> = line.split('\t').collect();
+            let start = parse_position(fields[1])?;
+            let start = Position::try_from(start)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            resolve_vcf_end(&fields, start)
+        }
+
+        assert_eq!(t("sq0\t8\t.\tACGT\tA\t.\t.\t.")?, 11);
+        assert_eq!(t("sq0\t8\t.\tA\t<DEL>\t.\t.\tEND=21;SVLEN=-5")?, 21);
+        assert_eq!(t("sq0\t8\t.\tA\t<DEL>\t.\t.\tSVLEN=-5")?, 13);
+        assert_eq!(t("sq0\t8\t.\tA\t<DUP:TANDEM>,<DEL>\t.\t.\tSVLEN=3,-5")?, 13);
+        assert_eq!(t("sq0\t8\t.\tA\t<INS>,<DEL>\t.\t.\tSVLEN=21,.")?, 8);
+        assert_eq!(t("sq0\t8\t.\tA\t<INS>\t.\t.\tSVLEN=13")?, 8);
+
+        assert!(matches!(
+            t("sq0\t8\t.\tA\t<DEL>\t.\t.\tSVLEN=ndls"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_from_bgzf_with_unsorted_records() -> io::Result<()> {
+        let data = compress(b"sq0\t7\t13\nsq0\t0\t5\n")?;
+        assert!(matches!(
+            index_from_bgzf(&data[..], header::Builder::bed().build()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let data = compress(b"sq0\t7\t13\nsq1\t0\t5\nsq0\t21\t34\n")?;
+        assert!(matches!(
+            index_from_bgzf(&data[..], header::Builder::bed().build()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
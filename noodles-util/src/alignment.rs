@@ -1,12 +1,16 @@
 //! I/O for alignment formats.
 
 mod compression_method;
+pub mod depth;
+pub mod fastq;
 mod format;
 pub mod indexed_reader;
+pub mod pileup;
 pub mod reader;
+pub mod spill_buffer;
 pub mod writer;
 
 pub use self::{
     compression_method::CompressionMethod, format::Format, indexed_reader::IndexedReader,
-    reader::Reader, writer::Writer,
+    reader::Reader, spill_buffer::SpillBuffer, writer::Writer,
 };
@@ -0,0 +1,214 @@
+//! Alignment coverage depth.
+
+use std::{
+    io::{self, Read, Seek},
+    iter,
+};
+
+use noodles_bam as bam;
+use noodles_core::{Position, Region};
+use noodles_sam::{self as sam, alignment::Record};
+
+use super::IndexedReader;
+
+/// Returns the coverage depth of each position in the given region.
+///
+/// The depth at index `i` is the depth at the `i`-th position of the region. If the region does
+/// not have an end position, the reference sequence length in the header is used.
+///
+/// See [`depth_iter`] for how depth is counted. This is the equivalent of `samtools depth -a -r
+/// <region>`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_util::alignment::{self, depth};
+///
+/// let mut reader = alignment::indexed_reader::Builder::default().build_from_path("sample.bam")?;
+/// let header = reader.read_header()?;
+///
+/// let region = "sq0:8-13".parse()?;
+/// let depths = depth::query(&mut reader, &header, &region)?;
+/// assert_eq!(depths.len(), 6);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn query<R>(
+    reader: &mut IndexedReader<R>,
+    header: &sam::Header,
+    region: &Region,
+) -> io::Result<Vec<u32>>
+where
+    R: Read + Seek,
+{
+    depth_iter(reader, header, region)?
+        .map(|result| result.map(|(_, depth)| depth))
+        .collect()
+}
+
+/// Returns an iterator over the coverage depth of each position in the given region.
+///
+/// Every position of the region is yielded, including ones with no coverage. If the region does
+/// not have an end position, the reference sequence length in the header is used.
+///
+/// The depth at a position is the number of records with an aligned base at that position.
+/// Deletions (`D`) and reference skips (`N`) do not count as coverage, and insertions (`I`) do not
+/// add to the depth of adjacent positions. Unmapped, secondary, QC fail, and duplicate records are
+/// excluded.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_util::alignment::{self, depth};
+///
+/// let mut reader = alignment::indexed_reader::Builder::default().build_from_path("sample.bam")?;
+/// let header = reader.read_header()?;
+///
+/// let region = "sq0:8-13".parse()?;
+///
+/// for result in depth::depth_iter(&mut reader, &header, &region)? {
+///     let (position, depth) = result?;
+///     println!("{position}\t{depth}");
+/// }
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn depth_iter<'r, 'h: 'r, R>(
+    reader: &'r mut IndexedReader<R>,
+    header: &'h sam::Header,
+    region: &Region,
+) -> io::Result<impl Iterator<Item = io::Result<(Position, u32)>> + 'r>
+where
+    R: Read + Seek,
+{
+    let (start, end) = resolve_interval(header, region)?;
+    let records = reader.query(header, region)?;
+    Ok(depths(records, start, end))
+}
+
+fn resolve_interval(header: &sam::Header, region: &Region) -> io::Result<(Position, Position)> {
+    let interval = region.interval();
+
+    let start = interval.start().unwrap_or(Position::MIN);
+
+    let end = match interval.end() {
+        Some(end) => end,
+        None => header
+            .reference_sequences()
+            .get(region.name())
+            .and_then(|reference_sequence| Position::new(reference_sequence.length().get()))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid reference sequence name: {}", region.name()),
+                )
+            })?,
+    };
+
+    Ok((start, end))
+}
+
+fn depths<I>(
+    records: I,
+    start: Position,
+    end: Position,
+) -> impl Iterator<Item = io::Result<(Position, u32)>>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    let records = records.filter(|result| match result {
+        Ok(record) => !is_filtered(record),
+        Err(_) => true,
+    });
+
+    let mut columns = bam::pileup(records).peekable();
+    let mut next_position = Some(start).filter(|&position| position <= end);
+
+    iter::from_fn(move || {
+        let position = next_position?;
+
+        while columns
+            .next_if(|result| matches!(result, Ok(column) if column.position() < position))
+            .is_some()
+        {}
+
+        let depth = match columns.next_if(|result| match result {
+            Ok(column) => column.position() == position,
+            Err(_) => true,
+        }) {
+            Some(Ok(column)) => column.depth(),
+            Some(Err(e)) => {
+                next_position = None;
+                return Some(Err(e));
+            }
+            None => 0,
+        };
+
+        next_position = if position < end {
+            position.checked_add(1)
+        } else {
+            None
+        };
+
+        let depth = u32::try_from(depth).unwrap_or(u32::MAX);
+
+        Some(Ok((position, depth)))
+    })
+}
+
+fn is_filtered(record: &Record) -> bool {
+    let flags = record.flags();
+    flags.is_secondary() || flags.is_qc_fail() || flags.is_duplicate()
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::record::Flags;
+
+    use super::*;
+
+    #[test]
+    fn test_depths() -> Result<(), Box<dyn std::error::Error>> {
+        fn build_record(
+            flags: Flags,
+            alignment_start: usize,
+            cigar: &str,
+        ) -> Result<Record, Box<dyn std::error::Error>> {
+            let record = Record::builder()
+                .set_flags(flags)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(alignment_start)?)
+                .set_cigar(cigar.parse()?)
+                .build();
+
+            Ok(record)
+        }
+
+        let records = [
+            build_record(Flags::empty(), 2, "1M2I1M1D1M")?,
+            build_record(Flags::empty(), 3, "1M2N1M")?,
+            build_record(Flags::DUPLICATE, 3, "4M")?,
+            build_record(Flags::empty(), 9, "2M")?,
+        ];
+
+        let start = Position::try_from(1)?;
+        let end = Position::try_from(8)?;
+
+        let actual: Vec<_> = depths(records.into_iter().map(Ok), start, end)
+            .map(|result| result.map(|(position, depth)| (usize::from(position), depth)))
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            (1, 0),
+            (2, 1),
+            (3, 2),
+            (4, 0),
+            (5, 1),
+            (6, 1),
+            (7, 0),
+            (8, 0),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
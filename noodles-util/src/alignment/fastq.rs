@@ -0,0 +1,165 @@
+//! Alignment record to FASTQ record conversion.
+
+use std::io;
+
+use noodles_fastq as fastq;
+use noodles_sam::{alignment::Record, record::quality_scores::Score};
+
+/// Converts an alignment record to a FASTQ record.
+///
+/// The FASTQ record is in the original read orientation, i.e., if the alignment record is
+/// reverse complemented, the sequence is reverse complemented and the quality scores are
+/// reversed.
+///
+/// If the alignment record is missing quality scores (`*`), each base is given
+/// `missing_quality_score`. If `missing_quality_score` is `None`, an error is returned instead.
+///
+/// This is similar to `samtools fastq`, without read name suffixes.
+///
+/// # Errors
+///
+/// An error is returned if the record is missing a read name, if the record is missing quality
+/// scores and no `missing_quality_score` is given, or if the sequence and quality scores lengths
+/// differ.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{
+///     self as sam,
+///     record::{quality_scores::Score, Flags},
+/// };
+/// use noodles_util::alignment::fastq;
+///
+/// let record = sam::alignment::Record::builder()
+///     .set_read_name("r0".parse()?)
+///     .set_flags(Flags::REVERSE_COMPLEMENTED)
+///     .set_sequence("ACGG".parse()?)
+///     .set_quality_scores("NDLS".parse()?)
+///     .build();
+///
+/// let fastq_record = fastq::to_fastq(&record, None)?;
+/// assert_eq!(fastq_record.name(), b"r0");
+/// assert_eq!(fastq_record.sequence(), b"CCGT");
+/// assert_eq!(fastq_record.quality_scores(), b"SLDN");
+///
+/// let record = sam::alignment::Record::builder()
+///     .set_read_name("r1".parse()?)
+///     .set_sequence("AC".parse()?)
+///     .build();
+///
+/// let missing_quality_score = Score::try_from(40)?;
+/// let fastq_record = fastq::to_fastq(&record, Some(missing_quality_score))?;
+/// assert_eq!(fastq_record.quality_scores(), b"II");
+///
+/// assert!(fastq::to_fastq(&record, None).is_err());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn to_fastq(
+    record: &Record,
+    missing_quality_score: Option<Score>,
+) -> io::Result<fastq::Record> {
+    let name = record
+        .read_name()
+        .map(|read_name| Vec::from(read_name.clone()))
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing read name"))?;
+
+    let bases = record.sequence().as_ref();
+    let scores = record.quality_scores().as_ref();
+
+    let mut quality_scores: Vec<u8> = if scores.is_empty() && !bases.is_empty() {
+        let score = missing_quality_score
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing quality scores"))?;
+
+        vec![score_to_u8(score); bases.len()]
+    } else if scores.len() == bases.len() {
+        scores.iter().copied().map(score_to_u8).collect()
+    } else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "quality scores length mismatch: expected {}, got {}",
+                bases.len(),
+                scores.len()
+            ),
+        ));
+    };
+
+    let sequence: Vec<u8> = if record.flags().is_reverse_complemented() {
+        quality_scores.reverse();
+
+        let sequence = record.sequence().reverse_complement();
+        sequence.as_ref().iter().copied().map(u8::from).collect()
+    } else {
+        bases.iter().copied().map(u8::from).collect()
+    };
+
+    Ok(fastq::Record::new(
+        fastq::record::Definition::new(name, ""),
+        sequence,
+        quality_scores,
+    ))
+}
+
+fn score_to_u8(score: Score) -> u8 {
+    // `char::from(Score)` is always in the printable ASCII range.
+    char::from(score) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::record::Flags;
+
+    use super::*;
+
+    #[test]
+    fn test_to_fastq() -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_sequence("ACGN".parse()?)
+            .set_quality_scores("NDLS".parse()?)
+            .build();
+
+        let actual = to_fastq(&record, None)?;
+        let expected = fastq::Record::new(fastq::record::Definition::new("r0", ""), "ACGN", "NDLS");
+        assert_eq!(actual, expected);
+
+        let record = Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_flags(Flags::REVERSE_COMPLEMENTED)
+            .set_sequence("ACGN".parse()?)
+            .build();
+
+        let actual = to_fastq(&record, Some(Score::try_from(40)?))?;
+        let expected = fastq::Record::new(fastq::record::Definition::new("r0", ""), "NCGT", "IIII");
+        assert_eq!(actual, expected);
+
+        assert!(matches!(
+            to_fastq(&record, None),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let record = Record::builder()
+            .set_sequence("ACGN".parse()?)
+            .set_quality_scores("NDLS".parse()?)
+            .build();
+
+        assert!(matches!(
+            to_fastq(&record, None),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let record = Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_sequence("ACGN".parse()?)
+            .set_quality_scores("ND".parse()?)
+            .build();
+
+        assert!(matches!(
+            to_fastq(&record, None),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
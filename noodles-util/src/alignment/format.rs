@@ -1,3 +1,7 @@
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom};
+
+use super::reader::builder::{detect_compression_method, detect_format};
+
 /// An alignment format.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Format {
@@ -8,3 +12,87 @@ pub enum Format {
     /// CRAM.
     Cram,
 }
+
+impl Format {
+    /// Detects the alignment format from the magic numbers at the start of the input.
+    ///
+    /// If the input is gzip-compressed, the first block is decompressed to check for the BAM magic
+    /// number. Otherwise, the input is checked for the BAM and CRAM magic numbers. Other input is
+    /// assumed to be SAM. This returns `None` if the input is empty.
+    ///
+    /// The reader is returned to its original position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_util::alignment::Format;
+    ///
+    /// let mut reader = io::Cursor::new(b"CRAM");
+    /// assert_eq!(Format::detect(&mut reader)?, Some(Format::Cram));
+    /// assert_eq!(reader.position(), 0);
+    ///
+    /// let mut reader = io::Cursor::new(b"@HD\tVN:1.6\n");
+    /// assert_eq!(Format::detect(&mut reader)?, Some(Format::Sam));
+    ///
+    /// let mut reader = io::Cursor::new(b"");
+    /// assert!(Format::detect(&mut reader)?.is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn detect<R>(reader: &mut R) -> io::Result<Option<Self>>
+    where
+        R: Read + Seek,
+    {
+        let position = reader.stream_position()?;
+
+        let result = {
+            let mut reader = BufReader::new(&mut *reader);
+
+            if reader.fill_buf()?.is_empty() {
+                Ok(None)
+            } else {
+                detect_compression_method(&mut reader)
+                    .and_then(|compression_method| detect_format(&mut reader, compression_method))
+                    .map(Some)
+            }
+        };
+
+        reader.seek(SeekFrom::Start(position))?;
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+
+    #[test]
+    fn test_detect() -> io::Result<()> {
+        fn t(src: &[u8], expected: Option<Format>) -> io::Result<()> {
+            let mut reader = io::Cursor::new(src);
+            assert_eq!(Format::detect(&mut reader)?, expected);
+            assert_eq!(reader.position(), 0);
+            Ok(())
+        }
+
+        t(b"", None)?;
+        t(b"@HD\tVN:1.6\n", Some(Format::Sam))?;
+        t(b"BAM\x01", Some(Format::Bam))?;
+        t(b"CRAM", Some(Format::Cram))?;
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"@HD\tVN:1.6\n")?;
+        t(&writer.finish()?, Some(Format::Sam))?;
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"BAM\x01")?;
+        t(&writer.finish()?, Some(Format::Bam))?;
+
+        Ok(())
+    }
+}
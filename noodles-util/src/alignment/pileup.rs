@@ -0,0 +1,100 @@
+//! Alignment pileup.
+
+pub use noodles_bam::pileup::{PileupColumn, PileupElement};
+
+use std::io::{self, Read, Seek};
+
+use noodles_bam as bam;
+use noodles_core::{region::Interval, Region};
+use noodles_sam::{self as sam, alignment::Record};
+
+use super::IndexedReader;
+
+/// Returns an iterator over pileup columns in the given region.
+///
+/// This queries the indexed reader for records that intersect the region and piles them up (see
+/// [`noodles_bam::pileup`]). Only columns within the region are returned, though their elements
+/// include records that start or end outside of it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_util::alignment::{self, pileup};
+///
+/// let mut reader = alignment::indexed_reader::Builder::default().build_from_path("sample.bam")?;
+/// let header = reader.read_header()?;
+///
+/// let region = "sq0:8-13".parse()?;
+///
+/// for result in pileup::query(&mut reader, &header, &region)? {
+///     let column = result?;
+///     println!("{}\t{}", column.position(), column.depth());
+/// }
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn query<'r, 'h: 'r, R>(
+    reader: &'r mut IndexedReader<R>,
+    header: &'h sam::Header,
+    region: &Region,
+) -> io::Result<impl Iterator<Item = io::Result<PileupColumn>> + 'r>
+where
+    R: Read + Seek,
+{
+    let records = reader.query(header, region)?;
+    Ok(pileup_interval(records, region.interval()))
+}
+
+fn pileup_interval<I>(
+    records: I,
+    interval: Interval,
+) -> impl Iterator<Item = io::Result<PileupColumn>>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    bam::pileup(records).filter(move |result| match result {
+        Ok(column) => interval.contains(column.position()),
+        Err(_) => true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_sam::record::Flags;
+
+    use super::*;
+
+    #[test]
+    fn test_pileup_interval() -> Result<(), Box<dyn std::error::Error>> {
+        fn build_record(
+            alignment_start: usize,
+            cigar: &str,
+        ) -> Result<Record, Box<dyn std::error::Error>> {
+            let record = Record::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(alignment_start)?)
+                .set_cigar(cigar.parse()?)
+                .build();
+
+            Ok(record)
+        }
+
+        let records = [build_record(1, "4M")?, build_record(3, "4M")?];
+
+        let start = Position::try_from(2)?;
+        let end = Position::try_from(5)?;
+
+        let columns: Vec<_> = pileup_interval(records.into_iter().map(Ok), (start..=end).into())
+            .collect::<io::Result<_>>()?;
+
+        let actual: Vec<_> = columns
+            .iter()
+            .map(|column| (usize::from(column.position()), column.elements().len()))
+            .collect();
+
+        assert_eq!(actual, [(2, 1), (3, 2), (4, 2), (5, 1)]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,245 @@
+//! Alignment spill buffer.
+
+mod records;
+
+pub use self::records::Records;
+
+use std::{
+    env,
+    fs::{self, File, OpenOptions},
+    io,
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use noodles_bam as bam;
+use noodles_bgzf as bgzf;
+use noodles_sam::{self as sam, alignment::Record};
+
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// An alignment spill buffer.
+///
+/// A spill buffer writes alignment records to a temporary BGZF-compressed BAM file and streams
+/// them back in the order they were written. This is useful for algorithms that need to make
+/// multiple passes over a set of records that may not fit in memory.
+///
+/// The temporary file is removed when the spill buffer is dropped.
+pub struct SpillBuffer {
+    header: sam::Header,
+    path: PathBuf,
+    writer: Option<bam::Writer<bgzf::Writer<File>>>,
+}
+
+impl SpillBuffer {
+    /// Creates a spill buffer in the system temporary directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::SpillBuffer;
+    /// let spill_buffer = SpillBuffer::new(sam::Header::default())?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn new(header: sam::Header) -> io::Result<Self> {
+        Self::new_in(env::temp_dir(), header)
+    }
+
+    /// Creates a spill buffer in the given directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::env;
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::SpillBuffer;
+    /// let spill_buffer = SpillBuffer::new_in(env::temp_dir(), sam::Header::default())?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn new_in<P>(dir: P, header: sam::Header) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let (path, file) = create_temp_file(dir.as_ref())?;
+
+        let mut writer = bam::Writer::new(file);
+
+        if let Err(e) = writer.write_header(&header) {
+            fs::remove_file(&path).ok();
+            return Err(e);
+        }
+
+        Ok(Self {
+            header,
+            path,
+            writer: Some(writer),
+        })
+    }
+
+    /// Returns the SAM header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::SpillBuffer;
+    /// let spill_buffer = SpillBuffer::new(sam::Header::default())?;
+    /// assert!(spill_buffer.header().is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn header(&self) -> &sam::Header {
+        &self.header
+    }
+
+    /// Writes an alignment record to the spill buffer.
+    ///
+    /// This returns an error if the spill buffer was already replayed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::Record};
+    /// use noodles_util::alignment::SpillBuffer;
+    ///
+    /// let mut spill_buffer = SpillBuffer::new(sam::Header::default())?;
+    /// spill_buffer.write_record(&Record::default())?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        let writer = self.writer.as_mut().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "spill buffer is finished")
+        })?;
+
+        writer.write_record(&self.header, record)
+    }
+
+    /// Returns an iterator over the records written to the spill buffer.
+    ///
+    /// The first call finishes the temporary file, after which no more records can be written.
+    /// The records can be replayed any number of times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::Record};
+    /// use noodles_util::alignment::SpillBuffer;
+    ///
+    /// let mut spill_buffer = SpillBuffer::new(sam::Header::default())?;
+    /// spill_buffer.write_record(&Record::default())?;
+    ///
+    /// let records: Vec<_> = spill_buffer.replay()?.collect::<std::io::Result<_>>()?;
+    /// assert_eq!(records, [Record::default()]);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn replay(&mut self) -> io::Result<Records<'_>> {
+        if let Some(mut writer) = self.writer.take() {
+            writer.try_finish()?;
+        }
+
+        let mut reader = File::open(&self.path).map(bam::Reader::new)?;
+        reader.read_header()?;
+
+        Ok(Records::new(reader, &self.header))
+    }
+}
+
+impl Drop for SpillBuffer {
+    fn drop(&mut self) {
+        // The writer holds the file open, which prevents its removal on some platforms.
+        drop(self.writer.take());
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+fn create_temp_file(dir: &Path) -> io::Result<(PathBuf, File)> {
+    const MAX_ATTEMPTS: usize = 16;
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.subsec_nanos())
+        .unwrap_or_default();
+
+    for _ in 0..MAX_ATTEMPTS {
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let filename = format!(".noodles-spill-{}-{nanos}-{n}.bam", process::id());
+        let path = dir.join(filename);
+
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => return Ok((path, file)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    Err(io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        "failed to create temporary file",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use noodles_sam::{
+        header::record::value::{map::ReferenceSequence, Map},
+        record::{
+            data::field::{tag, Value},
+            Flags, MappingQuality,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_replay() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        let records = [
+            Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(2)?)
+                .set_mapping_quality(MappingQuality::try_from(13)?)
+                .set_cigar("4M".parse()?)
+                .set_sequence("ACGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .set_data(
+                    [(tag::ALIGNMENT_HIT_COUNT, Value::UInt8(1))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+            Record::default(),
+        ];
+
+        let mut spill_buffer = SpillBuffer::new(header)?;
+        let path = spill_buffer.path.clone();
+
+        for record in &records {
+            spill_buffer.write_record(record)?;
+        }
+
+        for _ in 0..2 {
+            let actual: Vec<_> = spill_buffer.replay()?.collect::<io::Result<_>>()?;
+            assert_eq!(actual, records);
+        }
+
+        assert!(spill_buffer.write_record(&Record::default()).is_err());
+
+        drop(spill_buffer);
+        assert!(!path.exists());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use std::{fs::File, io};
+
+use noodles_bam as bam;
+use noodles_sam::{self as sam, alignment::Record};
+
+/// An iterator over records of a spill buffer.
+///
+/// This is created by calling [`super::SpillBuffer::replay`].
+pub struct Records<'a> {
+    reader: bam::Reader<noodles_bgzf::Reader<File>>,
+    header: &'a sam::Header,
+    record: Record,
+}
+
+impl<'a> Records<'a> {
+    pub(super) fn new(
+        reader: bam::Reader<noodles_bgzf::Reader<File>>,
+        header: &'a sam::Header,
+    ) -> Self {
+        Self {
+            reader,
+            header,
+            record: Record::default(),
+        }
+    }
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.reader.read_record(self.header, &mut self.record) {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(self.record.clone())),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
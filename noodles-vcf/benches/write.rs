@@ -0,0 +1,144 @@
+//! Measures the time and allocations of serializing VCF records.
+//!
+//! Run with `cargo bench -p noodles-vcf --bench write`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    io::{self, BufWriter, Write},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
+
+use noodles_vcf::{self as vcf, writer::KeyOrder};
+
+const RECORD_COUNT: usize = 100_000;
+const SAMPLE_COUNT: usize = 32;
+
+struct CountingAllocator;
+
+static ALLOCATION_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATION_COUNT.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATION_COUNT.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let (header, record) = build_header_and_record()?;
+
+    bench("Record::to_string", || {
+        let mut writer = BufWriter::new(io::sink());
+
+        for _ in 0..RECORD_COUNT {
+            let s = record.to_string();
+            writer.write_all(s.as_bytes())?;
+            writer.write_all(b"\n")?;
+        }
+
+        writer.flush()
+    })?;
+
+    bench("Writer::write_record", || {
+        let mut writer = vcf::Writer::new(BufWriter::new(io::sink()));
+
+        for _ in 0..RECORD_COUNT {
+            writer.write_record(&header, &record)?;
+        }
+
+        writer.get_mut().flush()
+    })?;
+
+    bench("Writer::encode_record", || {
+        let mut writer = vcf::Writer::new(io::sink());
+        let mut buf = Vec::new();
+
+        for _ in 0..RECORD_COUNT {
+            buf.clear();
+            writer.encode_record(&mut buf, &header, &record)?;
+            writer.get_mut().write_all(&buf)?;
+        }
+
+        Ok(())
+    })?;
+
+    bench("Writer::encode_record (KeyOrder::Header)", || {
+        let mut writer = vcf::writer::Builder::default()
+            .set_key_order(KeyOrder::Header)
+            .build_with_writer(io::sink());
+
+        let mut buf = Vec::new();
+
+        for _ in 0..RECORD_COUNT {
+            buf.clear();
+            writer.encode_record(&mut buf, &header, &record)?;
+            writer.get_mut().write_all(&buf)?;
+        }
+
+        Ok(())
+    })?;
+
+    Ok(())
+}
+
+fn bench<F>(name: &str, f: F) -> io::Result<()>
+where
+    F: FnOnce() -> io::Result<()>,
+{
+    let allocation_count = ALLOCATION_COUNT.load(Ordering::Relaxed);
+    let start = Instant::now();
+
+    f()?;
+
+    let elapsed = start.elapsed();
+    let allocation_count = ALLOCATION_COUNT.load(Ordering::Relaxed) - allocation_count;
+
+    println!(
+        "{name}: {elapsed:?} ({} allocations/record)",
+        allocation_count as f64 / RECORD_COUNT as f64
+    );
+
+    Ok(())
+}
+
+fn build_header_and_record() -> Result<(vcf::Header, vcf::Record), Box<dyn std::error::Error>> {
+    let mut raw_header = String::from(
+        r#"##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data">
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples">
+##INFO=<ID=AF,Number=A,Type=Float,Description="Allele frequency">
+##FORMAT=<ID=GT,Number=1,Type=String,Description="Genotype">
+##FORMAT=<ID=GQ,Number=1,Type=Integer,Description="Conditional genotype quality">
+##FORMAT=<ID=DP,Number=1,Type=Integer,Description="Read depth">
+##FORMAT=<ID=AD,Number=R,Type=Integer,Description="Read depth for each allele">
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT"#,
+    );
+
+    let mut raw_record =
+        String::from("sq0\t8\trs13\tA\tC,G\t21\tPASS\tDP=377;AF=0.25,0.5;NS=32\tGT:DP:GQ:AD");
+
+    for i in 0..SAMPLE_COUNT {
+        raw_header.push_str(&format!("\tsample{i}"));
+        raw_record.push_str("\t0/1:13:34:5,8,0");
+    }
+
+    raw_header.push('\n');
+
+    let header = raw_header.parse()?;
+    let record = vcf::Record::try_from((&header, raw_record.as_str()))?;
+
+    Ok((header, record))
+}
@@ -40,7 +40,7 @@ fn main() -> io::Result<()> {
         let start = Position::try_from(usize::from(record.position()))
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
         let end = record
-            .end()
+            .end_position()
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
             .and_then(|position| {
                 Position::try_from(usize::from(position))
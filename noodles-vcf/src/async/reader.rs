@@ -146,7 +146,7 @@ where
         match read_line(&mut self.inner, &mut self.buf).await? {
             0 => Ok(0),
             n => {
-                parse_record(&self.buf, header, record)
+                parse_record(&self.buf, header, record, false)
                     .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
                 Ok(n)
@@ -3,13 +3,21 @@
 mod builder;
 pub mod file_format;
 mod fmt;
+mod merge;
 mod number;
 pub mod parser;
 pub mod record;
+mod validate;
 
 pub use self::{
-    builder::Builder, file_format::FileFormat, number::Number, parser::ParseError, parser::Parser,
+    builder::Builder,
+    file_format::FileFormat,
+    merge::{MergeError, MergeMode},
+    number::Number,
+    parser::ParseError,
+    parser::Parser,
     record::Record,
+    validate::ValidationError,
 };
 
 use std::{hash::Hash, str::FromStr};
@@ -513,6 +521,135 @@ impl Header {
 
         collection.add(value)
     }
+
+    /// Merges another header into this header.
+    ///
+    /// This is used to combine headers from multiple files, e.g., the outputs of a scatter-gather
+    /// variant calling workflow. The file format is set to the lower version of the two headers.
+    /// Info, filter, format, alternative allele, contig, and other records are unioned in order.
+    /// When a record is in both headers, the one in this header is kept.
+    ///
+    /// Sample names are unioned or must be disjoint, depending on the merge mode.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if an info or format record has a different number or type in the
+    /// other header, if a contig has a different length, or, when the merge mode is
+    /// [`MergeMode::Disjoint`], if a sample name is in both headers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{
+    ///         record::value::{map::Info, Map},
+    ///         MergeMode,
+    ///     },
+    ///     record::info::field::key,
+    /// };
+    ///
+    /// let a = vcf::Header::builder()
+    ///     .add_info(key::TOTAL_DEPTH, Map::<Info>::from(&key::TOTAL_DEPTH))
+    ///     .add_sample_name("sample0")
+    ///     .build();
+    ///
+    /// let b = vcf::Header::builder()
+    ///     .add_info(key::ALLELE_FREQUENCIES, Map::<Info>::from(&key::ALLELE_FREQUENCIES))
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let header = a.merge(b, MergeMode::Disjoint)?;
+    ///
+    /// assert_eq!(header.infos().len(), 2);
+    /// assert_eq!(header.sample_names().len(), 2);
+    /// # Ok::<_, vcf::header::MergeError>(())
+    /// ```
+    pub fn merge(self, other: Self, mode: MergeMode) -> Result<Self, MergeError> {
+        merge::merge(self, other, mode, false)
+    }
+
+    /// Merges another header into this one, matching contigs by equivalent names.
+    ///
+    /// This is the same as [`Self::merge`], except that contig names are compared after
+    /// normalization (see [`noodles_core::region::name`]), e.g., `chr1` and `1`, or `chrM` and
+    /// `MT`, are the same contig. The name in this header is kept.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::merge`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{
+    ///         record::value::{map::Contig, Map},
+    ///         MergeMode,
+    ///     },
+    /// };
+    ///
+    /// let a = vcf::Header::builder()
+    ///     .add_contig("chr1".parse()?, Map::<Contig>::new())
+    ///     .build();
+    ///
+    /// let b = vcf::Header::builder()
+    ///     .add_contig("1".parse()?, Map::<Contig>::new())
+    ///     .add_contig("MT".parse()?, Map::<Contig>::new())
+    ///     .build();
+    ///
+    /// let header = a.merge_with_normalized_contig_names(b, MergeMode::Union)?;
+    ///
+    /// let names: Vec<_> = header.contigs().keys().map(|name| name.as_ref()).collect();
+    /// assert_eq!(names, ["chr1", "MT"]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn merge_with_normalized_contig_names(
+        self,
+        other: Self,
+        mode: MergeMode,
+    ) -> Result<Self, MergeError> {
+        merge::merge(self, other, mode, true)
+    }
+
+    /// Validates a record against the header definitions.
+    ///
+    /// This checks that each info and genotype field is defined in the header, that its value
+    /// has the defined type, and that its number of values matches the defined number. `A` and
+    /// `R` numbers use the number of alleles in the record. `G` numbers use the ploidy of the
+    /// sample genotype (`GT`), so they are not checked for info fields or samples without a
+    /// genotype. Missing values (`.`) are not checked.
+    ///
+    /// All errors are returned rather than only the first one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::ValidationError};
+    ///
+    /// let header: vcf::Header = "##fileformat=VCFv4.3
+    /// ###INFO=<ID=AF,Number=A,Type=Float,Description=\"Allele frequency\">
+    /// ##CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// ".parse()?;
+    ///
+    /// let record = vcf::Record::try_from((&header, "sq0\t1\t.\tA\tC\t.\t.\tAF=0.5"))?;
+    /// assert!(header.validate_record(&record).is_ok());
+    ///
+    /// let record = vcf::Record::try_from((&header, "sq0\t1\t.\tA\tC,G\t.\t.\tAF=0.5"))?;
+    /// assert_eq!(
+    ///     header.validate_record(&record),
+    ///     Err(vec![ValidationError::InvalidInfoFieldValueCount {
+    ///         key: "AF".parse()?,
+    ///         expected: 2,
+    ///         actual: 1,
+    ///     }])
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate_record(&self, record: &crate::Record) -> Result<(), Vec<ValidationError>> {
+        validate::validate_record(self, record)
+    }
 }
 
 impl Default for Header {
@@ -0,0 +1,462 @@
+use std::{error, fmt};
+
+use super::{
+    record::value::{
+        map::{contig, format, info},
+        Collection,
+    },
+    Header, Number,
+};
+
+/// How sample names are merged.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MergeMode {
+    /// Sample names are unioned.
+    ///
+    /// This is used when the headers describe the same samples, e.g., per-region outputs of a
+    /// scatter-gather workflow.
+    #[default]
+    Union,
+    /// Sample names must be disjoint.
+    ///
+    /// This is used when the headers describe different samples, e.g., per-sample outputs that
+    /// are combined into a multi-sample VCF.
+    Disjoint,
+}
+
+/// An error returned when VCF headers fail to merge.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MergeError {
+    /// An info field has different definitions in the headers.
+    ConflictingInfoDefinition {
+        /// The info field key.
+        key: crate::record::info::field::Key,
+        /// The number in the first header.
+        number_a: Number,
+        /// The type in the first header.
+        type_a: info::Type,
+        /// The number in the second header.
+        number_b: Number,
+        /// The type in the second header.
+        type_b: info::Type,
+    },
+    /// A genotype field has different definitions in the headers.
+    ConflictingFormatDefinition {
+        /// The genotype field key.
+        key: crate::record::genotypes::keys::Key,
+        /// The number in the first header.
+        number_a: Number,
+        /// The type in the first header.
+        type_a: format::Type,
+        /// The number in the second header.
+        number_b: Number,
+        /// The type in the second header.
+        type_b: format::Type,
+    },
+    /// A contig has different lengths in the headers.
+    ConflictingContigLength {
+        /// The contig name.
+        name: contig::Name,
+        /// The length in the first header.
+        len_a: usize,
+        /// The length in the second header.
+        len_b: usize,
+    },
+    /// A sample name is in both headers when merging with [`MergeMode::Disjoint`].
+    DuplicateSampleName(String),
+}
+
+impl error::Error for MergeError {}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConflictingInfoDefinition {
+                key,
+                number_a,
+                type_a,
+                number_b,
+                type_b,
+            } => write!(
+                f,
+                "conflicting info definition: {key}: expected Number={number_a},Type={type_a}, got Number={number_b},Type={type_b}"
+            ),
+            Self::ConflictingFormatDefinition {
+                key,
+                number_a,
+                type_a,
+                number_b,
+                type_b,
+            } => write!(
+                f,
+                "conflicting format definition: {key}: expected Number={number_a},Type={type_a}, got Number={number_b},Type={type_b}"
+            ),
+            Self::ConflictingContigLength { name, len_a, len_b } => write!(
+                f,
+                "conflicting contig length: {name}: expected {len_a}, got {len_b}"
+            ),
+            Self::DuplicateSampleName(sample_name) => {
+                write!(f, "duplicate sample name: {sample_name}")
+            }
+        }
+    }
+}
+
+pub(super) fn merge(
+    mut dst: Header,
+    src: Header,
+    mode: MergeMode,
+    normalize_names: bool,
+) -> Result<Header, MergeError> {
+    use noodles_core::region::name::find;
+
+    dst.file_format = dst.file_format.min(src.file_format);
+
+    for (key, info) in src.infos {
+        if let Some(prev_info) = dst.infos.get(&key) {
+            let (number_a, type_a) = (prev_info.number(), prev_info.ty());
+            let (number_b, type_b) = (info.number(), info.ty());
+
+            if number_a != number_b || type_a != type_b {
+                return Err(MergeError::ConflictingInfoDefinition {
+                    key,
+                    number_a,
+                    type_a,
+                    number_b,
+                    type_b,
+                });
+            }
+        } else {
+            dst.infos.insert(key, info);
+        }
+    }
+
+    for (id, filter) in src.filters {
+        dst.filters.entry(id).or_insert(filter);
+    }
+
+    for (key, format) in src.formats {
+        if let Some(prev_format) = dst.formats.get(&key) {
+            let (number_a, type_a) = (prev_format.number(), prev_format.ty());
+            let (number_b, type_b) = (format.number(), format.ty());
+
+            if number_a != number_b || type_a != type_b {
+                return Err(MergeError::ConflictingFormatDefinition {
+                    key,
+                    number_a,
+                    type_a,
+                    number_b,
+                    type_b,
+                });
+            }
+        } else {
+            dst.formats.insert(key, format);
+        }
+    }
+
+    for (symbol, alternative_allele) in src.alternative_alleles {
+        dst.alternative_alleles
+            .entry(symbol)
+            .or_insert(alternative_allele);
+    }
+
+    for (name, contig) in src.contigs {
+        let i = if normalize_names {
+            find(dst.contigs.keys().map(|n| n.as_ref()), name.as_ref())
+        } else {
+            dst.contigs.get_index_of(&name)
+        };
+
+        if let Some((_, prev_contig)) = i.and_then(|i| dst.contigs.get_index_mut(i)) {
+            match (prev_contig.length(), contig.length()) {
+                (Some(len_a), Some(len_b)) if len_a != len_b => {
+                    return Err(MergeError::ConflictingContigLength { name, len_a, len_b });
+                }
+                (None, Some(len_b)) => *prev_contig.length_mut() = Some(len_b),
+                _ => {}
+            }
+        } else {
+            dst.contigs.insert(name, contig);
+        }
+    }
+
+    for sample_name in src.sample_names {
+        if dst.sample_names.contains(&sample_name) {
+            if mode == MergeMode::Disjoint {
+                return Err(MergeError::DuplicateSampleName(sample_name));
+            }
+        } else {
+            dst.sample_names.insert(sample_name);
+        }
+    }
+
+    for (key, collection) in src.other_records {
+        if let Some(prev_collection) = dst.other_records.get_mut(&key) {
+            merge_collections(prev_collection, collection);
+        } else {
+            dst.other_records.insert(key, collection);
+        }
+    }
+
+    Ok(dst)
+}
+
+// Collections of different kinds are not merged; the first is kept.
+fn merge_collections(dst: &mut Collection, src: Collection) {
+    match (dst, src) {
+        (Collection::Unstructured(dst), Collection::Unstructured(src)) => {
+            for value in src {
+                if !dst.contains(&value) {
+                    dst.push(value);
+                }
+            }
+        }
+        (Collection::Structured(dst), Collection::Structured(src)) => {
+            for (id, map) in src {
+                dst.entry(id).or_insert(map);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        header::{
+            record::value::{
+                map::{Contig, Filter, Format, Info},
+                Map,
+            },
+            FileFormat,
+        },
+        record::{genotypes::keys::key, info::field::key as info_key},
+    };
+
+    #[test]
+    fn test_merge() -> Result<(), Box<dyn std::error::Error>> {
+        let a = Header::builder()
+            .set_file_format(FileFormat::new(4, 4))
+            .add_info(
+                info_key::TOTAL_DEPTH,
+                Map::<Info>::from(&info_key::TOTAL_DEPTH),
+            )
+            .add_filter("PASS", Map::<Filter>::pass())
+            .add_format(key::GENOTYPE, Map::<Format>::from(&key::GENOTYPE))
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_sample_name("sample0")
+            .insert("source".parse()?, "noodles".into())?
+            .build();
+
+        let mut contig = Map::<Contig>::new();
+        *contig.length_mut() = Some(8);
+
+        let b = Header::builder()
+            .set_file_format(FileFormat::new(4, 3))
+            .add_info(
+                info_key::TOTAL_DEPTH,
+                Map::<Info>::from(&info_key::TOTAL_DEPTH),
+            )
+            .add_info(
+                info_key::ALLELE_FREQUENCIES,
+                Map::<Info>::from(&info_key::ALLELE_FREQUENCIES),
+            )
+            .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+            .add_format(key::GENOTYPE, Map::<Format>::from(&key::GENOTYPE))
+            .add_format(
+                key::CONDITIONAL_GENOTYPE_QUALITY,
+                Map::<Format>::from(&key::CONDITIONAL_GENOTYPE_QUALITY),
+            )
+            .add_contig("sq0".parse()?, contig.clone())
+            .add_contig("sq1".parse()?, Map::<Contig>::new())
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .insert("source".parse()?, "noodles".into())?
+            .insert("source".parse()?, "ndls".into())?
+            .build();
+
+        let actual = merge(a, b, MergeMode::Union, false)?;
+
+        let expected = Header::builder()
+            .set_file_format(FileFormat::new(4, 3))
+            .add_info(
+                info_key::TOTAL_DEPTH,
+                Map::<Info>::from(&info_key::TOTAL_DEPTH),
+            )
+            .add_info(
+                info_key::ALLELE_FREQUENCIES,
+                Map::<Info>::from(&info_key::ALLELE_FREQUENCIES),
+            )
+            .add_filter("PASS", Map::<Filter>::pass())
+            .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+            .add_format(key::GENOTYPE, Map::<Format>::from(&key::GENOTYPE))
+            .add_format(
+                key::CONDITIONAL_GENOTYPE_QUALITY,
+                Map::<Format>::from(&key::CONDITIONAL_GENOTYPE_QUALITY),
+            )
+            .add_contig("sq0".parse()?, contig)
+            .add_contig("sq1".parse()?, Map::<Contig>::new())
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .insert("source".parse()?, "noodles".into())?
+            .insert("source".parse()?, "ndls".into())?
+            .build();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_disjoint_mode() -> Result<(), Box<dyn std::error::Error>> {
+        let a = Header::builder().add_sample_name("sample0").build();
+        let b = Header::builder().add_sample_name("sample1").build();
+
+        let actual = merge(a.clone(), b, MergeMode::Disjoint, false)?;
+        let expected = Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build();
+        assert_eq!(actual, expected);
+
+        let b = Header::builder()
+            .add_sample_name("sample1")
+            .add_sample_name("sample0")
+            .build();
+
+        assert_eq!(
+            merge(a, b, MergeMode::Disjoint, false),
+            Err(MergeError::DuplicateSampleName(String::from("sample0")))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_conflicts() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::Number;
+
+        let a = Header::builder()
+            .add_info(
+                info_key::TOTAL_DEPTH,
+                Map::<Info>::new(Number::Count(1), info::Type::Integer, "Total depth"),
+            )
+            .build();
+
+        let b = Header::builder()
+            .add_info(
+                info_key::TOTAL_DEPTH,
+                Map::<Info>::new(Number::Count(1), info::Type::Float, "Total depth"),
+            )
+            .build();
+
+        assert_eq!(
+            merge(a, b, MergeMode::Union, false),
+            Err(MergeError::ConflictingInfoDefinition {
+                key: info_key::TOTAL_DEPTH,
+                number_a: Number::Count(1),
+                type_a: info::Type::Integer,
+                number_b: Number::Count(1),
+                type_b: info::Type::Float,
+            })
+        );
+
+        let a = Header::builder()
+            .add_format(
+                key::READ_DEPTH,
+                Map::<Format>::new(Number::Count(1), format::Type::Integer, "Read depth"),
+            )
+            .build();
+
+        let b = Header::builder()
+            .add_format(
+                key::READ_DEPTH,
+                Map::<Format>::new(Number::R, format::Type::Integer, "Read depth"),
+            )
+            .build();
+
+        assert_eq!(
+            merge(a, b, MergeMode::Union, false),
+            Err(MergeError::ConflictingFormatDefinition {
+                key: key::READ_DEPTH,
+                number_a: Number::Count(1),
+                type_a: format::Type::Integer,
+                number_b: Number::R,
+                type_b: format::Type::Integer,
+            })
+        );
+
+        let mut contig_a = Map::<Contig>::new();
+        *contig_a.length_mut() = Some(8);
+        let a = Header::builder()
+            .add_contig("sq0".parse()?, contig_a)
+            .build();
+
+        let mut contig_b = Map::<Contig>::new();
+        *contig_b.length_mut() = Some(13);
+        let b = Header::builder()
+            .add_contig("sq0".parse()?, contig_b)
+            .build();
+
+        assert_eq!(
+            merge(a, b, MergeMode::Union, false),
+            Err(MergeError::ConflictingContigLength {
+                name: "sq0".parse()?,
+                len_a: 8,
+                len_b: 13
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_normalized_names() -> Result<(), Box<dyn std::error::Error>> {
+        fn build_header(
+            contigs: &[(&str, Option<usize>)],
+        ) -> Result<Header, Box<dyn std::error::Error>> {
+            let mut builder = Header::builder();
+
+            for &(name, len) in contigs {
+                let mut contig = Map::<Contig>::new();
+                *contig.length_mut() = len;
+                builder = builder.add_contig(name.parse()?, contig);
+            }
+
+            Ok(builder.build())
+        }
+
+        let a = build_header(&[("chr1", None), ("chrM", Some(16569))])?;
+        let b = build_header(&[("1", Some(8)), ("MT", Some(16569)), ("2", None)])?;
+
+        let actual = merge(a.clone(), b.clone(), MergeMode::Union, true)?;
+        let expected = build_header(&[("chr1", Some(8)), ("chrM", Some(16569)), ("2", None)])?;
+        assert_eq!(actual, expected);
+
+        // Names are compared exactly unless normalization is enabled.
+        let actual = merge(a, b, MergeMode::Union, false)?;
+        let expected = build_header(&[
+            ("chr1", None),
+            ("chrM", Some(16569)),
+            ("1", Some(8)),
+            ("MT", Some(16569)),
+            ("2", None),
+        ])?;
+        assert_eq!(actual, expected);
+
+        let a = build_header(&[("chr1", Some(8))])?;
+        let b = build_header(&[("1", Some(13))])?;
+
+        assert_eq!(
+            merge(a, b, MergeMode::Union, true),
+            Err(MergeError::ConflictingContigLength {
+                name: "1".parse()?,
+                len_a: 8,
+                len_b: 13
+            })
+        );
+
+        Ok(())
+    }
+}
@@ -13,7 +13,7 @@ use indexmap::IndexSet;
 use super::{
     file_format::{self, FileFormat},
     record::Record,
-    Header,
+    Header, SampleNames,
 };
 
 /// A VCF header parser.
@@ -30,7 +30,7 @@ impl Parser {
 
     /// Parses a raw VCF header.
     pub fn parse(&self, s: &str) -> Result<Header, ParseError> {
-        let mut builder = Header::builder();
+        let mut header = Header::default();
         let mut lines = s.lines();
 
         let line = lines.next().ok_or(ParseError::MissingFileFormat)?;
@@ -42,18 +42,18 @@ impl Parser {
             Err(e) => return Err(e),
         };
 
-        builder = builder.set_file_format(file_format);
+        *header.file_format_mut() = file_format;
 
         let mut has_header = false;
 
         for line in &mut lines {
             if line.starts_with("#CHROM") {
-                builder = parse_header(builder, line)?;
+                *header.sample_names_mut() = parse_sample_names(line)?;
                 has_header = true;
                 break;
             }
 
-            builder = add_record(file_format, builder, line)?;
+            add_record(file_format, &mut header, line)?;
         }
 
         if !has_header {
@@ -64,10 +64,112 @@ impl Parser {
             return Err(ParseError::ExpectedEof);
         }
 
-        Ok(builder.build())
+        Ok(header)
+    }
+
+    /// Parses a raw VCF header, collecting errors rather than stopping at the first one.
+    ///
+    /// This always returns a best-effort header along with the errors encountered. Each error is
+    /// paired with the 1-based line number it occurred on and, if the line was not added to the
+    /// header, the raw line. A missing header (`#CHROM`...) is reported on the line after the last
+    /// line and has no raw line.
+    ///
+    /// Malformed lines are not added to the header. Use the raw lines to, e.g., write them back
+    /// as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::{parser::ParseError, Parser};
+    ///
+    /// let s = "##fileformat=VCFv4.3
+    /// ###INFO=<ID=NS,Number=1>
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// ";
+    ///
+    /// let (header, errors) = Parser::default().parse_lenient(s);
+    ///
+    /// assert_eq!(errors.len(), 1);
+    /// assert!(matches!(
+    ///     errors[0],
+    ///     (2, ParseError::InvalidRecord(_), Some("##INFO=<ID=NS,Number=1>"))
+    /// ));
+    ///
+    /// assert!(header.infos().is_empty());
+    /// assert!(header.get("INFO").is_none());
+    /// ```
+    pub fn parse_lenient<'a>(&self, s: &'a str) -> (Header, Vec<LenientParseError<'a>>) {
+        let mut header = Header::default();
+        let mut errors = Vec::new();
+
+        let mut lines = s
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line))
+            .peekable();
+
+        let file_format = match lines.peek().map(|(_, line)| parse_file_format(line)) {
+            Some(Ok(file_format)) => {
+                lines.next();
+                file_format
+            }
+            Some(Err(ParseError::MissingFileFormat)) | None => {
+                errors.push((1, ParseError::MissingFileFormat, None));
+                FileFormat::default()
+            }
+            Some(Err(e)) => {
+                let line = lines.next().map(|(_, line)| line);
+                errors.push((1, e, line));
+                FileFormat::default()
+            }
+        };
+
+        let file_format = match self.file_format_option {
+            FileFormatOption::Auto => file_format,
+            FileFormatOption::FileFormat(f) => f,
+        };
+
+        *header.file_format_mut() = file_format;
+
+        let mut has_header = false;
+        let mut line_count = 0;
+
+        for (line_number, line) in &mut lines {
+            line_count = line_number;
+
+            if line.starts_with("#CHROM") {
+                match parse_sample_names(line) {
+                    Ok(sample_names) => *header.sample_names_mut() = sample_names,
+                    Err(e) => errors.push((line_number, e, Some(line))),
+                }
+
+                has_header = true;
+                break;
+            }
+
+            if let Err(e) = add_record(file_format, &mut header, line) {
+                errors.push((line_number, e, Some(line)));
+            }
+        }
+
+        if !has_header {
+            errors.push((line_count + 1, ParseError::MissingHeader, None));
+        }
+
+        if let Some((line_number, line)) = lines.next() {
+            errors.push((line_number, ParseError::ExpectedEof, Some(line)));
+        }
+
+        (header, errors)
     }
 }
 
+/// An error encountered when leniently parsing a raw VCF header.
+///
+/// This is the 1-based line number, the error, and the raw line, if it was not added to the
+/// header.
+pub type LenientParseError<'a> = (usize, ParseError, Option<&'a str>);
+
 /// An error returned when a raw VCF header fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
@@ -142,32 +244,38 @@ fn parse_file_format(s: &str) -> Result<FileFormat, ParseError> {
     }
 }
 
-fn add_record(
-    file_format: FileFormat,
-    mut builder: super::Builder,
-    line: &str,
-) -> Result<super::Builder, ParseError> {
+fn add_record(file_format: FileFormat, header: &mut Header, line: &str) -> Result<(), ParseError> {
     let record =
         record::parse_record(line.as_bytes(), file_format).map_err(ParseError::InvalidRecord)?;
 
-    builder = match record {
+    match record {
         Record::FileFormat(_) => return Err(ParseError::UnexpectedFileFormat),
-        Record::Info(id, info) => builder.add_info(id, info),
-        Record::Filter(id, filter) => builder.add_filter(id, filter),
-        Record::Format(id, format) => builder.add_format(id, format),
+        Record::Info(id, info) => {
+            header.infos_mut().insert(id, info);
+        }
+        Record::Filter(id, filter) => {
+            header.filters_mut().insert(id, filter);
+        }
+        Record::Format(id, format) => {
+            header.formats_mut().insert(id, format);
+        }
         Record::AlternativeAllele(id, alternative_allele) => {
-            builder.add_alternative_allele(id, alternative_allele)
+            header
+                .alternative_alleles_mut()
+                .insert(id, alternative_allele);
+        }
+        Record::Contig(id, contig) => {
+            header.contigs_mut().insert(id, contig);
         }
-        Record::Contig(id, contig) => builder.add_contig(id, contig),
-        Record::Other(key, value) => builder
+        Record::Other(key, value) => header
             .insert(key, value)
             .map_err(ParseError::InvalidRecordValue)?,
-    };
+    }
 
-    Ok(builder)
+    Ok(())
 }
 
-fn parse_header(mut builder: super::Builder, line: &str) -> Result<super::Builder, ParseError> {
+fn parse_sample_names(line: &str) -> Result<SampleNames, ParseError> {
     static HEADERS: &[&str] = &[
         "#CHROM", "POS", "ID", "REF", "ALT", "QUAL", "FILTER", "INFO",
     ];
@@ -201,10 +309,10 @@ fn parse_header(mut builder: super::Builder, line: &str) -> Result<super::Builde
             }
         }
 
-        builder = builder.set_sample_names(sample_names);
+        Ok(sample_names)
+    } else {
+        Ok(SampleNames::new())
     }
-
-    Ok(builder)
 }
 
 #[cfg(test)]
@@ -400,4 +508,110 @@ mod tests {
             Err(ParseError::DuplicateSampleName(String::from("sample0")))
         );
     }
+
+    #[test]
+    fn test_parse_lenient() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::record::value::{map::Filter, Map};
+
+        let s = "##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1>
+##FILTER=<ID=q10,Description=\"Quality below 10\">
+##fileformat=VCFv4.3
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0	sample0
+";
+
+        let (header, errors) = Parser::default().parse_lenient(s);
+
+        assert_eq!(errors.len(), 3);
+        assert!(matches!(
+            errors[0],
+            (
+                2,
+                ParseError::InvalidRecord(_),
+                Some("##INFO=<ID=NS,Number=1>")
+            )
+        ));
+        assert_eq!(
+            errors[1],
+            (
+                4,
+                ParseError::UnexpectedFileFormat,
+                Some("##fileformat=VCFv4.3")
+            )
+        );
+        assert_eq!(
+            errors[2],
+            (
+                5,
+                ParseError::DuplicateSampleName(String::from("sample0")),
+                Some("#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample0")
+            )
+        );
+
+        assert_eq!(header.file_format(), FileFormat::new(4, 3));
+        assert!(header.infos().is_empty());
+        assert_eq!(
+            header.filters().get("q10"),
+            Some(&Map::<Filter>::new("Quality below 10"))
+        );
+        assert!(header.get("INFO").is_none());
+        assert!(header.get("fileformat").is_none());
+        assert!(header.sample_names().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_lenient_with_valid_header() -> Result<(), ParseError> {
+        let s = "##fileformat=VCFv4.3
+##FILTER=<ID=q10,Description=\"Quality below 10\">
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0
+";
+
+        let parser = Parser::default();
+        let (header, errors) = parser.parse_lenient(s);
+
+        assert!(errors.is_empty());
+        assert_eq!(header, parser.parse(s)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_lenient_with_missing_file_format_and_header() {
+        let s = r#"##FILTER=<ID=q10,Description="Quality below 10">
+"#;
+
+        let (header, errors) = Parser::default().parse_lenient(s);
+
+        assert_eq!(
+            errors,
+            [
+                (1, ParseError::MissingFileFormat, None),
+                (2, ParseError::MissingHeader, None)
+            ]
+        );
+
+        assert_eq!(header.file_format(), FileFormat::default());
+        assert_eq!(header.filters().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_lenient_with_data_after_header() {
+        let s = "##fileformat=VCFv4.3
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+##contig=<ID=sq0,length=8>
+";
+
+        let (header, errors) = Parser::default().parse_lenient(s);
+        assert_eq!(
+            errors,
+            [(
+                3,
+                ParseError::ExpectedEof,
+                Some("##contig=<ID=sq0,length=8>")
+            )]
+        );
+        assert!(header.contigs().is_empty());
+    }
 }
@@ -0,0 +1,405 @@
+use std::{error, fmt};
+
+use super::{
+    record::value::map::{format, info},
+    Header, Number,
+};
+use crate::{
+    record::{genotypes, info::field},
+    Record,
+};
+
+/// An error returned when a VCF record does not conform to the header definitions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// An info field is not defined in the header.
+    UndefinedInfoField(field::Key),
+    /// An info field value does not have the type defined in the header.
+    InfoFieldTypeMismatch {
+        /// The info field key.
+        key: field::Key,
+        /// The type defined in the header.
+        expected: info::Type,
+    },
+    /// An info field value does not have the number of values defined in the header.
+    InvalidInfoFieldValueCount {
+        /// The info field key.
+        key: field::Key,
+        /// The number of values defined in the header.
+        expected: usize,
+        /// The actual number of values.
+        actual: usize,
+    },
+    /// A genotype field is not defined in the header.
+    UndefinedFormatField(genotypes::keys::Key),
+    /// A genotype field value does not have the type defined in the header.
+    FormatFieldTypeMismatch {
+        /// The index of the sample.
+        sample_index: usize,
+        /// The genotype field key.
+        key: genotypes::keys::Key,
+        /// The type defined in the header.
+        expected: format::Type,
+    },
+    /// A genotype field value does not have the number of values defined in the header.
+    InvalidFormatFieldValueCount {
+        /// The index of the sample.
+        sample_index: usize,
+        /// The genotype field key.
+        key: genotypes::keys::Key,
+        /// The number of values defined in the header.
+        expected: usize,
+        /// The actual number of values.
+        actual: usize,
+    },
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UndefinedInfoField(key) => write!(f, "undefined info field: {key}"),
+            Self::InfoFieldTypeMismatch { key, expected } => {
+                write!(f, "info field type mismatch: {key}: expected {expected}")
+            }
+            Self::InvalidInfoFieldValueCount {
+                key,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "invalid info field value count: {key}: expected {expected}, got {actual}"
+            ),
+            Self::UndefinedFormatField(key) => write!(f, "undefined format field: {key}"),
+            Self::FormatFieldTypeMismatch {
+                sample_index,
+                key,
+                expected,
+            } => write!(
+                f,
+                "format field type mismatch: sample {sample_index}: {key}: expected {expected}"
+            ),
+            Self::InvalidFormatFieldValueCount {
+                sample_index,
+                key,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "invalid format field value count: sample {sample_index}: {key}: expected {expected}, got {actual}"
+            ),
+        }
+    }
+}
+
+pub(super) fn validate_record(
+    header: &Header,
+    record: &Record,
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::new();
+
+    let allele_count = record.alternate_bases().len() + 1;
+
+    validate_info(header, record, allele_count, &mut errors);
+    validate_genotypes(header, record, allele_count, &mut errors);
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn validate_info(
+    header: &Header,
+    record: &Record,
+    allele_count: usize,
+    errors: &mut Vec<ValidationError>,
+) {
+    for (key, value) in record.info().keys().zip(record.info().values()) {
+        let definition = match header.infos().get(key) {
+            Some(definition) => definition,
+            None => {
+                errors.push(ValidationError::UndefinedInfoField(key.clone()));
+                continue;
+            }
+        };
+
+        let value = match value {
+            Some(value) => value,
+            None => continue,
+        };
+
+        if !info_type_matches(definition.ty(), value) {
+            errors.push(ValidationError::InfoFieldTypeMismatch {
+                key: key.clone(),
+                expected: definition.ty(),
+            });
+
+            continue;
+        }
+
+        // The number of genotypes is undefined without a ploidy.
+        let expected = match expected_value_count(definition.number(), allele_count, None) {
+            Some(n) => n,
+            None => continue,
+        };
+
+        let actual = match value {
+            field::Value::Flag => 0,
+            field::Value::Array(array) => info_array_len(array),
+            _ => 1,
+        };
+
+        if actual != expected {
+            errors.push(ValidationError::InvalidInfoFieldValueCount {
+                key: key.clone(),
+                expected,
+                actual,
+            });
+        }
+    }
+}
+
+fn validate_genotypes(
+    header: &Header,
+    record: &Record,
+    allele_count: usize,
+    errors: &mut Vec<ValidationError>,
+) {
+    use crate::record::genotypes::sample::Value;
+
+    let genotypes = record.genotypes();
+    let keys = genotypes.keys();
+
+    for key in keys.iter() {
+        if !header.formats().contains_key(key) {
+            errors.push(ValidationError::UndefinedFormatField(key.clone()));
+        }
+    }
+
+    for (sample_index, sample) in genotypes.values().enumerate() {
+        let ploidy = sample
+            .genotype()
+            .and_then(|result| result.ok())
+            .map(|genotype| genotype.len());
+
+        for (key, value) in keys.iter().zip(sample.values()) {
+            let (definition, value) = match (header.formats().get(key), value) {
+                (Some(definition), Some(value)) => (definition, value),
+                _ => continue,
+            };
+
+            if !format_type_matches(definition.ty(), value) {
+                errors.push(ValidationError::FormatFieldTypeMismatch {
+                    sample_index,
+                    key: key.clone(),
+                    expected: definition.ty(),
+                });
+
+                continue;
+            }
+
+            let expected = match expected_value_count(definition.number(), allele_count, ploidy) {
+                Some(n) => n,
+                None => continue,
+            };
+
+            let actual = match value {
+                Value::Array(array) => format_array_len(array),
+                _ => 1,
+            };
+
+            if actual != expected {
+                errors.push(ValidationError::InvalidFormatFieldValueCount {
+                    sample_index,
+                    key: key.clone(),
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+}
+
+fn info_type_matches(ty: info::Type, value: &field::Value) -> bool {
+    use field::value::Array;
+
+    matches!(
+        (ty, value),
+        (info::Type::Integer, field::Value::Integer(_))
+            | (info::Type::Integer, field::Value::Array(Array::Integer(_)))
+            | (info::Type::Float, field::Value::Float(_))
+            | (info::Type::Float, field::Value::Array(Array::Float(_)))
+            | (info::Type::Flag, field::Value::Flag)
+            | (info::Type::Character, field::Value::Character(_))
+            | (
+                info::Type::Character,
+                field::Value::Array(Array::Character(_))
+            )
+            | (info::Type::String, field::Value::String(_))
+            | (info::Type::String, field::Value::Array(Array::String(_)))
+    )
+}
+
+fn format_type_matches(ty: format::Type, value: &genotypes::sample::Value) -> bool {
+    use genotypes::sample::{value::Array, Value};
+
+    matches!(
+        (ty, value),
+        (format::Type::Integer, Value::Integer(_))
+            | (format::Type::Integer, Value::Array(Array::Integer(_)))
+            | (format::Type::Float, Value::Float(_))
+            | (format::Type::Float, Value::Array(Array::Float(_)))
+            | (format::Type::Character, Value::Character(_))
+            | (format::Type::Character, Value::Array(Array::Character(_)))
+            | (format::Type::String, Value::String(_))
+            | (format::Type::String, Value::Array(Array::String(_)))
+    )
+}
+
+fn info_array_len(array: &field::value::Array) -> usize {
+    use field::value::Array;
+
+    match array {
+        Array::Integer(values) => values.len(),
+        Array::Float(values) => values.len(),
+        Array::Character(values) => values.len(),
+        Array::String(values) => values.len(),
+    }
+}
+
+fn format_array_len(array: &genotypes::sample::value::Array) -> usize {
+    use genotypes::sample::value::Array;
+
+    match array {
+        Array::Integer(values) => values.len(),
+        Array::Float(values) => values.len(),
+        Array::Character(values) => values.len(),
+        Array::String(values) => values.len(),
+    }
+}
+
+// Returns the number of values a field is expected to have, if known.
+fn expected_value_count(
+    number: Number,
+    allele_count: usize,
+    ploidy: Option<usize>,
+) -> Option<usize> {
+    match number {
+        Number::Count(n) => Some(n),
+        Number::A => Some(allele_count - 1),
+        Number::R => Some(allele_count),
+        Number::G => ploidy.map(|p| genotype_count(allele_count, p)),
+        Number::Unknown => None,
+    }
+}
+
+// The number of unordered genotypes of the given ploidy, i.e., C(allele_count + ploidy - 1,
+// ploidy).
+fn genotype_count(allele_count: usize, ploidy: usize) -> usize {
+    (1..=ploidy).fold(1, |n, i| n * (allele_count + i - 1) / i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = r#"##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data">
+##INFO=<ID=AF,Number=A,Type=Float,Description="Allele frequency">
+##INFO=<ID=DB,Number=0,Type=Flag,Description="dbSNP membership">
+##FORMAT=<ID=GT,Number=1,Type=String,Description="Genotype">
+##FORMAT=<ID=AD,Number=R,Type=Integer,Description="Read depth for each allele">
+##FORMAT=<ID=PL,Number=G,Type=Integer,Description="Phred-scaled genotype likelihoods">
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0	sample1
+"#
+        .parse()?;
+
+        let record = Record::try_from((&header, "sq0\t1\t.\tA\tC,G\t.\t.\tNS=2;AF=0.25,0.5;DB\tGT:AD:PL\t0/1:5,3,0:0,1,2,3,4,5\t1:2,1,1:0,1,2"))?;
+        assert_eq!(header.validate_record(&record), Ok(()));
+
+        let record = Record::try_from((
+            &header,
+            "sq0\t1\t.\tA\tC,G\t.\t.\tNS=2;AF=0.25;DP=8\tGT:AD:PL:GQ\t0/1:5,3:0,1,2:.\t.:.:.:.",
+        ))?;
+
+        assert_eq!(
+            header.validate_record(&record),
+            Err(vec![
+                ValidationError::InvalidInfoFieldValueCount {
+                    key: "AF".parse()?,
+                    expected: 2,
+                    actual: 1,
+                },
+                ValidationError::UndefinedInfoField("DP".parse()?),
+                ValidationError::UndefinedFormatField("GQ".parse()?),
+                ValidationError::InvalidFormatFieldValueCount {
+                    sample_index: 0,
+                    key: "AD".parse()?,
+                    expected: 3,
+                    actual: 2,
+                },
+                ValidationError::InvalidFormatFieldValueCount {
+                    sample_index: 0,
+                    key: "PL".parse()?,
+                    expected: 6,
+                    actual: 3,
+                },
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_record_with_type_mismatches() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::{genotypes::sample::Value, Genotypes};
+
+        let header: Header = r#"##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data">
+##FORMAT=<ID=DP,Number=1,Type=Integer,Description="Read depth">
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#
+        .parse()?;
+
+        let mut record = Record::try_from((&header, "sq0\t1\t.\tA\t.\t.\t.\t."))?;
+
+        record
+            .info_mut()
+            .insert("NS".parse()?, Some(field::Value::from("two")));
+
+        *record.genotypes_mut() = Genotypes::new(
+            vec!["DP".parse()?].try_into()?,
+            vec![vec![Some(Value::Float(8.0))]],
+        );
+
+        assert_eq!(
+            header.validate_record(&record),
+            Err(vec![
+                ValidationError::InfoFieldTypeMismatch {
+                    key: "NS".parse()?,
+                    expected: info::Type::Integer,
+                },
+                ValidationError::FormatFieldTypeMismatch {
+                    sample_index: 0,
+                    key: "DP".parse()?,
+                    expected: format::Type::Integer,
+                },
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_genotype_count() {
+        assert_eq!(genotype_count(2, 1), 2);
+        assert_eq!(genotype_count(2, 2), 3);
+        assert_eq!(genotype_count(3, 2), 6);
+        assert_eq!(genotype_count(2, 3), 4);
+    }
+}
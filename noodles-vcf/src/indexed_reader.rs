@@ -83,6 +83,35 @@ where
     R: Read + Seek,
 {
     /// Returns an iterator over records that intersects the given region.
+    ///
+    /// The region reference sequence name is resolved using the reference sequence names in the
+    /// tabix index header. Records are read from the chunks of the bins that overlap the region
+    /// and are filtered to those whose interval, from the start position to the end position (see
+    /// [`Record::end_position`]), intersects the region.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the index does not have a tabix header or if the region reference
+    /// sequence name is not in the index.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut reader = vcf::indexed_reader::Builder::default().build_from_path("sample.vcf.gz")?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let region = "sq0:8-13".parse()?;
+    /// let query = reader.query(&header, &region)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
     pub fn query<'r, 'h>(
         &'r mut self,
         header: &'h Header,
@@ -91,3 +120,63 @@ where
         self.inner.query(header, &self.index, region)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IndexedWriter;
+
+    fn build_record(
+        chromosome: &str,
+        position: usize,
+        alternate_bases: &str,
+        info: &str,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        use crate::record::Position;
+
+        Ok(Record::builder()
+            .set_chromosome(chromosome.parse()?)
+            .set_position(Position::from(position))
+            .set_reference_bases("A".parse()?)
+            .set_alternate_bases(alternate_bases.parse()?)
+            .set_info(info.parse()?)
+            .build()?)
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+
+        let records = [
+            build_record("sq0", 1, "<DEL>", "SVLEN=-20")?,
+            build_record("sq0", 3, "C", "NS=1")?,
+            build_record("sq0", 13, "G", "NS=1")?,
+            build_record("sq1", 8, "T", "NS=1")?,
+        ];
+
+        let mut buf = Vec::new();
+        let mut writer = IndexedWriter::new(&mut buf);
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_record(&header, record)?;
+        }
+
+        let index = writer.finish()?;
+
+        let mut reader = IndexedReader::new(io::Cursor::new(buf), index);
+        let header = reader.read_header()?;
+
+        let region = "sq0:8-13".parse()?;
+        let actual: Vec<_> = reader.query(&header, &region)?.collect::<io::Result<_>>()?;
+        assert_eq!(actual, [records[0].clone(), records[2].clone()]);
+
+        let region = "sq2".parse()?;
+        assert!(matches!(
+            reader.query(&header, &region),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
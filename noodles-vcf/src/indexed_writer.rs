@@ -0,0 +1,223 @@
+//! Indexed VCF writer.
+
+use std::{
+    collections::HashSet,
+    io::{self, Write},
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::{self as csi, index::reference_sequence::bin::Chunk};
+use noodles_tabix as tabix;
+
+use super::{Header, Record, Writer};
+
+/// An indexed VCF writer.
+///
+/// This writes a bgzipped, coordinate-sorted VCF and builds its tabix index at the same time,
+/// which removes the need to read the output again to index it.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, record::Position};
+///
+/// let header = vcf::Header::default();
+///
+/// let mut writer = vcf::IndexedWriter::new(Vec::new());
+/// writer.write_header(&header)?;
+///
+/// let record = vcf::Record::builder()
+///     .set_chromosome("sq0".parse()?)
+///     .set_position(Position::from(1))
+///     .set_reference_bases("A".parse()?)
+///     .build()?;
+///
+/// writer.write_record(&header, &record)?;
+///
+/// let index = writer.finish()?;
+/// assert_eq!(index.reference_sequences().len(), 1);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: Writer<bgzf::Writer<W>>,
+    indexer: tabix::index::Indexer,
+    reference_sequence_names: HashSet<String>,
+    last_sort_key: Option<(String, Position)>,
+}
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed VCF writer with a default compression level.
+    pub fn new(inner: W) -> Self {
+        let mut indexer = tabix::index::Indexer::default();
+        indexer.set_header(csi::index::header::Builder::vcf().build());
+
+        Self {
+            inner: Writer::new(bgzf::Writer::new(inner)),
+            indexer,
+            reference_sequence_names: HashSet::new(),
+            last_sort_key: None,
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &bgzf::Writer<W> {
+        self.inner.get_ref()
+    }
+
+    /// Writes a VCF header.
+    pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
+        self.inner.write_header(header)
+    }
+
+    /// Writes a record and adds it to the index.
+    ///
+    /// Records must be coordinate-sorted, i.e., grouped by chromosome and ordered by position
+    /// within each chromosome.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the record is out of order.
+    pub fn write_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
+        let reference_sequence_name = record.chromosome().to_string();
+
+        let start = Position::try_from(usize::from(record.position()))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let end = record
+            .end_position()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+            .and_then(|position| {
+                Position::try_from(usize::from(position))
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+            })?;
+
+        if !self.is_in_order(&reference_sequence_name, start) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "record is out of order: records must be coordinate-sorted to be indexed",
+            ));
+        }
+
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_record(header, record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        self.indexer.add_record(
+            &reference_sequence_name,
+            start,
+            end,
+            Chunk::new(start_position, end_position),
+        )?;
+
+        self.reference_sequence_names
+            .insert(reference_sequence_name.clone());
+        self.last_sort_key = Some((reference_sequence_name, start));
+
+        Ok(())
+    }
+
+    /// Finishes the output stream and returns the built index.
+    pub fn finish(mut self) -> io::Result<csi::Index> {
+        self.inner.get_mut().try_finish()?;
+        Ok(self.indexer.build())
+    }
+
+    fn is_in_order(&self, reference_sequence_name: &str, start: Position) -> bool {
+        match &self.last_sort_key {
+            Some((last_reference_sequence_name, last_start))
+                if last_reference_sequence_name == reference_sequence_name =>
+            {
+                *last_start <= start
+            }
+            _ => !self
+                .reference_sequence_names
+                .contains(reference_sequence_name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_record(
+        chromosome: &str,
+        position: usize,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        use crate::record::Position;
+
+        Ok(Record::builder()
+            .set_chromosome(chromosome.parse()?)
+            .set_position(Position::from(position))
+            .set_reference_bases("A".parse()?)
+            .build()?)
+    }
+
+    #[test]
+    fn test_write_record() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::IndexedReader;
+
+        let header = Header::default();
+
+        let records = [
+            build_record("sq0", 1)?,
+            build_record("sq0", 8)?,
+            build_record("sq1", 3)?,
+        ];
+
+        let mut buf = Vec::new();
+        let mut writer = IndexedWriter::new(&mut buf);
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_record(&header, record)?;
+        }
+
+        let index = writer.finish()?;
+
+        assert_eq!(index.reference_sequences().len(), 2);
+
+        let mut reader = IndexedReader::new(io::Cursor::new(buf), index);
+        let header = reader.read_header()?;
+
+        let region = "sq0:5-13".parse()?;
+        let actual: Vec<_> = reader.query(&header, &region)?.collect::<io::Result<_>>()?;
+        assert_eq!(actual, [records[1].clone()]);
+
+        let region = "sq1".parse()?;
+        let actual: Vec<_> = reader.query(&header, &region)?.collect::<io::Result<_>>()?;
+        assert_eq!(actual, [records[2].clone()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_record(&header, &build_record("sq0", 8)?)?;
+
+        assert!(matches!(
+            writer.write_record(&header, &build_record("sq0", 1)?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        writer.write_record(&header, &build_record("sq1", 1)?)?;
+
+        assert!(matches!(
+            writer.write_record(&header, &build_record("sq0", 13)?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
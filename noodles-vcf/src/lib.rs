@@ -24,7 +24,9 @@ mod r#async;
 
 pub mod header;
 pub mod indexed_reader;
+pub mod indexed_writer;
 pub mod lazy;
+pub mod normalize;
 pub mod reader;
 pub mod record;
 mod variant_reader;
@@ -32,8 +34,9 @@ mod variant_writer;
 pub mod writer;
 
 pub use self::{
-    header::Header, indexed_reader::IndexedReader, reader::Reader, record::Record,
-    variant_reader::VariantReader, variant_writer::VariantWriter, writer::Writer,
+    header::Header, indexed_reader::IndexedReader, indexed_writer::IndexedWriter,
+    normalize::normalize_stream, reader::Reader, record::Record, variant_reader::VariantReader,
+    variant_writer::VariantWriter, writer::Writer,
 };
 
 #[cfg(feature = "async")]
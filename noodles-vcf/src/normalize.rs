@@ -0,0 +1,1389 @@
+//! VCF record normalization.
+//!
+//! Normalization represents a variant in its most parsimonious and left-aligned form. This is
+//! equivalent to `bcftools norm`.
+
+use std::{
+    collections::VecDeque,
+    error, fmt,
+    io::{self, BufRead, Write},
+};
+
+use noodles_fasta as fasta;
+
+use crate::{
+    header::Number,
+    record::{
+        alternate_bases::Allele,
+        genotypes::{self, keys::key, sample::Value},
+        info,
+        reference_bases::Base,
+        Chromosome, Position,
+    },
+    Header, Reader, Record, Writer,
+};
+
+/// The number of bases records are buffered for when sorting normalized records.
+///
+/// See [`normalize_stream`].
+pub const WINDOW_SIZE: usize = 1000;
+
+/// An error returned when a VCF record fails to normalize.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NormalizeError {
+    /// The record position is outside of the reference sequence.
+    PositionOutOfBounds(Position),
+    /// The reference bases do not match the reference sequence.
+    ReferenceMismatch(Position),
+}
+
+impl error::Error for NormalizeError {}
+
+impl fmt::Display for NormalizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PositionOutOfBounds(position) => {
+                write!(f, "position out of bounds: {position}")
+            }
+            Self::ReferenceMismatch(position) => {
+                write!(f, "reference bases mismatch at position {position}")
+            }
+        }
+    }
+}
+
+/// Normalizes a VCF record.
+///
+/// This trims bases common to all alleles and left-aligns the variant in the given reference
+/// sequence. The reference sequence is the full sequence of the record's chromosome.
+///
+/// Records with alternate alleles that are not bases (e.g., symbolic alleles) or that are equal to
+/// the reference bases are left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{normalize::normalize, record::Position, Record};
+///
+/// let mut record = Record::builder()
+///     .set_chromosome("sq0".parse()?)
+///     .set_position(Position::from(7))
+///     .set_reference_bases("ACA".parse()?)
+///     .set_alternate_bases("A".parse()?)
+///     .build()?;
+///
+/// normalize(&mut record, b"TTGCACACAT")?;
+///
+/// assert_eq!(record.position(), Position::from(3));
+/// assert_eq!(record.reference_bases().to_string(), "GCA");
+/// assert_eq!(record.alternate_bases().to_string(), "G");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn normalize(record: &mut Record, reference_sequence: &[u8]) -> Result<(), NormalizeError> {
+    let alleles = match base_alleles(record) {
+        Some(alleles) => alleles,
+        None => return Ok(()),
+    };
+
+    let (position, alleles) = normalize_alleles(
+        alleles,
+        usize::from(record.position()),
+        reference_sequence,
+        1,
+    )?;
+
+    set_alleles(record, position, alleles);
+
+    Ok(())
+}
+
+/// Left-aligns a VCF record in a region of a reference sequence.
+///
+/// This is similar to [`normalize`], but `reference_sequence` can be a subsequence of the
+/// record's chromosome, starting at the 1-based position `reference_sequence_start`. It must
+/// cover the record and the bases the variant is shifted over. If the variant can be shifted past
+/// the start of a subsequence, an error is returned rather than a partially left-aligned record.
+///
+/// Each alternate allele of a multiallelic record is normalized independently against the
+/// reference bases. The resulting alleles are then padded with reference bases to a common
+/// position and span, so the returned record represents each allele in its leftmost position.
+///
+/// Records with alternate alleles that are not bases (e.g., symbolic alleles) or that are equal to
+/// the reference bases are returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{normalize::normalize_left_align, record::Position, Record};
+///
+/// let record = Record::builder()
+///     .set_chromosome("sq0".parse()?)
+///     .set_position(Position::from(107))
+///     .set_reference_bases("ACA".parse()?)
+///     .set_alternate_bases("A,ACACA".parse()?)
+///     .build()?;
+///
+/// // sq0:101-110
+/// let reference_sequence = b"TTGCACACAT";
+/// let normalized_record = normalize_left_align(&record, reference_sequence, Position::from(101))?;
+///
+/// assert_eq!(normalized_record.position(), Position::from(103));
+/// assert_eq!(normalized_record.reference_bases().to_string(), "GCA");
+/// assert_eq!(normalized_record.alternate_bases().to_string(), "G,GCACA");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn normalize_left_align(
+    record: &Record,
+    reference_sequence: &[u8],
+    reference_sequence_start: Position,
+) -> Result<Record, NormalizeError> {
+    let mut record = record.clone();
+
+    let mut alleles = match base_alleles(&record) {
+        Some(alleles) => alleles,
+        None => return Ok(record),
+    };
+
+    let position = usize::from(record.position());
+    let reference_sequence_start = usize::from(reference_sequence_start);
+
+    if alleles.len() <= 2 {
+        let (position, alleles) = normalize_alleles(
+            alleles,
+            position,
+            reference_sequence,
+            reference_sequence_start,
+        )?;
+
+        set_alleles(&mut record, position, alleles);
+
+        return Ok(record);
+    }
+
+    let reference_bases = alleles.remove(0);
+    let mut normalized_alleles = Vec::with_capacity(alleles.len());
+
+    for alternate_bases in alleles {
+        let (position, mut alleles) = normalize_alleles(
+            vec![reference_bases.clone(), alternate_bases],
+            position,
+            reference_sequence,
+            reference_sequence_start,
+        )?;
+
+        let alternate_bases = alleles.pop().expect("missing alternate bases");
+        let end = position + alleles[0].len();
+        normalized_alleles.push((position, end, alternate_bases));
+    }
+
+    // SAFETY: `normalized_alleles` is not empty.
+    let start = normalized_alleles.iter().map(|(p, _, _)| *p).min().unwrap();
+    let end = normalized_alleles.iter().map(|(_, e, _)| *e).max().unwrap();
+
+    let reference_bases_between = |from: usize, to: usize| -> Result<Vec<Base>, NormalizeError> {
+        from.checked_sub(reference_sequence_start)
+            .and_then(|i| reference_sequence.get(i..i + (to - from)))
+            .map(|bases| bases.iter().copied().map(base_from_u8).collect())
+            .ok_or(NormalizeError::PositionOutOfBounds(Position::from(from)))
+    };
+
+    let mut alleles = vec![reference_bases_between(start, end)?];
+
+    for (position, allele_end, alternate_bases) in normalized_alleles {
+        let mut allele = reference_bases_between(start, position)?;
+        allele.extend(alternate_bases);
+        allele.extend(reference_bases_between(allele_end, end)?);
+        alleles.push(allele);
+    }
+
+    set_alleles(&mut record, start, alleles);
+
+    Ok(record)
+}
+
+// Returns the reference bases followed by the alternate bases, or `None` if any alternate allele
+// is not bases or is equal to the reference bases.
+fn base_alleles(record: &Record) -> Option<Vec<Vec<Base>>> {
+    let mut alleles = vec![record.reference_bases().to_vec()];
+
+    for allele in record.alternate_bases().iter() {
+        match allele {
+            Allele::Bases(bases) if bases.as_slice() != alleles[0].as_slice() => {
+                alleles.push(bases.clone());
+            }
+            _ => return None,
+        }
+    }
+
+    Some(alleles)
+}
+
+fn set_alleles(record: &mut Record, position: usize, alleles: Vec<Vec<Base>>) {
+    let mut alleles = alleles.into_iter();
+
+    if let Some(reference_bases) = alleles.next() {
+        *record.position_mut() = Position::from(position);
+        record.reference_bases_mut().0 = reference_bases;
+        *record.alternate_bases_mut() = alleles.map(Allele::Bases).collect::<Vec<_>>().into();
+    }
+}
+
+// Trims and left-aligns alleles, where the first allele is the reference bases.
+//
+// `reference_sequence_start` is the 1-based position of the first base of `reference_sequence`.
+fn normalize_alleles(
+    mut alleles: Vec<Vec<Base>>,
+    mut position: usize,
+    reference_sequence: &[u8],
+    reference_sequence_start: usize,
+) -> Result<(usize, Vec<Vec<Base>>), NormalizeError> {
+    validate_reference_bases(
+        reference_sequence,
+        reference_sequence_start,
+        Position::from(position),
+        &alleles[0],
+    )?;
+
+    loop {
+        let last_base = alleles[0].last().copied();
+
+        if alleles
+            .iter()
+            .any(|allele| allele.last().copied() != last_base)
+        {
+            break;
+        }
+
+        if alleles.iter().any(|allele| allele.len() == 1) {
+            if position <= 1 {
+                break;
+            }
+
+            position -= 1;
+
+            let base = position
+                .checked_sub(reference_sequence_start)
+                .and_then(|i| reference_sequence.get(i))
+                .copied()
+                .map(base_from_u8)
+                .ok_or(NormalizeError::PositionOutOfBounds(Position::from(
+                    position,
+                )))?;
+
+            for allele in &mut alleles {
+                allele.insert(0, base);
+            }
+        }
+
+        for allele in &mut alleles {
+            allele.pop();
+        }
+    }
+
+    while alleles.iter().all(|allele| allele.len() > 1)
+        && alleles.iter().all(|allele| allele[0] == alleles[0][0])
+    {
+        for allele in &mut alleles {
+            allele.remove(0);
+        }
+
+        position += 1;
+    }
+
+    Ok((position, alleles))
+}
+
+fn validate_reference_bases(
+    reference_sequence: &[u8],
+    reference_sequence_start: usize,
+    position: Position,
+    reference_bases: &[Base],
+) -> Result<(), NormalizeError> {
+    let expected_bases = usize::from(position)
+        .checked_sub(reference_sequence_start)
+        .and_then(|start| reference_sequence.get(start..start + reference_bases.len()))
+        .ok_or(NormalizeError::PositionOutOfBounds(position))?;
+
+    for (&expected_base, &actual_base) in expected_bases.iter().zip(reference_bases) {
+        let expected_base = base_from_u8(expected_base);
+
+        if expected_base != actual_base && expected_base != Base::N && actual_base != Base::N {
+            return Err(NormalizeError::ReferenceMismatch(position));
+        }
+    }
+
+    Ok(())
+}
+
+fn base_from_u8(b: u8) -> Base {
+    match b.to_ascii_uppercase() {
+        b'A' => Base::A,
+        b'C' => Base::C,
+        b'G' => Base::G,
+        b'T' => Base::T,
+        _ => Base::N,
+    }
+}
+
+/// How genotype (`GT`) alleles are recoded when a multiallelic record is split.
+///
+/// In either mode, the reference allele stays `0`, and the alternate allele of a split record
+/// becomes `1`.
+///
+/// The default is [`Self::Missing`], since a call of another alternate allele is not a call of
+/// the reference allele.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum GenotypeRecoding {
+    /// Other alternate alleles are set to the reference allele (`0`).
+    ///
+    /// This is the behavior of `bcftools norm --multiallelics -`. The `AC`, `AN`, and `AF` INFO
+    /// fields are subset but not recalculated.
+    Reference,
+    /// Other alternate alleles are set to missing (`.`).
+    ///
+    /// The allele count (`AC`), total allele count (`AN`), and allele frequency (`AF`) INFO fields
+    /// are recalculated from the recoded genotypes, as the missing alleles are no longer called.
+    #[default]
+    Missing,
+}
+
+/// Splits a multiallelic VCF record into biallelic records.
+///
+/// Each alternate allele is written to its own record. INFO and genotype fields with a number of
+/// `A`, `R`, or `G` are subset to the values of the allele, and genotypes (`GT`) are recoded so
+/// that other alternate alleles are set to missing. The `AC`, `AN`, and `AF` INFO fields are then
+/// recalculated from the recoded genotypes. See [`GenotypeRecoding::Missing`].
+///
+/// A record with at most one alternate allele is returned as is.
+///
+/// See [`split_multiallelics_with`] to set other alternate alleles to the reference allele
+/// instead.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, normalize::split_multiallelics, record::Position};
+///
+/// let header = vcf::Header::default();
+///
+/// let record = vcf::Record::builder()
+///     .set_chromosome("sq0".parse()?)
+///     .set_position(Position::from(1))
+///     .set_reference_bases("A".parse()?)
+///     .set_alternate_bases("C,G".parse()?)
+///     .build()?;
+///
+/// let records = split_multiallelics(&header, &record);
+/// assert_eq!(records.len(), 2);
+/// assert_eq!(records[0].alternate_bases().to_string(), "C");
+/// assert_eq!(records[1].alternate_bases().to_string(), "G");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn split_multiallelics(header: &Header, record: &Record) -> Vec<Record> {
+    split_multiallelics_with(header, record, GenotypeRecoding::default())
+}
+
+/// Splits a multiallelic VCF record into biallelic records, recoding genotypes with the given
+/// mode.
+///
+/// This is the same as [`split_multiallelics`], except other alternate alleles in genotypes are
+/// recoded according to `genotype_recoding`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, normalize::{split_multiallelics_with, GenotypeRecoding}};
+///
+/// let data = b"##fileformat=VCFv4.3
+/// ###INFO=<ID=AC,Number=A,Type=Integer,Description=\"Allele count\">
+/// ###INFO=<ID=AN,Number=1,Type=Integer,Description=\"Total allele count\">
+/// ###FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+/// ##CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+/// sq0\t1\t.\tA\tT,C\t.\t.\tAC=1,1;AN=2\tGT\t1/2
+/// ";
+///
+/// let mut reader = vcf::Reader::new(&data[..]);
+/// let header = reader.read_header()?;
+/// let record = reader.records(&header).next().transpose()?.unwrap();
+///
+/// let records = split_multiallelics_with(&header, &record, GenotypeRecoding::Reference);
+///
+/// assert_eq!(records[0].to_string(), "sq0\t1\t.\tA\tT\t.\t.\tAC=1;AN=2\tGT\t1/0");
+/// assert_eq!(records[1].to_string(), "sq0\t1\t.\tA\tC\t.\t.\tAC=1;AN=2\tGT\t0/1");
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn split_multiallelics_with(
+    header: &Header,
+    record: &Record,
+    genotype_recoding: GenotypeRecoding,
+) -> Vec<Record> {
+    let alternate_allele_count = record.alternate_bases().len();
+
+    if alternate_allele_count <= 1 {
+        return vec![record.clone()];
+    }
+
+    (1..=alternate_allele_count)
+        .map(|i| {
+            let mut split_record = record.clone();
+
+            *split_record.alternate_bases_mut() =
+                vec![record.alternate_bases()[i - 1].clone()].into();
+
+            split_info(header, split_record.info_mut(), alternate_allele_count, i);
+
+            *split_record.genotypes_mut() = split_genotypes(
+                header,
+                record.genotypes(),
+                alternate_allele_count,
+                i,
+                genotype_recoding,
+            );
+
+            if genotype_recoding == GenotypeRecoding::Missing {
+                update_allele_counts(&mut split_record);
+            }
+
+            split_record
+        })
+        .collect()
+}
+
+fn split_info(
+    header: &Header,
+    info: &mut info::Info,
+    alternate_allele_count: usize,
+    allele_index: usize,
+) {
+    use info::field::value::Array;
+
+    for (key, value) in info.as_mut().iter_mut() {
+        let number = match header.infos().get(key) {
+            Some(map) => map.number(),
+            None => continue,
+        };
+
+        if let Some(info::field::Value::Array(array)) = value {
+            let indices = match allele_indices(number, alternate_allele_count, allele_index) {
+                Some(indices) => indices,
+                None => continue,
+            };
+
+            let subset = match array {
+                Array::Integer(values) => select(values, &indices).map(Array::Integer),
+                Array::Float(values) => select(values, &indices).map(Array::Float),
+                Array::Character(values) => select(values, &indices).map(Array::Character),
+                Array::String(values) => select(values, &indices).map(Array::String),
+            };
+
+            *value = subset.map(info::field::Value::Array);
+        }
+    }
+}
+
+fn split_genotypes(
+    header: &Header,
+    genotypes: &genotypes::Genotypes,
+    alternate_allele_count: usize,
+    allele_index: usize,
+    genotype_recoding: GenotypeRecoding,
+) -> genotypes::Genotypes {
+    use genotypes::sample::value::Array;
+
+    let keys = genotypes.keys().clone();
+
+    let values = genotypes
+        .values()
+        .map(|sample| {
+            keys.iter()
+                .zip(sample.values())
+                .map(|(key, value)| {
+                    if key == &key::GENOTYPE {
+                        return match value {
+                            Some(Value::String(s)) => Some(Value::String(recode_genotype(
+                                s,
+                                allele_index,
+                                genotype_recoding,
+                            ))),
+                            _ => value.clone(),
+                        };
+                    }
+
+                    let number = match header.formats().get(key) {
+                        Some(map) => map.number(),
+                        None => return value.clone(),
+                    };
+
+                    let array = match value {
+                        Some(Value::Array(array)) => array,
+                        _ => return value.clone(),
+                    };
+
+                    let len = match array {
+                        Array::Integer(values) => values.len(),
+                        Array::Float(values) => values.len(),
+                        Array::Character(values) => values.len(),
+                        Array::String(values) => values.len(),
+                    };
+
+                    let indices = match number {
+                        Number::G => genotype_indices(len, alternate_allele_count, allele_index),
+                        _ => allele_indices(number, alternate_allele_count, allele_index),
+                    };
+
+                    let indices = match indices {
+                        Some(indices) => indices,
+                        None => return value.clone(),
+                    };
+
+                    let subset = match array {
+                        Array::Integer(values) => select(values, &indices).map(Array::Integer),
+                        Array::Float(values) => select(values, &indices).map(Array::Float),
+                        Array::Character(values) => select(values, &indices).map(Array::Character),
+                        Array::String(values) => select(values, &indices).map(Array::String),
+                    };
+
+                    subset.map(Value::Array)
+                })
+                .collect()
+        })
+        .collect();
+
+    genotypes::Genotypes::new(keys, values)
+}
+
+fn allele_indices(
+    number: Number,
+    alternate_allele_count: usize,
+    allele_index: usize,
+) -> Option<Vec<usize>> {
+    match number {
+        Number::A if alternate_allele_count > 0 => Some(vec![allele_index - 1]),
+        Number::R => Some(vec![0, allele_index]),
+        _ => None,
+    }
+}
+
+fn genotype_indices(
+    len: usize,
+    alternate_allele_count: usize,
+    allele_index: usize,
+) -> Option<Vec<usize>> {
+    let allele_count = alternate_allele_count + 1;
+
+    if len == allele_count {
+        // haploid
+        Some(vec![0, allele_index])
+    } else if len == allele_count * (allele_count + 1) / 2 {
+        // diploid: the index of genotype j/k (j <= k) is k * (k + 1) / 2 + j.
+        let k = allele_index * (allele_index + 1) / 2;
+        Some(vec![0, k, k + allele_index])
+    } else {
+        None
+    }
+}
+
+fn select<T>(values: &[Option<T>], indices: &[usize]) -> Option<Vec<Option<T>>>
+where
+    T: Clone,
+{
+    indices.iter().map(|&i| values.get(i).cloned()).collect()
+}
+
+fn recode_genotype(s: &str, allele_index: usize, genotype_recoding: GenotypeRecoding) -> String {
+    let mut dst = String::with_capacity(s.len());
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        if !c.is_ascii_digit() {
+            dst.push(c);
+            continue;
+        }
+
+        let mut end = start + 1;
+
+        while let Some((i, _)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+            end = i + 1;
+        }
+
+        match s[start..end].parse::<usize>() {
+            Ok(0) => dst.push('0'),
+            Ok(i) if i == allele_index => dst.push('1'),
+            Ok(_) => match genotype_recoding {
+                GenotypeRecoding::Reference => dst.push('0'),
+                GenotypeRecoding::Missing => dst.push('.'),
+            },
+            Err(_) => dst.push_str(&s[start..end]),
+        }
+    }
+
+    dst
+}
+
+// Recalculates the AC, AN, and AF INFO fields of a biallelic record from its genotypes.
+//
+// Only fields that are already in the record are updated. They are left unchanged if the record
+// has no genotypes (`GT`) or they are invalid.
+fn update_allele_counts(record: &mut Record) {
+    use info::field::key as info_key;
+
+    const KEYS: [info::field::Key; 3] = [
+        info_key::ALLELE_COUNT,
+        info_key::TOTAL_ALLELE_COUNT,
+        info_key::ALLELE_FREQUENCIES,
+    ];
+
+    if !record.genotypes().keys().contains(&key::GENOTYPE) {
+        return;
+    }
+
+    let is_present = KEYS.map(|k| record.info().as_ref().contains_key(&k));
+
+    if record.recompute_allele_count_info().is_err() {
+        return;
+    }
+
+    let info = record.info_mut().as_mut();
+
+    for (k, is_present) in KEYS.iter().zip(is_present) {
+        if !is_present {
+            info.shift_remove(k);
+        }
+    }
+}
+
+/// Normalizes all records in a VCF.
+///
+/// Each record is optionally split into biallelic records (see [`split_multiallelics`]) and then
+/// normalized (see [`normalize`]) using the reference sequence of its chromosome. Records with a
+/// symbolic chromosome are written unchanged.
+///
+/// The input is expected to be sorted. Since left-alignment can move a record to a lower
+/// position, normalized records are buffered and sorted in a window of [`WINDOW_SIZE`] bases: a
+/// record is written once the input position advances more than `WINDOW_SIZE` bases past it or
+/// the chromosome changes. This bounds memory usage while keeping output sorted, as long as no
+/// record is left-aligned by more than `WINDOW_SIZE` bases. If it is, an error is returned rather
+/// than writing unsorted output.
+///
+/// The header is not written.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+/// use noodles_vcf as vcf;
+///
+/// let data = b"##fileformat=VCFv4.3
+/// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+/// sq0\t7\t.\tACA\tA\t.\t.\t.
+/// ";
+/// let mut reader = vcf::Reader::new(&data[..]);
+/// let header = reader.read_header()?;
+///
+/// let reference_sequence_repository = fasta::Repository::new(vec![fasta::Record::new(
+///     Definition::new("sq0", None),
+///     Sequence::from(b"TTGCACACAT".to_vec()),
+/// )]);
+///
+/// let mut writer = vcf::Writer::new(Vec::new());
+///
+/// vcf::normalize_stream(
+///     &mut reader,
+///     &header,
+///     &reference_sequence_repository,
+///     &mut writer,
+///     false,
+/// )?;
+///
+/// assert_eq!(writer.get_ref(), b"sq0\t3\t.\tGCA\tG\t.\t.\t.\n");
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn normalize_stream<R, W>(
+    reader: &mut Reader<R>,
+    header: &Header,
+    reference_sequence_repository: &fasta::Repository,
+    writer: &mut Writer<W>,
+    split_multiallelic_records: bool,
+) -> io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
+    let mut window = Window::default();
+    let mut chromosome: Option<Chromosome> = None;
+    let mut reference_sequence: Option<fasta::record::Sequence> = None;
+
+    for result in reader.records(header) {
+        let record = result?;
+
+        if chromosome.as_ref() != Some(record.chromosome()) {
+            window.flush(header, writer, usize::MAX)?;
+            window.last_position = None;
+
+            chromosome = Some(record.chromosome().clone());
+
+            reference_sequence = match record.chromosome() {
+                Chromosome::Name(name) => {
+                    let sequence = reference_sequence_repository.get(name).transpose()?;
+
+                    let sequence = sequence.ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            format!("missing reference sequence: {name}"),
+                        )
+                    })?;
+
+                    Some(sequence)
+                }
+                Chromosome::Symbol(_) => None,
+            };
+        }
+
+        let input_position = usize::from(record.position());
+
+        let records = if split_multiallelic_records {
+            split_multiallelics(header, &record)
+        } else {
+            vec![record]
+        };
+
+        for mut record in records {
+            if let Some(sequence) = &reference_sequence {
+                normalize(&mut record, sequence.as_ref())
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            }
+
+            window.push(record);
+        }
+
+        window.flush(header, writer, input_position.saturating_sub(WINDOW_SIZE))?;
+    }
+
+    window.flush(header, writer, usize::MAX)
+}
+
+#[derive(Default)]
+struct Window {
+    records: VecDeque<Record>,
+    last_position: Option<usize>,
+}
+
+impl Window {
+    fn push(&mut self, record: Record) {
+        let position = record.position();
+        let i = self.records.partition_point(|r| r.position() <= position);
+        self.records.insert(i, record);
+    }
+
+    fn flush<W>(&mut self, header: &Header, writer: &mut Writer<W>, end: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        while let Some(record) = self.records.front() {
+            let position = usize::from(record.position());
+
+            if position >= end {
+                break;
+            }
+
+            if matches!(self.last_position, Some(last_position) if position < last_position) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("record moved beyond the sort window: {position}"),
+                ));
+            }
+
+            if let Some(record) = self.records.pop_front() {
+                writer.write_record(header, &record)?;
+                self.last_position = Some(position);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection, prelude::*, sample};
+
+    use super::*;
+
+    fn repository() -> fasta::Repository {
+        use fasta::record::{Definition, Sequence};
+
+        fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"TTGCACACATGA".to_vec()),
+        )])
+    }
+
+    #[test]
+    fn test_normalize() -> Result<(), Box<dyn std::error::Error>> {
+        fn t(
+            reference_sequence: &[u8],
+            (position, reference_bases, alternate_bases): (usize, &str, &str),
+            expected: (usize, &str, &str),
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let mut record = Record::builder()
+                .set_chromosome("sq0".parse()?)
+                .set_position(Position::from(position))
+                .set_reference_bases(reference_bases.parse()?)
+                .set_alternate_bases(alternate_bases.parse()?)
+                .build()?;
+
+            normalize(&mut record, reference_sequence)?;
+
+            let actual = (
+                usize::from(record.position()),
+                record.reference_bases().to_string(),
+                record.alternate_bases().to_string(),
+            );
+
+            let expected = (
+                expected.0,
+                String::from(expected.1),
+                String::from(expected.2),
+            );
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        let reference_sequence = b"TTGCACACATGA";
+
+        // SNV
+        t(reference_sequence, (5, "A", "G"), (5, "A", "G"))?;
+        // left-alignable deletion
+        t(reference_sequence, (7, "ACA", "A"), (3, "GCA", "G"))?;
+        // left-alignable insertion
+        t(reference_sequence, (9, "A", "ACA"), (3, "G", "GCA"))?;
+        // trim
+        t(reference_sequence, (1, "TTG", "TCG"), (2, "T", "C"))?;
+        t(reference_sequence, (10, "TGA", "TCA"), (11, "G", "C"))?;
+        // multiallelic
+        t(
+            reference_sequence,
+            (7, "ACA", "A,ACACA"),
+            (3, "GCA", "G,GCACA"),
+        )?;
+        // symbolic
+        t(reference_sequence, (7, "A", "<DEL>"), (7, "A", "<DEL>"))?;
+
+        let mut record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(7))
+            .set_reference_bases("G".parse()?)
+            .set_alternate_bases("T".parse()?)
+            .build()?;
+
+        assert_eq!(
+            normalize(&mut record, reference_sequence),
+            Err(NormalizeError::ReferenceMismatch(Position::from(7)))
+        );
+
+        *record.position_mut() = Position::from(13);
+        assert_eq!(
+            normalize(&mut record, reference_sequence),
+            Err(NormalizeError::PositionOutOfBounds(Position::from(13)))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_left_align() -> Result<(), Box<dyn std::error::Error>> {
+        fn t(
+            reference_sequence: &[u8],
+            reference_sequence_start: usize,
+            (position, reference_bases, alternate_bases): (usize, &str, &str),
+            expected: (usize, &str, &str),
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let record = Record::builder()
+                .set_chromosome("sq0".parse()?)
+                .set_position(Position::from(position))
+                .set_reference_bases(reference_bases.parse()?)
+                .set_alternate_bases(alternate_bases.parse()?)
+                .build()?;
+
+            let record = normalize_left_align(
+                &record,
+                reference_sequence,
+                Position::from(reference_sequence_start),
+            )?;
+
+            let actual = (
+                usize::from(record.position()),
+                record.reference_bases().to_string(),
+                record.alternate_bases().to_string(),
+            );
+
+            let expected = (
+                expected.0,
+                String::from(expected.1),
+                String::from(expected.2),
+            );
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        let reference_sequence = b"TTGCACACATGA";
+
+        t(reference_sequence, 1, (7, "ACA", "A"), (3, "GCA", "G"))?;
+        t(reference_sequence, 1, (9, "A", "ACA"), (3, "G", "GCA"))?;
+        t(reference_sequence, 1, (5, "A", "G"), (5, "A", "G"))?;
+        t(reference_sequence, 1, (7, "A", "<DEL>"), (7, "A", "<DEL>"))?;
+
+        // subsequence (sq0:101-112)
+        t(
+            reference_sequence,
+            101,
+            (107, "ACA", "A"),
+            (103, "GCA", "G"),
+        )?;
+        t(
+            reference_sequence,
+            101,
+            (107, "ACA", "A,ACACA"),
+            (103, "GCA", "G,GCACA"),
+        )?;
+
+        // Multiallelic alleles are left-aligned independently. The deletion of C cannot be
+        // shifted, but the insertion of AC can.
+        t(
+            reference_sequence,
+            1,
+            (7, "AC", "A,ACAC"),
+            (3, "GCACAC", "GCACA,GCACACAC"),
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_left_align_with_short_reference_sequence(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(107))
+            .set_reference_bases("ACA".parse()?)
+            .set_alternate_bases("A".parse()?)
+            .build()?;
+
+        // sq0:104-112
+        assert_eq!(
+            normalize_left_align(&record, b"CACACATGA", Position::from(104)),
+            Err(NormalizeError::PositionOutOfBounds(Position::from(103)))
+        );
+
+        // sq0:101-108
+        assert_eq!(
+            normalize_left_align(&record, b"TTGCACAC", Position::from(101)),
+            Err(NormalizeError::PositionOutOfBounds(Position::from(107)))
+        );
+
+        assert_eq!(
+            normalize_left_align(&record, b"TTGCACACATGA", Position::from(108)),
+            Err(NormalizeError::PositionOutOfBounds(Position::from(107)))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_left_align_produces_leftmost_deletions(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        // Every deletion in the reference sequence is normalized to an equivalent deletion that
+        // cannot be shifted further left.
+        const REFERENCE_SEQUENCE: &[u8] = b"GATTTACACAGGAGGAGTT";
+
+        fn delete(start: usize, len: usize) -> Vec<u8> {
+            let mut haplotype = REFERENCE_SEQUENCE.to_vec();
+            haplotype.drain(start - 1..start - 1 + len);
+            haplotype
+        }
+
+        for len in 1..=3 {
+            // The deleted bases follow an anchor base.
+            for position in 1..REFERENCE_SEQUENCE.len() - len {
+                let i = position - 1;
+                let reference_bases = &REFERENCE_SEQUENCE[i..=i + len];
+
+                let record = Record::builder()
+                    .set_chromosome("sq0".parse()?)
+                    .set_position(Position::from(position))
+                    .set_reference_bases(std::str::from_utf8(reference_bases)?.parse()?)
+                    .set_alternate_bases(std::str::from_utf8(&reference_bases[..1])?.parse()?)
+                    .build()?;
+
+                let record = normalize_left_align(&record, REFERENCE_SEQUENCE, Position::from(1))?;
+
+                let actual_position = usize::from(record.position());
+                assert_eq!(record.reference_bases().len(), len + 1);
+                assert_eq!(record.alternate_bases().to_string().len(), 1);
+
+                let haplotype = delete(position + 1, len);
+                assert_eq!(delete(actual_position + 1, len), haplotype);
+
+                for p in 1..actual_position {
+                    assert_ne!(delete(p + 1, len), haplotype);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // A reference sequence, the 0-based start and length of the reference bases in it, and
+    // alternate alleles.
+    fn arbitrary_variant() -> impl Strategy<Value = (Vec<u8>, usize, usize, Vec<Vec<u8>>)> {
+        const BASES: &[u8] = b"ACGT";
+
+        collection::vec(sample::select(BASES), 1..32)
+            .prop_flat_map(|reference_sequence| {
+                let len = reference_sequence.len();
+                (Just(reference_sequence), 0..len)
+            })
+            .prop_flat_map(move |(reference_sequence, start)| {
+                let max_len = (reference_sequence.len() - start).min(4);
+
+                (
+                    Just(reference_sequence),
+                    Just(start),
+                    1..=max_len,
+                    collection::vec(collection::vec(sample::select(BASES), 1..6), 1..4),
+                )
+            })
+    }
+
+    fn build_variant_record(
+        start: usize,
+        reference_bases: &[u8],
+        alternate_bases: &[Vec<u8>],
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        let alternate_bases = alternate_bases
+            .iter()
+            .map(|allele| std::str::from_utf8(allele))
+            .collect::<Result<Vec<_>, _>>()?
+            .join(",");
+
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(start + 1))
+            .set_reference_bases(std::str::from_utf8(reference_bases)?.parse()?)
+            .set_alternate_bases(alternate_bases.parse()?)
+            .build()?;
+
+        Ok(record)
+    }
+
+    fn record_alleles(record: &Record) -> (usize, String, Vec<String>) {
+        let alternate_bases = record
+            .alternate_bases()
+            .iter()
+            .map(|allele| allele.to_string())
+            .collect();
+
+        (
+            usize::from(record.position()),
+            record.reference_bases().to_string(),
+            alternate_bases,
+        )
+    }
+
+    // Applies each alternate allele to the reference sequence.
+    fn haplotypes(reference_sequence: &[u8], record: &Record) -> Vec<Vec<u8>> {
+        let (position, reference_bases, alternate_bases) = record_alleles(record);
+        let start = position - 1;
+        let end = start + reference_bases.len();
+
+        alternate_bases
+            .iter()
+            .map(|allele| {
+                let mut haplotype = reference_sequence[..start].to_vec();
+                haplotype.extend(allele.as_bytes());
+                haplotype.extend(&reference_sequence[end..]);
+                haplotype
+            })
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn test_normalize_left_align_is_idempotent(
+            (reference_sequence, start, len, alternate_bases) in arbitrary_variant(),
+        ) {
+            let reference_bases = &reference_sequence[start..start + len];
+            prop_assume!(alternate_bases.iter().all(|allele| allele != reference_bases));
+
+            let record = build_variant_record(start, reference_bases, &alternate_bases)
+                .map_err(|e| TestCaseError::fail(e.to_string()))?;
+
+            let normalized_record =
+                normalize_left_align(&record, &reference_sequence, Position::from(1))?;
+            let renormalized_record =
+                normalize_left_align(&normalized_record, &reference_sequence, Position::from(1))?;
+
+            prop_assert_eq!(
+                record_alleles(&renormalized_record),
+                record_alleles(&normalized_record)
+            );
+        }
+
+        #[test]
+        fn test_normalize_left_align_preserves_sequences(
+            (reference_sequence, start, len, alternate_bases) in arbitrary_variant(),
+        ) {
+            let reference_bases = &reference_sequence[start..start + len];
+            prop_assume!(alternate_bases.iter().all(|allele| allele != reference_bases));
+
+            let record = build_variant_record(start, reference_bases, &alternate_bases)
+                .map_err(|e| TestCaseError::fail(e.to_string()))?;
+
+            let normalized_record =
+                normalize_left_align(&record, &reference_sequence, Position::from(1))?;
+
+            // The reference bases still match the reference sequence.
+            let (position, normalized_reference_bases, _) = record_alleles(&normalized_record);
+            let i = position - 1;
+            prop_assert_eq!(
+                normalized_reference_bases.as_bytes(),
+                &reference_sequence[i..i + normalized_reference_bases.len()]
+            );
+
+            prop_assert_eq!(
+                haplotypes(&reference_sequence, &normalized_record),
+                haplotypes(&reference_sequence, &record)
+            );
+        }
+
+        #[test]
+        fn test_normalize_left_align_is_left_aligned(
+            (reference_sequence, start, len, alternate_bases) in arbitrary_variant(),
+        ) {
+            let reference_bases = &reference_sequence[start..start + len];
+            let alternate_bases = &alternate_bases[..1];
+            prop_assume!(alternate_bases[0] != reference_bases);
+
+            let record = build_variant_record(start, reference_bases, alternate_bases)
+                .map_err(|e| TestCaseError::fail(e.to_string()))?;
+
+            let normalized_record =
+                normalize_left_align(&record, &reference_sequence, Position::from(1))?;
+
+            let (position, reference_bases, alternate_bases) = record_alleles(&normalized_record);
+            let (reference_bases, alternate_bases) =
+                (reference_bases.as_bytes(), alternate_bases[0].as_bytes());
+
+            // The alleles cannot be shifted left, i.e., their last bases differ, unless the
+            // variant is at the start of the reference sequence.
+            prop_assert!(position == 1 || reference_bases.last() != alternate_bases.last());
+
+            // The alleles do not share a leading base that can be trimmed.
+            prop_assert!(
+                reference_bases.len() == 1
+                    || alternate_bases.len() == 1
+                    || reference_bases[0] != alternate_bases[0]
+            );
+
+            // No equivalent deletion, or insertion of the same bases, starts further left.
+            let haplotype = haplotypes(&reference_sequence, &normalized_record).remove(0);
+            let (ref_len, alt_len) = (reference_bases.len(), alternate_bases.len());
+
+            if ref_len != alt_len && ref_len.min(alt_len) == 1 {
+                for p in 0..position - 1 {
+                    let end = p + ref_len;
+
+                    if end > reference_sequence.len() {
+                        break;
+                    }
+
+                    let inserted_bases = &alternate_bases[1..];
+                    let mut candidate = reference_sequence[..=p].to_vec();
+                    candidate.extend(inserted_bases);
+                    candidate.extend(&reference_sequence[end..]);
+
+                    prop_assert_ne!(&candidate, &haplotype);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_multiallelics() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"##fileformat=VCFv4.3
+##INFO=<ID=AC,Number=A,Type=Integer,Description=\"Allele count\">
+##INFO=<ID=AD,Number=R,Type=Integer,Description=\"Allele depth\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##FORMAT=<ID=PL,Number=G,Type=Integer,Description=\"Phred-scaled genotype likelihoods\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1
+sq0\t1\t.\tT\tC,G\t.\t.\tAC=1,2;AD=3,4,5\tGT:PL\t1/2:0,1,2,3,4,5\t0|2:6,7,8,9,10,11
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let header = reader.read_header()?;
+        let record = reader.records(&header).next().transpose()?.unwrap();
+
+        let records = split_multiallelics(&header, &record);
+
+        let actual: Vec<_> = records.iter().map(|record| record.to_string()).collect();
+        let expected = [
+            "sq0\t1\t.\tT\tC\t.\t.\tAC=1;AD=3,4\tGT:PL\t1/.:0,1,2\t0|.:6,7,8",
+            "sq0\t1\t.\tT\tG\t.\t.\tAC=2;AD=3,5\tGT:PL\t./1:0,3,5\t0|1:6,9,11",
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_multiallelics_with_reference_genotype_recoding(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"##fileformat=VCFv4.3
+##INFO=<ID=AC,Number=A,Type=Integer,Description=\"Allele count\">
+##INFO=<ID=AN,Number=1,Type=Integer,Description=\"Total allele count\">
+##INFO=<ID=AD,Number=R,Type=Integer,Description=\"Allele depth\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##FORMAT=<ID=PL,Number=G,Type=Integer,Description=\"Phred-scaled genotype likelihoods\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1
+sq0\t1\t.\tT\tC,G\t.\t.\tAC=1,2;AN=4;AD=3,4,5\tGT:PL\t1/2:0,1,2,3,4,5\t0|2:6,7,8,9,10,11
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let header = reader.read_header()?;
+        let record = reader.records(&header).next().transpose()?.unwrap();
+
+        let records = split_multiallelics_with(&header, &record, GenotypeRecoding::Reference);
+
+        let actual: Vec<_> = records.iter().map(|record| record.to_string()).collect();
+        let expected = [
+            "sq0\t1\t.\tT\tC\t.\t.\tAC=1;AN=4;AD=3,4\tGT:PL\t1/0:0,1,2\t0|0:6,7,8",
+            "sq0\t1\t.\tT\tG\t.\t.\tAC=2;AN=4;AD=3,5\tGT:PL\t0/1:0,3,5\t0|1:6,9,11",
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_multiallelics_with_missing_genotype_recoding(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"##fileformat=VCFv4.3
+##INFO=<ID=AC,Number=A,Type=Integer,Description=\"Allele count\">
+##INFO=<ID=AN,Number=1,Type=Integer,Description=\"Total allele count\">
+##INFO=<ID=AF,Number=A,Type=Float,Description=\"Allele frequency\">
+##INFO=<ID=AD,Number=R,Type=Integer,Description=\"Allele depth\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1\tsample2
+sq0\t1\tid0\tA\tT,C\t.\t.\tAC=2,2;AN=6;AF=0.333,0.333;AD=3,4,5\tGT\t1/2\t0|2\t1/1
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let header = reader.read_header()?;
+        let record = reader.records(&header).next().transpose()?.unwrap();
+
+        let records = split_multiallelics_with(&header, &record, GenotypeRecoding::Missing);
+
+        let actual: Vec<_> = records.iter().map(|record| record.to_string()).collect();
+        let expected = [
+            "sq0\t1\tid0\tA\tT\t.\t.\tAC=3;AN=4;AF=0.75;AD=3,4\tGT\t1/.\t0|.\t1/1",
+            "sq0\t1\tid0\tA\tC\t.\t.\tAC=2;AN=3;AF=0.6666667;AD=3,5\tGT\t./1\t0|1\t./.",
+        ];
+        assert_eq!(actual, expected);
+
+        // round trip
+        let mut writer = Writer::new(Vec::new());
+
+        for record in &records {
+            writer.write_record(&header, record)?;
+        }
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        let actual: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+        assert_eq!(actual, records);
+
+        // An allele with no called alleles has a missing allele frequency.
+        let record = Record::try_from((
+            &header,
+            "sq0\t1\tid0\tA\tT,C\t.\t.\tAC=0,2;AN=2;AF=0,1\tGT\t2/2\t./.\t./.",
+        ))?;
+
+        let records = split_multiallelics_with(&header, &record, GenotypeRecoding::Missing);
+
+        assert_eq!(
+            records[0].to_string(),
+            "sq0\t1\tid0\tA\tT\t.\t.\tAC=0;AN=0;AF=.\tGT\t./.\t./.\t./."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_recode_genotype() {
+        fn t(s: &str, allele_index: usize, expected: (&str, &str)) {
+            assert_eq!(
+                recode_genotype(s, allele_index, GenotypeRecoding::Reference),
+                expected.0
+            );
+            assert_eq!(
+                recode_genotype(s, allele_index, GenotypeRecoding::Missing),
+                expected.1
+            );
+        }
+
+        t("0/1", 1, ("0/1", "0/1"));
+        t("1/2", 2, ("0/1", "./1"));
+        t("12|2", 12, ("1|0", "1|."));
+        t("./.", 1, ("./.", "./."));
+    }
+
+    #[test]
+    fn test_normalize_stream() -> io::Result<()> {
+        let data = b"##fileformat=VCFv4.3
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+sq0\t5\t.\tA\tG\t.\t.\t.\tGT\t0/1
+sq0\t7\t.\tACA\tA\t.\t.\t.\tGT\t1/1
+sq0\t10\t.\tT\tC,G\t.\t.\t.\tGT\t1/2
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let header = reader.read_header()?;
+
+        let mut writer = Writer::new(Vec::new());
+        normalize_stream(&mut reader, &header, &repository(), &mut writer, true)?;
+
+        let expected = b"sq0\t3\t.\tGCA\tG\t.\t.\t.\tGT\t1/1
+sq0\t5\t.\tA\tG\t.\t.\t.\tGT\t0/1
+sq0\t10\t.\tT\tC\t.\t.\t.\tGT\t1/.
+sq0\t10\t.\tT\tG\t.\t.\t.\tGT\t./1
+";
+
+        assert_eq!(writer.get_ref(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_stream_with_missing_reference_sequence() -> io::Result<()> {
+        let data = b"##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq1\t1\t.\tA\tG\t.\t.\t.
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let header = reader.read_header()?;
+
+        let mut writer = Writer::new(Vec::new());
+
+        assert!(matches!(
+            normalize_stream(&mut reader, &header, &repository(), &mut writer, false),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
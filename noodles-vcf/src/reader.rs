@@ -50,6 +50,7 @@ use super::{Header, Record, VariantReader};
 pub struct Reader<R> {
     inner: R,
     buf: String,
+    validate_filters: bool,
 }
 
 impl<R> Reader<R>
@@ -74,6 +75,7 @@ where
         Self {
             inner,
             buf: String::new(),
+            validate_filters: false,
         }
     }
 
@@ -181,7 +183,7 @@ where
         match read_line(&mut self.inner, &mut self.buf)? {
             0 => Ok(0),
             n => {
-                parse_record(&self.buf, header, record)
+                parse_record(&self.buf, header, record, self.validate_filters)
                     .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
                 Ok(n)
@@ -473,7 +475,8 @@ pub(crate) fn resolve_region(index: &csi::Index, region: &Region) -> io::Result<
             io::Error::new(
                 io::ErrorKind::InvalidInput,
                 format!(
-                    "region reference sequence does not exist in reference sequences: {region:?}"
+                    "region reference sequence does not exist in index: {}",
+                    region.name()
                 ),
             )
         })?;
@@ -508,6 +511,37 @@ sq0\t1\t.\tA\t.\t.\tPASS\t.
         Ok(())
     }
 
+    #[test]
+    fn test_read_record_with_filter_validation() -> io::Result<()> {
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+##FILTER=<ID=q10,Description=\"Quality below 10\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\tq10\t.
+sq0\t2\t.\tA\t.\t.\ts50\t.
+";
+
+        let mut reader = Builder::default()
+            .set_validate_filters(true)
+            .build_from_reader(DATA)?;
+        let header = reader.read_header()?;
+
+        let mut record = Record::default();
+        reader.read_record(&header, &mut record)?;
+
+        assert!(matches!(
+            reader.read_record(&header, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut reader = Reader::new(DATA);
+        let header = reader.read_header()?;
+        reader.read_record(&header, &mut record)?;
+        reader.read_record(&header, &mut record)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = String::new();
@@ -10,9 +10,29 @@ use super::Reader;
 
 /// A VCF reader builder.
 #[derive(Debug, Default)]
-pub struct Builder;
+pub struct Builder {
+    validate_filters: bool,
+}
 
 impl Builder {
+    /// Sets whether to validate record filters against the header.
+    ///
+    /// If `true`, reading a record fails if it has a filter that is not defined by a header
+    /// filter record (`FILTER`). `PASS` is always allowed.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let builder = vcf::reader::Builder::default().set_validate_filters(true);
+    /// ```
+    pub fn set_validate_filters(mut self, validate_filters: bool) -> Self {
+        self.validate_filters = validate_filters;
+        self
+    }
+
     /// Builds a VCF reader from a path.
     pub fn build_from_path<P>(self, src: P) -> io::Result<Reader<Box<dyn BufRead>>>
     where
@@ -35,6 +55,8 @@ impl Builder {
     where
         R: BufRead,
     {
-        Ok(Reader::new(reader))
+        let mut reader = Reader::new(reader);
+        reader.validate_filters = self.validate_filters;
+        Ok(reader)
     }
 }
@@ -87,7 +87,7 @@ pub(crate) fn intersects(
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
     let end = record
-        .end()
+        .end_position()
         .map(usize::from)
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
         .and_then(|n| {
@@ -15,9 +15,15 @@ use std::{error, fmt};
 use noodles_core as core;
 
 use self::{
-    alternate_bases::parse_alternate_bases, chromosome::parse_chromosome, filters::parse_filters,
-    genotypes::parse_genotypes, ids::parse_ids, info::parse_info, position::parse_position,
-    quality_score::parse_quality_score, reference_bases::parse_reference_bases,
+    alternate_bases::parse_alternate_bases,
+    chromosome::parse_chromosome,
+    filters::{parse_filters, parse_filters_with_header},
+    genotypes::parse_genotypes,
+    ids::parse_ids,
+    info::parse_info,
+    position::parse_position,
+    quality_score::parse_quality_score,
+    reference_bases::parse_reference_bases,
 };
 use crate::{Header, Record};
 
@@ -89,6 +95,7 @@ pub(crate) fn parse_record(
     mut s: &str,
     header: &Header,
     record: &mut Record,
+    validate_filters: bool,
 ) -> Result<(), ParseError> {
     let field = next_field(&mut s);
     parse_chromosome(field, record.chromosome_mut()).map_err(ParseError::InvalidChromosome)?;
@@ -126,7 +133,12 @@ pub(crate) fn parse_record(
         MISSING => {
             record.filters_mut().take();
         }
-        _ => parse_filters(field, record.filters_mut()).map_err(ParseError::InvalidFilters)?,
+        _ => if validate_filters {
+            parse_filters_with_header(field, header, record.filters_mut())
+        } else {
+            parse_filters(field, record.filters_mut())
+        }
+        .map_err(ParseError::InvalidFilters)?,
     }
 
     record.info_mut().clear();
@@ -3,7 +3,7 @@ use std::{error, fmt, mem};
 use indexmap::IndexSet;
 use noodles_core as core;
 
-use crate::record::Filters;
+use crate::{record::Filters, Header};
 
 /// An error when raw VCF record filters fail to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -14,6 +14,8 @@ pub enum ParseError {
     InvalidFilter,
     /// A filter is duplicated.
     DuplicateFilter,
+    /// A filter is not defined in the header.
+    UndefinedFilter(String),
 }
 
 impl error::Error for ParseError {}
@@ -24,6 +26,7 @@ impl fmt::Display for ParseError {
             Self::Empty => write!(f, "empty input"),
             Self::InvalidFilter => write!(f, "invalid filter"),
             Self::DuplicateFilter => write!(f, "duplicate filter"),
+            Self::UndefinedFilter(id) => write!(f, "undefined filter: {id}"),
         }
     }
 }
@@ -66,6 +69,26 @@ pub(super) fn parse_filters(s: &str, filters: &mut Option<Filters>) -> Result<()
     Ok(())
 }
 
+pub(super) fn parse_filters_with_header(
+    s: &str,
+    header: &Header,
+    filters: &mut Option<Filters>,
+) -> Result<(), ParseError> {
+    const PASS: &str = "PASS";
+
+    parse_filters(s, filters)?;
+
+    if let Some(Filters::Fail(ids)) = filters {
+        for id in ids.iter() {
+            if id != PASS && !header.filters().contains_key(id) {
+                return Err(ParseError::UndefinedFilter(id.clone()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn is_valid_filter(s: &str) -> bool {
     match s {
         "" | "0" => false,
@@ -128,4 +151,35 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_filters_with_header() -> Result<(), ParseError> {
+        use crate::header::record::value::{map::Filter, Map};
+
+        let header = Header::builder()
+            .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+            .build();
+
+        let mut filters = None;
+
+        parse_filters_with_header("PASS", &header, &mut filters)?;
+        assert_eq!(filters, Some(Filters::Pass));
+
+        parse_filters_with_header("q10", &header, &mut filters)?;
+        assert_eq!(
+            filters,
+            Some(Filters::Fail([String::from("q10")].into_iter().collect()))
+        );
+
+        assert_eq!(
+            parse_filters_with_header("q10;s50", &header, &mut filters),
+            Err(ParseError::UndefinedFilter(String::from("s50")))
+        );
+        assert_eq!(
+            parse_filters_with_header("q 10", &header, &mut filters),
+            Err(ParseError::InvalidFilter)
+        );
+
+        Ok(())
+    }
 }
@@ -19,7 +19,9 @@ pub use self::{
     reference_bases::ReferenceBases,
 };
 
-use std::{error, fmt, num, str::FromStr};
+use std::{error, fmt, io, num, str::FromStr};
+
+use noodles_fasta as fasta;
 
 use super::{reader::record::ParseError, Header};
 
@@ -619,6 +621,10 @@ pub enum EndError {
     InvalidReferenceBasesLength(num::TryFromIntError),
     /// The calculation of the end position overflowed.
     PositionOverflow(usize, usize),
+    /// The INFO structural variant lengths (`SVLEN`) field value type is invalid.
+    InvalidInfoSvLengthsFieldValue,
+    /// The end position is less than the start position.
+    EndBeforeStart(usize, usize),
 }
 
 impl error::Error for EndError {
@@ -642,6 +648,46 @@ impl fmt::Display for EndError {
                 f,
                 "calculation of the end position overflowed: {start} + {len}",
             ),
+            Self::InvalidInfoSvLengthsFieldValue => {
+                write!(f, "invalid INFO SV lengths (`SVLEN`) field value type")
+            }
+            Self::EndBeforeStart(start, end) => {
+                write!(
+                    f,
+                    "end position ({end}) is less than start position ({start})"
+                )
+            }
+        }
+    }
+}
+
+/// An error returned when the allele count INFO fields fail to be calculated.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AlleleCountInfoError {
+    /// A genotype is invalid.
+    InvalidGenotype(genotypes::sample::GenotypeError),
+    /// A genotype allele position is not in the alternate bases.
+    InvalidAllelePosition(usize),
+    /// An allele count is invalid (> [`i32::MAX`]).
+    InvalidCount(num::TryFromIntError),
+}
+
+impl error::Error for AlleleCountInfoError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidGenotype(e) => Some(e),
+            Self::InvalidAllelePosition(_) => None,
+            Self::InvalidCount(e) => Some(e),
+        }
+    }
+}
+
+impl fmt::Display for AlleleCountInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidGenotype(_) => f.write_str("invalid genotype"),
+            Self::InvalidAllelePosition(i) => write!(f, "invalid allele position: {i}"),
+            Self::InvalidCount(_) => f.write_str("invalid count"),
         }
     }
 }
@@ -707,6 +753,311 @@ impl Record {
 
         Ok(Position::from(end))
     }
+
+    /// Returns or calculates the end position on the reference sequence, taking structural
+    /// variants into account.
+    ///
+    /// This is the position of the last reference base the record spans and should be used for
+    /// region overlap tests. The end position is, in order of precedence,
+    ///
+    ///   1. the value of the `END` INFO field;
+    ///   2. the start position plus the largest absolute `SVLEN` INFO field value of the deletion
+    ///      (`<DEL>`), duplication (`<DUP>`), inversion (`<INV>`), and copy number variation
+    ///      (`<CNV>`) symbolic alternate alleles; or
+    ///   3. calculated using the start position and reference bases length, as in [`Self::end`].
+    ///
+    /// Breakends and insertions do not span more than the reference bases.
+    ///
+    /// The end position is 1-based, inclusive.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the `END` or `SVLEN` INFO field values are invalid or if the end
+    /// position is less than the start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::Position};
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::from(8))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases("<DEL>".parse()?)
+    ///     .set_info("SVLEN=-5".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.end(), Ok(Position::from(8)));
+    /// assert_eq!(record.end_position(), Ok(Position::from(13)));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn end_position(&self) -> Result<Position, EndError> {
+        use self::info::field::key;
+
+        let start = usize::from(self.position());
+
+        let end = if self.info().get(&key::END_POSITION).is_some() {
+            self.end().map(usize::from)?
+        } else if let Some(len) = self.max_sv_length()? {
+            start
+                .checked_add(len)
+                .ok_or(EndError::PositionOverflow(start, len))?
+        } else {
+            self.end().map(usize::from)?
+        };
+
+        if end < start {
+            return Err(EndError::EndBeforeStart(start, end));
+        }
+
+        Ok(Position::from(end))
+    }
+
+    /// Calculates and sets the allele count INFO fields from the genotypes.
+    ///
+    /// This sets the allele count (`AC`), total number of alleles (`AN`), and allele frequency
+    /// (`AF`) INFO fields using the genotypes (`GT`) of all samples, similar to `bcftools +fill-tags
+    /// -- -t AC,AN,AF`. See [`Genotypes::allele_counts`] for how alleles are counted. If no alleles
+    /// are called, the allele frequencies are missing (`.`). If there are no alternate bases, `AC`
+    /// and `AF` are removed.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if a genotype is invalid or calls an allele that is not in the
+    /// alternate bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     record::{
+    ///         genotypes::{keys::key, sample::Value, Keys},
+    ///         Genotypes, Position,
+    ///     },
+    /// };
+    ///
+    /// let genotypes = Genotypes::new(
+    ///     Keys::try_from(vec![key::GENOTYPE])?,
+    ///     vec![
+    ///         vec![Some(Value::from("0|1"))],
+    ///         vec![Some(Value::from("1/1"))],
+    ///     ],
+    /// );
+    ///
+    /// let mut record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases("C".parse()?)
+    ///     .set_genotypes(genotypes)
+    ///     .build()?;
+    ///
+    /// record.recompute_allele_count_info()?;
+    /// assert_eq!(record.info().to_string(), "AC=3;AN=4;AF=0.75");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn recompute_allele_count_info(&mut self) -> Result<(), AlleleCountInfoError> {
+        use self::info::field::{key, Value};
+
+        let alternate_allele_count = self.alternate_bases().len();
+
+        let counts = self
+            .genotypes()
+            .allele_counts()
+            .map_err(AlleleCountInfoError::InvalidGenotype)?;
+
+        if counts.len() > alternate_allele_count + 1 {
+            return Err(AlleleCountInfoError::InvalidAllelePosition(
+                counts.len() - 1,
+            ));
+        }
+
+        let allele_number: usize = counts.iter().sum();
+
+        let alternate_allele_counts = (1..=alternate_allele_count)
+            .map(|i| {
+                let n = counts.get(i).copied().unwrap_or_default();
+                i32::try_from(n).map(Some)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(AlleleCountInfoError::InvalidCount)?;
+
+        let allele_frequencies: Vec<_> = alternate_allele_counts
+            .iter()
+            .map(|n| {
+                if allele_number == 0 {
+                    None
+                } else {
+                    n.map(|n| n as f32 / allele_number as f32)
+                }
+            })
+            .collect();
+
+        let allele_number =
+            i32::try_from(allele_number).map_err(AlleleCountInfoError::InvalidCount)?;
+
+        let info = self.info_mut();
+
+        if alternate_allele_count == 0 {
+            info.as_mut().shift_remove(&key::ALLELE_COUNT);
+            info.insert(key::TOTAL_ALLELE_COUNT, Some(Value::from(allele_number)));
+            info.as_mut().shift_remove(&key::ALLELE_FREQUENCIES);
+        } else {
+            info.insert(
+                key::ALLELE_COUNT,
+                Some(Value::from(alternate_allele_counts)),
+            );
+            info.insert(key::TOTAL_ALLELE_COUNT, Some(Value::from(allele_number)));
+            info.insert(
+                key::ALLELE_FREQUENCIES,
+                Some(Value::from(allele_frequencies)),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Normalizes the record against its reference sequence.
+    ///
+    /// This trims bases common to all alleles and left-aligns the variant, similar to `bcftools
+    /// norm`. The alternate alleles of a multiallelic record are normalized independently and
+    /// padded to a common position. See [`crate::normalize::normalize_left_align`].
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the reference sequence name does not match the record chromosome,
+    /// the record position is out of bounds, or the reference bases do not match the reference
+    /// sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+    /// use noodles_vcf::{self as vcf, record::Position};
+    ///
+    /// let reference_sequence = fasta::Record::new(
+    ///     Definition::new("sq0", None),
+    ///     Sequence::from(b"TTGCACACAT".to_vec()),
+    /// );
+    ///
+    /// let mut record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::from(7))
+    ///     .set_reference_bases("ACA".parse()?)
+    ///     .set_alternate_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// record.normalize(&reference_sequence)?;
+    ///
+    /// assert_eq!(record.position(), Position::from(3));
+    /// assert_eq!(record.reference_bases().to_string(), "GCA");
+    /// assert_eq!(record.alternate_bases().to_string(), "G");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn normalize(&mut self, reference: &fasta::Record) -> io::Result<()> {
+        let name = self.chromosome().to_string();
+
+        if reference.name() != name {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "reference sequence name mismatch: expected {name}, got {}",
+                    reference.name()
+                ),
+            ));
+        }
+
+        *self = crate::normalize::normalize_left_align(
+            self,
+            reference.sequence().as_ref(),
+            Position::from(1),
+        )
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        Ok(())
+    }
+
+    /// Splits a multiallelic record into biallelic records.
+    ///
+    /// This returns one record per alternate allele. INFO and genotype fields with a number of
+    /// `A`, `R`, or `G` are subset to the values of the allele, and genotypes (`GT`) are recoded
+    /// so that other alternate alleles are set to missing. Fields with other numbers are copied
+    /// unchanged, except the `AC`, `AN`, and `AF` INFO fields, which are recalculated from the
+    /// recoded genotypes. See [`crate::normalize::split_multiallelics`].
+    ///
+    /// A record with at most one alternate allele is returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// ###INFO=<ID=AF,Number=A,Type=Float,Description=\"Allele frequency\">
+    /// ###FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+    /// ###FORMAT=<ID=AD,Number=R,Type=Integer,Description=\"Read depth for each allele\">
+    /// ##CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+    /// sq0\t1\t.\tA\tT,C\t.\t.\tAF=0.25,0.5\tGT:AD\t1/2:3,5,8
+    /// ";
+    ///
+    /// let mut reader = vcf::Reader::new(&data[..]);
+    /// let header = reader.read_header()?;
+    /// let record = reader.records(&header).next().transpose()?.unwrap();
+    ///
+    /// let records = record.split_multiallelic(&header);
+    ///
+    /// assert_eq!(records.len(), 2);
+    /// assert_eq!(records[0].to_string(), "sq0\t1\t.\tA\tT\t.\t.\tAF=1\tGT:AD\t1/.:3,5");
+    /// assert_eq!(records[1].to_string(), "sq0\t1\t.\tA\tC\t.\t.\tAF=1\tGT:AD\t./1:3,8");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn split_multiallelic(&self, header: &Header) -> Vec<Self> {
+        crate::normalize::split_multiallelics(header, self)
+    }
+
+    // Returns the largest absolute SV length of the symbolic alternate alleles that span the
+    // reference sequence.
+    fn max_sv_length(&self) -> Result<Option<usize>, EndError> {
+        use self::{
+            alternate_bases::{
+                allele::{symbol::structural_variant::Type, Symbol},
+                Allele,
+            },
+            info::field::{key, value::Array, Value},
+        };
+
+        let sv_lengths = match self.info().get(&key::SV_LENGTHS) {
+            Some(Some(Value::Integer(n))) => vec![Some(*n)],
+            Some(Some(Value::Array(Array::Integer(values)))) => values.clone(),
+            Some(Some(_)) => return Err(EndError::InvalidInfoSvLengthsFieldValue),
+            Some(None) | None => return Ok(None),
+        };
+
+        let mut max_len = None;
+
+        for (allele, sv_length) in self.alternate_bases().iter().zip(sv_lengths) {
+            let spans_reference = matches!(
+                allele,
+                Allele::Symbol(Symbol::StructuralVariant(sv)) if matches!(
+                    sv.ty(),
+                    Type::Deletion | Type::Duplication | Type::Inversion | Type::CopyNumberVariation
+                )
+            );
+
+            if !spans_reference {
+                continue;
+            }
+
+            if let Some(n) = sv_length {
+                let len = usize::try_from(n.unsigned_abs()).map_err(EndError::InvalidPosition)?;
+                max_len = max_len.max(Some(len));
+            }
+        }
+
+        Ok(max_len)
+    }
 }
 
 impl fmt::Display for Record {
@@ -833,6 +1184,172 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_recompute_allele_count_info() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::genotypes::{keys::key, sample::Value, Keys};
+
+        fn build(
+            alternate_bases: &str,
+            genotypes: &[&str],
+        ) -> Result<Record, Box<dyn std::error::Error>> {
+            let genotypes = Genotypes::new(
+                Keys::try_from(vec![key::GENOTYPE])?,
+                genotypes
+                    .iter()
+                    .map(|s| vec![Some(Value::from(*s))])
+                    .collect(),
+            );
+
+            let record = Record::builder()
+                .set_chromosome("sq0".parse()?)
+                .set_position(Position::from(1))
+                .set_reference_bases("A".parse()?)
+                .set_alternate_bases(alternate_bases.parse()?)
+                .set_info("NS=3;AC=8".parse()?)
+                .set_genotypes(genotypes)
+                .build()?;
+
+            Ok(record)
+        }
+
+        let mut record = build("C,G", &["0/1", "2|.", "1/1/0"])?;
+        record.recompute_allele_count_info()?;
+        assert_eq!(
+            record.info().to_string(),
+            "NS=3;AC=3,1;AN=6;AF=0.5,0.16666667"
+        );
+
+        let mut record = build("C", &["./.", "."])?;
+        record.recompute_allele_count_info()?;
+        assert_eq!(record.info().to_string(), "NS=3;AC=0;AN=0;AF=.");
+
+        let mut record = build("C", &["0/0"])?;
+        *record.alternate_bases_mut() = AlternateBases::default();
+        record.recompute_allele_count_info()?;
+        assert_eq!(record.info().to_string(), "NS=3;AN=2");
+
+        let mut record = build("C", &["0/2"])?;
+        assert_eq!(
+            record.recompute_allele_count_info(),
+            Err(AlleleCountInfoError::InvalidAllelePosition(2))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_end_position() -> Result<(), Box<dyn std::error::Error>> {
+        fn build(alt: &str, info: &str) -> Result<Record, Box<dyn std::error::Error>> {
+            let record = Record::builder()
+                .set_chromosome("sq0".parse()?)
+                .set_position(Position::from(8))
+                .set_reference_bases("A".parse()?)
+                .set_alternate_bases(alt.parse()?)
+                .set_info(info.parse()?)
+                .build()?;
+
+            Ok(record)
+        }
+
+        let record = build("<DEL>", "END=21;SVLEN=-5")?;
+        assert_eq!(record.end_position(), Ok(Position::from(21)));
+
+        let record = build("<DEL>", "SVLEN=-5")?;
+        assert_eq!(record.end_position(), Ok(Position::from(13)));
+
+        let record = build("<DUP>,<DEL>", "SVLEN=3,-5")?;
+        assert_eq!(record.end_position(), Ok(Position::from(13)));
+
+        let record = build("<DEL>", "SVLEN=.")?;
+        assert_eq!(record.end_position(), Ok(Position::from(8)));
+
+        let record = build("<INS>", "SVLEN=13")?;
+        assert_eq!(record.end_position(), Ok(Position::from(8)));
+
+        let record = build("A[sq1:13[", "SVLEN=5")?;
+        assert_eq!(record.end_position(), Ok(Position::from(8)));
+
+        let record = build("<DEL>", "SVTYPE=DEL")?;
+        assert_eq!(record.end_position(), Ok(Position::from(8)));
+
+        let mut record = build("<DEL>", "SVTYPE=DEL")?;
+        record.info_mut().insert(
+            info::field::key::SV_LENGTHS,
+            Some(info::field::Value::from("ndls")),
+        );
+        assert_eq!(
+            record.end_position(),
+            Err(EndError::InvalidInfoSvLengthsFieldValue)
+        );
+
+        let record = build("<DEL>", "END=5")?;
+        assert_eq!(record.end_position(), Err(EndError::EndBeforeStart(8, 5)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize() -> Result<(), Box<dyn std::error::Error>> {
+        use fasta::record::{Definition, Sequence};
+
+        let reference_sequence = fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"TTGCACACAT".to_vec()),
+        );
+
+        let mut record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(7))
+            .set_reference_bases("ACA".parse()?)
+            .set_alternate_bases("A,ACACA".parse()?)
+            .build()?;
+
+        record.normalize(&reference_sequence)?;
+
+        assert_eq!(record.position(), Position::from(3));
+        assert_eq!(record.reference_bases().to_string(), "GCA");
+        assert_eq!(record.alternate_bases().to_string(), "G,GCACA");
+
+        let mut record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(7))
+            .set_reference_bases("AC".parse()?)
+            .set_alternate_bases("A,ACAC".parse()?)
+            .build()?;
+
+        record.normalize(&reference_sequence)?;
+
+        assert_eq!(record.position(), Position::from(3));
+        assert_eq!(record.reference_bases().to_string(), "GCACAC");
+        assert_eq!(record.alternate_bases().to_string(), "GCACA,GCACACAC");
+
+        let mut record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(7))
+            .set_reference_bases("AT".parse()?)
+            .set_alternate_bases("A".parse()?)
+            .build()?;
+
+        assert!(matches!(
+            record.normalize(&reference_sequence),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut record = Record::builder()
+            .set_chromosome("sq1".parse()?)
+            .set_position(Position::from(7))
+            .set_reference_bases("ACA".parse()?)
+            .set_alternate_bases("A".parse()?)
+            .build()?;
+
+        assert!(matches!(
+            record.normalize(&reference_sequence),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), Box<dyn std::error::Error>> {
         let record = Record::builder()
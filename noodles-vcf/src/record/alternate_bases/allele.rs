@@ -1,8 +1,9 @@
 //! VCF record alternate bases allele and symbol.
 
+pub mod class;
 pub mod symbol;
 
-pub use self::symbol::Symbol;
+pub use self::{class::Class, symbol::Symbol};
 
 use std::{
     error,
@@ -10,7 +11,10 @@ use std::{
     str::FromStr,
 };
 
-use crate::record::reference_bases::{base, Base};
+use crate::record::{
+    reference_bases::{base, Base},
+    ReferenceBases,
+};
 
 /// A VCF alternate bases allele.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -25,6 +29,80 @@ pub enum Allele {
     OverlappingDeletion,
 }
 
+impl Allele {
+    /// Classifies the allele relative to the given reference bases.
+    ///
+    /// For a bases allele, the bases shared with the reference bases at the start and then the end
+    /// are removed. The allele is the reference if neither side has remaining bases, an insertion
+    /// or deletion if only one side has remaining bases, a single nucleotide variant if both sides
+    /// have one remaining base, and a multiple nucleotide variant otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{alternate_bases::{allele::Class, Allele}, ReferenceBases};
+    ///
+    /// let reference_bases: ReferenceBases = "AC".parse()?;
+    ///
+    /// let allele: Allele = "A".parse()?;
+    /// assert_eq!(
+    ///     allele.classify(&reference_bases),
+    ///     Class::Deletion { deleted_bases: String::from("C") }
+    /// );
+    ///
+    /// let allele: Allele = "<INS:ME:L1>".parse()?;
+    /// assert_eq!(
+    ///     allele.classify(&reference_bases),
+    ///     Class::Symbolic(String::from("INS:ME:L1"))
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn classify(&self, reference_bases: &ReferenceBases) -> Class {
+        match self {
+            Self::Bases(bases) => classify_bases(reference_bases, bases),
+            Self::Symbol(symbol) => Class::Symbolic(symbol.to_string()),
+            Self::Breakend(breakend) => Class::Breakend(breakend.clone()),
+            Self::OverlappingDeletion => Class::OverlappingDeletion,
+        }
+    }
+}
+
+fn classify_bases(reference_bases: &[Base], bases: &[Base]) -> Class {
+    fn to_string(bases: &[Base]) -> String {
+        bases.iter().copied().map(char::from).collect()
+    }
+
+    let prefix_len = reference_bases
+        .iter()
+        .zip(bases)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let (reference_bases, bases) = (&reference_bases[prefix_len..], &bases[prefix_len..]);
+
+    let suffix_len = reference_bases
+        .iter()
+        .rev()
+        .zip(bases.iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let reference_bases = &reference_bases[..reference_bases.len() - suffix_len];
+    let bases = &bases[..bases.len() - suffix_len];
+
+    match (reference_bases.len(), bases.len()) {
+        (0, 0) => Class::Reference,
+        (1, 1) => Class::Snv,
+        (0, _) => Class::Insertion {
+            inserted_bases: to_string(bases),
+        },
+        (_, 0) => Class::Deletion {
+            deleted_bases: to_string(reference_bases),
+        },
+        _ => Class::Mnv,
+    }
+}
+
 impl fmt::Display for Allele {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -109,6 +187,61 @@ fn is_breakend(s: &str) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_classify() -> Result<(), Box<dyn std::error::Error>> {
+        fn t(
+            reference_bases: &str,
+            allele: &str,
+            expected: Class,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let reference_bases: ReferenceBases = reference_bases.parse()?;
+            let allele: Allele = allele.parse()?;
+            assert_eq!(allele.classify(&reference_bases), expected);
+            Ok(())
+        }
+
+        t("A", "A", Class::Reference)?;
+        t("ACG", "ACG", Class::Reference)?;
+
+        t("A", "C", Class::Snv)?;
+        t("ACG", "ATG", Class::Snv)?;
+        t("AC", "GT", Class::Mnv)?;
+        t("AC", "GTT", Class::Mnv)?;
+
+        t(
+            "A",
+            "ACG",
+            Class::Insertion {
+                inserted_bases: String::from("CG"),
+            },
+        )?;
+        t(
+            "T",
+            "CGT",
+            Class::Insertion {
+                inserted_bases: String::from("CG"),
+            },
+        )?;
+        t(
+            "ACG",
+            "A",
+            Class::Deletion {
+                deleted_bases: String::from("CG"),
+            },
+        )?;
+
+        t("A", "<DEL>", Class::Symbolic(String::from("DEL")))?;
+        t(
+            "A",
+            "<INS:ME:L1>",
+            Class::Symbolic(String::from("INS:ME:L1")),
+        )?;
+        t("A", "]sq0:5]A", Class::Breakend(String::from("]sq0:5]A")))?;
+        t("A", "*", Class::OverlappingDeletion)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() {
         let allele = Allele::Bases(vec![Base::G]);
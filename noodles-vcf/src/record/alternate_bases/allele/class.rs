@@ -0,0 +1,35 @@
+//! VCF record alternate bases allele class.
+
+/// A VCF alternate bases allele class.
+///
+/// This is the kind of variant an alternate allele describes relative to the reference bases.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Class {
+    /// The allele is the same as the reference bases, i.e., it is not a variant.
+    Reference,
+    /// A single nucleotide variant.
+    Snv,
+    /// An insertion of bases.
+    Insertion {
+        /// The inserted bases.
+        inserted_bases: String,
+    },
+    /// A deletion of bases.
+    Deletion {
+        /// The deleted bases.
+        deleted_bases: String,
+    },
+    /// A multiple nucleotide variant.
+    ///
+    /// This includes complex substitutions that change the length of the allele.
+    Mnv,
+    /// A symbolic allele with the angle brackets removed (e.g., `DEL`, `INS:ME:L1`, etc.).
+    Symbolic(String),
+    /// A breakend.
+    ///
+    /// This is currently the raw allele (e.g., `]sq0:5]A`). It may be parsed into its mate
+    /// position and orientation in the future.
+    Breakend(String),
+    /// An overlapping deletion (`*`).
+    OverlappingDeletion,
+}
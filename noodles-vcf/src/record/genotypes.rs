@@ -5,7 +5,10 @@ pub mod sample;
 
 pub use self::{keys::Keys, sample::Sample};
 
+use self::keys::Key;
+
 use std::{
+    collections::BTreeMap,
     error,
     fmt::{self, Write},
     str::FromStr,
@@ -147,6 +150,204 @@ impl Genotypes {
             .map(|sample| sample.genotype().transpose())
             .collect()
     }
+
+    /// Returns the number of each allele in the genotypes (`GT`) of all samples.
+    ///
+    /// The list is indexed by allele position, i.e., the count of the reference allele is at 0,
+    /// and its length is one more than the largest called allele position. Missing alleles (`.`)
+    /// and samples with missing genotypes are not counted. Phasing is ignored, and samples can
+    /// have different ploidies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{
+    ///     genotypes::{keys::key, sample::Value, Keys},
+    ///     Genotypes,
+    /// };
+    ///
+    /// let genotypes = Genotypes::new(
+    ///     Keys::try_from(vec![key::GENOTYPE])?,
+    ///     vec![
+    ///         vec![Some(Value::from("0|1"))],
+    ///         vec![Some(Value::from("1/."))],
+    ///         vec![Some(Value::from("2"))],
+    ///         vec![None],
+    ///     ],
+    /// );
+    ///
+    /// assert_eq!(genotypes.allele_counts()?, [1, 2, 1]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn allele_counts(&self) -> Result<Vec<usize>, sample::GenotypeError> {
+        let mut counts = Vec::new();
+
+        self.for_each_called_allele(|position| {
+            if position >= counts.len() {
+                counts.resize(position + 1, 0);
+            }
+
+            counts[position] += 1;
+        })?;
+
+        Ok(counts)
+    }
+
+    /// Returns the total number of called alleles in the genotypes (`GT`) of all samples.
+    ///
+    /// This is the sum of [`Self::allele_counts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{
+    ///     genotypes::{keys::key, sample::Value, Keys},
+    ///     Genotypes,
+    /// };
+    ///
+    /// let genotypes = Genotypes::new(
+    ///     Keys::try_from(vec![key::GENOTYPE])?,
+    ///     vec![
+    ///         vec![Some(Value::from("0|1"))],
+    ///         vec![Some(Value::from("1/."))],
+    ///         vec![Some(Value::from("2"))],
+    ///         vec![None],
+    ///     ],
+    /// );
+    ///
+    /// assert_eq!(genotypes.allele_number()?, 4);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn allele_number(&self) -> Result<usize, sample::GenotypeError> {
+        let mut n = 0;
+        self.for_each_called_allele(|_| n += 1)?;
+        Ok(n)
+    }
+
+    fn for_each_called_allele<F>(&self, mut f: F) -> Result<(), sample::GenotypeError>
+    where
+        F: FnMut(usize),
+    {
+        for sample in self.values() {
+            // A missing genotype value (`.`) has no called alleles.
+            if matches!(sample.get(&keys::key::GENOTYPE), Some(None)) {
+                continue;
+            }
+
+            if let Some(genotype) = sample.genotype().transpose()? {
+                genotype.allele_positions().flatten().for_each(&mut f);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Groups samples by their phase set (`PS`) value.
+    ///
+    /// Each phase set maps to the samples in that block, along with their indices. Samples
+    /// without a phase set value are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{
+    ///     genotypes::{keys::key, sample::Value, Keys},
+    ///     Genotypes,
+    /// };
+    ///
+    /// let genotypes = Genotypes::new(
+    ///     Keys::try_from(vec![key::GENOTYPE, key::PHASE_SET])?,
+    ///     vec![
+    ///         vec![Some(Value::from("0|1")), Some(Value::from(8))],
+    ///         vec![Some(Value::from("0/1"))],
+    ///     ],
+    /// );
+    ///
+    /// let phase_sets = genotypes.phase_sets()?;
+    /// assert_eq!(phase_sets.len(), 1);
+    ///
+    /// let samples = &phase_sets[&8];
+    /// assert_eq!(samples.len(), 1);
+    /// assert_eq!(samples[0].0, 0);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn phase_sets(
+        &self,
+    ) -> Result<BTreeMap<i32, Vec<(usize, Sample<'_>)>>, sample::PhaseSetError> {
+        let mut phase_sets: BTreeMap<_, Vec<_>> = BTreeMap::new();
+
+        for (i, sample) in self.values().enumerate() {
+            if let Some(phase_set) = sample.phase_set().transpose()? {
+                phase_sets.entry(phase_set).or_default().push((i, sample));
+            }
+        }
+
+        Ok(phase_sets)
+    }
+
+    /// Returns a copy of the genotypes with only the given keys.
+    ///
+    /// The keys of the new genotypes are in the given order. Sample values that are missing for a
+    /// key are set to `None`, and samples with no values (`.`) are left empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{
+    ///     genotypes::{keys::key, sample::Value, Keys},
+    ///     Genotypes,
+    /// };
+    ///
+    /// let genotypes = Genotypes::new(
+    ///     Keys::try_from(vec![key::GENOTYPE, key::CONDITIONAL_GENOTYPE_QUALITY, key::READ_DEPTH])?,
+    ///     vec![
+    ///         vec![Some(Value::from("0|0")), Some(Value::from(13)), Some(Value::from(5))],
+    ///         vec![Some(Value::from("0/1"))],
+    ///     ],
+    /// );
+    ///
+    /// let actual = genotypes.subset_to_format_keys(&[key::GENOTYPE, key::READ_DEPTH])?;
+    ///
+    /// let expected = Genotypes::new(
+    ///     Keys::try_from(vec![key::GENOTYPE, key::READ_DEPTH])?,
+    ///     vec![
+    ///         vec![Some(Value::from("0|0")), Some(Value::from(5))],
+    ///         vec![Some(Value::from("0/1")), None],
+    ///     ],
+    /// );
+    ///
+    /// assert_eq!(actual, expected);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn subset_to_format_keys(&self, keys: &[Key]) -> Result<Genotypes, GenotypesError> {
+        let indices = keys
+            .iter()
+            .map(|key| {
+                self.keys
+                    .get_index_of(key)
+                    .ok_or_else(|| GenotypesError::UnknownKey(key.clone()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let subset_keys = Keys::try_from(keys.to_vec()).map_err(GenotypesError::InvalidKeys)?;
+
+        let values = self
+            .values
+            .iter()
+            .map(|sample_values| {
+                if sample_values.is_empty() {
+                    Vec::new()
+                } else {
+                    indices
+                        .iter()
+                        .map(|&i| sample_values.get(i).cloned().flatten())
+                        .collect()
+                }
+            })
+            .collect();
+
+        Ok(Genotypes::new(subset_keys, values))
+    }
 }
 
 impl fmt::Display for Genotypes {
@@ -175,6 +376,33 @@ impl fmt::Display for Genotypes {
     }
 }
 
+/// An error returned when VCF record genotypes fail to be transformed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GenotypesError {
+    /// A key is not in the genotypes keys.
+    UnknownKey(Key),
+    /// The keys are invalid.
+    InvalidKeys(keys::TryFromKeyVectorError),
+}
+
+impl error::Error for GenotypesError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::UnknownKey(_) => None,
+            Self::InvalidKeys(e) => Some(e),
+        }
+    }
+}
+
+impl fmt::Display for GenotypesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownKey(key) => write!(f, "unknown key: {key}"),
+            Self::InvalidKeys(_) => f.write_str("invalid keys"),
+        }
+    }
+}
+
 /// An error returned when raw VCF record genotypes fail to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
@@ -306,6 +534,108 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_allele_counts() -> Result<(), Box<dyn std::error::Error>> {
+        let genotypes = Genotypes::new(
+            Keys::try_from(vec![key::GENOTYPE, key::CONDITIONAL_GENOTYPE_QUALITY])?,
+            vec![
+                vec![Some(Value::from("0|0")), Some(Value::from(7))],
+                vec![Some(Value::from("./.")), Some(Value::from(20))],
+                vec![Some(Value::from("1/2/2")), None],
+                vec![None, Some(Value::from(8))],
+                vec![],
+                vec![Some(Value::from("0/."))],
+            ],
+        );
+
+        assert_eq!(genotypes.allele_counts()?, [3, 1, 2]);
+        assert_eq!(genotypes.allele_number()?, 6);
+
+        let genotypes = Genotypes::default();
+        assert!(genotypes.allele_counts()?.is_empty());
+        assert_eq!(genotypes.allele_number()?, 0);
+
+        let genotypes = Genotypes::new(
+            Keys::try_from(vec![key::GENOTYPE])?,
+            vec![vec![Some(Value::from(0))]],
+        );
+
+        assert!(matches!(
+            genotypes.allele_counts(),
+            Err(sample::GenotypeError::InvalidValueType(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_phase_sets() -> Result<(), Box<dyn std::error::Error>> {
+        let genotypes = Genotypes::new(
+            Keys::try_from(vec![key::GENOTYPE, key::PHASE_SET])?,
+            vec![
+                vec![Some(Value::from("0|1")), Some(Value::from(13))],
+                vec![Some(Value::from("1|0")), Some(Value::from(8))],
+                vec![Some(Value::from("0/1"))],
+                vec![Some(Value::from("1|1")), Some(Value::from(13))],
+                vec![Some(Value::from("0/0")), None],
+            ],
+        );
+
+        let phase_sets = genotypes.phase_sets()?;
+
+        let actual: Vec<_> = phase_sets
+            .iter()
+            .map(|(phase_set, samples)| {
+                let indices: Vec<_> = samples.iter().map(|(i, _)| *i).collect();
+                (*phase_set, indices)
+            })
+            .collect();
+
+        assert_eq!(actual, [(8, vec![1]), (13, vec![0, 3])]);
+
+        let (_, sample) = &phase_sets[&13][1];
+        assert_eq!(sample.genotype().transpose()?, Some("1|1".parse()?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_subset_to_format_keys() -> Result<(), Box<dyn std::error::Error>> {
+        let genotypes = Genotypes::new(
+            Keys::try_from(vec![key::GENOTYPE, key::CONDITIONAL_GENOTYPE_QUALITY])?,
+            vec![
+                vec![Some(Value::from("0|0")), Some(Value::from(13))],
+                vec![Some(Value::from("1/1"))],
+                Vec::new(),
+            ],
+        );
+
+        let actual = genotypes.subset_to_format_keys(&[key::CONDITIONAL_GENOTYPE_QUALITY])?;
+        let expected = Genotypes::new(
+            Keys::try_from(vec![key::CONDITIONAL_GENOTYPE_QUALITY])?,
+            vec![vec![Some(Value::from(13))], vec![None], Vec::new()],
+        );
+        assert_eq!(actual, expected);
+
+        let actual = genotypes.subset_to_format_keys(&[])?;
+        let expected = Genotypes::new(Keys::default(), vec![Vec::new(), Vec::new(), Vec::new()]);
+        assert_eq!(actual, expected);
+
+        assert_eq!(
+            genotypes.subset_to_format_keys(&[key::READ_DEPTH]),
+            Err(GenotypesError::UnknownKey(key::READ_DEPTH))
+        );
+
+        assert_eq!(
+            genotypes.subset_to_format_keys(&[key::CONDITIONAL_GENOTYPE_QUALITY, key::GENOTYPE]),
+            Err(GenotypesError::InvalidKeys(
+                keys::TryFromKeyVectorError::InvalidGenotypeKeyPosition
+            ))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), super::keys::TryFromKeyVectorError> {
         let genotypes = Genotypes::new(
@@ -53,6 +53,18 @@ impl<'g> Sample<'g> {
             _ => Err(GenotypeError::InvalidValueType(value.cloned())),
         })
     }
+
+    /// Returns the VCF record genotypes phase set value.
+    ///
+    /// This is a convenience method to return the phase set (`PS`) field value. A missing value
+    /// (`.`) is treated the same as a missing field.
+    pub fn phase_set(&self) -> Option<Result<i32, PhaseSetError>> {
+        self.get(&key::PHASE_SET).and_then(|value| match value {
+            None => None,
+            Some(Value::Integer(n)) => Some(Ok(*n)),
+            Some(_) => Some(Err(PhaseSetError::InvalidValueType(value.cloned()))),
+        })
+    }
 }
 
 /// An error returned when a raw VCF genotype fails to parse.
@@ -116,6 +128,25 @@ impl fmt::Display for GenotypeError {
     }
 }
 
+/// An error returned when a phase set (`PS`) field value is invalid.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PhaseSetError {
+    /// The phase set field value type is invalid.
+    ///
+    /// The `PS` field value must be an `Integer`.
+    InvalidValueType(Option<Value>),
+}
+
+impl error::Error for PhaseSetError {}
+
+impl fmt::Display for PhaseSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidValueType(value) => write!(f, "invalid Integer, got {value:?}"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,4 +173,32 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_phase_set() -> Result<(), crate::record::genotypes::keys::TryFromKeyVectorError> {
+        let keys = Keys::try_from(vec![key::GENOTYPE, key::PHASE_SET])?;
+
+        let values = vec![Some(Value::from("0|1")), Some(Value::from(8))];
+        let sample = Sample::new(&keys, &values);
+        assert_eq!(sample.phase_set(), Some(Ok(8)));
+
+        let values = vec![Some(Value::from("0|1")), None];
+        let sample = Sample::new(&keys, &values);
+        assert!(sample.phase_set().is_none());
+
+        let values = vec![Some(Value::from("0|1"))];
+        let sample = Sample::new(&keys, &values);
+        assert!(sample.phase_set().is_none());
+
+        let values = vec![Some(Value::from("0|1")), Some(Value::from("ndls"))];
+        let sample = Sample::new(&keys, &values);
+        assert_eq!(
+            sample.phase_set(),
+            Some(Err(PhaseSetError::InvalidValueType(Some(Value::from(
+                "ndls"
+            )))))
+        );
+
+        Ok(())
+    }
 }
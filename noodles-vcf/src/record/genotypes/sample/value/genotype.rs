@@ -15,6 +15,130 @@ use std::{
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Genotype(Vec<Allele>);
 
+impl Genotype {
+    /// Returns an iterator over the allele positions.
+    ///
+    /// An allele position is the index of the allele in the record's reference and alternate
+    /// bases, i.e., 0 is the reference allele. A missing allele (`.`) is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::sample::value::Genotype;
+    /// let genotype: Genotype = "0/.".parse()?;
+    /// assert_eq!(genotype.allele_positions().collect::<Vec<_>>(), [Some(0), None]);
+    /// # Ok::<_, noodles_vcf::record::genotypes::sample::value::genotype::ParseError>(())
+    /// ```
+    pub fn allele_positions(&self) -> impl Iterator<Item = Option<usize>> + '_ {
+        self.0.iter().map(|allele| allele.position())
+    }
+
+    /// Returns the number of alleles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::sample::value::Genotype;
+    /// assert_eq!("0".parse::<Genotype>()?.ploidy(), 1);
+    /// assert_eq!("0/1".parse::<Genotype>()?.ploidy(), 2);
+    /// # Ok::<_, noodles_vcf::record::genotypes::sample::value::genotype::ParseError>(())
+    /// ```
+    pub fn ploidy(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether all alleles are phased.
+    ///
+    /// A haploid genotype is phased.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::sample::value::Genotype;
+    /// assert!("0|1".parse::<Genotype>()?.is_phased());
+    /// assert!(!"0/1".parse::<Genotype>()?.is_phased());
+    /// assert!(!"0|1/2".parse::<Genotype>()?.is_phased());
+    /// # Ok::<_, noodles_vcf::record::genotypes::sample::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_phased(&self) -> bool {
+        self.0
+            .iter()
+            .all(|allele| allele.phasing() == allele::Phasing::Phased)
+    }
+
+    /// Returns whether all alleles are missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::sample::value::Genotype;
+    /// assert!("./.".parse::<Genotype>()?.is_missing());
+    /// assert!(!"0/.".parse::<Genotype>()?.is_missing());
+    /// # Ok::<_, noodles_vcf::record::genotypes::sample::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_missing(&self) -> bool {
+        self.allele_positions().all(|position| position.is_none())
+    }
+
+    /// Returns whether all alleles are the reference allele.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::sample::value::Genotype;
+    /// assert!("0/0".parse::<Genotype>()?.is_hom_ref());
+    /// assert!(!"0/1".parse::<Genotype>()?.is_hom_ref());
+    /// assert!(!"0/.".parse::<Genotype>()?.is_hom_ref());
+    /// # Ok::<_, noodles_vcf::record::genotypes::sample::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_hom_ref(&self) -> bool {
+        self.allele_positions().all(|position| position == Some(0))
+    }
+
+    /// Returns whether all alleles are the same alternate allele.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::sample::value::Genotype;
+    /// assert!("1/1".parse::<Genotype>()?.is_hom_alt());
+    /// assert!(!"1/2".parse::<Genotype>()?.is_hom_alt());
+    /// assert!(!"0/0".parse::<Genotype>()?.is_hom_alt());
+    /// # Ok::<_, noodles_vcf::record::genotypes::sample::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_hom_alt(&self) -> bool {
+        let mut positions = self.allele_positions();
+
+        match positions.next() {
+            Some(Some(first)) if first > 0 => positions.all(|position| position == Some(first)),
+            _ => false,
+        }
+    }
+
+    /// Returns whether there are at least two different called alleles.
+    ///
+    /// Missing alleles are ignored, e.g., `0/1/.` is heterozygous, but `0/.` is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::sample::value::Genotype;
+    /// assert!("0/1".parse::<Genotype>()?.is_het());
+    /// assert!("1|2".parse::<Genotype>()?.is_het());
+    /// assert!(!"1/1".parse::<Genotype>()?.is_het());
+    /// assert!(!"0/.".parse::<Genotype>()?.is_het());
+    /// # Ok::<_, noodles_vcf::record::genotypes::sample::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_het(&self) -> bool {
+        let mut positions = self.allele_positions().flatten();
+
+        match positions.next() {
+            Some(first) => positions.any(|position| position != first),
+            None => false,
+        }
+    }
+}
+
 impl Deref for Genotype {
     type Target = [Allele];
 
@@ -141,6 +265,42 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_predicates() -> Result<(), ParseError> {
+        fn t(s: &str, expected: (usize, bool, bool, bool, bool, bool)) -> Result<(), ParseError> {
+            let genotype: Genotype = s.parse()?;
+
+            let actual = (
+                genotype.ploidy(),
+                genotype.is_phased(),
+                genotype.is_missing(),
+                genotype.is_hom_ref(),
+                genotype.is_hom_alt(),
+                genotype.is_het(),
+            );
+
+            assert_eq!(actual, expected, "{s}");
+
+            Ok(())
+        }
+
+        // (ploidy, is_phased, is_missing, is_hom_ref, is_hom_alt, is_het)
+        t("0", (1, true, false, true, false, false))?;
+        t("1", (1, true, false, false, true, false))?;
+        t(".", (1, true, true, false, false, false))?;
+        t("0/0", (2, false, false, true, false, false))?;
+        t("0|1", (2, true, false, false, false, true))?;
+        t("1/1", (2, false, false, false, true, false))?;
+        t("1|2", (2, true, false, false, false, true))?;
+        t("./.", (2, false, true, false, false, false))?;
+        t("0/.", (2, false, false, false, false, false))?;
+        t("1/.", (2, false, false, false, false, false))?;
+        t("0/1/.", (3, false, false, false, false, true))?;
+        t("2/2|2", (3, false, false, false, true, false))?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_try_from_alleles_for_genotype() {
         use allele::Phasing;
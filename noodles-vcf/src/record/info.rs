@@ -6,8 +6,8 @@ use std::{error, fmt, hash::Hash, str::FromStr};
 
 use indexmap::IndexMap;
 
-use self::field::Key;
-use crate::header;
+use self::field::{value::Array, Key, Value};
+use crate::header::{self, record::value::map::info::Type};
 
 const DELIMITER: char = ';';
 
@@ -89,6 +89,206 @@ impl Info {
         self.0.get(key).map(|value| value.as_ref())
     }
 
+    /// Returns the integer value with the given key.
+    ///
+    /// This returns `None` if the field is absent or its value is missing (`.`) and an error if
+    /// the value is not an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{info::field::{key, Value}, Info};
+    ///
+    /// let dp = (key::TOTAL_DEPTH, Some(Value::Integer(13)));
+    /// let info: Info = [dp].into_iter().collect();
+    ///
+    /// assert_eq!(info.get_integer(&key::TOTAL_DEPTH), Some(Ok(13)));
+    /// assert!(info.get_float(&key::TOTAL_DEPTH).unwrap().is_err());
+    /// assert!(info.get_integer(&key::SAMPLES_WITH_DATA_COUNT).is_none());
+    /// ```
+    pub fn get_integer<K>(&self, key: &K) -> Option<Result<i32, TypeMismatchError>>
+    where
+        K: Hash + indexmap::Equivalent<Key>,
+    {
+        self.get_typed(key, Type::Integer, false, |value| match value {
+            Value::Integer(n) => Some(*n),
+            _ => None,
+        })
+    }
+
+    /// Returns the floating-point value with the given key.
+    ///
+    /// This returns `None` if the field is absent or its value is missing (`.`) and an error if
+    /// the value is not a floating-point.
+    pub fn get_float<K>(&self, key: &K) -> Option<Result<f32, TypeMismatchError>>
+    where
+        K: Hash + indexmap::Equivalent<Key>,
+    {
+        self.get_typed(key, Type::Float, false, |value| match value {
+            Value::Float(n) => Some(*n),
+            _ => None,
+        })
+    }
+
+    /// Returns whether the flag with the given key is set.
+    ///
+    /// An absent field is an unset flag. This returns an error if the value is not a flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{info::field::{key, Value}, Info};
+    ///
+    /// let somatic = (key::IS_SOMATIC_MUTATION, Some(Value::Flag));
+    /// let info: Info = [somatic].into_iter().collect();
+    ///
+    /// assert_eq!(info.get_flag(&key::IS_SOMATIC_MUTATION), Ok(true));
+    /// assert_eq!(info.get_flag(&key::IS_IN_DB_SNP), Ok(false));
+    /// ```
+    pub fn get_flag<K>(&self, key: &K) -> Result<bool, TypeMismatchError>
+    where
+        K: Hash + indexmap::Equivalent<Key>,
+    {
+        self.get_typed(key, Type::Flag, false, |value| match value {
+            Value::Flag => Some(true),
+            _ => None,
+        })
+        .unwrap_or(Ok(false))
+    }
+
+    /// Returns the character value with the given key.
+    ///
+    /// This returns `None` if the field is absent or its value is missing (`.`) and an error if
+    /// the value is not a character.
+    pub fn get_character<K>(&self, key: &K) -> Option<Result<char, TypeMismatchError>>
+    where
+        K: Hash + indexmap::Equivalent<Key>,
+    {
+        self.get_typed(key, Type::Character, false, |value| match value {
+            Value::Character(c) => Some(*c),
+            _ => None,
+        })
+    }
+
+    /// Returns the string value with the given key.
+    ///
+    /// This returns `None` if the field is absent or its value is missing (`.`) and an error if
+    /// the value is not a string.
+    pub fn get_string<K>(&self, key: &K) -> Option<Result<&str, TypeMismatchError>>
+    where
+        K: Hash + indexmap::Equivalent<Key>,
+    {
+        self.get_typed(key, Type::String, false, |value| match value {
+            Value::String(s) => Some(s.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Returns the integer array value with the given key.
+    ///
+    /// This returns `None` if the field is absent or its value is missing (`.`) and an error if
+    /// the value is not an integer array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{info::field::{key, value::Array, Value}, Info};
+    ///
+    /// let ac = (
+    ///     key::ALLELE_COUNT,
+    ///     Some(Value::Array(Array::Integer(vec![Some(2), None]))),
+    /// );
+    /// let info: Info = [ac].into_iter().collect();
+    ///
+    /// assert_eq!(
+    ///     info.get_integer_array(&key::ALLELE_COUNT),
+    ///     Some(Ok(&[Some(2), None][..]))
+    /// );
+    /// assert!(info.get_integer(&key::ALLELE_COUNT).unwrap().is_err());
+    /// ```
+    pub fn get_integer_array<K>(&self, key: &K) -> Option<Result<&[Option<i32>], TypeMismatchError>>
+    where
+        K: Hash + indexmap::Equivalent<Key>,
+    {
+        self.get_typed(key, Type::Integer, true, |value| match value {
+            Value::Array(Array::Integer(values)) => Some(values.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// Returns the floating-point array value with the given key.
+    ///
+    /// This returns `None` if the field is absent or its value is missing (`.`) and an error if
+    /// the value is not a floating-point array.
+    pub fn get_float_array<K>(&self, key: &K) -> Option<Result<&[Option<f32>], TypeMismatchError>>
+    where
+        K: Hash + indexmap::Equivalent<Key>,
+    {
+        self.get_typed(key, Type::Float, true, |value| match value {
+            Value::Array(Array::Float(values)) => Some(values.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// Returns the character array value with the given key.
+    ///
+    /// This returns `None` if the field is absent or its value is missing (`.`) and an error if
+    /// the value is not a character array.
+    pub fn get_character_array<K>(
+        &self,
+        key: &K,
+    ) -> Option<Result<&[Option<char>], TypeMismatchError>>
+    where
+        K: Hash + indexmap::Equivalent<Key>,
+    {
+        self.get_typed(key, Type::Character, true, |value| match value {
+            Value::Array(Array::Character(values)) => Some(values.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// Returns the string array value with the given key.
+    ///
+    /// This returns `None` if the field is absent or its value is missing (`.`) and an error if
+    /// the value is not a string array.
+    pub fn get_string_array<K>(
+        &self,
+        key: &K,
+    ) -> Option<Result<&[Option<String>], TypeMismatchError>>
+    where
+        K: Hash + indexmap::Equivalent<Key>,
+    {
+        self.get_typed(key, Type::String, true, |value| match value {
+            Value::Array(Array::String(values)) => Some(values.as_slice()),
+            _ => None,
+        })
+    }
+
+    fn get_typed<'a, K, T, F>(
+        &'a self,
+        key: &K,
+        ty: Type,
+        is_array: bool,
+        f: F,
+    ) -> Option<Result<T, TypeMismatchError>>
+    where
+        K: Hash + indexmap::Equivalent<Key>,
+        F: FnOnce(&'a Value) -> Option<T>,
+    {
+        let value = self.get(key)??;
+
+        Some(f(value).ok_or_else(|| {
+            let (actual, actual_is_array) = value_type(value);
+
+            TypeMismatchError {
+                expected: ty,
+                expected_is_array: is_array,
+                actual,
+                actual_is_array,
+            }
+        }))
+    }
+
     /// Returns a mutable reference to the field value with the given key.
     ///
     /// # Examples
@@ -355,10 +555,120 @@ impl fmt::Display for TryFromFieldsError {
     }
 }
 
+/// An error returned when a VCF info field value is not of the requested type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TypeMismatchError {
+    expected: Type,
+    expected_is_array: bool,
+    actual: Type,
+    actual_is_array: bool,
+}
+
+impl TypeMismatchError {
+    /// Returns the requested value type.
+    pub fn expected(&self) -> Type {
+        self.expected
+    }
+
+    /// Returns the type of the value.
+    ///
+    /// For an array, this is the type of its elements.
+    pub fn actual(&self) -> Type {
+        self.actual
+    }
+}
+
+impl error::Error for TypeMismatchError {}
+
+impl fmt::Display for TypeMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn fmt_type(f: &mut fmt::Formatter<'_>, ty: Type, is_array: bool) -> fmt::Result {
+            write!(f, "{ty}")?;
+
+            if is_array {
+                f.write_str(" array")?;
+            }
+
+            Ok(())
+        }
+
+        f.write_str("type mismatch: expected ")?;
+        fmt_type(f, self.expected, self.expected_is_array)?;
+        f.write_str(", got ")?;
+        fmt_type(f, self.actual, self.actual_is_array)
+    }
+}
+
+fn value_type(value: &Value) -> (Type, bool) {
+    match value {
+        Value::Integer(_) => (Type::Integer, false),
+        Value::Float(_) => (Type::Float, false),
+        Value::Flag => (Type::Flag, false),
+        Value::Character(_) => (Type::Character, false),
+        Value::String(_) => (Type::String, false),
+        Value::Array(Array::Integer(_)) => (Type::Integer, true),
+        Value::Array(Array::Float(_)) => (Type::Float, true),
+        Value::Array(Array::Character(_)) => (Type::Character, true),
+        Value::Array(Array::String(_)) => (Type::String, true),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{field::key, *};
 
+    #[test]
+    fn test_get_typed() {
+        let info: Info = [
+            (key::TOTAL_DEPTH, Some(Value::Integer(13))),
+            (key::SAMPLES_WITH_DATA_COUNT, None),
+            (key::IS_SOMATIC_MUTATION, Some(Value::Flag)),
+            (
+                key::ALLELE_FREQUENCIES,
+                Some(Value::Array(Array::Float(vec![Some(0.5)]))),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(info.get_integer(&key::TOTAL_DEPTH), Some(Ok(13)));
+        assert!(info.get_integer(&key::SAMPLES_WITH_DATA_COUNT).is_none());
+        assert!(info.get_integer(&key::ALLELE_COUNT).is_none());
+
+        assert_eq!(info.get_flag(&key::IS_SOMATIC_MUTATION), Ok(true));
+        assert_eq!(info.get_flag(&key::IS_IN_DB_SNP), Ok(false));
+
+        assert_eq!(
+            info.get_float_array(&key::ALLELE_FREQUENCIES),
+            Some(Ok(&[Some(0.5)][..]))
+        );
+
+        let e = TypeMismatchError {
+            expected: Type::String,
+            expected_is_array: false,
+            actual: Type::Integer,
+            actual_is_array: false,
+        };
+        assert_eq!(info.get_string(&key::TOTAL_DEPTH), Some(Err(e)));
+
+        let e = TypeMismatchError {
+            expected: Type::Float,
+            expected_is_array: false,
+            actual: Type::Float,
+            actual_is_array: true,
+        };
+        assert_eq!(
+            info.get_float(&key::ALLELE_FREQUENCIES),
+            Some(Err(e.clone()))
+        );
+        assert_eq!(
+            e.to_string(),
+            "type mismatch: expected Float, got Float array"
+        );
+
+        assert!(info.get_flag(&key::TOTAL_DEPTH).is_err());
+    }
+
     #[test]
     fn test_fmt() {
         let info = Info::default();
@@ -5,7 +5,7 @@ pub fn parse(s: &str, header: &Header) -> Result<Record, ParseError> {
     use crate::reader::parse_record;
 
     let mut record = Record::default();
-    parse_record(s, header, &mut record)?;
+    parse_record(s, header, &mut record, false)?;
     Ok(record)
 }
 
@@ -1,12 +1,13 @@
 //! VCF writer.
 
 mod builder;
+mod key_order;
 mod record;
 
 use std::io::{self, Write};
 
-pub use self::builder::Builder;
 use self::record::write_record;
+pub use self::{builder::Builder, key_order::KeyOrder};
 use super::{Header, Record, VariantWriter};
 
 /// A VCF writer.
@@ -49,6 +50,11 @@ use super::{Header, Record, VariantWriter};
 #[derive(Debug)]
 pub struct Writer<W> {
     inner: W,
+    samples: Option<Vec<String>>,
+    sample_indices: Option<Vec<usize>>,
+    key_order: KeyOrder,
+    info_indices: Vec<usize>,
+    format_indices: Vec<usize>,
 }
 
 impl<W> Writer<W>
@@ -64,7 +70,14 @@ where
     /// let writer = vcf::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            samples: None,
+            sample_indices: None,
+            key_order: KeyOrder::default(),
+            info_indices: Vec::new(),
+            format_indices: Vec::new(),
+        }
     }
 
     /// Returns a reference to the underlying writer.
@@ -108,6 +121,13 @@ where
 
     /// Writes a VCF header.
     ///
+    /// If the writer was built with a sample subset ([`Builder::set_samples`]), only those sample
+    /// names are written, in the given order.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if a sample in the sample subset is not in the header.
+    ///
     /// # Examples
     ///
     /// ```
@@ -121,11 +141,36 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
-        write!(self.inner, "{header}")
+        if let Some(samples) = &self.samples {
+            let sample_indices = samples
+                .iter()
+                .map(|sample| {
+                    header.sample_names().get_index_of(sample).ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            format!("sample not in header: {sample}"),
+                        )
+                    })
+                })
+                .collect::<io::Result<_>>()?;
+
+            let mut subset_header = header.clone();
+            *subset_header.sample_names_mut() = samples.iter().cloned().collect();
+            write!(self.inner, "{subset_header}")?;
+
+            self.sample_indices = Some(sample_indices);
+
+            Ok(())
+        } else {
+            write!(self.inner, "{header}")
+        }
     }
 
     /// Writes a VCF record.
     ///
+    /// INFO and FORMAT keys are written in the writer key order ([`Builder::set_key_order`]).
+    /// The header is used to resolve the definition order of keys.
+    ///
     /// # Examples
     ///
     /// ```
@@ -143,8 +188,72 @@ where
     /// writer.write_record(&header, &record)?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn write_record(&mut self, _: &Header, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, record)
+    pub fn write_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
+        write_record(
+            &mut self.inner,
+            record,
+            self.sample_indices.as_deref(),
+            self.key_order
+                .info_indices(header, record.info(), &mut self.info_indices),
+            self.key_order.format_indices(
+                header,
+                record.genotypes().keys(),
+                &mut self.format_indices,
+            ),
+        )
+    }
+
+    /// Serializes a VCF record to a buffer.
+    ///
+    /// The record, including its trailing line feed, is appended to `dst`. It is serialized the
+    /// same as [`Self::write_record`], i.e., using the writer samples and key order, but it is not
+    /// written to the underlying writer.
+    ///
+    /// Reusing the same buffer between records avoids an allocation per record. The writer also
+    /// reuses its scratch buffers for ordering INFO and FORMAT keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::Position};
+    ///
+    /// let header = vcf::Header::default();
+    /// let mut writer = vcf::Writer::new(Vec::new());
+    /// let mut buf = Vec::new();
+    ///
+    /// for position in [1, 8] {
+    ///     let record = vcf::Record::builder()
+    ///         .set_chromosome("sq0".parse()?)
+    ///         .set_position(Position::from(position))
+    ///         .set_reference_bases("A".parse()?)
+    ///         .build()?;
+    ///
+    ///     buf.clear();
+    ///     writer.encode_record(&mut buf, &header, &record)?;
+    /// }
+    ///
+    /// assert_eq!(buf, b"sq0\t8\t.\tA\t.\t.\t.\t.\n");
+    /// assert!(writer.get_ref().is_empty());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn encode_record(
+        &mut self,
+        dst: &mut Vec<u8>,
+        header: &Header,
+        record: &Record,
+    ) -> io::Result<()> {
+        write_record(
+            dst,
+            record,
+            self.sample_indices.as_deref(),
+            self.key_order
+                .info_indices(header, record.info(), &mut self.info_indices),
+            self.key_order.format_indices(
+                header,
+                record.genotypes().keys(),
+                &mut self.format_indices,
+            ),
+        )
     }
 }
 
@@ -233,4 +342,160 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_encode_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = "##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##FORMAT=<ID=DP,Number=1,Type=Integer,Description=\"Read depth\">
+##FORMAT=<ID=GQ,Number=1,Type=Integer,Description=\"Conditional genotype quality\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1
+"
+        .parse()?;
+
+        let mut writer = Builder::default()
+            .set_samples(vec![String::from("sample1")])
+            .set_key_order(KeyOrder::Header)
+            .build_with_writer(Vec::new());
+
+        writer.write_header(&header)?;
+        let header_len = writer.get_ref().len();
+
+        let mut buf = Vec::new();
+
+        for (record, expected) in [
+            (
+                "sq0\t1\t.\tA\t.\t.\t.\tDP=13;NS=2\tGT:GQ:DP\t0|0:8:13\t0|1:5:21",
+                &b"sq0\t1\t.\tA\t.\t.\t.\tNS=2;DP=13\tGT:DP:GQ\t0|1:21:5\n"[..],
+            ),
+            (
+                "sq0\t8\t.\tC\t.\t.\t.\tDP=5\tGT:GQ\t1|1:3\t1|0:34",
+                &b"sq0\t8\t.\tC\t.\t.\t.\tDP=5\tGT:GQ\t1|0:34\n"[..],
+            ),
+        ] {
+            let record = Record::try_from((&header, record))?;
+
+            buf.clear();
+            writer.encode_record(&mut buf, &header, &record)?;
+            assert_eq!(buf, expected);
+        }
+
+        assert_eq!(writer.get_ref().len(), header_len);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_samples() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::{
+            genotypes::{keys::key, sample::Value, Keys},
+            Genotypes,
+        };
+
+        let header = Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .build();
+
+        let genotypes = Genotypes::new(
+            Keys::try_from(vec![key::GENOTYPE])?,
+            vec![
+                vec![Some(Value::from("0|0"))],
+                vec![Some(Value::from("0|1"))],
+                vec![Some(Value::from("1|1"))],
+            ],
+        );
+
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(1))
+            .set_reference_bases("A".parse()?)
+            .set_genotypes(genotypes)
+            .build()?;
+
+        let mut writer = Builder::default()
+            .set_samples(vec![String::from("sample2"), String::from("sample0")])
+            .build_with_writer(Vec::new());
+
+        writer.write_header(&header)?;
+        writer.write_record(&header, &record)?;
+
+        let expected = b"##fileformat=VCFv4.4
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample2\tsample0
+sq0\t1\t.\tA\t.\t.\t.\t.\tGT\t1|1\t0|0
+";
+        assert_eq!(writer.get_ref().as_slice(), &expected[..]);
+
+        let mut writer = Builder::default()
+            .set_samples(Vec::new())
+            .build_with_writer(Vec::new());
+
+        writer.write_header(&header)?;
+        writer.write_record(&header, &record)?;
+
+        let expected = b"##fileformat=VCFv4.4
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\t.\t.
+";
+        assert_eq!(writer.get_ref().as_slice(), &expected[..]);
+
+        let mut writer = Builder::default()
+            .set_samples(vec![String::from("sample3")])
+            .build_with_writer(Vec::new());
+
+        assert!(matches!(
+            writer.write_header(&header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_key_order() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = "##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##FORMAT=<ID=GQ,Number=1,Type=Integer,Description=\"Conditional genotype quality\">
+##FORMAT=<ID=DP,Number=1,Type=Integer,Description=\"Read depth\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+"
+        .parse()?;
+
+        let record = Record::try_from((
+            &header,
+            "sq0\t1\t.\tA\t.\t.\t.\tDP=13;NS=1\tGT:DP:GQ\t0|0:13:8",
+        ))?;
+
+        let mut writer = Builder::default()
+            .set_key_order(KeyOrder::Header)
+            .build_with_writer(Vec::new());
+        writer.write_record(&header, &record)?;
+        assert_eq!(
+            writer.get_ref(),
+            b"sq0\t1\t.\tA\t.\t.\t.\tNS=1;DP=13\tGT:GQ:DP\t0|0:8:13\n"
+        );
+
+        let mut writer = Builder::default()
+            .set_key_order(KeyOrder::Lexicographical)
+            .build_with_writer(Vec::new());
+        writer.write_record(&header, &record)?;
+        assert_eq!(
+            writer.get_ref(),
+            b"sq0\t1\t.\tA\t.\t.\t.\tDP=13;NS=1\tGT:DP:GQ\t0|0:13:8\n"
+        );
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_record(&header, &record)?;
+        assert_eq!(
+            writer.get_ref(),
+            b"sq0\t1\t.\tA\t.\t.\t.\tDP=13;NS=1\tGT:DP:GQ\t0|0:13:8\n"
+        );
+
+        Ok(())
+    }
 }
@@ -6,20 +6,63 @@ use std::{
 
 use noodles_bgzf as bgzf;
 
-use super::Writer;
+use super::{KeyOrder, Writer};
 
-/// A BAM writer builder.
+/// A VCF writer builder.
 #[derive(Debug, Default)]
-pub struct Builder;
+pub struct Builder {
+    samples: Option<Vec<String>>,
+    key_order: KeyOrder,
+}
 
 impl Builder {
+    /// Sets the samples to write.
+    ///
+    /// Only these samples are written, in the given order, and the other samples are dropped
+    /// from both the header and the records, similar to `bcftools view --samples`. Other fields,
+    /// e.g., allele counts in the INFO field, are written unchanged.
+    ///
+    /// The samples are checked against the header when it is written, i.e., writing a header
+    /// that does not include a given sample returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let builder = vcf::writer::Builder::default()
+    ///     .set_samples(vec![String::from("sample0")]);
+    /// ```
+    pub fn set_samples(mut self, samples: Vec<String>) -> Self {
+        self.samples = Some(samples);
+        self
+    }
+
+    /// Sets the order in which INFO and FORMAT keys are written.
+    ///
+    /// By default, keys are written in the order they are in the record
+    /// ([`KeyOrder::Insertion`]). Using a canonical order, i.e., [`KeyOrder::Header`] or
+    /// [`KeyOrder::Lexicographical`], writes the same output regardless of the order the fields
+    /// were added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, writer::KeyOrder};
+    /// let builder = vcf::writer::Builder::default().set_key_order(KeyOrder::Header);
+    /// ```
+    pub fn set_key_order(mut self, key_order: KeyOrder) -> Self {
+        self.key_order = key_order;
+        self
+    }
+
     /// Builds a VCF writer from a path.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// use noodles_vcf as vcf;
-    /// let writer = vcf::writer::Builder.build_from_path("out.vcf")?;
+    /// let writer = vcf::writer::Builder::default().build_from_path("out.vcf")?;
     /// # Ok::<_, std::io::Error>(())
     /// ```
     pub fn build_from_path<P>(self, dst: P) -> io::Result<Writer<Box<dyn Write>>>
@@ -35,6 +78,25 @@ impl Builder {
             _ => Box::new(BufWriter::new(file)),
         };
 
-        Ok(Writer::new(writer))
+        Ok(self.build_with_writer(writer))
+    }
+
+    /// Builds a VCF writer from a writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::writer::Builder::default().build_with_writer(io::sink());
+    /// ```
+    pub fn build_with_writer<W>(self, writer: W) -> Writer<W>
+    where
+        W: Write,
+    {
+        let mut writer = Writer::new(writer);
+        writer.samples = self.samples;
+        writer.key_order = self.key_order;
+        writer
     }
 }
@@ -0,0 +1,157 @@
+use crate::{
+    record::{genotypes::Keys, Info},
+    Header,
+};
+
+/// The order in which a VCF writer writes INFO and FORMAT keys.
+///
+/// When keys are reordered, the genotype sample values are reordered with their keys. `GT` is
+/// always written as the first FORMAT key, if present.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum KeyOrder {
+    /// Write keys in the order they are in the record.
+    ///
+    /// This preserves the input when reading and writing records.
+    #[default]
+    Insertion,
+    /// Write keys in the order they are defined in the header.
+    ///
+    /// Keys that are not defined in the header are written last, in the order they are in the
+    /// record.
+    Header,
+    /// Write keys in lexicographical order.
+    Lexicographical,
+}
+
+impl KeyOrder {
+    // Returns the indices of the INFO fields in write order, or `None` to use insertion order.
+    //
+    // `buf` is a scratch buffer that holds the indices.
+    pub(super) fn info_indices<'a>(
+        self,
+        header: &Header,
+        info: &Info,
+        buf: &'a mut Vec<usize>,
+    ) -> Option<&'a [usize]> {
+        let fields = info.as_ref();
+        let key = |i: usize| fields.get_index(i).map(|(key, _)| key);
+
+        match self {
+            Self::Insertion => None,
+            Self::Header => Some(sort_indices(buf, fields.len(), |i| {
+                key(i)
+                    .and_then(|key| header.infos().get_index_of(key))
+                    .unwrap_or(usize::MAX)
+            })),
+            Self::Lexicographical => Some(sort_indices(buf, fields.len(), |i| {
+                key(i).map(|key| key.as_ref())
+            })),
+        }
+    }
+
+    // Returns the indices of the FORMAT keys in write order, or `None` to use insertion order.
+    //
+    // `buf` is a scratch buffer that holds the indices.
+    pub(super) fn format_indices<'a>(
+        self,
+        header: &Header,
+        keys: &Keys,
+        buf: &'a mut Vec<usize>,
+    ) -> Option<&'a [usize]> {
+        use crate::record::genotypes::keys::key;
+
+        match self {
+            Self::Insertion => None,
+            Self::Header => Some(sort_indices(buf, keys.len(), |i| {
+                let key = keys.get_index(i);
+                let j = key
+                    .and_then(|key| header.formats().get_index_of(key))
+                    .unwrap_or(usize::MAX);
+                (key != Some(&key::GENOTYPE), j)
+            })),
+            Self::Lexicographical => Some(sort_indices(buf, keys.len(), |i| {
+                let key = keys.get_index(i);
+                (key != Some(&key::GENOTYPE), key.map(|key| key.as_ref()))
+            })),
+        }
+    }
+}
+
+// The sort is stable, so keys with equal sort keys keep their insertion order.
+fn sort_indices<F, K>(buf: &mut Vec<usize>, len: usize, mut f: F) -> &[usize]
+where
+    F: FnMut(usize) -> K,
+    K: Ord,
+{
+    buf.clear();
+    buf.extend(0..len);
+    buf.sort_by_key(|&i| f(i));
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_info_indices() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = "##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+"
+        .parse()?;
+
+        let info = Info::try_from_str("DP=13;ZZ=1;NS=2;AA=3", header.infos())?;
+
+        let mut buf = Vec::new();
+
+        assert!(KeyOrder::Insertion
+            .info_indices(&header, &info, &mut buf)
+            .is_none());
+        assert_eq!(
+            KeyOrder::Header.info_indices(&header, &info, &mut buf),
+            Some(&[2, 0, 1, 3][..])
+        );
+        assert_eq!(
+            KeyOrder::Lexicographical.info_indices(&header, &info, &mut buf),
+            Some(&[3, 0, 2, 1][..])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_indices() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = "##fileformat=VCFv4.3
+##FORMAT=<ID=DP,Number=1,Type=Integer,Description=\"Read depth\">
+##FORMAT=<ID=GQ,Number=1,Type=Integer,Description=\"Conditional genotype quality\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+"
+        .parse()?;
+
+        // `Keys` parsing requires `GT` to be first.
+        let mut keys = Keys::default();
+
+        for key in ["GQ", "AD", "GT", "DP"] {
+            keys.insert(key.parse()?);
+        }
+
+        let mut buf = Vec::new();
+
+        assert!(KeyOrder::Insertion
+            .format_indices(&header, &keys, &mut buf)
+            .is_none());
+        assert_eq!(
+            KeyOrder::Header.format_indices(&header, &keys, &mut buf),
+            Some(&[2, 3, 0, 1][..])
+        );
+        assert_eq!(
+            KeyOrder::Lexicographical.format_indices(&header, &keys, &mut buf),
+            Some(&[2, 1, 3, 0][..])
+        );
+
+        Ok(())
+    }
+}
@@ -15,7 +15,13 @@ use crate::Record;
 
 const MISSING: &[u8] = b".";
 
-pub(super) fn write_record<W>(writer: &mut W, record: &Record) -> io::Result<()>
+pub(super) fn write_record<W>(
+    writer: &mut W,
+    record: &Record,
+    sample_indices: Option<&[usize]>,
+    info_indices: Option<&[usize]>,
+    format_indices: Option<&[usize]>,
+) -> io::Result<()>
 where
     W: Write,
 {
@@ -47,11 +53,11 @@ where
     write_filters(writer, record.filters())?;
 
     writer.write_all(DELIMITER)?;
-    write_info(writer, record.info())?;
+    write_info(writer, record.info(), info_indices)?;
 
-    if !record.genotypes().is_empty() {
+    if !matches!(sample_indices, Some([])) && !record.genotypes().is_empty() {
         writer.write_all(DELIMITER)?;
-        write_genotypes(writer, record.genotypes())?;
+        write_genotypes(writer, record.genotypes(), sample_indices, format_indices)?;
     }
 
     writer.write_all(b"\n")?;
@@ -73,7 +79,7 @@ mod tests {
             .build()?;
 
         let mut buf = Vec::new();
-        write_record(&mut buf, &record)?;
+        write_record(&mut buf, &record, None, None, None)?;
         assert_eq!(buf, b"sq0\t1\t.\tA\t.\t.\t.\t.\n");
 
         Ok(())
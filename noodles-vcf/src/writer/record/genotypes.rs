@@ -9,29 +9,54 @@ use crate::record::{
     Genotypes,
 };
 
-pub(super) fn write_genotypes<W>(writer: &mut W, genotypes: &Genotypes) -> io::Result<()>
+pub(super) fn write_genotypes<W>(
+    writer: &mut W,
+    genotypes: &Genotypes,
+    sample_indices: Option<&[usize]>,
+    key_indices: Option<&[usize]>,
+) -> io::Result<()>
 where
     W: Write,
 {
     const DELIMITER: &[u8] = b"\t";
 
-    write_keys(writer, genotypes.keys())?;
+    write_keys(writer, genotypes.keys(), key_indices)?;
 
-    for sample in genotypes.values() {
-        writer.write_all(DELIMITER)?;
-        write_sample(writer, &sample)?;
+    if let Some(indices) = sample_indices {
+        for &i in indices {
+            let sample = genotypes.get_index(i).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("missing sample at index {i}"),
+                )
+            })?;
+
+            writer.write_all(DELIMITER)?;
+            write_sample(writer, &sample, key_indices)?;
+        }
+    } else {
+        for sample in genotypes.values() {
+            writer.write_all(DELIMITER)?;
+            write_sample(writer, &sample, key_indices)?;
+        }
     }
 
     Ok(())
 }
 
-fn write_keys<W>(writer: &mut W, keys: &Keys) -> io::Result<()>
+fn write_keys<W>(writer: &mut W, keys: &Keys, indices: Option<&[usize]>) -> io::Result<()>
 where
     W: Write,
 {
     const DELIMITER: &[u8] = b":";
 
-    for (i, key) in keys.iter().enumerate() {
+    for i in 0..keys.len() {
+        let j = indices.map_or(i, |indices| indices[i]);
+
+        let key = keys
+            .get_index(j)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid key index"))?;
+
         if i > 0 {
             writer.write_all(DELIMITER)?;
         }
@@ -42,13 +67,19 @@ where
     Ok(())
 }
 
-fn write_sample<W>(writer: &mut W, sample: &Sample<'_>) -> io::Result<()>
+fn write_sample<W>(writer: &mut W, sample: &Sample<'_>, indices: Option<&[usize]>) -> io::Result<()>
 where
     W: Write,
 {
     const DELIMITER: &[u8] = b":";
 
-    for (i, value) in sample.values().iter().enumerate() {
+    let values = sample.values();
+    let len = indices.map_or(values.len(), |indices| indices.len());
+
+    for i in 0..len {
+        let j = indices.map_or(i, |indices| indices[i]);
+        let value = values.get(j).and_then(|value| value.as_ref());
+
         if i > 0 {
             writer.write_all(DELIMITER)?;
         }
@@ -146,7 +177,7 @@ mod tests {
 
         fn t(buf: &mut Vec<u8>, genotypes: &Genotypes, expected: &[u8]) -> io::Result<()> {
             buf.clear();
-            write_genotypes(buf, genotypes)?;
+            write_genotypes(buf, genotypes, None, None)?;
             assert_eq!(buf, expected);
             Ok(())
         }
@@ -168,6 +199,10 @@ mod tests {
         );
         t(&mut buf, &genotypes, b"GT:GQ\t0|0:13\t0/1:8")?;
 
+        buf.clear();
+        write_genotypes(&mut buf, &genotypes, None, Some(&[1, 0]))?;
+        assert_eq!(buf, b"GQ:GT\t13:0|0\t8:0/1");
+
         Ok(())
     }
 
@@ -6,7 +6,11 @@ use crate::record::{
     Info,
 };
 
-pub(super) fn write_info<W>(writer: &mut W, info: &Info) -> io::Result<()>
+pub(super) fn write_info<W>(
+    writer: &mut W,
+    info: &Info,
+    indices: Option<&[usize]>,
+) -> io::Result<()>
 where
     W: Write,
 {
@@ -16,7 +20,15 @@ where
     if info.is_empty() {
         writer.write_all(MISSING)?;
     } else {
-        for (i, (key, value)) in info.as_ref().iter().enumerate() {
+        let fields = info.as_ref();
+
+        for i in 0..fields.len() {
+            let j = indices.map_or(i, |indices| indices[i]);
+
+            let (key, value) = fields
+                .get_index(j)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid info index"))?;
+
             if i > 0 {
                 writer.write_all(DELIMITER)?;
             }
@@ -125,7 +137,7 @@ mod tests {
 
         fn t(buf: &mut Vec<u8>, info: &Info, expected: &[u8]) -> io::Result<()> {
             buf.clear();
-            write_info(buf, info)?;
+            write_info(buf, info, None)?;
             assert_eq!(buf, expected);
             Ok(())
         }
@@ -149,6 +161,10 @@ mod tests {
 
         t(&mut buf, &info, b"NS=2;DB")?;
 
+        buf.clear();
+        write_info(&mut buf, &info, Some(&[1, 0]))?;
+        assert_eq!(buf, b"DB;NS=2");
+
         Ok(())
     }
 }